@@ -1,7 +1,21 @@
 library enigo_flutter;
 
 import 'src/rust/api/enigo.dart';
+export 'src/rust/api/audit.dart';
+export 'src/rust/api/backend.dart';
+export 'src/rust/api/dry_run.dart';
 export 'src/rust/api/enigo.dart';
+export 'src/rust/api/hotkey.dart';
+export 'src/rust/api/listener.dart';
+export 'src/rust/api/logging.dart';
+export 'src/rust/api/metrics.dart';
+export 'src/rust/api/mock.dart';
+export 'src/rust/api/pacer.dart';
+export 'src/rust/api/policy.dart';
+export 'src/rust/api/recorder.dart';
+export 'src/rust/api/remap.dart';
+export 'src/rust/api/settings.dart';
+export 'src/rust/api/system.dart';
 export 'src/rust/frb_generated.dart' show RustLib;
 
 Enigo? _enigo;
@@ -0,0 +1,28 @@
+// This file is automatically generated, so please do not edit it.
+// @generated by `flutter_rust_bridge`@ 2.10.0.
+
+import 'package:flutter/services.dart';
+
+// ignore_for_file: invalid_use_of_internal_member, unused_import, unnecessary_import
+
+import '../frb_generated.dart';
+import 'package:flutter_rust_bridge/flutter_rust_bridge_for_generated.dart';
+
+
+            // These functions are ignored because they are not marked as `pub`: `append`, `chain_hash`, `open`, `record`
+// These types are ignored because they are neither used by any `pub` functions nor (for structs and enums) marked `#[frb(unignore)]`: `AuditLog`, `Audited`, `Chain`
+// These function are ignored because they are on traits that is not defined in current crate (put an empty `#[frb]` on it to unignore): `button`, `fast_text`, `key`, `location`, `main_display`, `move_mouse`, `raw`, `scroll`, `text`
+// These functions are ignored (category: IgnoreBecauseOwnerTyShouldIgnore): `open`, `scroll_hi_res`
+
+
+            /// Check the hash chain of an audit log written by [`Enigo::set_audit_log`]
+/// and return how many records it has.
+///
+/// Fails on the first record that was changed, removed or inserted after it
+/// was written.
+///
+/// [`Enigo::set_audit_log`]: super::enigo::Enigo::set_audit_log
+BigInt  verifyAuditLog({required String path }) => RustLib.instance.api.crateApiAuditVerifyAuditLog(path: path);
+
+            
+            
\ No newline at end of file
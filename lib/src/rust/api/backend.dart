@@ -0,0 +1,147 @@
+// This file is automatically generated, so please do not edit it.
+// @generated by `flutter_rust_bridge`@ 2.10.0.
+
+import 'package:flutter/services.dart';
+
+// ignore_for_file: invalid_use_of_internal_member, unused_import, unnecessary_import
+
+import '../frb_generated.dart';
+import 'enigo.dart';
+import 'package:flutter_rust_bridge/flutter_rust_bridge_for_generated.dart';
+
+
+            // These functions are ignored because they are not marked as `pub`: `connect_remote`, `connect_to`, `connect`, `info`, `new`, `only`
+// These types are ignored because they are neither used by any `pub` functions nor (for structs and enums) marked `#[frb(unignore)]`: `Native`
+// These function are ignored because they are on traits that is not defined in current crate (put an empty `#[frb]` on it to unignore): `assert_fields_are_eq`, `button`, `clone`, `clone`, `clone`, `eq`, `fast_text`, `fmt`, `key`, `location`, `main_display`, `move_mouse`, `raw`, `scroll`, `text`
+// These functions are ignored (category: IgnoreBecauseExplicitAttribute): `is_alive`, `is_alive`, `is_alive`, `is_alive`, `lock_state`, `lock_state`, `lock_state`, `lock_state`, `mock`, `mock`, `mock`, `mock`, `modifier_state`, `modifier_state`, `modifier_state`, `modifier_state`, `reconnect`, `reconnect`, `reconnect`, `reconnect`, `reconnect`, `scroll_hi_res`, `scroll_hi_res`, `scroll_hi_res`, `take_violation`, `take_violation`, `take_violation`, `take_violation`, `take_violation`
+// These functions are ignored (category: IgnoreBecauseOwnerTyShouldIgnore): `is_alive`
+
+
+            
+
+            /// Where an [`Enigo`] sends its input.
+///
+/// In settings files and `ENIGO_BACKEND` the names are lower case, e.g.
+/// `"uinput"`.
+///
+/// [`Enigo`]: super::enigo::Enigo
+enum Backend {
+                    /// Whatever enigo uses on this platform: SendInput on Windows, CGEvent on
+/// macOS and, on Linux, the first of [`Backend::X11`],
+/// [`Backend::Wayland`] and [`Backend::Libei`] this build includes that
+/// connects, which [`BackendInfo::backend`] then reports.
+native,
+/// Linux only, with the `x11rb` or `xdo` cargo feature.
+x11,
+/// Linux only, with the `wayland` cargo feature. Needs a compositor that
+/// implements the virtual keyboard and pointer protocols.
+wayland,
+/// Linux only, with the `libei` cargo feature.
+libei,
+/// A virtual keyboard and mouse created through `/dev/uinput`, which also
+/// works on a bare TTY, in compositors without the virtual input
+/// protocols and for applications that read evdev directly.
+///
+/// Linux only, and only when built with the `uinput` cargo feature. The
+/// process needs write access to `/dev/uinput`.
+uinput,
+/// Sends nothing and records every call instead, for tests that run
+/// without a display. See [`Enigo::mock`].
+///
+/// [`Enigo::mock`]: super::enigo::Enigo::mock
+mock,
+/// An [`InputServer`] on another machine, which sends the input with its
+/// own backend. See [`Enigo::remote`].
+///
+/// Only with the `client` cargo feature; the README shows how to turn it
+/// on through cargokit.
+///
+/// [`InputServer`]: super::server::InputServer
+/// [`Enigo::remote`]: super::enigo::Enigo::remote
+remote,
+                    ;
+                    /// Every backend this build can use.
+static List<Backend>  compiled()=>RustLib.instance.api.crateApiBackendBackendCompiled();
+
+
+static Future<Backend>  default_()=>RustLib.instance.api.crateApiBackendBackendDefault();
+
+
+/// Whether this build can use the backend at all.
+ bool get isCompiled=>RustLib.instance.api.crateApiBackendBackendIsCompiled(that: this, );
+
+
+                }
+
+/// What [`Enigo::backend_info`] reports.
+///
+/// [`Enigo::backend_info`]: super::enigo::Enigo::backend_info
+class BackendInfo  {
+                /// The backend that connected.
+final Backend backend;
+/// The X11 or Wayland display it talks to, or the address of the input
+/// server for [`Backend::Remote`].
+final String? display;
+/// Every backend this build can use.
+final List<Backend> compiled;
+final Capabilities capabilities;
+
+                const BackendInfo({required this.backend ,this.display ,required this.compiled ,required this.capabilities ,});
+
+                
+                
+
+                
+        @override
+        int get hashCode => backend.hashCode^display.hashCode^compiled.hashCode^capabilities.hashCode;
+        
+
+                
+        @override
+        bool operator ==(Object other) =>
+            identical(this, other) ||
+            other is BackendInfo &&
+                runtimeType == other.runtimeType
+                && backend == other.backend&& display == other.display&& compiled == other.compiled&& capabilities == other.capabilities;
+        
+            }
+
+/// Operations that don't work with every backend, so a UI can hide them
+/// instead of failing.
+class Capabilities  {
+                /// Buttons `button` and `click` accept.
+final List<Button> buttons;
+/// `move_mouse` with [`Coordinate::Abs`](enigo::Coordinate::Abs).
+final bool absoluteMove;
+/// `location`.
+final bool location;
+/// `text` with arbitrary Unicode rather than what the keyboard layout
+/// can type.
+final bool unicodeText;
+/// `raw`. Its keycodes are scan codes on Windows, virtual keycodes on
+/// macOS and X11 keycodes on Linux.
+final bool rawKeycodes;
+/// Steps per wheel notch that `scroll_smooth` can send; 1 means whole
+/// notches only.
+final int scrollResolution;
+
+                const Capabilities({required this.buttons ,required this.absoluteMove ,required this.location ,required this.unicodeText ,required this.rawKeycodes ,required this.scrollResolution ,});
+
+                
+                
+
+                
+        @override
+        int get hashCode => buttons.hashCode^absoluteMove.hashCode^location.hashCode^unicodeText.hashCode^rawKeycodes.hashCode^scrollResolution.hashCode;
+        
+
+                
+        @override
+        bool operator ==(Object other) =>
+            identical(this, other) ||
+            other is Capabilities &&
+                runtimeType == other.runtimeType
+                && buttons == other.buttons&& absoluteMove == other.absoluteMove&& location == other.location&& unicodeText == other.unicodeText&& rawKeycodes == other.rawKeycodes&& scrollResolution == other.scrollResolution;
+        
+            }
+            
\ No newline at end of file
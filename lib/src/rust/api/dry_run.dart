@@ -0,0 +1,56 @@
+// This file is automatically generated, so please do not edit it.
+// @generated by `flutter_rust_bridge`@ 2.10.0.
+
+import 'package:flutter/services.dart';
+
+// ignore_for_file: invalid_use_of_internal_member, unused_import, unnecessary_import
+
+import '../frb_generated.dart';
+import 'mock.dart';
+import 'package:flutter_rust_bridge/flutter_rust_bridge_for_generated.dart';
+
+
+            // These functions are ignored because they are not marked as `pub`: `record`, `to_json`, `trace_to_json`, `trace_to_text`
+// These types are ignored because they are neither used by any `pub` functions nor (for structs and enums) marked `#[frb(unignore)]`: `DryRun`
+// These function are ignored because they are on traits that is not defined in current crate (put an empty `#[frb]` on it to unignore): `button`, `clone`, `fast_text`, `key`, `location`, `main_display`, `move_mouse`, `raw`, `scroll`
+// These functions are ignored (category: IgnoreBecauseOwnerTyShouldIgnore): `new`, `scroll_hi_res`
+
+
+            
+
+            
+                // Rust type: RustOpaqueMoi<flutter_rust_bridge::for_generated::RustAutoOpaqueInner<TraceEntry>>
+                abstract class TraceEntry implements RustOpaqueInterface {
+                     MockAction get action;
+
+
+ BigInt get offsetMs;
+
+
+ (int,int)? get target;
+
+
+ String? get warning;
+
+
+  set action(MockAction action);
+
+
+  set offsetMs(BigInt offsetMs);
+
+
+  set target((int,int)? target);
+
+
+  set warning(String? warning);
+
+
+/// One line such as `+120 ms  move to (10, 20)`.
+ String  describe();
+
+
+
+                    
+                }
+                
+            
\ No newline at end of file
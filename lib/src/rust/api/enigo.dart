@@ -6,135 +6,539 @@ import 'package:flutter/services.dart';
 // ignore_for_file: invalid_use_of_internal_member, unused_import, unnecessary_import
 
 import '../frb_generated.dart';
+import 'backend.dart';
+import 'dry_run.dart';
+import 'metrics.dart';
+import 'mock.dart';
+import 'pacer.dart';
 import 'package:flutter_rust_bridge/flutter_rust_bridge_for_generated.dart';
+import 'package:freezed_annotation/freezed_annotation.dart' hide protected;
+import 'policy.dart';
+import 'recorder.dart';
+import 'system.dart';
+part 'enigo.freezed.dart';
 
-// Rust type: RustOpaqueMoi<flutter_rust_bridge::for_generated::RustAutoOpaqueInner<Enigo>>
-abstract class Enigo implements RustOpaqueInterface {
-  void button({required Button button, required Direction direction});
+            // These functions are ignored because they are not marked as `pub`: `apply`, `call_backend`, `call`, `end_dry_run`, `install_release_on_panic_hook`, `is_alive`, `metrics`, `metrics`, `pacer`, `pacer`, `permit`, `real_held`, `rebuild`, `reconnect`, `register`, `release_after_panic`, `release_all`, `remote_policy`, `run_op`, `run_paced`, `run`, `send_action`, `send_key`, `send`, `spawn`, `trace`, `track_button`, `track_key`, `track_raw`, `track`, `track`, `try_location`, `try_main_display`, `type_text`, `with_input`, `x11_display`
+// These types are ignored because they are neither used by any `pub` functions nor (for structs and enums) marked `#[frb(unignore)]`: `State`, `Worker`
+// These function are ignored because they are on traits that is not defined in current crate (put an empty `#[frb]` on it to unignore): `clone`, `clone`, `clone`, `clone`, `clone`, `clone`, `clone`, `eq`, `fmt`, `fmt`, `fmt`, `fmt`, `fmt`, `fmt`
 
-  static bool hasPermission({required bool openPrompt}) => RustLib.instance.api
-      .crateApiEnigoEnigoHasPermission(openPrompt: openPrompt);
 
-  void key({required PhysicalKeyboardKey key, required Direction direction});
+            
 
-  (int, int) location();
+            
+                // Rust type: RustOpaqueMoi<flutter_rust_bridge::for_generated::RustAutoOpaqueInner<Enigo>>
+                abstract class Enigo implements RustOpaqueInterface {
+                    /// Which backend this instance ended up with, and what it can do.
+ BackendInfo  backendInfo();
 
-  (int, int) mainDisplay();
 
-  void moveMouse(
-      {required int x, required int y, required Coordinate coordinate});
+ void  button({required Button button , required Direction direction });
 
-  factory Enigo({required Settings settings}) =>
-      RustLib.instance.api.crateApiEnigoEnigoNew(settings: settings);
 
-  static Enigo preset() => RustLib.instance.api.crateApiEnigoEnigoPreset();
+/// What this instance's backend supports; shorthand for
+/// `backend_info().capabilities`.
+ Capabilities  capabilities();
 
-  void raw({required int keycode, required Direction direction});
 
-  void scroll({required int length, required Axis axis});
+ void  clearMockCalls();
 
-  void text({required String text});
-}
 
-// Rust type: RustOpaqueMoi<flutter_rust_bridge::for_generated::RustAutoOpaqueInner<_Button>>
-abstract class Button implements RustOpaqueInterface {
-  static Button get back => RustLib.instance.api.crateApiEnigoButtonBack();
+/// Click `button` `count` times natively, so FFI jitter can't stretch the
+/// gap between clicks past the double-click threshold.
+///
+/// Without `interval_ms` the clicks are spaced at a quarter of the system
+/// double-click time, or of [`DEFAULT_DOUBLE_CLICK_TIME_MS`] if that
+/// can't be read.
+ Future<void>  click({required Button button , required int count , int? intervalMs });
 
-  static Button get forward =>
-      RustLib.instance.api.crateApiEnigoButtonForward();
 
-  static Button get left => RustLib.instance.api.crateApiEnigoButtonLeft();
+/// The longest gap, in milliseconds, the system accepts between the clicks
+/// of a double-click.
+ int  doubleClickTime();
 
-  static Button get middle => RustLib.instance.api.crateApiEnigoButtonMiddle();
 
-  factory Button({required String value}) =>
-      RustLib.instance.api.crateApiEnigoButtonNew(value: value);
+/// What this instance would have sent since dry-run mode was switched
+/// on; empty when it is off.
+ List<TraceEntry>  dryRunTrace();
 
-  static Button get right => RustLib.instance.api.crateApiEnigoButtonRight();
 
-  static Button get scrollDown =>
-      RustLib.instance.api.crateApiEnigoButtonScrollDown();
+/// [`Enigo::dry_run_trace`] as a JSON array.
+ String  dryRunTraceJson();
 
-  static Button get scrollLeft =>
-      RustLib.instance.api.crateApiEnigoButtonScrollLeft();
 
-  static Button get scrollRight =>
-      RustLib.instance.api.crateApiEnigoButtonScrollRight();
+/// [`Enigo::dry_run_trace`] with one readable line per call.
+ String  dryRunTraceText();
 
-  static Button get scrollUp =>
-      RustLib.instance.api.crateApiEnigoButtonScrollUp();
 
-  @override
-  String toString();
-}
+static bool  hasPermission({required bool openPrompt })=>RustLib.instance.api.crateApiEnigoEnigoHasPermission(openPrompt: openPrompt);
+
+
+/// Keys, buttons and raw keycodes pressed through this instance and not
+/// released yet.
+ HeldInput  held();
+
+
+/// Hold `key` down for `duration_ms`, optionally emitting auto-repeat
+/// presses the way a physically held key would.
+///
+/// Fails with the [`PolicyViolation`] if the policy refuses a press, and
+/// before pressing anything if `repeat` is [`RepeatPolicy::System`] and
+/// the system's repeat settings can't be read.
+///
+/// Not `sync`: the hold is timed on a Rust worker thread instead of
+/// blocking the Dart isolate.
+ Future<void>  holdKey({required PhysicalKeyboardKey key , required int durationMs , required RepeatPolicy repeat });
+
+
+/// Whether the backend's connection still works: X11 is asked for the
+/// cursor location and an input server is pinged. The other backends
+/// have no connection to lose, or none that can be checked, and are
+/// always alive.
+ bool  isAlive();
+
+
+ bool get isDryRun;
+
+
+ void  key({required PhysicalKeyboardKey key , required Direction direction });
+
+
+ (int,int)  location();
+
+
+/// Whether each lock key is on, as the backend sees it: the system's
+/// for local backends, the server's for a remote one and the simulated
+/// one for a mock.
+ LockState  lockState();
+
+
+ (int,int)  mainDisplay();
+
+
+/// Timings and outcomes of this instance's calls since it was created
+/// or [`Enigo::reset_metrics`] was called.
+ Metrics  metrics();
+
+
+/// An instance on [`Backend::Mock`] with a display of the given size and
+/// the cursor in its center.
+static Enigo  mock({required int displayWidth , required int displayHeight })=>RustLib.instance.api.crateApiEnigoEnigoMock(displayWidth: displayWidth, displayHeight: displayHeight);
+
+
+/// Every call a [`Backend::Mock`] instance received, oldest first.
+ List<MockCall>  mockCalls();
+
+
+/// Which modifiers are held, as the backend sees it; see
+/// [`Enigo::lock_state`].
+ ModifierState  modifierState();
+
+
+ void  moveMouse({required int x , required int y , required Coordinate coordinate });
+
+
+/// An instance on the backends `ENIGO_BACKEND` lists in order of
+/// preference, separated by commas, or [`Backend::Native`] if it isn't
+/// set. A value that doesn't parse, or whose backends can't connect, is
+/// logged and Native used instead.
+factory Enigo({required Settings settings })=>RustLib.instance.api.crateApiEnigoEnigoNew(settings: settings);
+
+
+/// How much the pacer has held events back since it was set.
+ PacerStats?  pacerStats();
+
+
+/// Replay a [`Recording`] with its original timing, adjusted by
+/// `options`.
+ Future<void>  play({required Recording recording , required PlaybackOptions options });
+
+
+ Policy? get policy;
+
+
+static Enigo  preset()=>RustLib.instance.api.crateApiEnigoEnigoPreset();
+
+
+ void  raw({required int keycode , required Direction direction });
+
+
+/// Recreate the backend from the settings and backend preference this
+/// instance was created with.
+ void  reconnect();
+
+
+/// Receive a [`ReconnectEvent`] whenever the reconnect policy kicks in.
+ Stream<ReconnectEvent>  reconnects();
+
+
+/// Release everything reported by [`Enigo::held`], most recent first.
+ void  releaseAll();
+
+
+/// An instance that sends its input to the [`InputServer`] at `address`
+/// on another machine, authenticating with `token` if there is one.
+///
+/// `address` is `host:port` for plain TCP or a `ws://` URL for
+/// WebSocket. Errors the server reports surface as they would locally:
+/// a refusal by its policy as a [`PolicyViolation`], anything else as a
+/// panic with the backend's error. If the connection is lost, the call
+/// reconnects up to 3 times, half a second apart; see
+/// [`Enigo::set_reconnect_policy`]. The server releases what was held
+/// when the connection went away, and recognizes a call sent again
+/// after a reconnect, so it isn't carried out twice.
+///
+/// Needs the `client` cargo feature, which the README shows how to turn
+/// on through cargokit; without it this fails.
+///
+/// Not `sync`: connecting waits on the network, which would block the
+/// Dart isolate.
+///
+/// [`InputServer`]: super::server::InputServer
+static Future<Enigo>  remote({required String address , String? token })=>RustLib.instance.api.crateApiEnigoEnigoRemote(address: address, token: token);
+
+
+ void  resetMetrics();
+
+
+ void  scroll({required int length , required Axis axis });
+
+
+/// Scroll by `dx`/`dy` notches, which may be fractional, spread over
+/// `duration_ms` in many small steps.
+///
+/// Partial notches are sent as-is where the backend supports high
+/// resolution wheel events and are accumulated into whole notches
+/// elsewhere.
+ Future<void>  scrollSmooth({required double dx , required double dy , required int durationMs });
+
+
+/// Identifies this instance in audit log records.
+ String get sessionId;
+
+
+/// Append a record of every event sent from now on to the JSON-lines
+/// file at `path`, or stop with `None`. Check the file with
+/// [`verify_audit_log`].
+///
+/// [`verify_audit_log`]: super::audit::verify_audit_log
+ void  setAuditLog({String? path });
+
+
+/// Switch dry-run mode on or off. While it is on, every call goes
+/// through the same key resolution, coordinate conversion and bounds
+/// checks as usual but is only added to the trace instead of being sent.
+///
+/// Switching it on starts a new trace. Input held before stays held and
+/// is tracked again once dry-run mode ends.
+ void  setDryRun({required bool enabled });
+
+
+/// Space out events as `config` says, or send them as they come with
+/// `None`. Calls block while their event waits, after the events of
+/// earlier calls; other calls on this instance go ahead meanwhile.
+///
+/// The wait is on the calling thread, which for the `sync` methods is
+/// the Dart isolate that calls them: with a low rate, or text longer
+/// than the burst, call them from a background isolate, or the UI
+/// freezes until the events are sent.
+///
+/// Fails if `config` has a rate that can't work; see
+/// [`PacerConfig::events_per_second`].
+ void  setPacer({PacerConfig? config });
+
+
+/// Refuse calls that `policy` doesn't allow with a [`PolicyViolation`],
+/// or allow everything again with `None`.
+ void  setPolicy({Policy? policy });
+
+
+/// What to do when a call fails because the connection went away, for
+/// example because the X server restarted. Defaults to
+/// [`ReconnectPolicy::Never`].
+ void  setReconnectPolicy({required ReconnectPolicy policy });
+
+
+ Settings  settings();
+
+
+ void  text({required String text });
+
+
+ void  textWithOptions({required String text , required TextOptions options });
+
+
+/// Change some settings, including the backend preference. The backend
+/// is rebuilt if anything changed, and input held down through this
+/// instance stays held. Settings [`validate_settings`] rejects are
+/// rejected here too.
+///
+/// [`validate_settings`]: super::settings::validate_settings
+ void  updateSettings({required SettingsPatch patch });
+
+
+/// Like [`Enigo::new`], but sending input through `backend`.
+static Enigo  withBackend({required Settings settings , required Backend backend })=>RustLib.instance.api.crateApiEnigoEnigoWithBackend(settings: settings, backend: backend);
+
+
+/// Like [`Enigo::new`], but sending input through the first backend in
+/// `preference` that connects.
+static Enigo  withBackends({required Settings settings , required List<Backend> preference })=>RustLib.instance.api.crateApiEnigoEnigoWithBackends(settings: settings, preference: preference);
+
+
+
+                    
+                }
+                
+
+
+                // Rust type: RustOpaqueMoi<flutter_rust_bridge::for_generated::RustAutoOpaqueInner<_Button>>
+                abstract class Button implements RustOpaqueInterface {
+                    static Button get back=>RustLib.instance.api.crateApiEnigoButtonBack();
+
+
+static Button get forward=>RustLib.instance.api.crateApiEnigoButtonForward();
+
+
+static Button get left=>RustLib.instance.api.crateApiEnigoButtonLeft();
+
+
+static Button get middle=>RustLib.instance.api.crateApiEnigoButtonMiddle();
+
+
+factory Button({required String value })=>RustLib.instance.api.crateApiEnigoButtonNew(value: value);
+
+
+static Button get right=>RustLib.instance.api.crateApiEnigoButtonRight();
+
+
+static Button get scrollDown=>RustLib.instance.api.crateApiEnigoButtonScrollDown();
+
+
+static Button get scrollLeft=>RustLib.instance.api.crateApiEnigoButtonScrollLeft();
+
+
+static Button get scrollRight=>RustLib.instance.api.crateApiEnigoButtonScrollRight();
+
+
+static Button get scrollUp=>RustLib.instance.api.crateApiEnigoButtonScrollUp();
+
+
+ String  toString();
+
+
+
+                    
+                }
+                
 
 enum Axis {
-  horizontal,
-  vertical,
-  ;
-}
+                    horizontal,
+vertical,
+                    ;
+                    
+                }
 
 enum Coordinate {
-  abs,
-  rel,
-  ;
-}
+                    abs,
+rel,
+                    ;
+                    
+                }
 
 enum Direction {
-  press,
-  release,
-  click,
-  ;
-}
-
-class Settings {
-  final int linuxDelay;
-  final String? x11Display;
-  final String? waylandDisplay;
-  final BigInt? windowsDwExtraInfo;
-  final PlatformInt64? eventSourceUserData;
-  final bool releaseKeysWhenDropped;
-  final bool openPromptToGetPermissions;
-  final bool independentOfKeyboardState;
-  final bool windowsSubjectToMouseSpeedAndAccelerationLevel;
-
-  const Settings({
-    required this.linuxDelay,
-    this.x11Display,
-    this.waylandDisplay,
-    this.windowsDwExtraInfo,
-    this.eventSourceUserData,
-    required this.releaseKeysWhenDropped,
-    required this.openPromptToGetPermissions,
-    required this.independentOfKeyboardState,
-    required this.windowsSubjectToMouseSpeedAndAccelerationLevel,
-  });
-
-  @override
-  int get hashCode =>
-      linuxDelay.hashCode ^
-      x11Display.hashCode ^
-      waylandDisplay.hashCode ^
-      windowsDwExtraInfo.hashCode ^
-      eventSourceUserData.hashCode ^
-      releaseKeysWhenDropped.hashCode ^
-      openPromptToGetPermissions.hashCode ^
-      independentOfKeyboardState.hashCode ^
-      windowsSubjectToMouseSpeedAndAccelerationLevel.hashCode;
-
-  @override
-  bool operator ==(Object other) =>
-      identical(this, other) ||
-      other is Settings &&
-          runtimeType == other.runtimeType &&
-          linuxDelay == other.linuxDelay &&
-          x11Display == other.x11Display &&
-          waylandDisplay == other.waylandDisplay &&
-          windowsDwExtraInfo == other.windowsDwExtraInfo &&
-          eventSourceUserData == other.eventSourceUserData &&
-          releaseKeysWhenDropped == other.releaseKeysWhenDropped &&
-          openPromptToGetPermissions == other.openPromptToGetPermissions &&
-          independentOfKeyboardState == other.independentOfKeyboardState &&
-          windowsSubjectToMouseSpeedAndAccelerationLevel ==
-              other.windowsSubjectToMouseSpeedAndAccelerationLevel;
-}
+                    press,
+release,
+click,
+                    ;
+                    
+                }
+
+/// Input currently held down by an [`Enigo`], in press order.
+class HeldInput  {
+                final List<PhysicalKeyboardKey> keys;
+final List<Button> buttons;
+final Uint16List raw;
+
+                const HeldInput({required this.keys ,required this.buttons ,required this.raw ,});
+
+                static Future<HeldInput>  default_()=>RustLib.instance.api.crateApiEnigoHeldInputDefault();
+
+
+ bool get isEmpty=>RustLib.instance.api.crateApiEnigoHeldInputIsEmpty(that: this, );
+
+
+                
+
+                
+        @override
+        int get hashCode => keys.hashCode^buttons.hashCode^raw.hashCode;
+        
+
+                
+        @override
+        bool operator ==(Object other) =>
+            identical(this, other) ||
+            other is HeldInput &&
+                runtimeType == other.runtimeType
+                && keys == other.keys&& buttons == other.buttons&& raw == other.raw;
+        
+            }
+
+/// Sent to [`Enigo::reconnects`] after a successful reconnect.
+class ReconnectEvent  {
+                /// The backend now in use, which may differ from the one before.
+final Backend backend;
+final int attempts;
+/// Why the call that triggered the reconnect failed.
+final String error;
+
+                const ReconnectEvent({required this.backend ,required this.attempts ,required this.error ,});
+
+                
+                
+
+                
+        @override
+        int get hashCode => backend.hashCode^attempts.hashCode^error.hashCode;
+        
+
+                
+        @override
+        bool operator ==(Object other) =>
+            identical(this, other) ||
+            other is ReconnectEvent &&
+                runtimeType == other.runtimeType
+                && backend == other.backend&& attempts == other.attempts&& error == other.error;
+        
+            }
+
+@freezed
+                sealed class ReconnectPolicy with _$ReconnectPolicy  {
+                    const ReconnectPolicy._();
+
+                     /// Fail the call.
+const factory ReconnectPolicy.never() = ReconnectPolicy_Never;
+ /// Recreate the backend, up to `max_attempts` times with `delay_ms`
+/// between attempts, and repeat the call once it is back.
+const factory ReconnectPolicy.retry({   required int maxAttempts ,  required int delayMs , }) = ReconnectPolicy_Retry;
+
+                    
+
+                    
+                }
+
+@freezed
+                sealed class RepeatPolicy with _$RepeatPolicy  {
+                    const RepeatPolicy._();
+
+                     /// Press once and stay silent until the release.
+const factory RepeatPolicy.silent() = RepeatPolicy_Silent;
+ /// Repeat at the delay and rate configured in the system settings.
+const factory RepeatPolicy.system() = RepeatPolicy_System;
+ const factory RepeatPolicy.custom({   required int delayMs ,  required int intervalMs , }) = RepeatPolicy_Custom;
+
+                    
+
+                    
+                }
+
+class Settings  {
+                final int linuxDelay;
+final String? x11Display;
+final String? waylandDisplay;
+final BigInt? windowsDwExtraInfo;
+final PlatformInt64? eventSourceUserData;
+final bool releaseKeysWhenDropped;
+final bool openPromptToGetPermissions;
+final bool independentOfKeyboardState;
+final bool windowsSubjectToMouseSpeedAndAccelerationLevel;
+
+                const Settings({required this.linuxDelay ,this.x11Display ,this.waylandDisplay ,this.windowsDwExtraInfo ,this.eventSourceUserData ,required this.releaseKeysWhenDropped ,required this.openPromptToGetPermissions ,required this.independentOfKeyboardState ,required this.windowsSubjectToMouseSpeedAndAccelerationLevel ,});
+
+                
+                
+
+                
+        @override
+        int get hashCode => linuxDelay.hashCode^x11Display.hashCode^waylandDisplay.hashCode^windowsDwExtraInfo.hashCode^eventSourceUserData.hashCode^releaseKeysWhenDropped.hashCode^openPromptToGetPermissions.hashCode^independentOfKeyboardState.hashCode^windowsSubjectToMouseSpeedAndAccelerationLevel.hashCode;
+        
+
+                
+        @override
+        bool operator ==(Object other) =>
+            identical(this, other) ||
+            other is Settings &&
+                runtimeType == other.runtimeType
+                && linuxDelay == other.linuxDelay&& x11Display == other.x11Display&& waylandDisplay == other.waylandDisplay&& windowsDwExtraInfo == other.windowsDwExtraInfo&& eventSourceUserData == other.eventSourceUserData&& releaseKeysWhenDropped == other.releaseKeysWhenDropped&& openPromptToGetPermissions == other.openPromptToGetPermissions&& independentOfKeyboardState == other.independentOfKeyboardState&& windowsSubjectToMouseSpeedAndAccelerationLevel == other.windowsSubjectToMouseSpeedAndAccelerationLevel;
+        
+            }
+
+/// Changes for [`Enigo::update_settings`]; `None` leaves a setting as it is.
+///
+/// Settings files have the same shape.
+class SettingsPatch  {
+                /// Backends in order of preference, as for [`Enigo::with_backends`].
+/// Not part of enigo's `Settings`, so it only takes effect through
+/// [`Enigo::update_settings`].
+final List<Backend>? backend;
+final int? linuxDelay;
+/// An empty name goes back to the default display.
+final String? x11Display;
+/// An empty name goes back to the default display.
+final String? waylandDisplay;
+final BigInt? windowsDwExtraInfo;
+final PlatformInt64? eventSourceUserData;
+final bool? releaseKeysWhenDropped;
+final bool? openPromptToGetPermissions;
+final bool? independentOfKeyboardState;
+final bool? windowsSubjectToMouseSpeedAndAccelerationLevel;
+
+                const SettingsPatch({this.backend ,this.linuxDelay ,this.x11Display ,this.waylandDisplay ,this.windowsDwExtraInfo ,this.eventSourceUserData ,this.releaseKeysWhenDropped ,this.openPromptToGetPermissions ,this.independentOfKeyboardState ,this.windowsSubjectToMouseSpeedAndAccelerationLevel ,});
+
+                static Future<SettingsPatch>  default_()=>RustLib.instance.api.crateApiEnigoSettingsPatchDefault();
+
+
+                
+
+                
+        @override
+        int get hashCode => backend.hashCode^linuxDelay.hashCode^x11Display.hashCode^waylandDisplay.hashCode^windowsDwExtraInfo.hashCode^eventSourceUserData.hashCode^releaseKeysWhenDropped.hashCode^openPromptToGetPermissions.hashCode^independentOfKeyboardState.hashCode^windowsSubjectToMouseSpeedAndAccelerationLevel.hashCode;
+        
+
+                
+        @override
+        bool operator ==(Object other) =>
+            identical(this, other) ||
+            other is SettingsPatch &&
+                runtimeType == other.runtimeType
+                && backend == other.backend&& linuxDelay == other.linuxDelay&& x11Display == other.x11Display&& waylandDisplay == other.waylandDisplay&& windowsDwExtraInfo == other.windowsDwExtraInfo&& eventSourceUserData == other.eventSourceUserData&& releaseKeysWhenDropped == other.releaseKeysWhenDropped&& openPromptToGetPermissions == other.openPromptToGetPermissions&& independentOfKeyboardState == other.independentOfKeyboardState&& windowsSubjectToMouseSpeedAndAccelerationLevel == other.windowsSubjectToMouseSpeedAndAccelerationLevel;
+        
+            }
+
+/// Options for [`Enigo::text_with_options`].
+class TextOptions  {
+                /// Switch Caps Lock off while typing and restore it afterwards.
+final bool normalizeLockState;
+
+                const TextOptions({required this.normalizeLockState ,});
+
+                static Future<TextOptions>  default_()=>RustLib.instance.api.crateApiEnigoTextOptionsDefault();
+
+
+                
+
+                
+        @override
+        int get hashCode => normalizeLockState.hashCode;
+        
+
+                
+        @override
+        bool operator ==(Object other) =>
+            identical(this, other) ||
+            other is TextOptions &&
+                runtimeType == other.runtimeType
+                && normalizeLockState == other.normalizeLockState;
+        
+            }
+            
\ No newline at end of file
@@ -0,0 +1,73 @@
+// This file is automatically generated, so please do not edit it.
+// @generated by `flutter_rust_bridge`@ 2.10.0.
+
+import 'package:flutter/services.dart';
+
+// ignore_for_file: invalid_use_of_internal_member, unused_import, unnecessary_import
+
+import '../frb_generated.dart';
+import 'enigo.dart';
+import 'package:flutter_rust_bridge/flutter_rust_bridge_for_generated.dart';
+
+
+            // These functions are ignored because they are not marked as `pub`: `raw_keycode`, `usage`
+// These types are ignored because they are neither used by any `pub` functions nor (for structs and enums) marked `#[frb(unignore)]`: `Hotkey`, `Modifiers`, `Shared`
+// These function are ignored because they are on traits that is not defined in current crate (put an empty `#[frb]` on it to unignore): `assert_fields_are_eq`, `assert_fields_are_eq`, `clone`, `clone`, `clone`, `drop`, `eq`, `eq`, `fmt`, `fmt`, `fmt`
+// These functions are ignored (category: IgnoreBecauseType): `grab`, `ungrab`
+// These functions are ignored (category: IgnoreBecauseOwnerTyShouldIgnore): `activate_matching`, `activate`, `default`, `default`, `hotkeys`, `parse`
+
+
+            
+
+            
+                // Rust type: RustOpaqueMoi<flutter_rust_bridge::for_generated::RustAutoOpaqueInner<HotkeyManager>>
+                abstract class HotkeyManager implements RustOpaqueInterface {
+                    /// Stream every activation of a registered hotkey. A new stream replaces
+/// the previous one.
+ Stream<HotkeyEvent>  activations();
+
+
+factory HotkeyManager({required Settings settings })=>RustLib.instance.api.crateApiHotkeyHotkeyManagerNew(settings: settings);
+
+
+/// Register `accelerator`, e.g. `ctrl+alt+f9`, and return the id its
+/// activations are reported with.
+ int  register({required String accelerator });
+
+
+ void  unregister({required int id });
+
+
+ void  unregisterAll();
+
+
+
+                    
+                }
+                
+
+/// A registered hotkey was pressed.
+class HotkeyEvent  {
+                final int id;
+final String accelerator;
+
+                const HotkeyEvent({required this.id ,required this.accelerator ,});
+
+                
+                
+
+                
+        @override
+        int get hashCode => id.hashCode^accelerator.hashCode;
+        
+
+                
+        @override
+        bool operator ==(Object other) =>
+            identical(this, other) ||
+            other is HotkeyEvent &&
+                runtimeType == other.runtimeType
+                && id == other.id&& accelerator == other.accelerator;
+        
+            }
+            
\ No newline at end of file
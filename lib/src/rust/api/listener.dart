@@ -0,0 +1,52 @@
+// This file is automatically generated, so please do not edit it.
+// @generated by `flutter_rust_bridge`@ 2.10.0.
+
+import 'package:flutter/services.dart';
+
+// ignore_for_file: invalid_use_of_internal_member, unused_import, unnecessary_import
+
+import '../frb_generated.dart';
+import 'enigo.dart';
+import 'package:flutter_rust_bridge/flutter_rust_bridge_for_generated.dart';
+
+
+            // These functions are ignored because they are not marked as `pub`: `lock`, `spawn`
+// These types are ignored because they are neither used by any `pub` functions nor (for structs and enums) marked `#[frb(unignore)]`: `ListenerHandle`
+// These function are ignored because they are on traits that is not defined in current crate (put an empty `#[frb]` on it to unignore): `clone`, `drop`
+// These functions are ignored (category: IgnoreBecauseOwnerTyShouldIgnore): `is_running`
+
+
+            
+
+            
+                // Rust type: RustOpaqueMoi<flutter_rust_bridge::for_generated::RustAutoOpaqueInner<InputEvent>>
+                abstract class InputEvent implements RustOpaqueInterface {
+                    
+
+                    
+                }
+                
+
+
+                // Rust type: RustOpaqueMoi<flutter_rust_bridge::for_generated::RustAutoOpaqueInner<Listener>>
+                abstract class Listener implements RustOpaqueInterface {
+                     bool get isListening;
+
+
+/// Start capturing and push every event into `sink`, replacing any
+/// capture already running. Capturing stops when the Dart stream is
+/// cancelled or [`Listener::stop`] is called.
+ Stream<InputEvent>  listen();
+
+
+factory Listener({required Settings settings })=>RustLib.instance.api.crateApiListenerListenerNew(settings: settings);
+
+
+ void  stop();
+
+
+
+                    
+                }
+                
+            
\ No newline at end of file
@@ -0,0 +1,85 @@
+// This file is automatically generated, so please do not edit it.
+// @generated by `flutter_rust_bridge`@ 2.10.0.
+
+import 'package:flutter/services.dart';
+
+// ignore_for_file: invalid_use_of_internal_member, unused_import, unnecessary_import
+
+import '../frb_generated.dart';
+import 'package:flutter_rust_bridge/flutter_rust_bridge_for_generated.dart';
+
+
+            // These functions are ignored because they are not marked as `pub`: `install`, `line`, `push`, `sinks`, `wants`
+// These types are ignored because they are neither used by any `pub` functions nor (for structs and enums) marked `#[frb(unignore)]`: `FieldVisitor`, `Forward`, `Sinks`, `SpanFields`
+// These function are ignored because they are on traits that is not defined in current crate (put an empty `#[frb]` on it to unignore): `assert_fields_are_eq`, `clone`, `cmp`, `enabled`, `eq`, `fmt`, `from`, `on_event`, `on_new_span`, `on_record`, `partial_cmp`, `record_debug`, `record_str`, `register_callsite`
+// These functions are ignored (category: IgnoreBecauseOwnerTyShouldIgnore): `default`
+
+
+            /// Send records at `level` and above to Dart, replacing an earlier stream.
+Stream<LogRecord>  logStream({required LogLevel level }) => RustLib.instance.api.crateApiLoggingLogStream(level: level);
+
+void  stopLogStream() => RustLib.instance.api.crateApiLoggingStopLogStream();
+
+/// Also write records at `level` and above to `<prefix>.<date>.log` in
+/// `directory`, starting a new file as `rotation` says and deleting the
+/// oldest once there are more than `max_files` (0 keeps them all).
+void  logToFile({required String directory , required String prefix , required LogRotation rotation , required int maxFiles , required LogLevel level }) => RustLib.instance.api.crateApiLoggingLogToFile(directory: directory, prefix: prefix, rotation: rotation, maxFiles: maxFiles, level: level);
+
+void  stopLogFile() => RustLib.instance.api.crateApiLoggingStopLogFile();
+
+            /// Severity of a [`LogRecord`], least severe first.
+enum LogLevel {
+                    trace,
+debug,
+info,
+warn,
+error,
+                    ;
+                    
+                }
+
+/// An event logged by this library, with the fields of the spans it
+/// happened in: for a call on `Enigo`, the call's parameters followed by the
+/// backend, latency and result.
+class LogRecord  {
+                /// Milliseconds since the Unix epoch.
+final BigInt timestampMs;
+final LogLevel level;
+/// Module the event came from, e.g. `enigo_flutter::api::enigo`.
+final String target;
+/// Names of the spans the event happened in, outermost first.
+final List<String> spans;
+final String message;
+/// Fields of the spans and then of the event, as name and value.
+final List<(String,String)> fields;
+
+                const LogRecord({required this.timestampMs ,required this.level ,required this.target ,required this.spans ,required this.message ,required this.fields ,});
+
+                
+                
+
+                
+        @override
+        int get hashCode => timestampMs.hashCode^level.hashCode^target.hashCode^spans.hashCode^message.hashCode^fields.hashCode;
+        
+
+                
+        @override
+        bool operator ==(Object other) =>
+            identical(this, other) ||
+            other is LogRecord &&
+                runtimeType == other.runtimeType
+                && timestampMs == other.timestampMs&& level == other.level&& target == other.target&& spans == other.spans&& message == other.message&& fields == other.fields;
+        
+            }
+
+/// How often [`log_to_file`] starts a new file.
+enum LogRotation {
+                    minutely,
+hourly,
+daily,
+never,
+                    ;
+                    
+                }
+            
\ No newline at end of file
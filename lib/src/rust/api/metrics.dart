@@ -0,0 +1,119 @@
+// This file is automatically generated, so please do not edit it.
+// @generated by `flutter_rust_bridge`@ 2.10.0.
+
+import 'package:flutter/services.dart';
+
+// ignore_for_file: invalid_use_of_internal_member, unused_import, unnecessary_import
+
+import '../frb_generated.dart';
+import 'package:flutter_rust_bridge/flutter_rust_bridge_for_generated.dart';
+
+
+            // These functions are ignored because they are not marked as `pub`: `counters`, `histogram`, `input_error_kind`
+// These types are ignored because they are neither used by any `pub` functions nor (for structs and enums) marked `#[frb(unignore)]`: `Buckets`, `Counters`, `Registry`, `Stage`
+// These function are ignored because they are on traits that is not defined in current crate (put an empty `#[frb]` on it to unignore): `clone`, `clone`, `clone`, `clone`
+// These functions are ignored (category: IgnoreBecauseOwnerTyShouldIgnore): `default`, `default`, `default`, `failed`, `observe`, `snapshot`, `succeeded`
+
+
+            
+
+            class Histogram  {
+                final BigInt count;
+final BigInt sumUs;
+final BigInt maxUs;
+/// Upper bound in microseconds and how many observations were at most
+/// that long, like Prometheus' `le` buckets.
+final List<(BigInt,BigInt)> buckets;
+
+                const Histogram({required this.count ,required this.sumUs ,required this.maxUs ,required this.buckets ,});
+
+                static Future<Histogram>  default_()=>RustLib.instance.api.crateApiMetricsHistogramDefault();
+
+
+                
+
+                
+        @override
+        int get hashCode => count.hashCode^sumUs.hashCode^maxUs.hashCode^buckets.hashCode;
+        
+
+                
+        @override
+        bool operator ==(Object other) =>
+            identical(this, other) ||
+            other is Histogram &&
+                runtimeType == other.runtimeType
+                && count == other.count&& sumUs == other.sumUs&& maxUs == other.maxUs&& buckets == other.buckets;
+        
+            }
+
+/// Timings and outcomes of the calls made on an [`Enigo`], per operation;
+/// see [`Enigo::metrics`].
+///
+/// [`Enigo`]: super::enigo::Enigo
+/// [`Enigo::metrics`]: super::enigo::Enigo::metrics
+class Metrics  {
+                final List<OperationMetrics> operations;
+
+                const Metrics({required this.operations ,});
+
+                 String  toJson()=>RustLib.instance.api.crateApiMetricsMetricsToJson(that: this, );
+
+
+/// The Prometheus text exposition format, with durations in seconds.
+ String  toPrometheus()=>RustLib.instance.api.crateApiMetricsMetricsToPrometheus(that: this, );
+
+
+                
+
+                
+        @override
+        int get hashCode => operations.hashCode;
+        
+
+                
+        @override
+        bool operator ==(Object other) =>
+            identical(this, other) ||
+            other is Metrics &&
+                runtimeType == other.runtimeType
+                && operations == other.operations;
+        
+            }
+
+class OperationMetrics  {
+                /// The `Enigo` method, e.g. `move_mouse`.
+final String operation;
+/// From the call until the worker thread picked it up.
+final Histogram lockWait;
+/// Each call into the backend; an operation may make several.
+final Histogram backendCall;
+/// From the call until its result was back with the caller.
+final Histogram endToEnd;
+/// Backend calls that succeeded.
+final BigInt successes;
+/// Backend calls that failed and calls the policy refused, by kind:
+/// `mapping`, `unmapping`, `no_empty_keycodes`, `simulate`,
+/// `invalid_input` or `policy`.
+final List<(String,BigInt)> failures;
+
+                const OperationMetrics({required this.operation ,required this.lockWait ,required this.backendCall ,required this.endToEnd ,required this.successes ,required this.failures ,});
+
+                
+                
+
+                
+        @override
+        int get hashCode => operation.hashCode^lockWait.hashCode^backendCall.hashCode^endToEnd.hashCode^successes.hashCode^failures.hashCode;
+        
+
+                
+        @override
+        bool operator ==(Object other) =>
+            identical(this, other) ||
+            other is OperationMetrics &&
+                runtimeType == other.runtimeType
+                && operation == other.operation&& lockWait == other.lockWait&& backendCall == other.backendCall&& endToEnd == other.endToEnd&& successes == other.successes&& failures == other.failures;
+        
+            }
+            
\ No newline at end of file
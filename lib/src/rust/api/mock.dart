@@ -0,0 +1,48 @@
+// This file is automatically generated, so please do not edit it.
+// @generated by `flutter_rust_bridge`@ 2.10.0.
+
+import 'package:flutter/services.dart';
+
+// ignore_for_file: invalid_use_of_internal_member, unused_import, unnecessary_import
+
+import '../frb_generated.dart';
+import 'package:flutter_rust_bridge/flutter_rust_bridge_for_generated.dart';
+
+
+            // These functions are ignored because they are not marked as `pub`: `record`
+// These types are ignored because they are neither used by any `pub` functions nor (for structs and enums) marked `#[frb(unignore)]`: `MockBackend`
+// These function are ignored because they are on traits that is not defined in current crate (put an empty `#[frb]` on it to unignore): `button`, `clone`, `clone`, `eq`, `eq`, `fast_text`, `fmt`, `fmt`, `key`, `location`, `main_display`, `move_mouse`, `raw`, `scroll`
+// These functions are ignored (category: IgnoreBecauseOwnerTyShouldIgnore): `lock_state`, `mock`, `modifier_state`, `new`
+
+
+            
+
+            
+                // Rust type: RustOpaqueMoi<flutter_rust_bridge::for_generated::RustAutoOpaqueInner<MockAction>>
+                abstract class MockAction implements RustOpaqueInterface {
+                    
+
+                    
+                }
+                
+
+
+                // Rust type: RustOpaqueMoi<flutter_rust_bridge::for_generated::RustAutoOpaqueInner<MockCall>>
+                abstract class MockCall implements RustOpaqueInterface {
+                     MockAction get action;
+
+
+ BigInt get timestampUs;
+
+
+  set action(MockAction action);
+
+
+  set timestampUs(BigInt timestampUs);
+
+
+
+                    
+                }
+                
+            
\ No newline at end of file
@@ -0,0 +1,126 @@
+// This file is automatically generated, so please do not edit it.
+// @generated by `flutter_rust_bridge`@ 2.10.0.
+
+import 'package:flutter/services.dart';
+
+// ignore_for_file: invalid_use_of_internal_member, unused_import, unnecessary_import
+
+import '../frb_generated.dart';
+import 'package:flutter_rust_bridge/flutter_rust_bridge_for_generated.dart';
+
+
+            // These functions are ignored because they are not marked as `pub`: `gap`, `refill`, `validate`
+// These types are ignored because they are neither used by any `pub` functions nor (for structs and enums) marked `#[frb(unignore)]`: `Kind`, `Pacer`
+// These function are ignored because they are on traits that is not defined in current crate (put an empty `#[frb]` on it to unignore): `clone`, `clone`, `clone`, `clone`, `fmt`, `fmt`, `fmt`
+// These functions are ignored (category: IgnoreBecauseOwnerTyShouldIgnore): `current_stats`, `new`, `reserve`
+
+
+            
+
+            /// Minimum milliseconds between two events of each kind. Raw keycodes
+/// count as keys.
+class EventGaps  {
+                final int key;
+final int button;
+final int moveMouse;
+final int scroll;
+final int text;
+
+                const EventGaps({required this.key ,required this.button ,required this.moveMouse ,required this.scroll ,required this.text ,});
+
+                static Future<EventGaps>  default_()=>RustLib.instance.api.crateApiPacerEventGapsDefault();
+
+
+                
+
+                
+        @override
+        int get hashCode => key.hashCode^button.hashCode^moveMouse.hashCode^scroll.hashCode^text.hashCode;
+        
+
+                
+        @override
+        bool operator ==(Object other) =>
+            identical(this, other) ||
+            other is EventGaps &&
+                runtimeType == other.runtimeType
+                && key == other.key&& button == other.button&& moveMouse == other.moveMouse&& scroll == other.scroll&& text == other.text;
+        
+            }
+
+/// How [`Enigo`] spaces out events, see [`Enigo::set_pacer`].
+///
+/// Events wait for a token from a bucket refilled at `events_per_second`
+/// that holds up to `burst` of them; text takes one token per character.
+/// On top of that, two events of the same kind are at least their
+/// `min_gap_ms` apart. On Linux this adds to the per-event `linux_delay`
+/// setting.
+///
+/// [`Enigo`]: super::enigo::Enigo
+/// [`Enigo::set_pacer`]: super::enigo::Enigo::set_pacer
+class PacerConfig  {
+                /// 0 turns the token bucket off; anything else must be at least
+/// [`MIN_EVENTS_PER_SECOND`].
+final double eventsPerSecond;
+final int burst;
+final EventGaps minGapMs;
+
+                const PacerConfig({required this.eventsPerSecond ,required this.burst ,required this.minGapMs ,});
+
+                static Future<PacerConfig>  default_()=>RustLib.instance.api.crateApiPacerPacerConfigDefault();
+
+
+static PacerConfig  preset()=>RustLib.instance.api.crateApiPacerPacerConfigPreset();
+
+
+                
+
+                
+        @override
+        int get hashCode => eventsPerSecond.hashCode^burst.hashCode^minGapMs.hashCode;
+        
+
+                
+        @override
+        bool operator ==(Object other) =>
+            identical(this, other) ||
+            other is PacerConfig &&
+                runtimeType == other.runtimeType
+                && eventsPerSecond == other.eventsPerSecond&& burst == other.burst&& minGapMs == other.minGapMs;
+        
+            }
+
+/// How much the pacer has held events back.
+class PacerStats  {
+                /// Events that went through the pacer.
+final BigInt events;
+/// Events that had to wait.
+final BigInt delayed;
+final BigInt totalWaitMs;
+final BigInt maxWaitMs;
+/// Tokens in the bucket now; negative while events wait for theirs or
+/// after text longer than it holds.
+final double tokens;
+
+                const PacerStats({required this.events ,required this.delayed ,required this.totalWaitMs ,required this.maxWaitMs ,required this.tokens ,});
+
+                static Future<PacerStats>  default_()=>RustLib.instance.api.crateApiPacerPacerStatsDefault();
+
+
+                
+
+                
+        @override
+        int get hashCode => events.hashCode^delayed.hashCode^totalWaitMs.hashCode^maxWaitMs.hashCode^tokens.hashCode;
+        
+
+                
+        @override
+        bool operator ==(Object other) =>
+            identical(this, other) ||
+            other is PacerStats &&
+                runtimeType == other.runtimeType
+                && events == other.events&& delayed == other.delayed&& totalWaitMs == other.totalWaitMs&& maxWaitMs == other.maxWaitMs&& tokens == other.tokens;
+        
+            }
+            
\ No newline at end of file
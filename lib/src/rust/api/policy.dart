@@ -0,0 +1,135 @@
+// This file is automatically generated, so please do not edit it.
+// @generated by `flutter_rust_bridge`@ 2.10.0.
+
+import 'package:flutter/services.dart';
+
+// ignore_for_file: invalid_use_of_internal_member, unused_import, unnecessary_import
+
+import '../frb_generated.dart';
+import 'package:flutter_rust_bridge/flutter_rust_bridge_for_generated.dart';
+import 'package:freezed_annotation/freezed_annotation.dart' hide protected;
+part 'policy.freezed.dart';
+
+            // These functions are ignored because they are not marked as `pub`: `canonical`, `check_chords`, `check_key`, `check_rate`, `contains`
+// These types are ignored because they are neither used by any `pub` functions nor (for structs and enums) marked `#[frb(unignore)]`: `Enforcer`
+// These function are ignored because they are on traits that is not defined in current crate (put an empty `#[frb]` on it to unignore): `clone`, `clone`, `clone`, `clone`, `eq`, `fmt`, `fmt`, `fmt`, `fmt`, `fmt`
+// These functions are ignored (category: IgnoreBecauseOwnerTyShouldIgnore): `check`, `new`
+
+
+            
+
+            /// Limits on what an [`Enigo`] may send; see [`Enigo::set_policy`].
+///
+/// The default allows everything. Releases are never blocked, so nothing
+/// pressed before a policy was set can get stuck.
+///
+/// [`Enigo`]: super::enigo::Enigo
+/// [`Enigo::set_policy`]: super::enigo::Enigo::set_policy
+class Policy  {
+                /// Keys that may be pressed, and characters that may be typed as
+/// `Key::Unicode`. Empty allows every key that isn't denied.
+final List<PhysicalKeyboardKey> allowedKeys;
+final List<PhysicalKeyboardKey> deniedKeys;
+/// Combinations that may not be completed, e.g. `[Control, Alt, Delete]`.
+final List<List<PhysicalKeyboardKey>> deniedChords;
+/// Raw keycodes bypass the key lists, so they can be turned off.
+final bool allowRaw;
+/// Where buttons may be pressed. Empty allows the whole screen.
+final List<Region> clickRegions;
+/// In characters.
+final int? maxTextLen;
+final RateLimit? rateLimit;
+
+                const Policy({required this.allowedKeys ,required this.deniedKeys ,required this.deniedChords ,required this.allowRaw ,required this.clickRegions ,this.maxTextLen ,this.rateLimit ,});
+
+                static Future<Policy>  default_()=>RustLib.instance.api.crateApiPolicyPolicyDefault();
+
+
+static Policy  preset()=>RustLib.instance.api.crateApiPolicyPolicyPreset();
+
+
+                
+
+                
+        @override
+        int get hashCode => allowedKeys.hashCode^deniedKeys.hashCode^deniedChords.hashCode^allowRaw.hashCode^clickRegions.hashCode^maxTextLen.hashCode^rateLimit.hashCode;
+        
+
+                
+        @override
+        bool operator ==(Object other) =>
+            identical(this, other) ||
+            other is Policy &&
+                runtimeType == other.runtimeType
+                && allowedKeys == other.allowedKeys&& deniedKeys == other.deniedKeys&& deniedChords == other.deniedChords&& allowRaw == other.allowRaw&& clickRegions == other.clickRegions&& maxTextLen == other.maxTextLen&& rateLimit == other.rateLimit;
+        
+            }
+
+@freezed
+                sealed class PolicyViolation with _$PolicyViolation implements FrbException {
+                    const PolicyViolation._();
+
+                     const factory PolicyViolation.keyDenied({   required PhysicalKeyboardKey key , }) = PolicyViolation_KeyDenied;
+ const factory PolicyViolation.chordDenied({   required List<PhysicalKeyboardKey> chord , }) = PolicyViolation_ChordDenied;
+ const factory PolicyViolation.rawDenied({   required int keycode , }) = PolicyViolation_RawDenied;
+ const factory PolicyViolation.clickOutsideRegions({   required int x ,  required int y , }) = PolicyViolation_ClickOutsideRegions;
+ const factory PolicyViolation.textTooLong({   required int len ,  required int max , }) = PolicyViolation_TextTooLong;
+ const factory PolicyViolation.rateLimited({   required int maxEvents ,  required int windowMs , }) = PolicyViolation_RateLimited;
+
+                    
+
+                    
+                }
+
+/// At most `max_events` calls in any `window_ms`. Releases don't count.
+class RateLimit  {
+                final int maxEvents;
+final int windowMs;
+
+                const RateLimit({required this.maxEvents ,required this.windowMs ,});
+
+                
+                
+
+                
+        @override
+        int get hashCode => maxEvents.hashCode^windowMs.hashCode;
+        
+
+                
+        @override
+        bool operator ==(Object other) =>
+            identical(this, other) ||
+            other is RateLimit &&
+                runtimeType == other.runtimeType
+                && maxEvents == other.maxEvents&& windowMs == other.windowMs;
+        
+            }
+
+/// A rectangle in screen coordinates.
+class Region  {
+                final int x;
+final int y;
+final int width;
+final int height;
+
+                const Region({required this.x ,required this.y ,required this.width ,required this.height ,});
+
+                
+                
+
+                
+        @override
+        int get hashCode => x.hashCode^y.hashCode^width.hashCode^height.hashCode;
+        
+
+                
+        @override
+        bool operator ==(Object other) =>
+            identical(this, other) ||
+            other is Region &&
+                runtimeType == other.runtimeType
+                && x == other.x&& y == other.y&& width == other.width&& height == other.height;
+        
+            }
+            
\ No newline at end of file
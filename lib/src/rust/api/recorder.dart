@@ -0,0 +1,111 @@
+// This file is automatically generated, so please do not edit it.
+// @generated by `flutter_rust_bridge`@ 2.10.0.
+
+import 'package:flutter/services.dart';
+
+// ignore_for_file: invalid_use_of_internal_member, unused_import, unnecessary_import
+
+import '../frb_generated.dart';
+import 'enigo.dart';
+import 'package:flutter_rust_bridge/flutter_rust_bridge_for_generated.dart';
+
+
+            // These functions are ignored because they are not marked as `pub`: `from_file`, `is_ron`, `lock`, `push`, `to_file`
+// These types are ignored because they are neither used by any `pub` functions nor (for structs and enums) marked `#[frb(unignore)]`: `Action`, `RecordedEvent`, `RecordingFile`
+// These function are ignored because they are on traits that is not defined in current crate (put an empty `#[frb]` on it to unignore): `clone`, `clone`, `clone`, `clone`, `eq`, `eq`, `fmt`, `fmt`, `fmt`, `from`, `from`
+
+
+            
+
+            
+                // Rust type: RustOpaqueMoi<flutter_rust_bridge::for_generated::RustAutoOpaqueInner<Recorder>>
+                abstract class Recorder implements RustOpaqueInterface {
+                     bool get isRecording;
+
+
+factory Recorder({required Settings settings })=>RustLib.instance.api.crateApiRecorderRecorderNew(settings: settings);
+
+
+/// Start a new recording, discarding anything recorded so far.
+ void  start();
+
+
+/// Stop recording and return what was captured.
+ Recording  stop();
+
+
+
+                    
+                }
+                
+
+
+                // Rust type: RustOpaqueMoi<flutter_rust_bridge::for_generated::RustAutoOpaqueInner<Recording>>
+                abstract class Recording implements RustOpaqueInterface {
+                    static Future<Recording>  default_()=>RustLib.instance.api.crateApiRecorderRecordingDefault();
+
+
+ BigInt get durationMs;
+
+
+static Recording  fromJson({required String json })=>RustLib.instance.api.crateApiRecorderRecordingFromJson(json: json);
+
+
+ bool get isEmpty;
+
+
+ BigInt get len;
+
+
+/// Load a recording saved by [`Recording::save`]; `.ron` files are read
+/// as RON, anything else as JSON.
+static Recording  load({required String path })=>RustLib.instance.api.crateApiRecorderRecordingLoad(path: path);
+
+
+ void  save({required String path });
+
+
+ String  toJson();
+
+
+
+                    
+                }
+                
+
+/// How [`Enigo::play`] replays a [`Recording`].
+///
+/// [`Enigo::play`]: super::enigo::Enigo::play
+class PlaybackOptions  {
+                /// 2.0 plays twice as fast, 0.5 at half speed.
+final double speed;
+/// How many times to play the recording; 0 is treated as 1.
+final int loops;
+/// Shorten any pause between two events to at most this long.
+final int? maxGapMs;
+
+                const PlaybackOptions({required this.speed ,required this.loops ,this.maxGapMs ,});
+
+                static Future<PlaybackOptions>  default_()=>RustLib.instance.api.crateApiRecorderPlaybackOptionsDefault();
+
+
+static PlaybackOptions  preset()=>RustLib.instance.api.crateApiRecorderPlaybackOptionsPreset();
+
+
+                
+
+                
+        @override
+        int get hashCode => speed.hashCode^loops.hashCode^maxGapMs.hashCode;
+        
+
+                
+        @override
+        bool operator ==(Object other) =>
+            identical(this, other) ||
+            other is PlaybackOptions &&
+                runtimeType == other.runtimeType
+                && speed == other.speed&& loops == other.loops&& maxGapMs == other.maxGapMs;
+        
+            }
+            
\ No newline at end of file
@@ -0,0 +1,54 @@
+// This file is automatically generated, so please do not edit it.
+// @generated by `flutter_rust_bridge`@ 2.10.0.
+
+import 'package:flutter/services.dart';
+
+// ignore_for_file: invalid_use_of_internal_member, unused_import, unnecessary_import
+
+import '../frb_generated.dart';
+import 'enigo.dart';
+import 'package:flutter_rust_bridge/flutter_rust_bridge_for_generated.dart';
+
+
+            // These functions are ignored because they are not marked as `pub`: `compile_rules`, `compile`, `keycode`, `lock`
+// These types are ignored because they are neither used by any `pub` functions nor (for structs and enums) marked `#[frb(unignore)]`: `LayerConfig`, `RemapConfig`, `RuleConfig`, `Rule`, `Rules`
+// These function are ignored because they are on traits that is not defined in current crate (put an empty `#[frb]` on it to unignore): `assert_fields_are_eq`, `clone`, `clone`, `clone`, `clone`, `clone`, `eq`, `fmt`, `fmt`, `fmt`, `fmt`, `fmt`
+// These functions are ignored (category: IgnoreBecauseOwnerTyShouldIgnore): `default`, `default`, `lookup`, `rules`
+
+
+            
+
+            
+                // Rust type: RustOpaqueMoi<flutter_rust_bridge::for_generated::RustAutoOpaqueInner<Remapper>>
+                abstract class Remapper implements RustOpaqueInterface {
+                     bool get enabled;
+
+
+static Remapper  fromJson({required String config , required Settings settings })=>RustLib.instance.api.crateApiRemapRemapperFromJson(config: config, settings: settings);
+
+
+ bool get isRunning;
+
+
+/// Replace the rules; takes effect immediately if the remapper runs.
+ void  loadJson({required String config });
+
+
+/// While disabled the keyboards stay grabbed but events pass through
+/// unchanged.
+ void  setEnabled({required bool enabled });
+
+
+/// Grab the keyboards and start remapping.
+ void  start();
+
+
+/// Release the keyboards.
+ void  stop();
+
+
+
+                    
+                }
+                
+            
\ No newline at end of file
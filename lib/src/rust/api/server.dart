@@ -0,0 +1,112 @@
+// This file is automatically generated, so please do not edit it.
+// @generated by `flutter_rust_bridge`@ 2.10.0.
+
+import 'package:flutter/services.dart';
+
+// ignore_for_file: invalid_use_of_internal_member, unused_import, unnecessary_import
+
+import '../frb_generated.dart';
+import 'enigo.dart';
+import 'package:flutter_rust_bridge/flutter_rust_bridge_for_generated.dart';
+import 'policy.dart';
+
+
+            // These function are ignored because they are on traits that is not defined in current crate (put an empty `#[frb]` on it to unignore): `clone`, `clone`, `fmt`, `fmt`
+
+
+            
+
+            
+                // Rust type: RustOpaqueMoi<flutter_rust_bridge::for_generated::RustAutoOpaqueInner<InputServer>>
+                abstract class InputServer implements RustOpaqueInterface {
+                    /// The address the server listens on, with the port it got.
+ String get address;
+
+
+ int get connections;
+
+
+ bool get isRunning;
+
+
+/// Listen on `config.address` and serve clients on threads of their
+/// own until [`InputServer::stop`] is called or the server is dropped.
+/// Fails without the `server` cargo feature.
+static InputServer  start({required Enigo enigo , required ServerConfig config })=>RustLib.instance.api.crateApiServerInputServerStart(enigo: enigo, config: config);
+
+
+/// Stop accepting and close every connection, releasing what their
+/// clients held.
+ void  stop();
+
+
+
+                    
+                }
+                
+
+/// How an [`InputServer`] listens and whom it lets in.
+class ServerConfig  {
+                /// `host:port` to listen on; port 0 picks a free one. The default only
+/// accepts connections from this machine.
+final String address;
+/// Clients must send one of these before anything else. Without
+/// tokens anyone who can connect may send input, so the server then
+/// refuses to listen on anything but a loopback address.
+final List<ServerToken> tokens;
+/// For connections whose token has no policy of its own. Applies on
+/// top of the `Enigo`'s own policy.
+final Policy? policy;
+/// Connections beyond this many are closed as soon as they are
+/// accepted.
+final int maxConnections;
+
+                const ServerConfig({required this.address ,required this.tokens ,this.policy ,required this.maxConnections ,});
+
+                static Future<ServerConfig>  default_()=>RustLib.instance.api.crateApiServerServerConfigDefault();
+
+
+static ServerConfig  preset()=>RustLib.instance.api.crateApiServerServerConfigPreset();
+
+
+                
+
+                
+        @override
+        int get hashCode => address.hashCode^tokens.hashCode^policy.hashCode^maxConnections.hashCode;
+        
+
+                
+        @override
+        bool operator ==(Object other) =>
+            identical(this, other) ||
+            other is ServerConfig &&
+                runtimeType == other.runtimeType
+                && address == other.address&& tokens == other.tokens&& policy == other.policy&& maxConnections == other.maxConnections;
+        
+            }
+
+class ServerToken  {
+                final String token;
+final Policy? policy;
+
+                const ServerToken({required this.token ,this.policy ,});
+
+                
+                
+
+                
+        @override
+        int get hashCode => token.hashCode^policy.hashCode;
+        
+
+                
+        @override
+        bool operator ==(Object other) =>
+            identical(this, other) ||
+            other is ServerToken &&
+                runtimeType == other.runtimeType
+                && token == other.token&& policy == other.policy;
+        
+            }
+            
\ No newline at end of file
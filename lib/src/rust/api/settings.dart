@@ -0,0 +1,38 @@
+// This file is automatically generated, so please do not edit it.
+// @generated by `flutter_rust_bridge`@ 2.10.0.
+
+import 'package:flutter/services.dart';
+
+// ignore_for_file: invalid_use_of_internal_member, unused_import, unnecessary_import
+
+import '../frb_generated.dart';
+import 'enigo.dart';
+import 'package:flutter_rust_bridge/flutter_rust_bridge_for_generated.dart';
+
+
+            // These functions are ignored because they are not marked as `pub`: `apply_vars`, `backend_from_env`, `from_patch`, `is_toml`, `parse_backends`, `parse_bool`, `parse`, `validate`
+
+
+            /// Read settings from JSON. Missing fields keep their default.
+Settings  settingsFromJson({required String json }) => RustLib.instance.api.crateApiSettingsSettingsFromJson(json: json);
+
+String  settingsToJson({required Settings settings }) => RustLib.instance.api.crateApiSettingsSettingsToJson(settings: settings);
+
+/// Read settings from a file; `.toml` files are read as TOML, anything else
+/// as JSON. Missing fields keep their default.
+Settings  loadSettings({required String path }) => RustLib.instance.api.crateApiSettingsLoadSettings(path: path);
+
+void  saveSettings({required Settings settings , required String path }) => RustLib.instance.api.crateApiSettingsSaveSettings(settings: settings, path: path);
+
+/// `settings` with every `ENIGO_*` environment variable applied on top, for
+/// example `ENIGO_X11_DISPLAY=:1` or `ENIGO_LINUX_DELAY=0`.
+///
+/// Unknown `ENIGO_*` variables are logged as a warning and otherwise
+/// ignored, since other tools may use the prefix too.
+Settings  settingsFromEnv({required Settings settings }) => RustLib.instance.api.crateApiSettingsSettingsFromEnv(settings: settings);
+
+/// Reject settings that can't work.
+void  validateSettings({required Settings settings }) => RustLib.instance.api.crateApiSettingsValidateSettings(settings: settings);
+
+            
+            
\ No newline at end of file
@@ -0,0 +1,74 @@
+// This file is automatically generated, so please do not edit it.
+// @generated by `flutter_rust_bridge`@ 2.10.0.
+
+import 'package:flutter/services.dart';
+
+// ignore_for_file: invalid_use_of_internal_member, unused_import, unnecessary_import
+
+import '../frb_generated.dart';
+import 'package:flutter_rust_bridge/flutter_rust_bridge_for_generated.dart';
+
+
+            // These types are ignored because they are neither used by any `pub` functions nor (for structs and enums) marked `#[frb(unignore)]`: `RepeatInfo`
+// These function are ignored because they are on traits that is not defined in current crate (put an empty `#[frb]` on it to unignore): `assert_fields_are_eq`, `assert_fields_are_eq`, `assert_fields_are_eq`, `clone`, `clone`, `clone`, `eq`, `eq`, `eq`, `fmt`, `fmt`, `fmt`
+
+
+            
+
+            /// Whether each lock key is currently on.
+class LockState  {
+                final bool capsLock;
+final bool numLock;
+final bool scrollLock;
+
+                const LockState({required this.capsLock ,required this.numLock ,required this.scrollLock ,});
+
+                static Future<LockState>  default_()=>RustLib.instance.api.crateApiSystemLockStateDefault();
+
+
+                
+
+                
+        @override
+        int get hashCode => capsLock.hashCode^numLock.hashCode^scrollLock.hashCode;
+        
+
+                
+        @override
+        bool operator ==(Object other) =>
+            identical(this, other) ||
+            other is LockState &&
+                runtimeType == other.runtimeType
+                && capsLock == other.capsLock&& numLock == other.numLock&& scrollLock == other.scrollLock;
+        
+            }
+
+/// Which modifiers are physically held down, regardless of who pressed them.
+class ModifierState  {
+                final bool shift;
+final bool control;
+final bool alt;
+final bool meta;
+
+                const ModifierState({required this.shift ,required this.control ,required this.alt ,required this.meta ,});
+
+                static Future<ModifierState>  default_()=>RustLib.instance.api.crateApiSystemModifierStateDefault();
+
+
+                
+
+                
+        @override
+        int get hashCode => shift.hashCode^control.hashCode^alt.hashCode^meta.hashCode;
+        
+
+                
+        @override
+        bool operator ==(Object other) =>
+            identical(this, other) ||
+            other is ModifierState &&
+                runtimeType == other.runtimeType
+                && shift == other.shift&& control == other.control&& alt == other.alt&& meta == other.meta;
+        
+            }
+            
\ No newline at end of file
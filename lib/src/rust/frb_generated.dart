@@ -5,1500 +5,6419 @@ import 'package:flutter/services.dart';
 
 // ignore_for_file: unused_import, unused_element, unnecessary_import, duplicate_ignore, invalid_use_of_internal_member, annotate_overrides, non_constant_identifier_names, curly_braces_in_flow_control_structures, prefer_const_literals_to_create_immutables, unused_field
 
+import 'api/audit.dart';
+import 'api/backend.dart';
+import 'api/dry_run.dart';
 import 'api/enigo.dart';
+import 'api/hotkey.dart';
+import 'api/listener.dart';
+import 'api/logging.dart';
+import 'api/metrics.dart';
+import 'api/mock.dart';
+import 'api/pacer.dart';
+import 'api/policy.dart';
+import 'api/recorder.dart';
+import 'api/remap.dart';
+import 'api/server.dart';
+import 'api/settings.dart';
+import 'api/system.dart';
 import 'dart:async';
 import 'dart:convert';
 import 'frb_generated.dart';
-import 'frb_generated.io.dart'
-    if (dart.library.js_interop) 'frb_generated.web.dart';
+import 'frb_generated.io.dart' if (dart.library.js_interop) 'frb_generated.web.dart';
 import 'package:flutter_rust_bridge/flutter_rust_bridge_for_generated.dart';
 
-/// Main entrypoint of the Rust API
-class RustLib extends BaseEntrypoint<RustLibApi, RustLibApiImpl, RustLibWire> {
-  @internal
-  static final instance = RustLib._();
 
-  RustLib._();
+                /// Main entrypoint of the Rust API
+                class RustLib extends BaseEntrypoint<RustLibApi, RustLibApiImpl, RustLibWire> {
+                  @internal
+                  static final instance = RustLib._();
 
-  /// Initialize flutter_rust_bridge
-  static Future<void> init({
-    RustLibApi? api,
-    BaseHandler? handler,
-    ExternalLibrary? externalLibrary,
-  }) async {
-    await instance.initImpl(
-      api: api,
-      handler: handler,
-      externalLibrary: externalLibrary,
-    );
-  }
+                  RustLib._();
 
-  /// Initialize flutter_rust_bridge in mock mode.
-  /// No libraries for FFI are loaded.
-  static void initMock({
-    required RustLibApi api,
-  }) {
-    instance.initMockImpl(
-      api: api,
-    );
-  }
+                  /// Initialize flutter_rust_bridge
+                  static Future<void> init({
+                    RustLibApi? api,
+                    BaseHandler? handler,
+                    ExternalLibrary? externalLibrary,
+                  }) async {
+                    await instance.initImpl(
+                      api: api,
+                      handler: handler,
+                      externalLibrary: externalLibrary,
+                    );
+                  }
 
-  /// Dispose flutter_rust_bridge
-  ///
-  /// The call to this function is optional, since flutter_rust_bridge (and everything else)
-  /// is automatically disposed when the app stops.
-  static void dispose() => instance.disposeImpl();
+                  /// Initialize flutter_rust_bridge in mock mode.
+                  /// No libraries for FFI are loaded.
+                  static void initMock({
+                    required RustLibApi api,
+                  }) {
+                    instance.initMockImpl(
+                      api: api,
+                    );
+                  }
 
-  @override
-  ApiImplConstructor<RustLibApiImpl, RustLibWire> get apiImplConstructor =>
-      RustLibApiImpl.new;
+                  /// Dispose flutter_rust_bridge
+                  ///
+                  /// The call to this function is optional, since flutter_rust_bridge (and everything else)
+                  /// is automatically disposed when the app stops.
+                  static void dispose() => instance.disposeImpl();
 
-  @override
-  WireConstructor<RustLibWire> get wireConstructor =>
-      RustLibWire.fromExternalLibrary;
+                  @override
+                  ApiImplConstructor<RustLibApiImpl, RustLibWire> get apiImplConstructor => RustLibApiImpl.new;
 
-  @override
-  Future<void> executeRustInitializers() async {
-    await api.crateApiEnigoInitApp();
-  }
+                  @override
+                  WireConstructor<RustLibWire> get wireConstructor => RustLibWire.fromExternalLibrary;
 
-  @override
-  ExternalLibraryLoaderConfig get defaultExternalLibraryLoaderConfig =>
-      kDefaultExternalLibraryLoaderConfig;
+                  @override
+                  Future<void> executeRustInitializers() async {
+                    await api.crateApiEnigoInitApp();
 
-  @override
-  String get codegenVersion => '2.10.0';
+                  }
 
-  @override
-  int get rustContentHash => 1330927019;
+                  @override
+                  ExternalLibraryLoaderConfig get defaultExternalLibraryLoaderConfig => kDefaultExternalLibraryLoaderConfig;
 
-  static const kDefaultExternalLibraryLoaderConfig =
-      ExternalLibraryLoaderConfig(
-    stem: 'enigo_flutter',
-    ioDirectory: 'rust/target/release/',
-    webPrefix: 'pkg/',
-  );
-}
+                  @override
+                  String get codegenVersion => '2.10.0';
 
-abstract class RustLibApi extends BaseApi {
-  Button crateApiEnigoButtonBack();
+                  @override
+                  int get rustContentHash => 2012308180;
 
-  Button crateApiEnigoButtonForward();
+                  static const kDefaultExternalLibraryLoaderConfig = ExternalLibraryLoaderConfig(
+                    stem: 'enigo_flutter',
+                    ioDirectory: 'rust/target/release/',
+                    webPrefix: 'pkg/',
+                  );
+                }
+                
 
-  Button crateApiEnigoButtonLeft();
+                abstract class RustLibApi extends BaseApi {
+                  Button crateApiEnigoButtonBack();
 
-  Button crateApiEnigoButtonMiddle();
+Button crateApiEnigoButtonForward();
 
-  Button crateApiEnigoButtonNew({required String value});
+Button crateApiEnigoButtonLeft();
 
-  Button crateApiEnigoButtonRight();
+Button crateApiEnigoButtonMiddle();
 
-  Button crateApiEnigoButtonScrollDown();
+Button crateApiEnigoButtonNew({required String value });
 
-  Button crateApiEnigoButtonScrollLeft();
-
-  Button crateApiEnigoButtonScrollRight();
-
-  Button crateApiEnigoButtonScrollUp();
-
-  String crateApiEnigoButtonToString({required Button that});
-
-  void crateApiEnigoEnigoButton(
-      {required Enigo that,
-      required Button button,
-      required Direction direction});
-
-  bool crateApiEnigoEnigoHasPermission({required bool openPrompt});
-
-  void crateApiEnigoEnigoKey(
-      {required Enigo that,
-      required PhysicalKeyboardKey key,
-      required Direction direction});
-
-  (int, int) crateApiEnigoEnigoLocation({required Enigo that});
-
-  (int, int) crateApiEnigoEnigoMainDisplay({required Enigo that});
-
-  void crateApiEnigoEnigoMoveMouse(
-      {required Enigo that,
-      required int x,
-      required int y,
-      required Coordinate coordinate});
-
-  Enigo crateApiEnigoEnigoNew({required Settings settings});
-
-  Enigo crateApiEnigoEnigoPreset();
-
-  void crateApiEnigoEnigoRaw(
-      {required Enigo that,
-      required int keycode,
-      required Direction direction});
-
-  void crateApiEnigoEnigoScroll(
-      {required Enigo that, required int length, required Axis axis});
-
-  void crateApiEnigoEnigoText({required Enigo that, required String text});
-
-  Future<void> crateApiEnigoInitApp();
-
-  RustArcIncrementStrongCountFnType get rust_arc_increment_strong_count_Enigo;
-
-  RustArcDecrementStrongCountFnType get rust_arc_decrement_strong_count_Enigo;
-
-  CrossPlatformFinalizerArg get rust_arc_decrement_strong_count_EnigoPtr;
-
-  RustArcIncrementStrongCountFnType get rust_arc_increment_strong_count_Button;
-
-  RustArcDecrementStrongCountFnType get rust_arc_decrement_strong_count_Button;
-
-  CrossPlatformFinalizerArg get rust_arc_decrement_strong_count_ButtonPtr;
-}
-
-class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
-  RustLibApiImpl({
-    required super.handler,
-    required super.wire,
-    required super.generalizedFrbRustBinding,
-    required super.portManager,
-  });
-
-  @override
-  Button crateApiEnigoButtonBack() {
-    return handler.executeSync(SyncTask(
-      callFfi: () {
-        final serializer = SseSerializer(generalizedFrbRustBinding);
-        return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 1)!;
-      },
-      codec: SseCodec(
-        decodeSuccessData:
-            sse_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInner_Button,
-        decodeErrorData: null,
-      ),
-      constMeta: kCrateApiEnigoButtonBackConstMeta,
-      argValues: [],
-      apiImpl: this,
-    ));
-  }
-
-  TaskConstMeta get kCrateApiEnigoButtonBackConstMeta => const TaskConstMeta(
-        debugName: "Button_back",
-        argNames: [],
-      );
-
-  @override
-  Button crateApiEnigoButtonForward() {
-    return handler.executeSync(SyncTask(
-      callFfi: () {
-        final serializer = SseSerializer(generalizedFrbRustBinding);
-        return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 2)!;
-      },
-      codec: SseCodec(
-        decodeSuccessData:
-            sse_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInner_Button,
-        decodeErrorData: null,
-      ),
-      constMeta: kCrateApiEnigoButtonForwardConstMeta,
-      argValues: [],
-      apiImpl: this,
-    ));
-  }
-
-  TaskConstMeta get kCrateApiEnigoButtonForwardConstMeta => const TaskConstMeta(
-        debugName: "Button_forward",
-        argNames: [],
-      );
-
-  @override
-  Button crateApiEnigoButtonLeft() {
-    return handler.executeSync(SyncTask(
-      callFfi: () {
-        final serializer = SseSerializer(generalizedFrbRustBinding);
-        return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 3)!;
-      },
-      codec: SseCodec(
-        decodeSuccessData:
-            sse_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInner_Button,
-        decodeErrorData: null,
-      ),
-      constMeta: kCrateApiEnigoButtonLeftConstMeta,
-      argValues: [],
-      apiImpl: this,
-    ));
-  }
-
-  TaskConstMeta get kCrateApiEnigoButtonLeftConstMeta => const TaskConstMeta(
-        debugName: "Button_left",
-        argNames: [],
-      );
-
-  @override
-  Button crateApiEnigoButtonMiddle() {
-    return handler.executeSync(SyncTask(
-      callFfi: () {
-        final serializer = SseSerializer(generalizedFrbRustBinding);
-        return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 4)!;
-      },
-      codec: SseCodec(
-        decodeSuccessData:
-            sse_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInner_Button,
-        decodeErrorData: null,
-      ),
-      constMeta: kCrateApiEnigoButtonMiddleConstMeta,
-      argValues: [],
-      apiImpl: this,
-    ));
-  }
-
-  TaskConstMeta get kCrateApiEnigoButtonMiddleConstMeta => const TaskConstMeta(
-        debugName: "Button_middle",
-        argNames: [],
-      );
-
-  @override
-  Button crateApiEnigoButtonNew({required String value}) {
-    return handler.executeSync(SyncTask(
-      callFfi: () {
-        final serializer = SseSerializer(generalizedFrbRustBinding);
-        sse_encode_String(value, serializer);
-        return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 5)!;
-      },
-      codec: SseCodec(
-        decodeSuccessData:
-            sse_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInner_Button,
-        decodeErrorData: null,
-      ),
-      constMeta: kCrateApiEnigoButtonNewConstMeta,
-      argValues: [value],
-      apiImpl: this,
-    ));
-  }
-
-  TaskConstMeta get kCrateApiEnigoButtonNewConstMeta => const TaskConstMeta(
-        debugName: "Button_new",
-        argNames: ["value"],
-      );
-
-  @override
-  Button crateApiEnigoButtonRight() {
-    return handler.executeSync(SyncTask(
-      callFfi: () {
-        final serializer = SseSerializer(generalizedFrbRustBinding);
-        return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 6)!;
-      },
-      codec: SseCodec(
-        decodeSuccessData:
-            sse_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInner_Button,
-        decodeErrorData: null,
-      ),
-      constMeta: kCrateApiEnigoButtonRightConstMeta,
-      argValues: [],
-      apiImpl: this,
-    ));
-  }
-
-  TaskConstMeta get kCrateApiEnigoButtonRightConstMeta => const TaskConstMeta(
-        debugName: "Button_right",
-        argNames: [],
-      );
-
-  @override
-  Button crateApiEnigoButtonScrollDown() {
-    return handler.executeSync(SyncTask(
-      callFfi: () {
-        final serializer = SseSerializer(generalizedFrbRustBinding);
-        return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 7)!;
-      },
-      codec: SseCodec(
-        decodeSuccessData:
-            sse_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInner_Button,
-        decodeErrorData: null,
-      ),
-      constMeta: kCrateApiEnigoButtonScrollDownConstMeta,
-      argValues: [],
-      apiImpl: this,
-    ));
-  }
-
-  TaskConstMeta get kCrateApiEnigoButtonScrollDownConstMeta =>
-      const TaskConstMeta(
-        debugName: "Button_scroll_down",
-        argNames: [],
-      );
-
-  @override
-  Button crateApiEnigoButtonScrollLeft() {
-    return handler.executeSync(SyncTask(
-      callFfi: () {
-        final serializer = SseSerializer(generalizedFrbRustBinding);
-        return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 8)!;
-      },
-      codec: SseCodec(
-        decodeSuccessData:
-            sse_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInner_Button,
-        decodeErrorData: null,
-      ),
-      constMeta: kCrateApiEnigoButtonScrollLeftConstMeta,
-      argValues: [],
-      apiImpl: this,
-    ));
-  }
-
-  TaskConstMeta get kCrateApiEnigoButtonScrollLeftConstMeta =>
-      const TaskConstMeta(
-        debugName: "Button_scroll_left",
-        argNames: [],
-      );
-
-  @override
-  Button crateApiEnigoButtonScrollRight() {
-    return handler.executeSync(SyncTask(
-      callFfi: () {
-        final serializer = SseSerializer(generalizedFrbRustBinding);
-        return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 9)!;
-      },
-      codec: SseCodec(
-        decodeSuccessData:
-            sse_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInner_Button,
-        decodeErrorData: null,
-      ),
-      constMeta: kCrateApiEnigoButtonScrollRightConstMeta,
-      argValues: [],
-      apiImpl: this,
-    ));
-  }
-
-  TaskConstMeta get kCrateApiEnigoButtonScrollRightConstMeta =>
-      const TaskConstMeta(
-        debugName: "Button_scroll_right",
-        argNames: [],
-      );
-
-  @override
-  Button crateApiEnigoButtonScrollUp() {
-    return handler.executeSync(SyncTask(
-      callFfi: () {
-        final serializer = SseSerializer(generalizedFrbRustBinding);
-        return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 10)!;
-      },
-      codec: SseCodec(
-        decodeSuccessData:
-            sse_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInner_Button,
-        decodeErrorData: null,
-      ),
-      constMeta: kCrateApiEnigoButtonScrollUpConstMeta,
-      argValues: [],
-      apiImpl: this,
-    ));
-  }
-
-  TaskConstMeta get kCrateApiEnigoButtonScrollUpConstMeta =>
-      const TaskConstMeta(
-        debugName: "Button_scroll_up",
-        argNames: [],
-      );
-
-  @override
-  String crateApiEnigoButtonToString({required Button that}) {
-    return handler.executeSync(SyncTask(
-      callFfi: () {
-        final serializer = SseSerializer(generalizedFrbRustBinding);
-        sse_encode_Auto_RefMut_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInner_Button(
-            that, serializer);
-        return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 11)!;
-      },
-      codec: SseCodec(
-        decodeSuccessData: sse_decode_String,
-        decodeErrorData: null,
-      ),
-      constMeta: kCrateApiEnigoButtonToStringConstMeta,
-      argValues: [that],
-      apiImpl: this,
-    ));
-  }
-
-  TaskConstMeta get kCrateApiEnigoButtonToStringConstMeta =>
-      const TaskConstMeta(
-        debugName: "Button_to_string",
-        argNames: ["that"],
-      );
-
-  @override
-  void crateApiEnigoEnigoButton(
-      {required Enigo that,
-      required Button button,
-      required Direction direction}) {
-    return handler.executeSync(SyncTask(
-      callFfi: () {
-        final serializer = SseSerializer(generalizedFrbRustBinding);
-        sse_encode_Auto_RefMut_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerEnigo(
-            that, serializer);
-        sse_encode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInner_Button(
-            button, serializer);
-        sse_encode_direction(direction, serializer);
-        return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 12)!;
-      },
-      codec: SseCodec(
-        decodeSuccessData: sse_decode_unit,
-        decodeErrorData: null,
-      ),
-      constMeta: kCrateApiEnigoEnigoButtonConstMeta,
-      argValues: [that, button, direction],
-      apiImpl: this,
-    ));
-  }
-
-  TaskConstMeta get kCrateApiEnigoEnigoButtonConstMeta => const TaskConstMeta(
-        debugName: "Enigo_button",
-        argNames: ["that", "button", "direction"],
-      );
-
-  @override
-  bool crateApiEnigoEnigoHasPermission({required bool openPrompt}) {
-    return handler.executeSync(SyncTask(
-      callFfi: () {
-        final serializer = SseSerializer(generalizedFrbRustBinding);
-        sse_encode_bool(openPrompt, serializer);
-        return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 13)!;
-      },
-      codec: SseCodec(
-        decodeSuccessData: sse_decode_bool,
-        decodeErrorData: null,
-      ),
-      constMeta: kCrateApiEnigoEnigoHasPermissionConstMeta,
-      argValues: [openPrompt],
-      apiImpl: this,
-    ));
-  }
-
-  TaskConstMeta get kCrateApiEnigoEnigoHasPermissionConstMeta =>
-      const TaskConstMeta(
-        debugName: "Enigo_has_permission",
-        argNames: ["openPrompt"],
-      );
-
-  @override
-  void crateApiEnigoEnigoKey(
-      {required Enigo that,
-      required PhysicalKeyboardKey key,
-      required Direction direction}) {
-    return handler.executeSync(SyncTask(
-      callFfi: () {
-        final serializer = SseSerializer(generalizedFrbRustBinding);
-        sse_encode_Auto_RefMut_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerEnigo(
-            that, serializer);
-        sse_encode_CustomSerializer_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerKey(
-            key, serializer);
-        sse_encode_direction(direction, serializer);
-        return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 14)!;
-      },
-      codec: SseCodec(
-        decodeSuccessData: sse_decode_unit,
-        decodeErrorData: null,
-      ),
-      constMeta: kCrateApiEnigoEnigoKeyConstMeta,
-      argValues: [that, key, direction],
-      apiImpl: this,
-    ));
-  }
-
-  TaskConstMeta get kCrateApiEnigoEnigoKeyConstMeta => const TaskConstMeta(
-        debugName: "Enigo_key",
-        argNames: ["that", "key", "direction"],
-      );
-
-  @override
-  (int, int) crateApiEnigoEnigoLocation({required Enigo that}) {
-    return handler.executeSync(SyncTask(
-      callFfi: () {
-        final serializer = SseSerializer(generalizedFrbRustBinding);
-        sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerEnigo(
-            that, serializer);
-        return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 15)!;
-      },
-      codec: SseCodec(
-        decodeSuccessData: sse_decode_record_i_32_i_32,
-        decodeErrorData: null,
-      ),
-      constMeta: kCrateApiEnigoEnigoLocationConstMeta,
-      argValues: [that],
-      apiImpl: this,
-    ));
-  }
-
-  TaskConstMeta get kCrateApiEnigoEnigoLocationConstMeta => const TaskConstMeta(
-        debugName: "Enigo_location",
-        argNames: ["that"],
-      );
-
-  @override
-  (int, int) crateApiEnigoEnigoMainDisplay({required Enigo that}) {
-    return handler.executeSync(SyncTask(
-      callFfi: () {
-        final serializer = SseSerializer(generalizedFrbRustBinding);
-        sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerEnigo(
-            that, serializer);
-        return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 16)!;
-      },
-      codec: SseCodec(
-        decodeSuccessData: sse_decode_record_i_32_i_32,
-        decodeErrorData: null,
-      ),
-      constMeta: kCrateApiEnigoEnigoMainDisplayConstMeta,
-      argValues: [that],
-      apiImpl: this,
-    ));
-  }
-
-  TaskConstMeta get kCrateApiEnigoEnigoMainDisplayConstMeta =>
-      const TaskConstMeta(
-        debugName: "Enigo_main_display",
-        argNames: ["that"],
-      );
-
-  @override
-  void crateApiEnigoEnigoMoveMouse(
-      {required Enigo that,
-      required int x,
-      required int y,
-      required Coordinate coordinate}) {
-    return handler.executeSync(SyncTask(
-      callFfi: () {
-        final serializer = SseSerializer(generalizedFrbRustBinding);
-        sse_encode_Auto_RefMut_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerEnigo(
-            that, serializer);
-        sse_encode_i_32(x, serializer);
-        sse_encode_i_32(y, serializer);
-        sse_encode_coordinate(coordinate, serializer);
-        return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 17)!;
-      },
-      codec: SseCodec(
-        decodeSuccessData: sse_decode_unit,
-        decodeErrorData: null,
-      ),
-      constMeta: kCrateApiEnigoEnigoMoveMouseConstMeta,
-      argValues: [that, x, y, coordinate],
-      apiImpl: this,
-    ));
-  }
-
-  TaskConstMeta get kCrateApiEnigoEnigoMoveMouseConstMeta =>
-      const TaskConstMeta(
-        debugName: "Enigo_move_mouse",
-        argNames: ["that", "x", "y", "coordinate"],
-      );
-
-  @override
-  Enigo crateApiEnigoEnigoNew({required Settings settings}) {
-    return handler.executeSync(SyncTask(
-      callFfi: () {
-        final serializer = SseSerializer(generalizedFrbRustBinding);
-        sse_encode_box_autoadd_settings(settings, serializer);
-        return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 18)!;
-      },
-      codec: SseCodec(
-        decodeSuccessData:
-            sse_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerEnigo,
-        decodeErrorData: null,
-      ),
-      constMeta: kCrateApiEnigoEnigoNewConstMeta,
-      argValues: [settings],
-      apiImpl: this,
-    ));
-  }
-
-  TaskConstMeta get kCrateApiEnigoEnigoNewConstMeta => const TaskConstMeta(
-        debugName: "Enigo_new",
-        argNames: ["settings"],
-      );
-
-  @override
-  Enigo crateApiEnigoEnigoPreset() {
-    return handler.executeSync(SyncTask(
-      callFfi: () {
-        final serializer = SseSerializer(generalizedFrbRustBinding);
-        return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 19)!;
-      },
-      codec: SseCodec(
-        decodeSuccessData:
-            sse_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerEnigo,
-        decodeErrorData: null,
-      ),
-      constMeta: kCrateApiEnigoEnigoPresetConstMeta,
-      argValues: [],
-      apiImpl: this,
-    ));
-  }
-
-  TaskConstMeta get kCrateApiEnigoEnigoPresetConstMeta => const TaskConstMeta(
-        debugName: "Enigo_preset",
-        argNames: [],
-      );
-
-  @override
-  void crateApiEnigoEnigoRaw(
-      {required Enigo that,
-      required int keycode,
-      required Direction direction}) {
-    return handler.executeSync(SyncTask(
-      callFfi: () {
-        final serializer = SseSerializer(generalizedFrbRustBinding);
-        sse_encode_Auto_RefMut_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerEnigo(
-            that, serializer);
-        sse_encode_u_16(keycode, serializer);
-        sse_encode_direction(direction, serializer);
-        return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 20)!;
-      },
-      codec: SseCodec(
-        decodeSuccessData: sse_decode_unit,
-        decodeErrorData: null,
-      ),
-      constMeta: kCrateApiEnigoEnigoRawConstMeta,
-      argValues: [that, keycode, direction],
-      apiImpl: this,
-    ));
-  }
-
-  TaskConstMeta get kCrateApiEnigoEnigoRawConstMeta => const TaskConstMeta(
-        debugName: "Enigo_raw",
-        argNames: ["that", "keycode", "direction"],
-      );
-
-  @override
-  void crateApiEnigoEnigoScroll(
-      {required Enigo that, required int length, required Axis axis}) {
-    return handler.executeSync(SyncTask(
-      callFfi: () {
-        final serializer = SseSerializer(generalizedFrbRustBinding);
-        sse_encode_Auto_RefMut_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerEnigo(
-            that, serializer);
-        sse_encode_i_32(length, serializer);
-        sse_encode_axis(axis, serializer);
-        return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 21)!;
-      },
-      codec: SseCodec(
-        decodeSuccessData: sse_decode_unit,
-        decodeErrorData: null,
-      ),
-      constMeta: kCrateApiEnigoEnigoScrollConstMeta,
-      argValues: [that, length, axis],
-      apiImpl: this,
-    ));
-  }
-
-  TaskConstMeta get kCrateApiEnigoEnigoScrollConstMeta => const TaskConstMeta(
-        debugName: "Enigo_scroll",
-        argNames: ["that", "length", "axis"],
-      );
-
-  @override
-  void crateApiEnigoEnigoText({required Enigo that, required String text}) {
-    return handler.executeSync(SyncTask(
-      callFfi: () {
-        final serializer = SseSerializer(generalizedFrbRustBinding);
-        sse_encode_Auto_RefMut_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerEnigo(
-            that, serializer);
-        sse_encode_String(text, serializer);
-        return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 22)!;
-      },
-      codec: SseCodec(
-        decodeSuccessData: sse_decode_unit,
-        decodeErrorData: null,
-      ),
-      constMeta: kCrateApiEnigoEnigoTextConstMeta,
-      argValues: [that, text],
-      apiImpl: this,
-    ));
-  }
-
-  TaskConstMeta get kCrateApiEnigoEnigoTextConstMeta => const TaskConstMeta(
-        debugName: "Enigo_text",
-        argNames: ["that", "text"],
-      );
-
-  @override
-  Future<void> crateApiEnigoInitApp() {
-    return handler.executeNormal(NormalTask(
-      callFfi: (port_) {
-        final serializer = SseSerializer(generalizedFrbRustBinding);
-        pdeCallFfi(generalizedFrbRustBinding, serializer,
-            funcId: 23, port: port_);
-      },
-      codec: SseCodec(
-        decodeSuccessData: sse_decode_unit,
-        decodeErrorData: null,
-      ),
-      constMeta: kCrateApiEnigoInitAppConstMeta,
-      argValues: [],
-      apiImpl: this,
-    ));
-  }
-
-  TaskConstMeta get kCrateApiEnigoInitAppConstMeta => const TaskConstMeta(
-        debugName: "init_app",
-        argNames: [],
-      );
-
-  RustArcIncrementStrongCountFnType get rust_arc_increment_strong_count_Enigo =>
-      wire.rust_arc_increment_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerEnigo;
-
-  RustArcDecrementStrongCountFnType get rust_arc_decrement_strong_count_Enigo =>
-      wire.rust_arc_decrement_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerEnigo;
-
-  RustArcIncrementStrongCountFnType
-      get rust_arc_increment_strong_count_Button => wire
-          .rust_arc_increment_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInner_Button;
-
-  RustArcDecrementStrongCountFnType
-      get rust_arc_decrement_strong_count_Button => wire
-          .rust_arc_decrement_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInner_Button;
-
-  @protected
-  Enigo
-      dco_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerEnigo(
-          dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    return EnigoImpl.frbInternalDcoDecode(raw as List<dynamic>);
-  }
-
-  @protected
-  Button
-      dco_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInner_Button(
-          dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    return ButtonImpl.frbInternalDcoDecode(raw as List<dynamic>);
-  }
-
-  @protected
-  Enigo
-      dco_decode_Auto_RefMut_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerEnigo(
-          dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    return EnigoImpl.frbInternalDcoDecode(raw as List<dynamic>);
-  }
-
-  @protected
-  Button
-      dco_decode_Auto_RefMut_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInner_Button(
-          dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    return ButtonImpl.frbInternalDcoDecode(raw as List<dynamic>);
-  }
-
-  @protected
-  Enigo
-      dco_decode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerEnigo(
-          dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    return EnigoImpl.frbInternalDcoDecode(raw as List<dynamic>);
-  }
-
-  @protected
-  PhysicalKeyboardKey
-      dco_decode_CustomSerializer_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerKey(
-          dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    throw UnimplementedError(
-        'Not implemented in this codec, please use the other one');
-  }
-
-  @protected
-  Enigo
-      dco_decode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerEnigo(
-          dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    return EnigoImpl.frbInternalDcoDecode(raw as List<dynamic>);
-  }
-
-  @protected
-  Button
-      dco_decode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInner_Button(
-          dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    return ButtonImpl.frbInternalDcoDecode(raw as List<dynamic>);
-  }
-
-  @protected
-  String dco_decode_String(dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    return raw as String;
-  }
-
-  @protected
-  Axis dco_decode_axis(dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    return Axis.values[raw as int];
-  }
-
-  @protected
-  bool dco_decode_bool(dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    return raw as bool;
-  }
-
-  @protected
-  PlatformInt64 dco_decode_box_autoadd_i_64(dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    return dco_decode_i_64(raw);
-  }
-
-  @protected
-  Settings dco_decode_box_autoadd_settings(dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    return dco_decode_settings(raw);
-  }
-
-  @protected
-  BigInt dco_decode_box_autoadd_usize(dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    return dco_decode_usize(raw);
-  }
-
-  @protected
-  Coordinate dco_decode_coordinate(dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    return Coordinate.values[raw as int];
-  }
-
-  @protected
-  Direction dco_decode_direction(dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    return Direction.values[raw as int];
-  }
-
-  @protected
-  int dco_decode_i_32(dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    return raw as int;
-  }
-
-  @protected
-  PlatformInt64 dco_decode_i_64(dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    return dcoDecodeI64(raw);
-  }
-
-  @protected
-  Uint8List dco_decode_list_prim_u_8_strict(dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    return raw as Uint8List;
-  }
-
-  @protected
-  String? dco_decode_opt_String(dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    return raw == null ? null : dco_decode_String(raw);
-  }
-
-  @protected
-  PlatformInt64? dco_decode_opt_box_autoadd_i_64(dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    return raw == null ? null : dco_decode_box_autoadd_i_64(raw);
-  }
-
-  @protected
-  BigInt? dco_decode_opt_box_autoadd_usize(dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    return raw == null ? null : dco_decode_box_autoadd_usize(raw);
-  }
-
-  @protected
-  (int, int) dco_decode_record_i_32_i_32(dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    final arr = raw as List<dynamic>;
-    if (arr.length != 2) {
-      throw Exception('Expected 2 elements, got ${arr.length}');
-    }
-    return (
-      dco_decode_i_32(arr[0]),
-      dco_decode_i_32(arr[1]),
-    );
-  }
-
-  @protected
-  Settings dco_decode_settings(dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    final arr = raw as List<dynamic>;
-    if (arr.length != 9)
-      throw Exception('unexpected arr length: expect 9 but see ${arr.length}');
-    return Settings(
-      linuxDelay: dco_decode_u_32(arr[0]),
-      x11Display: dco_decode_opt_String(arr[1]),
-      waylandDisplay: dco_decode_opt_String(arr[2]),
-      windowsDwExtraInfo: dco_decode_opt_box_autoadd_usize(arr[3]),
-      eventSourceUserData: dco_decode_opt_box_autoadd_i_64(arr[4]),
-      releaseKeysWhenDropped: dco_decode_bool(arr[5]),
-      openPromptToGetPermissions: dco_decode_bool(arr[6]),
-      independentOfKeyboardState: dco_decode_bool(arr[7]),
-      windowsSubjectToMouseSpeedAndAccelerationLevel: dco_decode_bool(arr[8]),
-    );
-  }
-
-  @protected
-  int dco_decode_u_16(dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    return raw as int;
-  }
-
-  @protected
-  int dco_decode_u_32(dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    return raw as int;
-  }
-
-  @protected
-  int dco_decode_u_8(dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    return raw as int;
-  }
-
-  @protected
-  void dco_decode_unit(dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    return;
-  }
-
-  @protected
-  BigInt dco_decode_usize(dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    return dcoDecodeU64(raw);
-  }
-
-  @protected
-  Enigo
-      sse_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerEnigo(
-          SseDeserializer deserializer) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    return EnigoImpl.frbInternalSseDecode(
-        sse_decode_usize(deserializer), sse_decode_i_32(deserializer));
-  }
-
-  @protected
-  Button
-      sse_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInner_Button(
-          SseDeserializer deserializer) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    return ButtonImpl.frbInternalSseDecode(
-        sse_decode_usize(deserializer), sse_decode_i_32(deserializer));
-  }
-
-  @protected
-  Enigo
-      sse_decode_Auto_RefMut_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerEnigo(
-          SseDeserializer deserializer) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    return EnigoImpl.frbInternalSseDecode(
-        sse_decode_usize(deserializer), sse_decode_i_32(deserializer));
-  }
-
-  @protected
-  Button
-      sse_decode_Auto_RefMut_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInner_Button(
-          SseDeserializer deserializer) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    return ButtonImpl.frbInternalSseDecode(
-        sse_decode_usize(deserializer), sse_decode_i_32(deserializer));
-  }
-
-  @protected
-  Enigo
-      sse_decode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerEnigo(
-          SseDeserializer deserializer) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    return EnigoImpl.frbInternalSseDecode(
-        sse_decode_usize(deserializer), sse_decode_i_32(deserializer));
-  }
-
-  @protected
-  PhysicalKeyboardKey
-      sse_decode_CustomSerializer_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerKey(
-          SseDeserializer deserializer) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    var inner = sse_decode_u_32(deserializer);
-    return PhysicalKeyboardKey(inner);
-  }
-
-  @protected
-  Enigo
-      sse_decode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerEnigo(
-          SseDeserializer deserializer) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    return EnigoImpl.frbInternalSseDecode(
-        sse_decode_usize(deserializer), sse_decode_i_32(deserializer));
-  }
-
-  @protected
-  Button
-      sse_decode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInner_Button(
-          SseDeserializer deserializer) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    return ButtonImpl.frbInternalSseDecode(
-        sse_decode_usize(deserializer), sse_decode_i_32(deserializer));
-  }
-
-  @protected
-  String sse_decode_String(SseDeserializer deserializer) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    var inner = sse_decode_list_prim_u_8_strict(deserializer);
-    return utf8.decoder.convert(inner);
-  }
-
-  @protected
-  Axis sse_decode_axis(SseDeserializer deserializer) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    var inner = sse_decode_i_32(deserializer);
-    return Axis.values[inner];
-  }
-
-  @protected
-  bool sse_decode_bool(SseDeserializer deserializer) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    return deserializer.buffer.getUint8() != 0;
-  }
-
-  @protected
-  PlatformInt64 sse_decode_box_autoadd_i_64(SseDeserializer deserializer) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    return (sse_decode_i_64(deserializer));
-  }
-
-  @protected
-  Settings sse_decode_box_autoadd_settings(SseDeserializer deserializer) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    return (sse_decode_settings(deserializer));
-  }
-
-  @protected
-  BigInt sse_decode_box_autoadd_usize(SseDeserializer deserializer) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    return (sse_decode_usize(deserializer));
-  }
-
-  @protected
-  Coordinate sse_decode_coordinate(SseDeserializer deserializer) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    var inner = sse_decode_i_32(deserializer);
-    return Coordinate.values[inner];
-  }
-
-  @protected
-  Direction sse_decode_direction(SseDeserializer deserializer) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    var inner = sse_decode_i_32(deserializer);
-    return Direction.values[inner];
-  }
-
-  @protected
-  int sse_decode_i_32(SseDeserializer deserializer) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    return deserializer.buffer.getInt32();
-  }
-
-  @protected
-  PlatformInt64 sse_decode_i_64(SseDeserializer deserializer) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    return deserializer.buffer.getPlatformInt64();
-  }
-
-  @protected
-  Uint8List sse_decode_list_prim_u_8_strict(SseDeserializer deserializer) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    var len_ = sse_decode_i_32(deserializer);
-    return deserializer.buffer.getUint8List(len_);
-  }
-
-  @protected
-  String? sse_decode_opt_String(SseDeserializer deserializer) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-
-    if (sse_decode_bool(deserializer)) {
-      return (sse_decode_String(deserializer));
-    } else {
-      return null;
-    }
-  }
-
-  @protected
-  PlatformInt64? sse_decode_opt_box_autoadd_i_64(SseDeserializer deserializer) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-
-    if (sse_decode_bool(deserializer)) {
-      return (sse_decode_box_autoadd_i_64(deserializer));
-    } else {
-      return null;
-    }
-  }
-
-  @protected
-  BigInt? sse_decode_opt_box_autoadd_usize(SseDeserializer deserializer) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-
-    if (sse_decode_bool(deserializer)) {
-      return (sse_decode_box_autoadd_usize(deserializer));
-    } else {
-      return null;
-    }
-  }
-
-  @protected
-  (int, int) sse_decode_record_i_32_i_32(SseDeserializer deserializer) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    var var_field0 = sse_decode_i_32(deserializer);
-    var var_field1 = sse_decode_i_32(deserializer);
-    return (var_field0, var_field1);
-  }
-
-  @protected
-  Settings sse_decode_settings(SseDeserializer deserializer) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    var var_linuxDelay = sse_decode_u_32(deserializer);
-    var var_x11Display = sse_decode_opt_String(deserializer);
-    var var_waylandDisplay = sse_decode_opt_String(deserializer);
-    var var_windowsDwExtraInfo = sse_decode_opt_box_autoadd_usize(deserializer);
-    var var_eventSourceUserData = sse_decode_opt_box_autoadd_i_64(deserializer);
-    var var_releaseKeysWhenDropped = sse_decode_bool(deserializer);
-    var var_openPromptToGetPermissions = sse_decode_bool(deserializer);
-    var var_independentOfKeyboardState = sse_decode_bool(deserializer);
-    var var_windowsSubjectToMouseSpeedAndAccelerationLevel =
-        sse_decode_bool(deserializer);
-    return Settings(
-        linuxDelay: var_linuxDelay,
-        x11Display: var_x11Display,
-        waylandDisplay: var_waylandDisplay,
-        windowsDwExtraInfo: var_windowsDwExtraInfo,
-        eventSourceUserData: var_eventSourceUserData,
-        releaseKeysWhenDropped: var_releaseKeysWhenDropped,
-        openPromptToGetPermissions: var_openPromptToGetPermissions,
-        independentOfKeyboardState: var_independentOfKeyboardState,
-        windowsSubjectToMouseSpeedAndAccelerationLevel:
-            var_windowsSubjectToMouseSpeedAndAccelerationLevel);
-  }
-
-  @protected
-  int sse_decode_u_16(SseDeserializer deserializer) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    return deserializer.buffer.getUint16();
-  }
-
-  @protected
-  int sse_decode_u_32(SseDeserializer deserializer) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    return deserializer.buffer.getUint32();
-  }
-
-  @protected
-  int sse_decode_u_8(SseDeserializer deserializer) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    return deserializer.buffer.getUint8();
-  }
-
-  @protected
-  void sse_decode_unit(SseDeserializer deserializer) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-  }
-
-  @protected
-  BigInt sse_decode_usize(SseDeserializer deserializer) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    return deserializer.buffer.getBigUint64();
-  }
-
-  @protected
-  void
-      sse_encode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerEnigo(
-          Enigo self, SseSerializer serializer) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    sse_encode_usize(
-        (self as EnigoImpl).frbInternalSseEncode(move: true), serializer);
-  }
-
-  @protected
-  void
-      sse_encode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInner_Button(
-          Button self, SseSerializer serializer) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    sse_encode_usize(
-        (self as ButtonImpl).frbInternalSseEncode(move: true), serializer);
-  }
-
-  @protected
-  void
-      sse_encode_Auto_RefMut_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerEnigo(
-          Enigo self, SseSerializer serializer) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    sse_encode_usize(
-        (self as EnigoImpl).frbInternalSseEncode(move: false), serializer);
-  }
-
-  @protected
-  void
-      sse_encode_Auto_RefMut_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInner_Button(
-          Button self, SseSerializer serializer) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    sse_encode_usize(
-        (self as ButtonImpl).frbInternalSseEncode(move: false), serializer);
-  }
-
-  @protected
-  void
-      sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerEnigo(
-          Enigo self, SseSerializer serializer) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    sse_encode_usize(
-        (self as EnigoImpl).frbInternalSseEncode(move: false), serializer);
-  }
-
-  @protected
-  void
-      sse_encode_CustomSerializer_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerKey(
-          PhysicalKeyboardKey self, SseSerializer serializer) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    sse_encode_u_32(self.usbHidUsage, serializer);
-  }
-
-  @protected
-  void
-      sse_encode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerEnigo(
-          Enigo self, SseSerializer serializer) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    sse_encode_usize(
-        (self as EnigoImpl).frbInternalSseEncode(move: null), serializer);
-  }
-
-  @protected
-  void
-      sse_encode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInner_Button(
-          Button self, SseSerializer serializer) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    sse_encode_usize(
-        (self as ButtonImpl).frbInternalSseEncode(move: null), serializer);
-  }
-
-  @protected
-  void sse_encode_String(String self, SseSerializer serializer) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    sse_encode_list_prim_u_8_strict(utf8.encoder.convert(self), serializer);
-  }
-
-  @protected
-  void sse_encode_axis(Axis self, SseSerializer serializer) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    sse_encode_i_32(self.index, serializer);
-  }
-
-  @protected
-  void sse_encode_bool(bool self, SseSerializer serializer) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    serializer.buffer.putUint8(self ? 1 : 0);
-  }
-
-  @protected
-  void sse_encode_box_autoadd_i_64(
-      PlatformInt64 self, SseSerializer serializer) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    sse_encode_i_64(self, serializer);
-  }
-
-  @protected
-  void sse_encode_box_autoadd_settings(
-      Settings self, SseSerializer serializer) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    sse_encode_settings(self, serializer);
-  }
-
-  @protected
-  void sse_encode_box_autoadd_usize(BigInt self, SseSerializer serializer) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    sse_encode_usize(self, serializer);
-  }
-
-  @protected
-  void sse_encode_coordinate(Coordinate self, SseSerializer serializer) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    sse_encode_i_32(self.index, serializer);
-  }
-
-  @protected
-  void sse_encode_direction(Direction self, SseSerializer serializer) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    sse_encode_i_32(self.index, serializer);
-  }
-
-  @protected
-  void sse_encode_i_32(int self, SseSerializer serializer) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    serializer.buffer.putInt32(self);
-  }
-
-  @protected
-  void sse_encode_i_64(PlatformInt64 self, SseSerializer serializer) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    serializer.buffer.putPlatformInt64(self);
-  }
-
-  @protected
-  void sse_encode_list_prim_u_8_strict(
-      Uint8List self, SseSerializer serializer) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    sse_encode_i_32(self.length, serializer);
-    serializer.buffer.putUint8List(self);
-  }
-
-  @protected
-  void sse_encode_opt_String(String? self, SseSerializer serializer) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-
-    sse_encode_bool(self != null, serializer);
-    if (self != null) {
-      sse_encode_String(self, serializer);
-    }
-  }
-
-  @protected
-  void sse_encode_opt_box_autoadd_i_64(
-      PlatformInt64? self, SseSerializer serializer) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-
-    sse_encode_bool(self != null, serializer);
-    if (self != null) {
-      sse_encode_box_autoadd_i_64(self, serializer);
-    }
-  }
-
-  @protected
-  void sse_encode_opt_box_autoadd_usize(
-      BigInt? self, SseSerializer serializer) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-
-    sse_encode_bool(self != null, serializer);
-    if (self != null) {
-      sse_encode_box_autoadd_usize(self, serializer);
-    }
-  }
-
-  @protected
-  void sse_encode_record_i_32_i_32((int, int) self, SseSerializer serializer) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    sse_encode_i_32(self.$1, serializer);
-    sse_encode_i_32(self.$2, serializer);
-  }
-
-  @protected
-  void sse_encode_settings(Settings self, SseSerializer serializer) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    sse_encode_u_32(self.linuxDelay, serializer);
-    sse_encode_opt_String(self.x11Display, serializer);
-    sse_encode_opt_String(self.waylandDisplay, serializer);
-    sse_encode_opt_box_autoadd_usize(self.windowsDwExtraInfo, serializer);
-    sse_encode_opt_box_autoadd_i_64(self.eventSourceUserData, serializer);
-    sse_encode_bool(self.releaseKeysWhenDropped, serializer);
-    sse_encode_bool(self.openPromptToGetPermissions, serializer);
-    sse_encode_bool(self.independentOfKeyboardState, serializer);
-    sse_encode_bool(
-        self.windowsSubjectToMouseSpeedAndAccelerationLevel, serializer);
-  }
-
-  @protected
-  void sse_encode_u_16(int self, SseSerializer serializer) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    serializer.buffer.putUint16(self);
-  }
-
-  @protected
-  void sse_encode_u_32(int self, SseSerializer serializer) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    serializer.buffer.putUint32(self);
-  }
-
-  @protected
-  void sse_encode_u_8(int self, SseSerializer serializer) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    serializer.buffer.putUint8(self);
-  }
-
-  @protected
-  void sse_encode_unit(void self, SseSerializer serializer) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-  }
-
-  @protected
-  void sse_encode_usize(BigInt self, SseSerializer serializer) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    serializer.buffer.putBigUint64(self);
-  }
-}
-
-@sealed
-class ButtonImpl extends RustOpaque implements Button {
-  // Not to be used by end users
-  ButtonImpl.frbInternalDcoDecode(List<dynamic> wire)
-      : super.frbInternalDcoDecode(wire, _kStaticData);
-
-  // Not to be used by end users
-  ButtonImpl.frbInternalSseDecode(BigInt ptr, int externalSizeOnNative)
-      : super.frbInternalSseDecode(ptr, externalSizeOnNative, _kStaticData);
-
-  static final _kStaticData = RustArcStaticData(
-    rustArcIncrementStrongCount:
-        RustLib.instance.api.rust_arc_increment_strong_count_Button,
-    rustArcDecrementStrongCount:
-        RustLib.instance.api.rust_arc_decrement_strong_count_Button,
-    rustArcDecrementStrongCountPtr:
-        RustLib.instance.api.rust_arc_decrement_strong_count_ButtonPtr,
-  );
-
-  String toString() => RustLib.instance.api.crateApiEnigoButtonToString(
-        that: this,
-      );
-}
-
-@sealed
-class EnigoImpl extends RustOpaque implements Enigo {
-  // Not to be used by end users
-  EnigoImpl.frbInternalDcoDecode(List<dynamic> wire)
-      : super.frbInternalDcoDecode(wire, _kStaticData);
-
-  // Not to be used by end users
-  EnigoImpl.frbInternalSseDecode(BigInt ptr, int externalSizeOnNative)
-      : super.frbInternalSseDecode(ptr, externalSizeOnNative, _kStaticData);
-
-  static final _kStaticData = RustArcStaticData(
-    rustArcIncrementStrongCount:
-        RustLib.instance.api.rust_arc_increment_strong_count_Enigo,
-    rustArcDecrementStrongCount:
-        RustLib.instance.api.rust_arc_decrement_strong_count_Enigo,
-    rustArcDecrementStrongCountPtr:
-        RustLib.instance.api.rust_arc_decrement_strong_count_EnigoPtr,
-  );
-
-  void button({required Button button, required Direction direction}) =>
-      RustLib.instance.api.crateApiEnigoEnigoButton(
-          that: this, button: button, direction: direction);
-
-  void key({required PhysicalKeyboardKey key, required Direction direction}) =>
-      RustLib.instance.api
-          .crateApiEnigoEnigoKey(that: this, key: key, direction: direction);
-
-  (int, int) location() => RustLib.instance.api.crateApiEnigoEnigoLocation(
-        that: this,
-      );
-
-  (int, int) mainDisplay() =>
-      RustLib.instance.api.crateApiEnigoEnigoMainDisplay(
-        that: this,
-      );
-
-  void moveMouse(
-          {required int x, required int y, required Coordinate coordinate}) =>
-      RustLib.instance.api.crateApiEnigoEnigoMoveMouse(
-          that: this, x: x, y: y, coordinate: coordinate);
-
-  void raw({required int keycode, required Direction direction}) =>
-      RustLib.instance.api.crateApiEnigoEnigoRaw(
-          that: this, keycode: keycode, direction: direction);
-
-  void scroll({required int length, required Axis axis}) => RustLib.instance.api
-      .crateApiEnigoEnigoScroll(that: this, length: length, axis: axis);
-
-  void text({required String text}) =>
-      RustLib.instance.api.crateApiEnigoEnigoText(that: this, text: text);
-}
+Button crateApiEnigoButtonRight();
+
+Button crateApiEnigoButtonScrollDown();
+
+Button crateApiEnigoButtonScrollLeft();
+
+Button crateApiEnigoButtonScrollRight();
+
+Button crateApiEnigoButtonScrollUp();
+
+String crateApiEnigoButtonToString({required Button that });
+
+BackendInfo crateApiEnigoEnigoBackendInfo({required Enigo that });
+
+void crateApiEnigoEnigoButton({required Enigo that , required Button button , required Direction direction });
+
+Capabilities crateApiEnigoEnigoCapabilities({required Enigo that });
+
+void crateApiEnigoEnigoClearMockCalls({required Enigo that });
+
+Future<void> crateApiEnigoEnigoClick({required Enigo that , required Button button , required int count , int? intervalMs });
+
+int crateApiEnigoEnigoDoubleClickTime({required Enigo that });
+
+List<TraceEntry> crateApiEnigoEnigoDryRunTrace({required Enigo that });
+
+String crateApiEnigoEnigoDryRunTraceJson({required Enigo that });
+
+String crateApiEnigoEnigoDryRunTraceText({required Enigo that });
+
+bool crateApiEnigoEnigoHasPermission({required bool openPrompt });
+
+HeldInput crateApiEnigoEnigoHeld({required Enigo that });
+
+Future<void> crateApiEnigoEnigoHoldKey({required Enigo that , required PhysicalKeyboardKey key , required int durationMs , required RepeatPolicy repeat });
+
+bool crateApiEnigoEnigoIsAlive({required Enigo that });
+
+bool crateApiEnigoEnigoIsDryRun({required Enigo that });
+
+void crateApiEnigoEnigoKey({required Enigo that , required PhysicalKeyboardKey key , required Direction direction });
+
+(int,int) crateApiEnigoEnigoLocation({required Enigo that });
+
+LockState crateApiEnigoEnigoLockState({required Enigo that });
+
+(int,int) crateApiEnigoEnigoMainDisplay({required Enigo that });
+
+Metrics crateApiEnigoEnigoMetrics({required Enigo that });
+
+Enigo crateApiEnigoEnigoMock({required int displayWidth , required int displayHeight });
+
+List<MockCall> crateApiEnigoEnigoMockCalls({required Enigo that });
+
+ModifierState crateApiEnigoEnigoModifierState({required Enigo that });
+
+void crateApiEnigoEnigoMoveMouse({required Enigo that , required int x , required int y , required Coordinate coordinate });
+
+Enigo crateApiEnigoEnigoNew({required Settings settings });
+
+PacerStats? crateApiEnigoEnigoPacerStats({required Enigo that });
+
+Future<void> crateApiEnigoEnigoPlay({required Enigo that , required Recording recording , required PlaybackOptions options });
+
+Policy? crateApiEnigoEnigoPolicy({required Enigo that });
+
+Enigo crateApiEnigoEnigoPreset();
+
+void crateApiEnigoEnigoRaw({required Enigo that , required int keycode , required Direction direction });
+
+void crateApiEnigoEnigoReconnect({required Enigo that });
+
+Stream<ReconnectEvent> crateApiEnigoEnigoReconnects({required Enigo that });
+
+void crateApiEnigoEnigoReleaseAll({required Enigo that });
+
+Future<Enigo> crateApiEnigoEnigoRemote({required String address , String? token });
+
+void crateApiEnigoEnigoResetMetrics({required Enigo that });
+
+void crateApiEnigoEnigoScroll({required Enigo that , required int length , required Axis axis });
+
+Future<void> crateApiEnigoEnigoScrollSmooth({required Enigo that , required double dx , required double dy , required int durationMs });
+
+String crateApiEnigoEnigoSessionId({required Enigo that });
+
+void crateApiEnigoEnigoSetAuditLog({required Enigo that , String? path });
+
+void crateApiEnigoEnigoSetDryRun({required Enigo that , required bool enabled });
+
+void crateApiEnigoEnigoSetPacer({required Enigo that , PacerConfig? config });
+
+void crateApiEnigoEnigoSetPolicy({required Enigo that , Policy? policy });
+
+void crateApiEnigoEnigoSetReconnectPolicy({required Enigo that , required ReconnectPolicy policy });
+
+Settings crateApiEnigoEnigoSettings({required Enigo that });
+
+void crateApiEnigoEnigoText({required Enigo that , required String text });
+
+void crateApiEnigoEnigoTextWithOptions({required Enigo that , required String text , required TextOptions options });
+
+void crateApiEnigoEnigoUpdateSettings({required Enigo that , required SettingsPatch patch });
+
+Enigo crateApiEnigoEnigoWithBackend({required Settings settings , required Backend backend });
+
+Enigo crateApiEnigoEnigoWithBackends({required Settings settings , required List<Backend> preference });
+
+Stream<HotkeyEvent> crateApiHotkeyHotkeyManagerActivations({required HotkeyManager that });
+
+HotkeyManager crateApiHotkeyHotkeyManagerNew({required Settings settings });
+
+int crateApiHotkeyHotkeyManagerRegister({required HotkeyManager that , required String accelerator });
+
+void crateApiHotkeyHotkeyManagerUnregister({required HotkeyManager that , required int id });
+
+void crateApiHotkeyHotkeyManagerUnregisterAll({required HotkeyManager that });
+
+String crateApiServerInputServerAddress({required InputServer that });
+
+int crateApiServerInputServerConnections({required InputServer that });
+
+bool crateApiServerInputServerIsRunning({required InputServer that });
+
+InputServer crateApiServerInputServerStart({required Enigo enigo , required ServerConfig config });
+
+void crateApiServerInputServerStop({required InputServer that });
+
+bool crateApiListenerListenerIsListening({required Listener that });
+
+Stream<InputEvent> crateApiListenerListenerListen({required Listener that });
+
+Listener crateApiListenerListenerNew({required Settings settings });
+
+void crateApiListenerListenerStop({required Listener that });
+
+MockAction crateApiMockMockCallAutoAccessorGetAction({required MockCall that });
+
+BigInt crateApiMockMockCallAutoAccessorGetTimestampUs({required MockCall that });
+
+void crateApiMockMockCallAutoAccessorSetAction({required MockCall that , required MockAction action });
+
+void crateApiMockMockCallAutoAccessorSetTimestampUs({required MockCall that , required BigInt timestampUs });
+
+bool crateApiRecorderRecorderIsRecording({required Recorder that });
+
+Recorder crateApiRecorderRecorderNew({required Settings settings });
+
+void crateApiRecorderRecorderStart({required Recorder that });
+
+Recording crateApiRecorderRecorderStop({required Recorder that });
+
+Future<Recording> crateApiRecorderRecordingDefault();
+
+BigInt crateApiRecorderRecordingDurationMs({required Recording that });
+
+Recording crateApiRecorderRecordingFromJson({required String json });
+
+bool crateApiRecorderRecordingIsEmpty({required Recording that });
+
+BigInt crateApiRecorderRecordingLen({required Recording that });
+
+Recording crateApiRecorderRecordingLoad({required String path });
+
+void crateApiRecorderRecordingSave({required Recording that , required String path });
+
+String crateApiRecorderRecordingToJson({required Recording that });
+
+bool crateApiRemapRemapperEnabled({required Remapper that });
+
+Remapper crateApiRemapRemapperFromJson({required String config , required Settings settings });
+
+bool crateApiRemapRemapperIsRunning({required Remapper that });
+
+void crateApiRemapRemapperLoadJson({required Remapper that , required String config });
+
+void crateApiRemapRemapperSetEnabled({required Remapper that , required bool enabled });
+
+void crateApiRemapRemapperStart({required Remapper that });
+
+void crateApiRemapRemapperStop({required Remapper that });
+
+MockAction crateApiDryRunTraceEntryAutoAccessorGetAction({required TraceEntry that });
+
+BigInt crateApiDryRunTraceEntryAutoAccessorGetOffsetMs({required TraceEntry that });
+
+(int,int)? crateApiDryRunTraceEntryAutoAccessorGetTarget({required TraceEntry that });
+
+String? crateApiDryRunTraceEntryAutoAccessorGetWarning({required TraceEntry that });
+
+void crateApiDryRunTraceEntryAutoAccessorSetAction({required TraceEntry that , required MockAction action });
+
+void crateApiDryRunTraceEntryAutoAccessorSetOffsetMs({required TraceEntry that , required BigInt offsetMs });
+
+void crateApiDryRunTraceEntryAutoAccessorSetTarget({required TraceEntry that , (int,int)? target });
+
+void crateApiDryRunTraceEntryAutoAccessorSetWarning({required TraceEntry that , String? warning });
+
+String crateApiDryRunTraceEntryDescribe({required TraceEntry that });
+
+List<Backend> crateApiBackendBackendCompiled();
+
+Future<Backend> crateApiBackendBackendDefault();
+
+bool crateApiBackendBackendIsCompiled({required Backend that });
+
+Future<EventGaps> crateApiPacerEventGapsDefault();
+
+Future<HeldInput> crateApiEnigoHeldInputDefault();
+
+bool crateApiEnigoHeldInputIsEmpty({required HeldInput that });
+
+Future<Histogram> crateApiMetricsHistogramDefault();
+
+Future<void> crateApiEnigoInitApp();
+
+Settings crateApiSettingsLoadSettings({required String path });
+
+Future<LockState> crateApiSystemLockStateDefault();
+
+Stream<LogRecord> crateApiLoggingLogStream({required LogLevel level });
+
+void crateApiLoggingLogToFile({required String directory , required String prefix , required LogRotation rotation , required int maxFiles , required LogLevel level });
+
+String crateApiMetricsMetricsToJson({required Metrics that });
+
+String crateApiMetricsMetricsToPrometheus({required Metrics that });
+
+Future<ModifierState> crateApiSystemModifierStateDefault();
+
+Future<PacerConfig> crateApiPacerPacerConfigDefault();
+
+PacerConfig crateApiPacerPacerConfigPreset();
+
+Future<PacerStats> crateApiPacerPacerStatsDefault();
+
+Future<PlaybackOptions> crateApiRecorderPlaybackOptionsDefault();
+
+PlaybackOptions crateApiRecorderPlaybackOptionsPreset();
+
+Future<Policy> crateApiPolicyPolicyDefault();
+
+Policy crateApiPolicyPolicyPreset();
+
+void crateApiSettingsSaveSettings({required Settings settings , required String path });
+
+Future<ServerConfig> crateApiServerServerConfigDefault();
+
+ServerConfig crateApiServerServerConfigPreset();
+
+Settings crateApiSettingsSettingsFromEnv({required Settings settings });
+
+Settings crateApiSettingsSettingsFromJson({required String json });
+
+Future<SettingsPatch> crateApiEnigoSettingsPatchDefault();
+
+String crateApiSettingsSettingsToJson({required Settings settings });
+
+void crateApiLoggingStopLogFile();
+
+void crateApiLoggingStopLogStream();
+
+Future<TextOptions> crateApiEnigoTextOptionsDefault();
+
+void crateApiSettingsValidateSettings({required Settings settings });
+
+BigInt crateApiAuditVerifyAuditLog({required String path });
+
+RustArcIncrementStrongCountFnType get rust_arc_increment_strong_count_Enigo;
+
+RustArcDecrementStrongCountFnType get rust_arc_decrement_strong_count_Enigo;
+
+CrossPlatformFinalizerArg get rust_arc_decrement_strong_count_EnigoPtr;
+
+RustArcIncrementStrongCountFnType get rust_arc_increment_strong_count_HotkeyManager;
+
+RustArcDecrementStrongCountFnType get rust_arc_decrement_strong_count_HotkeyManager;
+
+CrossPlatformFinalizerArg get rust_arc_decrement_strong_count_HotkeyManagerPtr;
+
+RustArcIncrementStrongCountFnType get rust_arc_increment_strong_count_InputEvent;
+
+RustArcDecrementStrongCountFnType get rust_arc_decrement_strong_count_InputEvent;
+
+CrossPlatformFinalizerArg get rust_arc_decrement_strong_count_InputEventPtr;
+
+RustArcIncrementStrongCountFnType get rust_arc_increment_strong_count_InputServer;
+
+RustArcDecrementStrongCountFnType get rust_arc_decrement_strong_count_InputServer;
+
+CrossPlatformFinalizerArg get rust_arc_decrement_strong_count_InputServerPtr;
+
+RustArcIncrementStrongCountFnType get rust_arc_increment_strong_count_Listener;
+
+RustArcDecrementStrongCountFnType get rust_arc_decrement_strong_count_Listener;
+
+CrossPlatformFinalizerArg get rust_arc_decrement_strong_count_ListenerPtr;
+
+RustArcIncrementStrongCountFnType get rust_arc_increment_strong_count_MockAction;
+
+RustArcDecrementStrongCountFnType get rust_arc_decrement_strong_count_MockAction;
+
+CrossPlatformFinalizerArg get rust_arc_decrement_strong_count_MockActionPtr;
+
+RustArcIncrementStrongCountFnType get rust_arc_increment_strong_count_MockCall;
+
+RustArcDecrementStrongCountFnType get rust_arc_decrement_strong_count_MockCall;
+
+CrossPlatformFinalizerArg get rust_arc_decrement_strong_count_MockCallPtr;
+
+RustArcIncrementStrongCountFnType get rust_arc_increment_strong_count_Recorder;
+
+RustArcDecrementStrongCountFnType get rust_arc_decrement_strong_count_Recorder;
+
+CrossPlatformFinalizerArg get rust_arc_decrement_strong_count_RecorderPtr;
+
+RustArcIncrementStrongCountFnType get rust_arc_increment_strong_count_Recording;
+
+RustArcDecrementStrongCountFnType get rust_arc_decrement_strong_count_Recording;
+
+CrossPlatformFinalizerArg get rust_arc_decrement_strong_count_RecordingPtr;
+
+RustArcIncrementStrongCountFnType get rust_arc_increment_strong_count_Remapper;
+
+RustArcDecrementStrongCountFnType get rust_arc_decrement_strong_count_Remapper;
+
+CrossPlatformFinalizerArg get rust_arc_decrement_strong_count_RemapperPtr;
+
+RustArcIncrementStrongCountFnType get rust_arc_increment_strong_count_TraceEntry;
+
+RustArcDecrementStrongCountFnType get rust_arc_decrement_strong_count_TraceEntry;
+
+CrossPlatformFinalizerArg get rust_arc_decrement_strong_count_TraceEntryPtr;
+
+RustArcIncrementStrongCountFnType get rust_arc_increment_strong_count_Button;
+
+RustArcDecrementStrongCountFnType get rust_arc_decrement_strong_count_Button;
+
+CrossPlatformFinalizerArg get rust_arc_decrement_strong_count_ButtonPtr;
+
+
+                }
+                
+
+                class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
+                  RustLibApiImpl({
+                    required super.handler,
+                    required super.wire,
+                    required super.generalizedFrbRustBinding,
+                    required super.portManager,
+                  });
+
+                  @override Button crateApiEnigoButtonBack()  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 1)!;
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInner_Button,
+          decodeErrorData: null,
+        )
+        ,
+            constMeta: kCrateApiEnigoButtonBackConstMeta,
+            argValues: [],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiEnigoButtonBackConstMeta => const TaskConstMeta(
+            debugName: "Button_back",
+            argNames: [],
+        );
+        
+
+@override Button crateApiEnigoButtonForward()  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 2)!;
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInner_Button,
+          decodeErrorData: null,
+        )
+        ,
+            constMeta: kCrateApiEnigoButtonForwardConstMeta,
+            argValues: [],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiEnigoButtonForwardConstMeta => const TaskConstMeta(
+            debugName: "Button_forward",
+            argNames: [],
+        );
+        
+
+@override Button crateApiEnigoButtonLeft()  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 3)!;
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInner_Button,
+          decodeErrorData: null,
+        )
+        ,
+            constMeta: kCrateApiEnigoButtonLeftConstMeta,
+            argValues: [],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiEnigoButtonLeftConstMeta => const TaskConstMeta(
+            debugName: "Button_left",
+            argNames: [],
+        );
+        
+
+@override Button crateApiEnigoButtonMiddle()  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 4)!;
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInner_Button,
+          decodeErrorData: null,
+        )
+        ,
+            constMeta: kCrateApiEnigoButtonMiddleConstMeta,
+            argValues: [],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiEnigoButtonMiddleConstMeta => const TaskConstMeta(
+            debugName: "Button_middle",
+            argNames: [],
+        );
+        
+
+@override Button crateApiEnigoButtonNew({required String value })  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_String(value, serializer);
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 5)!;
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInner_Button,
+          decodeErrorData: null,
+        )
+        ,
+            constMeta: kCrateApiEnigoButtonNewConstMeta,
+            argValues: [value],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiEnigoButtonNewConstMeta => const TaskConstMeta(
+            debugName: "Button_new",
+            argNames: ["value"],
+        );
+        
+
+@override Button crateApiEnigoButtonRight()  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 6)!;
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInner_Button,
+          decodeErrorData: null,
+        )
+        ,
+            constMeta: kCrateApiEnigoButtonRightConstMeta,
+            argValues: [],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiEnigoButtonRightConstMeta => const TaskConstMeta(
+            debugName: "Button_right",
+            argNames: [],
+        );
+        
+
+@override Button crateApiEnigoButtonScrollDown()  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 7)!;
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInner_Button,
+          decodeErrorData: null,
+        )
+        ,
+            constMeta: kCrateApiEnigoButtonScrollDownConstMeta,
+            argValues: [],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiEnigoButtonScrollDownConstMeta => const TaskConstMeta(
+            debugName: "Button_scroll_down",
+            argNames: [],
+        );
+        
+
+@override Button crateApiEnigoButtonScrollLeft()  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 8)!;
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInner_Button,
+          decodeErrorData: null,
+        )
+        ,
+            constMeta: kCrateApiEnigoButtonScrollLeftConstMeta,
+            argValues: [],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiEnigoButtonScrollLeftConstMeta => const TaskConstMeta(
+            debugName: "Button_scroll_left",
+            argNames: [],
+        );
+        
+
+@override Button crateApiEnigoButtonScrollRight()  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 9)!;
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInner_Button,
+          decodeErrorData: null,
+        )
+        ,
+            constMeta: kCrateApiEnigoButtonScrollRightConstMeta,
+            argValues: [],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiEnigoButtonScrollRightConstMeta => const TaskConstMeta(
+            debugName: "Button_scroll_right",
+            argNames: [],
+        );
+        
+
+@override Button crateApiEnigoButtonScrollUp()  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 10)!;
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInner_Button,
+          decodeErrorData: null,
+        )
+        ,
+            constMeta: kCrateApiEnigoButtonScrollUpConstMeta,
+            argValues: [],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiEnigoButtonScrollUpConstMeta => const TaskConstMeta(
+            debugName: "Button_scroll_up",
+            argNames: [],
+        );
+        
+
+@override String crateApiEnigoButtonToString({required Button that })  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_Auto_RefMut_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInner_Button(that, serializer);
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 11)!;
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_String,
+          decodeErrorData: null,
+        )
+        ,
+            constMeta: kCrateApiEnigoButtonToStringConstMeta,
+            argValues: [that],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiEnigoButtonToStringConstMeta => const TaskConstMeta(
+            debugName: "Button_to_string",
+            argNames: ["that"],
+        );
+        
+
+@override BackendInfo crateApiEnigoEnigoBackendInfo({required Enigo that })  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerEnigo(that, serializer);
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 12)!;
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_backend_info,
+          decodeErrorData: null,
+        )
+        ,
+            constMeta: kCrateApiEnigoEnigoBackendInfoConstMeta,
+            argValues: [that],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiEnigoEnigoBackendInfoConstMeta => const TaskConstMeta(
+            debugName: "Enigo_backend_info",
+            argNames: ["that"],
+        );
+        
+
+@override void crateApiEnigoEnigoButton({required Enigo that , required Button button , required Direction direction })  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_Auto_RefMut_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerEnigo(that, serializer);
+sse_encode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInner_Button(button, serializer);
+sse_encode_direction(direction, serializer);
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 13)!;
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_unit,
+          decodeErrorData: sse_decode_policy_violation,
+        )
+        ,
+            constMeta: kCrateApiEnigoEnigoButtonConstMeta,
+            argValues: [that, button, direction],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiEnigoEnigoButtonConstMeta => const TaskConstMeta(
+            debugName: "Enigo_button",
+            argNames: ["that", "button", "direction"],
+        );
+        
+
+@override Capabilities crateApiEnigoEnigoCapabilities({required Enigo that })  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerEnigo(that, serializer);
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 14)!;
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_capabilities,
+          decodeErrorData: null,
+        )
+        ,
+            constMeta: kCrateApiEnigoEnigoCapabilitiesConstMeta,
+            argValues: [that],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiEnigoEnigoCapabilitiesConstMeta => const TaskConstMeta(
+            debugName: "Enigo_capabilities",
+            argNames: ["that"],
+        );
+        
+
+@override void crateApiEnigoEnigoClearMockCalls({required Enigo that })  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerEnigo(that, serializer);
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 15)!;
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_unit,
+          decodeErrorData: sse_decode_AnyhowException,
+        )
+        ,
+            constMeta: kCrateApiEnigoEnigoClearMockCallsConstMeta,
+            argValues: [that],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiEnigoEnigoClearMockCallsConstMeta => const TaskConstMeta(
+            debugName: "Enigo_clear_mock_calls",
+            argNames: ["that"],
+        );
+        
+
+@override Future<void> crateApiEnigoEnigoClick({required Enigo that , required Button button , required int count , int? intervalMs })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerEnigo(that, serializer);
+sse_encode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInner_Button(button, serializer);
+sse_encode_u_32(count, serializer);
+sse_encode_opt_box_autoadd_u_32(intervalMs, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 16, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_unit,
+          decodeErrorData: sse_decode_policy_violation,
+        )
+        ,
+            constMeta: kCrateApiEnigoEnigoClickConstMeta,
+            argValues: [that, button, count, intervalMs],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiEnigoEnigoClickConstMeta => const TaskConstMeta(
+            debugName: "Enigo_click",
+            argNames: ["that", "button", "count", "intervalMs"],
+        );
+        
+
+@override int crateApiEnigoEnigoDoubleClickTime({required Enigo that })  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerEnigo(that, serializer);
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 17)!;
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_u_32,
+          decodeErrorData: sse_decode_AnyhowException,
+        )
+        ,
+            constMeta: kCrateApiEnigoEnigoDoubleClickTimeConstMeta,
+            argValues: [that],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiEnigoEnigoDoubleClickTimeConstMeta => const TaskConstMeta(
+            debugName: "Enigo_double_click_time",
+            argNames: ["that"],
+        );
+        
+
+@override List<TraceEntry> crateApiEnigoEnigoDryRunTrace({required Enigo that })  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerEnigo(that, serializer);
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 18)!;
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_list_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerTraceEntry,
+          decodeErrorData: null,
+        )
+        ,
+            constMeta: kCrateApiEnigoEnigoDryRunTraceConstMeta,
+            argValues: [that],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiEnigoEnigoDryRunTraceConstMeta => const TaskConstMeta(
+            debugName: "Enigo_dry_run_trace",
+            argNames: ["that"],
+        );
+        
+
+@override String crateApiEnigoEnigoDryRunTraceJson({required Enigo that })  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerEnigo(that, serializer);
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 19)!;
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_String,
+          decodeErrorData: sse_decode_AnyhowException,
+        )
+        ,
+            constMeta: kCrateApiEnigoEnigoDryRunTraceJsonConstMeta,
+            argValues: [that],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiEnigoEnigoDryRunTraceJsonConstMeta => const TaskConstMeta(
+            debugName: "Enigo_dry_run_trace_json",
+            argNames: ["that"],
+        );
+        
+
+@override String crateApiEnigoEnigoDryRunTraceText({required Enigo that })  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerEnigo(that, serializer);
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 20)!;
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_String,
+          decodeErrorData: null,
+        )
+        ,
+            constMeta: kCrateApiEnigoEnigoDryRunTraceTextConstMeta,
+            argValues: [that],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiEnigoEnigoDryRunTraceTextConstMeta => const TaskConstMeta(
+            debugName: "Enigo_dry_run_trace_text",
+            argNames: ["that"],
+        );
+        
+
+@override bool crateApiEnigoEnigoHasPermission({required bool openPrompt })  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_bool(openPrompt, serializer);
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 21)!;
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_bool,
+          decodeErrorData: null,
+        )
+        ,
+            constMeta: kCrateApiEnigoEnigoHasPermissionConstMeta,
+            argValues: [openPrompt],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiEnigoEnigoHasPermissionConstMeta => const TaskConstMeta(
+            debugName: "Enigo_has_permission",
+            argNames: ["openPrompt"],
+        );
+        
+
+@override HeldInput crateApiEnigoEnigoHeld({required Enigo that })  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerEnigo(that, serializer);
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 22)!;
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_held_input,
+          decodeErrorData: null,
+        )
+        ,
+            constMeta: kCrateApiEnigoEnigoHeldConstMeta,
+            argValues: [that],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiEnigoEnigoHeldConstMeta => const TaskConstMeta(
+            debugName: "Enigo_held",
+            argNames: ["that"],
+        );
+        
+
+@override Future<void> crateApiEnigoEnigoHoldKey({required Enigo that , required PhysicalKeyboardKey key , required int durationMs , required RepeatPolicy repeat })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerEnigo(that, serializer);
+sse_encode_CustomSerializer_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerKey(key, serializer);
+sse_encode_u_32(durationMs, serializer);
+sse_encode_box_autoadd_repeat_policy(repeat, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 23, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_unit,
+          decodeErrorData: sse_decode_AnyhowException,
+        )
+        ,
+            constMeta: kCrateApiEnigoEnigoHoldKeyConstMeta,
+            argValues: [that, key, durationMs, repeat],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiEnigoEnigoHoldKeyConstMeta => const TaskConstMeta(
+            debugName: "Enigo_hold_key",
+            argNames: ["that", "key", "durationMs", "repeat"],
+        );
+        
+
+@override bool crateApiEnigoEnigoIsAlive({required Enigo that })  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerEnigo(that, serializer);
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 24)!;
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_bool,
+          decodeErrorData: null,
+        )
+        ,
+            constMeta: kCrateApiEnigoEnigoIsAliveConstMeta,
+            argValues: [that],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiEnigoEnigoIsAliveConstMeta => const TaskConstMeta(
+            debugName: "Enigo_is_alive",
+            argNames: ["that"],
+        );
+        
+
+@override bool crateApiEnigoEnigoIsDryRun({required Enigo that })  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerEnigo(that, serializer);
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 25)!;
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_bool,
+          decodeErrorData: null,
+        )
+        ,
+            constMeta: kCrateApiEnigoEnigoIsDryRunConstMeta,
+            argValues: [that],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiEnigoEnigoIsDryRunConstMeta => const TaskConstMeta(
+            debugName: "Enigo_is_dry_run",
+            argNames: ["that"],
+        );
+        
+
+@override void crateApiEnigoEnigoKey({required Enigo that , required PhysicalKeyboardKey key , required Direction direction })  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_Auto_RefMut_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerEnigo(that, serializer);
+sse_encode_CustomSerializer_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerKey(key, serializer);
+sse_encode_direction(direction, serializer);
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 26)!;
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_unit,
+          decodeErrorData: sse_decode_policy_violation,
+        )
+        ,
+            constMeta: kCrateApiEnigoEnigoKeyConstMeta,
+            argValues: [that, key, direction],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiEnigoEnigoKeyConstMeta => const TaskConstMeta(
+            debugName: "Enigo_key",
+            argNames: ["that", "key", "direction"],
+        );
+        
+
+@override (int,int) crateApiEnigoEnigoLocation({required Enigo that })  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerEnigo(that, serializer);
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 27)!;
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_record_i_32_i_32,
+          decodeErrorData: null,
+        )
+        ,
+            constMeta: kCrateApiEnigoEnigoLocationConstMeta,
+            argValues: [that],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiEnigoEnigoLocationConstMeta => const TaskConstMeta(
+            debugName: "Enigo_location",
+            argNames: ["that"],
+        );
+        
+
+@override LockState crateApiEnigoEnigoLockState({required Enigo that })  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerEnigo(that, serializer);
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 28)!;
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_lock_state,
+          decodeErrorData: sse_decode_AnyhowException,
+        )
+        ,
+            constMeta: kCrateApiEnigoEnigoLockStateConstMeta,
+            argValues: [that],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiEnigoEnigoLockStateConstMeta => const TaskConstMeta(
+            debugName: "Enigo_lock_state",
+            argNames: ["that"],
+        );
+        
+
+@override (int,int) crateApiEnigoEnigoMainDisplay({required Enigo that })  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerEnigo(that, serializer);
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 29)!;
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_record_i_32_i_32,
+          decodeErrorData: null,
+        )
+        ,
+            constMeta: kCrateApiEnigoEnigoMainDisplayConstMeta,
+            argValues: [that],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiEnigoEnigoMainDisplayConstMeta => const TaskConstMeta(
+            debugName: "Enigo_main_display",
+            argNames: ["that"],
+        );
+        
+
+@override Metrics crateApiEnigoEnigoMetrics({required Enigo that })  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerEnigo(that, serializer);
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 30)!;
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_metrics,
+          decodeErrorData: null,
+        )
+        ,
+            constMeta: kCrateApiEnigoEnigoMetricsConstMeta,
+            argValues: [that],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiEnigoEnigoMetricsConstMeta => const TaskConstMeta(
+            debugName: "Enigo_metrics",
+            argNames: ["that"],
+        );
+        
+
+@override Enigo crateApiEnigoEnigoMock({required int displayWidth , required int displayHeight })  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_i_32(displayWidth, serializer);
+sse_encode_i_32(displayHeight, serializer);
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 31)!;
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerEnigo,
+          decodeErrorData: sse_decode_AnyhowException,
+        )
+        ,
+            constMeta: kCrateApiEnigoEnigoMockConstMeta,
+            argValues: [displayWidth, displayHeight],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiEnigoEnigoMockConstMeta => const TaskConstMeta(
+            debugName: "Enigo_mock",
+            argNames: ["displayWidth", "displayHeight"],
+        );
+        
+
+@override List<MockCall> crateApiEnigoEnigoMockCalls({required Enigo that })  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerEnigo(that, serializer);
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 32)!;
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_list_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerMockCall,
+          decodeErrorData: sse_decode_AnyhowException,
+        )
+        ,
+            constMeta: kCrateApiEnigoEnigoMockCallsConstMeta,
+            argValues: [that],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiEnigoEnigoMockCallsConstMeta => const TaskConstMeta(
+            debugName: "Enigo_mock_calls",
+            argNames: ["that"],
+        );
+        
+
+@override ModifierState crateApiEnigoEnigoModifierState({required Enigo that })  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerEnigo(that, serializer);
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 33)!;
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_modifier_state,
+          decodeErrorData: sse_decode_AnyhowException,
+        )
+        ,
+            constMeta: kCrateApiEnigoEnigoModifierStateConstMeta,
+            argValues: [that],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiEnigoEnigoModifierStateConstMeta => const TaskConstMeta(
+            debugName: "Enigo_modifier_state",
+            argNames: ["that"],
+        );
+        
+
+@override void crateApiEnigoEnigoMoveMouse({required Enigo that , required int x , required int y , required Coordinate coordinate })  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_Auto_RefMut_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerEnigo(that, serializer);
+sse_encode_i_32(x, serializer);
+sse_encode_i_32(y, serializer);
+sse_encode_coordinate(coordinate, serializer);
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 34)!;
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_unit,
+          decodeErrorData: sse_decode_policy_violation,
+        )
+        ,
+            constMeta: kCrateApiEnigoEnigoMoveMouseConstMeta,
+            argValues: [that, x, y, coordinate],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiEnigoEnigoMoveMouseConstMeta => const TaskConstMeta(
+            debugName: "Enigo_move_mouse",
+            argNames: ["that", "x", "y", "coordinate"],
+        );
+        
+
+@override Enigo crateApiEnigoEnigoNew({required Settings settings })  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_box_autoadd_settings(settings, serializer);
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 35)!;
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerEnigo,
+          decodeErrorData: null,
+        )
+        ,
+            constMeta: kCrateApiEnigoEnigoNewConstMeta,
+            argValues: [settings],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiEnigoEnigoNewConstMeta => const TaskConstMeta(
+            debugName: "Enigo_new",
+            argNames: ["settings"],
+        );
+        
+
+@override PacerStats? crateApiEnigoEnigoPacerStats({required Enigo that })  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerEnigo(that, serializer);
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 36)!;
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_opt_box_autoadd_pacer_stats,
+          decodeErrorData: null,
+        )
+        ,
+            constMeta: kCrateApiEnigoEnigoPacerStatsConstMeta,
+            argValues: [that],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiEnigoEnigoPacerStatsConstMeta => const TaskConstMeta(
+            debugName: "Enigo_pacer_stats",
+            argNames: ["that"],
+        );
+        
+
+@override Future<void> crateApiEnigoEnigoPlay({required Enigo that , required Recording recording , required PlaybackOptions options })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerEnigo(that, serializer);
+sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerRecording(recording, serializer);
+sse_encode_box_autoadd_playback_options(options, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 37, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_unit,
+          decodeErrorData: sse_decode_policy_violation,
+        )
+        ,
+            constMeta: kCrateApiEnigoEnigoPlayConstMeta,
+            argValues: [that, recording, options],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiEnigoEnigoPlayConstMeta => const TaskConstMeta(
+            debugName: "Enigo_play",
+            argNames: ["that", "recording", "options"],
+        );
+        
+
+@override Policy? crateApiEnigoEnigoPolicy({required Enigo that })  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerEnigo(that, serializer);
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 38)!;
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_opt_box_autoadd_policy,
+          decodeErrorData: null,
+        )
+        ,
+            constMeta: kCrateApiEnigoEnigoPolicyConstMeta,
+            argValues: [that],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiEnigoEnigoPolicyConstMeta => const TaskConstMeta(
+            debugName: "Enigo_policy",
+            argNames: ["that"],
+        );
+        
+
+@override Enigo crateApiEnigoEnigoPreset()  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 39)!;
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerEnigo,
+          decodeErrorData: null,
+        )
+        ,
+            constMeta: kCrateApiEnigoEnigoPresetConstMeta,
+            argValues: [],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiEnigoEnigoPresetConstMeta => const TaskConstMeta(
+            debugName: "Enigo_preset",
+            argNames: [],
+        );
+        
+
+@override void crateApiEnigoEnigoRaw({required Enigo that , required int keycode , required Direction direction })  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_Auto_RefMut_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerEnigo(that, serializer);
+sse_encode_u_16(keycode, serializer);
+sse_encode_direction(direction, serializer);
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 40)!;
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_unit,
+          decodeErrorData: sse_decode_policy_violation,
+        )
+        ,
+            constMeta: kCrateApiEnigoEnigoRawConstMeta,
+            argValues: [that, keycode, direction],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiEnigoEnigoRawConstMeta => const TaskConstMeta(
+            debugName: "Enigo_raw",
+            argNames: ["that", "keycode", "direction"],
+        );
+        
+
+@override void crateApiEnigoEnigoReconnect({required Enigo that })  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerEnigo(that, serializer);
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 41)!;
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_unit,
+          decodeErrorData: sse_decode_AnyhowException,
+        )
+        ,
+            constMeta: kCrateApiEnigoEnigoReconnectConstMeta,
+            argValues: [that],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiEnigoEnigoReconnectConstMeta => const TaskConstMeta(
+            debugName: "Enigo_reconnect",
+            argNames: ["that"],
+        );
+        
+
+@override Stream<ReconnectEvent> crateApiEnigoEnigoReconnects({required Enigo that })  { 
+            final sink = RustStreamSink<ReconnectEvent>();
+            handler.executeSync(SyncTask(
+            callFfi: () {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerEnigo(that, serializer);
+sse_encode_StreamSink_reconnect_event_Sse(sink, serializer);
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 42)!;
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_unit,
+          decodeErrorData: null,
+        )
+        ,
+            constMeta: kCrateApiEnigoEnigoReconnectsConstMeta,
+            argValues: [that, sink],
+            apiImpl: this,
+        ));
+            return sink.stream;
+             }
+
+
+        TaskConstMeta get kCrateApiEnigoEnigoReconnectsConstMeta => const TaskConstMeta(
+            debugName: "Enigo_reconnects",
+            argNames: ["that", "sink"],
+        );
+        
+
+@override void crateApiEnigoEnigoReleaseAll({required Enigo that })  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_Auto_RefMut_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerEnigo(that, serializer);
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 43)!;
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_unit,
+          decodeErrorData: null,
+        )
+        ,
+            constMeta: kCrateApiEnigoEnigoReleaseAllConstMeta,
+            argValues: [that],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiEnigoEnigoReleaseAllConstMeta => const TaskConstMeta(
+            debugName: "Enigo_release_all",
+            argNames: ["that"],
+        );
+        
+
+@override Future<Enigo> crateApiEnigoEnigoRemote({required String address , String? token })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_String(address, serializer);
+sse_encode_opt_String(token, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 44, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerEnigo,
+          decodeErrorData: sse_decode_AnyhowException,
+        )
+        ,
+            constMeta: kCrateApiEnigoEnigoRemoteConstMeta,
+            argValues: [address, token],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiEnigoEnigoRemoteConstMeta => const TaskConstMeta(
+            debugName: "Enigo_remote",
+            argNames: ["address", "token"],
+        );
+        
+
+@override void crateApiEnigoEnigoResetMetrics({required Enigo that })  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerEnigo(that, serializer);
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 45)!;
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_unit,
+          decodeErrorData: null,
+        )
+        ,
+            constMeta: kCrateApiEnigoEnigoResetMetricsConstMeta,
+            argValues: [that],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiEnigoEnigoResetMetricsConstMeta => const TaskConstMeta(
+            debugName: "Enigo_reset_metrics",
+            argNames: ["that"],
+        );
+        
+
+@override void crateApiEnigoEnigoScroll({required Enigo that , required int length , required Axis axis })  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_Auto_RefMut_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerEnigo(that, serializer);
+sse_encode_i_32(length, serializer);
+sse_encode_axis(axis, serializer);
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 46)!;
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_unit,
+          decodeErrorData: sse_decode_policy_violation,
+        )
+        ,
+            constMeta: kCrateApiEnigoEnigoScrollConstMeta,
+            argValues: [that, length, axis],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiEnigoEnigoScrollConstMeta => const TaskConstMeta(
+            debugName: "Enigo_scroll",
+            argNames: ["that", "length", "axis"],
+        );
+        
+
+@override Future<void> crateApiEnigoEnigoScrollSmooth({required Enigo that , required double dx , required double dy , required int durationMs })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerEnigo(that, serializer);
+sse_encode_f_64(dx, serializer);
+sse_encode_f_64(dy, serializer);
+sse_encode_u_32(durationMs, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 47, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_unit,
+          decodeErrorData: sse_decode_policy_violation,
+        )
+        ,
+            constMeta: kCrateApiEnigoEnigoScrollSmoothConstMeta,
+            argValues: [that, dx, dy, durationMs],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiEnigoEnigoScrollSmoothConstMeta => const TaskConstMeta(
+            debugName: "Enigo_scroll_smooth",
+            argNames: ["that", "dx", "dy", "durationMs"],
+        );
+        
+
+@override String crateApiEnigoEnigoSessionId({required Enigo that })  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerEnigo(that, serializer);
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 48)!;
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_String,
+          decodeErrorData: null,
+        )
+        ,
+            constMeta: kCrateApiEnigoEnigoSessionIdConstMeta,
+            argValues: [that],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiEnigoEnigoSessionIdConstMeta => const TaskConstMeta(
+            debugName: "Enigo_session_id",
+            argNames: ["that"],
+        );
+        
+
+@override void crateApiEnigoEnigoSetAuditLog({required Enigo that , String? path })  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerEnigo(that, serializer);
+sse_encode_opt_String(path, serializer);
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 49)!;
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_unit,
+          decodeErrorData: sse_decode_AnyhowException,
+        )
+        ,
+            constMeta: kCrateApiEnigoEnigoSetAuditLogConstMeta,
+            argValues: [that, path],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiEnigoEnigoSetAuditLogConstMeta => const TaskConstMeta(
+            debugName: "Enigo_set_audit_log",
+            argNames: ["that", "path"],
+        );
+        
+
+@override void crateApiEnigoEnigoSetDryRun({required Enigo that , required bool enabled })  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerEnigo(that, serializer);
+sse_encode_bool(enabled, serializer);
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 50)!;
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_unit,
+          decodeErrorData: null,
+        )
+        ,
+            constMeta: kCrateApiEnigoEnigoSetDryRunConstMeta,
+            argValues: [that, enabled],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiEnigoEnigoSetDryRunConstMeta => const TaskConstMeta(
+            debugName: "Enigo_set_dry_run",
+            argNames: ["that", "enabled"],
+        );
+        
+
+@override void crateApiEnigoEnigoSetPacer({required Enigo that , PacerConfig? config })  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerEnigo(that, serializer);
+sse_encode_opt_box_autoadd_pacer_config(config, serializer);
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 51)!;
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_unit,
+          decodeErrorData: sse_decode_AnyhowException,
+        )
+        ,
+            constMeta: kCrateApiEnigoEnigoSetPacerConstMeta,
+            argValues: [that, config],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiEnigoEnigoSetPacerConstMeta => const TaskConstMeta(
+            debugName: "Enigo_set_pacer",
+            argNames: ["that", "config"],
+        );
+        
+
+@override void crateApiEnigoEnigoSetPolicy({required Enigo that , Policy? policy })  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerEnigo(that, serializer);
+sse_encode_opt_box_autoadd_policy(policy, serializer);
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 52)!;
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_unit,
+          decodeErrorData: null,
+        )
+        ,
+            constMeta: kCrateApiEnigoEnigoSetPolicyConstMeta,
+            argValues: [that, policy],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiEnigoEnigoSetPolicyConstMeta => const TaskConstMeta(
+            debugName: "Enigo_set_policy",
+            argNames: ["that", "policy"],
+        );
+        
+
+@override void crateApiEnigoEnigoSetReconnectPolicy({required Enigo that , required ReconnectPolicy policy })  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerEnigo(that, serializer);
+sse_encode_box_autoadd_reconnect_policy(policy, serializer);
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 53)!;
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_unit,
+          decodeErrorData: null,
+        )
+        ,
+            constMeta: kCrateApiEnigoEnigoSetReconnectPolicyConstMeta,
+            argValues: [that, policy],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiEnigoEnigoSetReconnectPolicyConstMeta => const TaskConstMeta(
+            debugName: "Enigo_set_reconnect_policy",
+            argNames: ["that", "policy"],
+        );
+        
+
+@override Settings crateApiEnigoEnigoSettings({required Enigo that })  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerEnigo(that, serializer);
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 54)!;
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_settings,
+          decodeErrorData: null,
+        )
+        ,
+            constMeta: kCrateApiEnigoEnigoSettingsConstMeta,
+            argValues: [that],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiEnigoEnigoSettingsConstMeta => const TaskConstMeta(
+            debugName: "Enigo_settings",
+            argNames: ["that"],
+        );
+        
+
+@override void crateApiEnigoEnigoText({required Enigo that , required String text })  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_Auto_RefMut_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerEnigo(that, serializer);
+sse_encode_String(text, serializer);
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 55)!;
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_unit,
+          decodeErrorData: sse_decode_policy_violation,
+        )
+        ,
+            constMeta: kCrateApiEnigoEnigoTextConstMeta,
+            argValues: [that, text],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiEnigoEnigoTextConstMeta => const TaskConstMeta(
+            debugName: "Enigo_text",
+            argNames: ["that", "text"],
+        );
+        
+
+@override void crateApiEnigoEnigoTextWithOptions({required Enigo that , required String text , required TextOptions options })  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_Auto_RefMut_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerEnigo(that, serializer);
+sse_encode_String(text, serializer);
+sse_encode_box_autoadd_text_options(options, serializer);
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 56)!;
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_unit,
+          decodeErrorData: sse_decode_policy_violation,
+        )
+        ,
+            constMeta: kCrateApiEnigoEnigoTextWithOptionsConstMeta,
+            argValues: [that, text, options],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiEnigoEnigoTextWithOptionsConstMeta => const TaskConstMeta(
+            debugName: "Enigo_text_with_options",
+            argNames: ["that", "text", "options"],
+        );
+        
+
+@override void crateApiEnigoEnigoUpdateSettings({required Enigo that , required SettingsPatch patch })  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerEnigo(that, serializer);
+sse_encode_box_autoadd_settings_patch(patch, serializer);
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 57)!;
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_unit,
+          decodeErrorData: sse_decode_AnyhowException,
+        )
+        ,
+            constMeta: kCrateApiEnigoEnigoUpdateSettingsConstMeta,
+            argValues: [that, patch],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiEnigoEnigoUpdateSettingsConstMeta => const TaskConstMeta(
+            debugName: "Enigo_update_settings",
+            argNames: ["that", "patch"],
+        );
+        
+
+@override Enigo crateApiEnigoEnigoWithBackend({required Settings settings , required Backend backend })  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_box_autoadd_settings(settings, serializer);
+sse_encode_backend(backend, serializer);
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 58)!;
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerEnigo,
+          decodeErrorData: sse_decode_AnyhowException,
+        )
+        ,
+            constMeta: kCrateApiEnigoEnigoWithBackendConstMeta,
+            argValues: [settings, backend],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiEnigoEnigoWithBackendConstMeta => const TaskConstMeta(
+            debugName: "Enigo_with_backend",
+            argNames: ["settings", "backend"],
+        );
+        
+
+@override Enigo crateApiEnigoEnigoWithBackends({required Settings settings , required List<Backend> preference })  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_box_autoadd_settings(settings, serializer);
+sse_encode_list_backend(preference, serializer);
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 59)!;
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerEnigo,
+          decodeErrorData: sse_decode_AnyhowException,
+        )
+        ,
+            constMeta: kCrateApiEnigoEnigoWithBackendsConstMeta,
+            argValues: [settings, preference],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiEnigoEnigoWithBackendsConstMeta => const TaskConstMeta(
+            debugName: "Enigo_with_backends",
+            argNames: ["settings", "preference"],
+        );
+        
+
+@override Stream<HotkeyEvent> crateApiHotkeyHotkeyManagerActivations({required HotkeyManager that })  { 
+            final sink = RustStreamSink<HotkeyEvent>();
+            unawaited(handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerHotkeyManager(that, serializer);
+sse_encode_StreamSink_hotkey_event_Sse(sink, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 60, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_unit,
+          decodeErrorData: sse_decode_AnyhowException,
+        )
+        ,
+            constMeta: kCrateApiHotkeyHotkeyManagerActivationsConstMeta,
+            argValues: [that, sink],
+            apiImpl: this,
+        )));
+            return sink.stream;
+             }
+
+
+        TaskConstMeta get kCrateApiHotkeyHotkeyManagerActivationsConstMeta => const TaskConstMeta(
+            debugName: "HotkeyManager_activations",
+            argNames: ["that", "sink"],
+        );
+        
+
+@override HotkeyManager crateApiHotkeyHotkeyManagerNew({required Settings settings })  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_box_autoadd_settings(settings, serializer);
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 61)!;
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerHotkeyManager,
+          decodeErrorData: sse_decode_AnyhowException,
+        )
+        ,
+            constMeta: kCrateApiHotkeyHotkeyManagerNewConstMeta,
+            argValues: [settings],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiHotkeyHotkeyManagerNewConstMeta => const TaskConstMeta(
+            debugName: "HotkeyManager_new",
+            argNames: ["settings"],
+        );
+        
+
+@override int crateApiHotkeyHotkeyManagerRegister({required HotkeyManager that , required String accelerator })  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerHotkeyManager(that, serializer);
+sse_encode_String(accelerator, serializer);
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 62)!;
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_u_32,
+          decodeErrorData: sse_decode_AnyhowException,
+        )
+        ,
+            constMeta: kCrateApiHotkeyHotkeyManagerRegisterConstMeta,
+            argValues: [that, accelerator],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiHotkeyHotkeyManagerRegisterConstMeta => const TaskConstMeta(
+            debugName: "HotkeyManager_register",
+            argNames: ["that", "accelerator"],
+        );
+        
+
+@override void crateApiHotkeyHotkeyManagerUnregister({required HotkeyManager that , required int id })  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerHotkeyManager(that, serializer);
+sse_encode_u_32(id, serializer);
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 63)!;
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_unit,
+          decodeErrorData: sse_decode_AnyhowException,
+        )
+        ,
+            constMeta: kCrateApiHotkeyHotkeyManagerUnregisterConstMeta,
+            argValues: [that, id],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiHotkeyHotkeyManagerUnregisterConstMeta => const TaskConstMeta(
+            debugName: "HotkeyManager_unregister",
+            argNames: ["that", "id"],
+        );
+        
+
+@override void crateApiHotkeyHotkeyManagerUnregisterAll({required HotkeyManager that })  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerHotkeyManager(that, serializer);
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 64)!;
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_unit,
+          decodeErrorData: sse_decode_AnyhowException,
+        )
+        ,
+            constMeta: kCrateApiHotkeyHotkeyManagerUnregisterAllConstMeta,
+            argValues: [that],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiHotkeyHotkeyManagerUnregisterAllConstMeta => const TaskConstMeta(
+            debugName: "HotkeyManager_unregister_all",
+            argNames: ["that"],
+        );
+        
+
+@override String crateApiServerInputServerAddress({required InputServer that })  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerInputServer(that, serializer);
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 65)!;
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_String,
+          decodeErrorData: null,
+        )
+        ,
+            constMeta: kCrateApiServerInputServerAddressConstMeta,
+            argValues: [that],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiServerInputServerAddressConstMeta => const TaskConstMeta(
+            debugName: "InputServer_address",
+            argNames: ["that"],
+        );
+        
+
+@override int crateApiServerInputServerConnections({required InputServer that })  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerInputServer(that, serializer);
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 66)!;
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_u_32,
+          decodeErrorData: null,
+        )
+        ,
+            constMeta: kCrateApiServerInputServerConnectionsConstMeta,
+            argValues: [that],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiServerInputServerConnectionsConstMeta => const TaskConstMeta(
+            debugName: "InputServer_connections",
+            argNames: ["that"],
+        );
+        
+
+@override bool crateApiServerInputServerIsRunning({required InputServer that })  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerInputServer(that, serializer);
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 67)!;
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_bool,
+          decodeErrorData: null,
+        )
+        ,
+            constMeta: kCrateApiServerInputServerIsRunningConstMeta,
+            argValues: [that],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiServerInputServerIsRunningConstMeta => const TaskConstMeta(
+            debugName: "InputServer_is_running",
+            argNames: ["that"],
+        );
+        
+
+@override InputServer crateApiServerInputServerStart({required Enigo enigo , required ServerConfig config })  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerEnigo(enigo, serializer);
+sse_encode_box_autoadd_server_config(config, serializer);
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 68)!;
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerInputServer,
+          decodeErrorData: sse_decode_AnyhowException,
+        )
+        ,
+            constMeta: kCrateApiServerInputServerStartConstMeta,
+            argValues: [enigo, config],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiServerInputServerStartConstMeta => const TaskConstMeta(
+            debugName: "InputServer_start",
+            argNames: ["enigo", "config"],
+        );
+        
+
+@override void crateApiServerInputServerStop({required InputServer that })  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerInputServer(that, serializer);
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 69)!;
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_unit,
+          decodeErrorData: null,
+        )
+        ,
+            constMeta: kCrateApiServerInputServerStopConstMeta,
+            argValues: [that],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiServerInputServerStopConstMeta => const TaskConstMeta(
+            debugName: "InputServer_stop",
+            argNames: ["that"],
+        );
+        
+
+@override bool crateApiListenerListenerIsListening({required Listener that })  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerListener(that, serializer);
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 70)!;
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_bool,
+          decodeErrorData: null,
+        )
+        ,
+            constMeta: kCrateApiListenerListenerIsListeningConstMeta,
+            argValues: [that],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiListenerListenerIsListeningConstMeta => const TaskConstMeta(
+            debugName: "Listener_is_listening",
+            argNames: ["that"],
+        );
+        
+
+@override Stream<InputEvent> crateApiListenerListenerListen({required Listener that })  { 
+            final sink = RustStreamSink<InputEvent>();
+            unawaited(handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerListener(that, serializer);
+sse_encode_StreamSink_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerInputEvent_Sse(sink, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 71, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_unit,
+          decodeErrorData: sse_decode_AnyhowException,
+        )
+        ,
+            constMeta: kCrateApiListenerListenerListenConstMeta,
+            argValues: [that, sink],
+            apiImpl: this,
+        )));
+            return sink.stream;
+             }
+
+
+        TaskConstMeta get kCrateApiListenerListenerListenConstMeta => const TaskConstMeta(
+            debugName: "Listener_listen",
+            argNames: ["that", "sink"],
+        );
+        
+
+@override Listener crateApiListenerListenerNew({required Settings settings })  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_box_autoadd_settings(settings, serializer);
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 72)!;
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerListener,
+          decodeErrorData: null,
+        )
+        ,
+            constMeta: kCrateApiListenerListenerNewConstMeta,
+            argValues: [settings],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiListenerListenerNewConstMeta => const TaskConstMeta(
+            debugName: "Listener_new",
+            argNames: ["settings"],
+        );
+        
+
+@override void crateApiListenerListenerStop({required Listener that })  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerListener(that, serializer);
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 73)!;
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_unit,
+          decodeErrorData: null,
+        )
+        ,
+            constMeta: kCrateApiListenerListenerStopConstMeta,
+            argValues: [that],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiListenerListenerStopConstMeta => const TaskConstMeta(
+            debugName: "Listener_stop",
+            argNames: ["that"],
+        );
+        
+
+@override MockAction crateApiMockMockCallAutoAccessorGetAction({required MockCall that })  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerMockCall(that, serializer);
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 74)!;
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerMockAction,
+          decodeErrorData: null,
+        )
+        ,
+            constMeta: kCrateApiMockMockCallAutoAccessorGetActionConstMeta,
+            argValues: [that],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiMockMockCallAutoAccessorGetActionConstMeta => const TaskConstMeta(
+            debugName: "MockCall_auto_accessor_get_action",
+            argNames: ["that"],
+        );
+        
+
+@override BigInt crateApiMockMockCallAutoAccessorGetTimestampUs({required MockCall that })  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerMockCall(that, serializer);
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 75)!;
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_u_64,
+          decodeErrorData: null,
+        )
+        ,
+            constMeta: kCrateApiMockMockCallAutoAccessorGetTimestampUsConstMeta,
+            argValues: [that],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiMockMockCallAutoAccessorGetTimestampUsConstMeta => const TaskConstMeta(
+            debugName: "MockCall_auto_accessor_get_timestamp_us",
+            argNames: ["that"],
+        );
+        
+
+@override void crateApiMockMockCallAutoAccessorSetAction({required MockCall that , required MockAction action })  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_Auto_RefMut_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerMockCall(that, serializer);
+sse_encode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerMockAction(action, serializer);
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 76)!;
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_unit,
+          decodeErrorData: null,
+        )
+        ,
+            constMeta: kCrateApiMockMockCallAutoAccessorSetActionConstMeta,
+            argValues: [that, action],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiMockMockCallAutoAccessorSetActionConstMeta => const TaskConstMeta(
+            debugName: "MockCall_auto_accessor_set_action",
+            argNames: ["that", "action"],
+        );
+        
+
+@override void crateApiMockMockCallAutoAccessorSetTimestampUs({required MockCall that , required BigInt timestampUs })  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_Auto_RefMut_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerMockCall(that, serializer);
+sse_encode_u_64(timestampUs, serializer);
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 77)!;
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_unit,
+          decodeErrorData: null,
+        )
+        ,
+            constMeta: kCrateApiMockMockCallAutoAccessorSetTimestampUsConstMeta,
+            argValues: [that, timestampUs],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiMockMockCallAutoAccessorSetTimestampUsConstMeta => const TaskConstMeta(
+            debugName: "MockCall_auto_accessor_set_timestamp_us",
+            argNames: ["that", "timestampUs"],
+        );
+        
+
+@override bool crateApiRecorderRecorderIsRecording({required Recorder that })  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerRecorder(that, serializer);
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 78)!;
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_bool,
+          decodeErrorData: null,
+        )
+        ,
+            constMeta: kCrateApiRecorderRecorderIsRecordingConstMeta,
+            argValues: [that],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiRecorderRecorderIsRecordingConstMeta => const TaskConstMeta(
+            debugName: "Recorder_is_recording",
+            argNames: ["that"],
+        );
+        
+
+@override Recorder crateApiRecorderRecorderNew({required Settings settings })  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_box_autoadd_settings(settings, serializer);
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 79)!;
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerRecorder,
+          decodeErrorData: null,
+        )
+        ,
+            constMeta: kCrateApiRecorderRecorderNewConstMeta,
+            argValues: [settings],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiRecorderRecorderNewConstMeta => const TaskConstMeta(
+            debugName: "Recorder_new",
+            argNames: ["settings"],
+        );
+        
+
+@override void crateApiRecorderRecorderStart({required Recorder that })  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerRecorder(that, serializer);
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 80)!;
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_unit,
+          decodeErrorData: sse_decode_AnyhowException,
+        )
+        ,
+            constMeta: kCrateApiRecorderRecorderStartConstMeta,
+            argValues: [that],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiRecorderRecorderStartConstMeta => const TaskConstMeta(
+            debugName: "Recorder_start",
+            argNames: ["that"],
+        );
+        
+
+@override Recording crateApiRecorderRecorderStop({required Recorder that })  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerRecorder(that, serializer);
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 81)!;
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerRecording,
+          decodeErrorData: null,
+        )
+        ,
+            constMeta: kCrateApiRecorderRecorderStopConstMeta,
+            argValues: [that],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiRecorderRecorderStopConstMeta => const TaskConstMeta(
+            debugName: "Recorder_stop",
+            argNames: ["that"],
+        );
+        
+
+@override Future<Recording> crateApiRecorderRecordingDefault()  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 82, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerRecording,
+          decodeErrorData: null,
+        )
+        ,
+            constMeta: kCrateApiRecorderRecordingDefaultConstMeta,
+            argValues: [],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiRecorderRecordingDefaultConstMeta => const TaskConstMeta(
+            debugName: "Recording_default",
+            argNames: [],
+        );
+        
+
+@override BigInt crateApiRecorderRecordingDurationMs({required Recording that })  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerRecording(that, serializer);
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 83)!;
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_u_64,
+          decodeErrorData: null,
+        )
+        ,
+            constMeta: kCrateApiRecorderRecordingDurationMsConstMeta,
+            argValues: [that],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiRecorderRecordingDurationMsConstMeta => const TaskConstMeta(
+            debugName: "Recording_duration_ms",
+            argNames: ["that"],
+        );
+        
+
+@override Recording crateApiRecorderRecordingFromJson({required String json })  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_String(json, serializer);
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 84)!;
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerRecording,
+          decodeErrorData: sse_decode_AnyhowException,
+        )
+        ,
+            constMeta: kCrateApiRecorderRecordingFromJsonConstMeta,
+            argValues: [json],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiRecorderRecordingFromJsonConstMeta => const TaskConstMeta(
+            debugName: "Recording_from_json",
+            argNames: ["json"],
+        );
+        
+
+@override bool crateApiRecorderRecordingIsEmpty({required Recording that })  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerRecording(that, serializer);
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 85)!;
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_bool,
+          decodeErrorData: null,
+        )
+        ,
+            constMeta: kCrateApiRecorderRecordingIsEmptyConstMeta,
+            argValues: [that],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiRecorderRecordingIsEmptyConstMeta => const TaskConstMeta(
+            debugName: "Recording_is_empty",
+            argNames: ["that"],
+        );
+        
+
+@override BigInt crateApiRecorderRecordingLen({required Recording that })  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerRecording(that, serializer);
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 86)!;
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_usize,
+          decodeErrorData: null,
+        )
+        ,
+            constMeta: kCrateApiRecorderRecordingLenConstMeta,
+            argValues: [that],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiRecorderRecordingLenConstMeta => const TaskConstMeta(
+            debugName: "Recording_len",
+            argNames: ["that"],
+        );
+        
+
+@override Recording crateApiRecorderRecordingLoad({required String path })  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_String(path, serializer);
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 87)!;
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerRecording,
+          decodeErrorData: sse_decode_AnyhowException,
+        )
+        ,
+            constMeta: kCrateApiRecorderRecordingLoadConstMeta,
+            argValues: [path],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiRecorderRecordingLoadConstMeta => const TaskConstMeta(
+            debugName: "Recording_load",
+            argNames: ["path"],
+        );
+        
+
+@override void crateApiRecorderRecordingSave({required Recording that , required String path })  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerRecording(that, serializer);
+sse_encode_String(path, serializer);
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 88)!;
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_unit,
+          decodeErrorData: sse_decode_AnyhowException,
+        )
+        ,
+            constMeta: kCrateApiRecorderRecordingSaveConstMeta,
+            argValues: [that, path],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiRecorderRecordingSaveConstMeta => const TaskConstMeta(
+            debugName: "Recording_save",
+            argNames: ["that", "path"],
+        );
+        
+
+@override String crateApiRecorderRecordingToJson({required Recording that })  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerRecording(that, serializer);
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 89)!;
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_String,
+          decodeErrorData: sse_decode_AnyhowException,
+        )
+        ,
+            constMeta: kCrateApiRecorderRecordingToJsonConstMeta,
+            argValues: [that],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiRecorderRecordingToJsonConstMeta => const TaskConstMeta(
+            debugName: "Recording_to_json",
+            argNames: ["that"],
+        );
+        
+
+@override bool crateApiRemapRemapperEnabled({required Remapper that })  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerRemapper(that, serializer);
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 90)!;
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_bool,
+          decodeErrorData: null,
+        )
+        ,
+            constMeta: kCrateApiRemapRemapperEnabledConstMeta,
+            argValues: [that],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiRemapRemapperEnabledConstMeta => const TaskConstMeta(
+            debugName: "Remapper_enabled",
+            argNames: ["that"],
+        );
+        
+
+@override Remapper crateApiRemapRemapperFromJson({required String config , required Settings settings })  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_String(config, serializer);
+sse_encode_box_autoadd_settings(settings, serializer);
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 91)!;
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerRemapper,
+          decodeErrorData: sse_decode_AnyhowException,
+        )
+        ,
+            constMeta: kCrateApiRemapRemapperFromJsonConstMeta,
+            argValues: [config, settings],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiRemapRemapperFromJsonConstMeta => const TaskConstMeta(
+            debugName: "Remapper_from_json",
+            argNames: ["config", "settings"],
+        );
+        
+
+@override bool crateApiRemapRemapperIsRunning({required Remapper that })  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerRemapper(that, serializer);
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 92)!;
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_bool,
+          decodeErrorData: null,
+        )
+        ,
+            constMeta: kCrateApiRemapRemapperIsRunningConstMeta,
+            argValues: [that],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiRemapRemapperIsRunningConstMeta => const TaskConstMeta(
+            debugName: "Remapper_is_running",
+            argNames: ["that"],
+        );
+        
+
+@override void crateApiRemapRemapperLoadJson({required Remapper that , required String config })  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerRemapper(that, serializer);
+sse_encode_String(config, serializer);
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 93)!;
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_unit,
+          decodeErrorData: sse_decode_AnyhowException,
+        )
+        ,
+            constMeta: kCrateApiRemapRemapperLoadJsonConstMeta,
+            argValues: [that, config],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiRemapRemapperLoadJsonConstMeta => const TaskConstMeta(
+            debugName: "Remapper_load_json",
+            argNames: ["that", "config"],
+        );
+        
+
+@override void crateApiRemapRemapperSetEnabled({required Remapper that , required bool enabled })  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerRemapper(that, serializer);
+sse_encode_bool(enabled, serializer);
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 94)!;
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_unit,
+          decodeErrorData: null,
+        )
+        ,
+            constMeta: kCrateApiRemapRemapperSetEnabledConstMeta,
+            argValues: [that, enabled],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiRemapRemapperSetEnabledConstMeta => const TaskConstMeta(
+            debugName: "Remapper_set_enabled",
+            argNames: ["that", "enabled"],
+        );
+        
+
+@override void crateApiRemapRemapperStart({required Remapper that })  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerRemapper(that, serializer);
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 95)!;
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_unit,
+          decodeErrorData: sse_decode_AnyhowException,
+        )
+        ,
+            constMeta: kCrateApiRemapRemapperStartConstMeta,
+            argValues: [that],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiRemapRemapperStartConstMeta => const TaskConstMeta(
+            debugName: "Remapper_start",
+            argNames: ["that"],
+        );
+        
+
+@override void crateApiRemapRemapperStop({required Remapper that })  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerRemapper(that, serializer);
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 96)!;
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_unit,
+          decodeErrorData: null,
+        )
+        ,
+            constMeta: kCrateApiRemapRemapperStopConstMeta,
+            argValues: [that],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiRemapRemapperStopConstMeta => const TaskConstMeta(
+            debugName: "Remapper_stop",
+            argNames: ["that"],
+        );
+        
+
+@override MockAction crateApiDryRunTraceEntryAutoAccessorGetAction({required TraceEntry that })  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerTraceEntry(that, serializer);
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 97)!;
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerMockAction,
+          decodeErrorData: null,
+        )
+        ,
+            constMeta: kCrateApiDryRunTraceEntryAutoAccessorGetActionConstMeta,
+            argValues: [that],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiDryRunTraceEntryAutoAccessorGetActionConstMeta => const TaskConstMeta(
+            debugName: "TraceEntry_auto_accessor_get_action",
+            argNames: ["that"],
+        );
+        
+
+@override BigInt crateApiDryRunTraceEntryAutoAccessorGetOffsetMs({required TraceEntry that })  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerTraceEntry(that, serializer);
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 98)!;
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_u_64,
+          decodeErrorData: null,
+        )
+        ,
+            constMeta: kCrateApiDryRunTraceEntryAutoAccessorGetOffsetMsConstMeta,
+            argValues: [that],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiDryRunTraceEntryAutoAccessorGetOffsetMsConstMeta => const TaskConstMeta(
+            debugName: "TraceEntry_auto_accessor_get_offset_ms",
+            argNames: ["that"],
+        );
+        
+
+@override (int,int)? crateApiDryRunTraceEntryAutoAccessorGetTarget({required TraceEntry that })  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerTraceEntry(that, serializer);
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 99)!;
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_opt_box_autoadd_record_i_32_i_32,
+          decodeErrorData: null,
+        )
+        ,
+            constMeta: kCrateApiDryRunTraceEntryAutoAccessorGetTargetConstMeta,
+            argValues: [that],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiDryRunTraceEntryAutoAccessorGetTargetConstMeta => const TaskConstMeta(
+            debugName: "TraceEntry_auto_accessor_get_target",
+            argNames: ["that"],
+        );
+        
+
+@override String? crateApiDryRunTraceEntryAutoAccessorGetWarning({required TraceEntry that })  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerTraceEntry(that, serializer);
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 100)!;
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_opt_String,
+          decodeErrorData: null,
+        )
+        ,
+            constMeta: kCrateApiDryRunTraceEntryAutoAccessorGetWarningConstMeta,
+            argValues: [that],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiDryRunTraceEntryAutoAccessorGetWarningConstMeta => const TaskConstMeta(
+            debugName: "TraceEntry_auto_accessor_get_warning",
+            argNames: ["that"],
+        );
+        
+
+@override void crateApiDryRunTraceEntryAutoAccessorSetAction({required TraceEntry that , required MockAction action })  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_Auto_RefMut_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerTraceEntry(that, serializer);
+sse_encode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerMockAction(action, serializer);
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 101)!;
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_unit,
+          decodeErrorData: null,
+        )
+        ,
+            constMeta: kCrateApiDryRunTraceEntryAutoAccessorSetActionConstMeta,
+            argValues: [that, action],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiDryRunTraceEntryAutoAccessorSetActionConstMeta => const TaskConstMeta(
+            debugName: "TraceEntry_auto_accessor_set_action",
+            argNames: ["that", "action"],
+        );
+        
+
+@override void crateApiDryRunTraceEntryAutoAccessorSetOffsetMs({required TraceEntry that , required BigInt offsetMs })  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_Auto_RefMut_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerTraceEntry(that, serializer);
+sse_encode_u_64(offsetMs, serializer);
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 102)!;
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_unit,
+          decodeErrorData: null,
+        )
+        ,
+            constMeta: kCrateApiDryRunTraceEntryAutoAccessorSetOffsetMsConstMeta,
+            argValues: [that, offsetMs],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiDryRunTraceEntryAutoAccessorSetOffsetMsConstMeta => const TaskConstMeta(
+            debugName: "TraceEntry_auto_accessor_set_offset_ms",
+            argNames: ["that", "offsetMs"],
+        );
+        
+
+@override void crateApiDryRunTraceEntryAutoAccessorSetTarget({required TraceEntry that , (int,int)? target })  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_Auto_RefMut_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerTraceEntry(that, serializer);
+sse_encode_opt_box_autoadd_record_i_32_i_32(target, serializer);
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 103)!;
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_unit,
+          decodeErrorData: null,
+        )
+        ,
+            constMeta: kCrateApiDryRunTraceEntryAutoAccessorSetTargetConstMeta,
+            argValues: [that, target],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiDryRunTraceEntryAutoAccessorSetTargetConstMeta => const TaskConstMeta(
+            debugName: "TraceEntry_auto_accessor_set_target",
+            argNames: ["that", "target"],
+        );
+        
+
+@override void crateApiDryRunTraceEntryAutoAccessorSetWarning({required TraceEntry that , String? warning })  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_Auto_RefMut_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerTraceEntry(that, serializer);
+sse_encode_opt_String(warning, serializer);
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 104)!;
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_unit,
+          decodeErrorData: null,
+        )
+        ,
+            constMeta: kCrateApiDryRunTraceEntryAutoAccessorSetWarningConstMeta,
+            argValues: [that, warning],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiDryRunTraceEntryAutoAccessorSetWarningConstMeta => const TaskConstMeta(
+            debugName: "TraceEntry_auto_accessor_set_warning",
+            argNames: ["that", "warning"],
+        );
+        
+
+@override String crateApiDryRunTraceEntryDescribe({required TraceEntry that })  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerTraceEntry(that, serializer);
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 105)!;
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_String,
+          decodeErrorData: null,
+        )
+        ,
+            constMeta: kCrateApiDryRunTraceEntryDescribeConstMeta,
+            argValues: [that],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiDryRunTraceEntryDescribeConstMeta => const TaskConstMeta(
+            debugName: "TraceEntry_describe",
+            argNames: ["that"],
+        );
+        
+
+@override List<Backend> crateApiBackendBackendCompiled()  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 106)!;
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_list_backend,
+          decodeErrorData: null,
+        )
+        ,
+            constMeta: kCrateApiBackendBackendCompiledConstMeta,
+            argValues: [],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiBackendBackendCompiledConstMeta => const TaskConstMeta(
+            debugName: "backend_compiled",
+            argNames: [],
+        );
+        
+
+@override Future<Backend> crateApiBackendBackendDefault()  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 107, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_backend,
+          decodeErrorData: null,
+        )
+        ,
+            constMeta: kCrateApiBackendBackendDefaultConstMeta,
+            argValues: [],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiBackendBackendDefaultConstMeta => const TaskConstMeta(
+            debugName: "backend_default",
+            argNames: [],
+        );
+        
+
+@override bool crateApiBackendBackendIsCompiled({required Backend that })  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_backend(that, serializer);
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 108)!;
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_bool,
+          decodeErrorData: null,
+        )
+        ,
+            constMeta: kCrateApiBackendBackendIsCompiledConstMeta,
+            argValues: [that],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiBackendBackendIsCompiledConstMeta => const TaskConstMeta(
+            debugName: "backend_is_compiled",
+            argNames: ["that"],
+        );
+        
+
+@override Future<EventGaps> crateApiPacerEventGapsDefault()  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 109, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_event_gaps,
+          decodeErrorData: null,
+        )
+        ,
+            constMeta: kCrateApiPacerEventGapsDefaultConstMeta,
+            argValues: [],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiPacerEventGapsDefaultConstMeta => const TaskConstMeta(
+            debugName: "event_gaps_default",
+            argNames: [],
+        );
+        
+
+@override Future<HeldInput> crateApiEnigoHeldInputDefault()  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 110, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_held_input,
+          decodeErrorData: null,
+        )
+        ,
+            constMeta: kCrateApiEnigoHeldInputDefaultConstMeta,
+            argValues: [],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiEnigoHeldInputDefaultConstMeta => const TaskConstMeta(
+            debugName: "held_input_default",
+            argNames: [],
+        );
+        
+
+@override bool crateApiEnigoHeldInputIsEmpty({required HeldInput that })  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_box_autoadd_held_input(that, serializer);
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 111)!;
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_bool,
+          decodeErrorData: null,
+        )
+        ,
+            constMeta: kCrateApiEnigoHeldInputIsEmptyConstMeta,
+            argValues: [that],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiEnigoHeldInputIsEmptyConstMeta => const TaskConstMeta(
+            debugName: "held_input_is_empty",
+            argNames: ["that"],
+        );
+        
+
+@override Future<Histogram> crateApiMetricsHistogramDefault()  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 112, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_histogram,
+          decodeErrorData: null,
+        )
+        ,
+            constMeta: kCrateApiMetricsHistogramDefaultConstMeta,
+            argValues: [],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiMetricsHistogramDefaultConstMeta => const TaskConstMeta(
+            debugName: "histogram_default",
+            argNames: [],
+        );
+        
+
+@override Future<void> crateApiEnigoInitApp()  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 113, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_unit,
+          decodeErrorData: null,
+        )
+        ,
+            constMeta: kCrateApiEnigoInitAppConstMeta,
+            argValues: [],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiEnigoInitAppConstMeta => const TaskConstMeta(
+            debugName: "init_app",
+            argNames: [],
+        );
+        
+
+@override Settings crateApiSettingsLoadSettings({required String path })  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_String(path, serializer);
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 114)!;
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_settings,
+          decodeErrorData: sse_decode_AnyhowException,
+        )
+        ,
+            constMeta: kCrateApiSettingsLoadSettingsConstMeta,
+            argValues: [path],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiSettingsLoadSettingsConstMeta => const TaskConstMeta(
+            debugName: "load_settings",
+            argNames: ["path"],
+        );
+        
+
+@override Future<LockState> crateApiSystemLockStateDefault()  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 115, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_lock_state,
+          decodeErrorData: null,
+        )
+        ,
+            constMeta: kCrateApiSystemLockStateDefaultConstMeta,
+            argValues: [],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiSystemLockStateDefaultConstMeta => const TaskConstMeta(
+            debugName: "lock_state_default",
+            argNames: [],
+        );
+        
+
+@override Stream<LogRecord> crateApiLoggingLogStream({required LogLevel level })  { 
+            final sink = RustStreamSink<LogRecord>();
+            handler.executeSync(SyncTask(
+            callFfi: () {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_StreamSink_log_record_Sse(sink, serializer);
+sse_encode_log_level(level, serializer);
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 116)!;
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_unit,
+          decodeErrorData: null,
+        )
+        ,
+            constMeta: kCrateApiLoggingLogStreamConstMeta,
+            argValues: [sink, level],
+            apiImpl: this,
+        ));
+            return sink.stream;
+             }
+
+
+        TaskConstMeta get kCrateApiLoggingLogStreamConstMeta => const TaskConstMeta(
+            debugName: "log_stream",
+            argNames: ["sink", "level"],
+        );
+        
+
+@override void crateApiLoggingLogToFile({required String directory , required String prefix , required LogRotation rotation , required int maxFiles , required LogLevel level })  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_String(directory, serializer);
+sse_encode_String(prefix, serializer);
+sse_encode_log_rotation(rotation, serializer);
+sse_encode_u_32(maxFiles, serializer);
+sse_encode_log_level(level, serializer);
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 117)!;
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_unit,
+          decodeErrorData: sse_decode_AnyhowException,
+        )
+        ,
+            constMeta: kCrateApiLoggingLogToFileConstMeta,
+            argValues: [directory, prefix, rotation, maxFiles, level],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiLoggingLogToFileConstMeta => const TaskConstMeta(
+            debugName: "log_to_file",
+            argNames: ["directory", "prefix", "rotation", "maxFiles", "level"],
+        );
+        
+
+@override String crateApiMetricsMetricsToJson({required Metrics that })  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_box_autoadd_metrics(that, serializer);
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 118)!;
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_String,
+          decodeErrorData: sse_decode_AnyhowException,
+        )
+        ,
+            constMeta: kCrateApiMetricsMetricsToJsonConstMeta,
+            argValues: [that],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiMetricsMetricsToJsonConstMeta => const TaskConstMeta(
+            debugName: "metrics_to_json",
+            argNames: ["that"],
+        );
+        
+
+@override String crateApiMetricsMetricsToPrometheus({required Metrics that })  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_box_autoadd_metrics(that, serializer);
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 119)!;
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_String,
+          decodeErrorData: null,
+        )
+        ,
+            constMeta: kCrateApiMetricsMetricsToPrometheusConstMeta,
+            argValues: [that],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiMetricsMetricsToPrometheusConstMeta => const TaskConstMeta(
+            debugName: "metrics_to_prometheus",
+            argNames: ["that"],
+        );
+        
+
+@override Future<ModifierState> crateApiSystemModifierStateDefault()  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 120, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_modifier_state,
+          decodeErrorData: null,
+        )
+        ,
+            constMeta: kCrateApiSystemModifierStateDefaultConstMeta,
+            argValues: [],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiSystemModifierStateDefaultConstMeta => const TaskConstMeta(
+            debugName: "modifier_state_default",
+            argNames: [],
+        );
+        
+
+@override Future<PacerConfig> crateApiPacerPacerConfigDefault()  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 121, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_pacer_config,
+          decodeErrorData: null,
+        )
+        ,
+            constMeta: kCrateApiPacerPacerConfigDefaultConstMeta,
+            argValues: [],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiPacerPacerConfigDefaultConstMeta => const TaskConstMeta(
+            debugName: "pacer_config_default",
+            argNames: [],
+        );
+        
+
+@override PacerConfig crateApiPacerPacerConfigPreset()  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 122)!;
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_pacer_config,
+          decodeErrorData: null,
+        )
+        ,
+            constMeta: kCrateApiPacerPacerConfigPresetConstMeta,
+            argValues: [],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiPacerPacerConfigPresetConstMeta => const TaskConstMeta(
+            debugName: "pacer_config_preset",
+            argNames: [],
+        );
+        
+
+@override Future<PacerStats> crateApiPacerPacerStatsDefault()  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 123, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_pacer_stats,
+          decodeErrorData: null,
+        )
+        ,
+            constMeta: kCrateApiPacerPacerStatsDefaultConstMeta,
+            argValues: [],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiPacerPacerStatsDefaultConstMeta => const TaskConstMeta(
+            debugName: "pacer_stats_default",
+            argNames: [],
+        );
+        
+
+@override Future<PlaybackOptions> crateApiRecorderPlaybackOptionsDefault()  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 124, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_playback_options,
+          decodeErrorData: null,
+        )
+        ,
+            constMeta: kCrateApiRecorderPlaybackOptionsDefaultConstMeta,
+            argValues: [],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiRecorderPlaybackOptionsDefaultConstMeta => const TaskConstMeta(
+            debugName: "playback_options_default",
+            argNames: [],
+        );
+        
+
+@override PlaybackOptions crateApiRecorderPlaybackOptionsPreset()  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 125)!;
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_playback_options,
+          decodeErrorData: null,
+        )
+        ,
+            constMeta: kCrateApiRecorderPlaybackOptionsPresetConstMeta,
+            argValues: [],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiRecorderPlaybackOptionsPresetConstMeta => const TaskConstMeta(
+            debugName: "playback_options_preset",
+            argNames: [],
+        );
+        
+
+@override Future<Policy> crateApiPolicyPolicyDefault()  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 126, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_policy,
+          decodeErrorData: null,
+        )
+        ,
+            constMeta: kCrateApiPolicyPolicyDefaultConstMeta,
+            argValues: [],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiPolicyPolicyDefaultConstMeta => const TaskConstMeta(
+            debugName: "policy_default",
+            argNames: [],
+        );
+        
+
+@override Policy crateApiPolicyPolicyPreset()  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 127)!;
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_policy,
+          decodeErrorData: null,
+        )
+        ,
+            constMeta: kCrateApiPolicyPolicyPresetConstMeta,
+            argValues: [],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiPolicyPolicyPresetConstMeta => const TaskConstMeta(
+            debugName: "policy_preset",
+            argNames: [],
+        );
+        
+
+@override void crateApiSettingsSaveSettings({required Settings settings , required String path })  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_box_autoadd_settings(settings, serializer);
+sse_encode_String(path, serializer);
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 128)!;
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_unit,
+          decodeErrorData: sse_decode_AnyhowException,
+        )
+        ,
+            constMeta: kCrateApiSettingsSaveSettingsConstMeta,
+            argValues: [settings, path],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiSettingsSaveSettingsConstMeta => const TaskConstMeta(
+            debugName: "save_settings",
+            argNames: ["settings", "path"],
+        );
+        
+
+@override Future<ServerConfig> crateApiServerServerConfigDefault()  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 129, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_server_config,
+          decodeErrorData: null,
+        )
+        ,
+            constMeta: kCrateApiServerServerConfigDefaultConstMeta,
+            argValues: [],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiServerServerConfigDefaultConstMeta => const TaskConstMeta(
+            debugName: "server_config_default",
+            argNames: [],
+        );
+        
+
+@override ServerConfig crateApiServerServerConfigPreset()  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 130)!;
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_server_config,
+          decodeErrorData: null,
+        )
+        ,
+            constMeta: kCrateApiServerServerConfigPresetConstMeta,
+            argValues: [],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiServerServerConfigPresetConstMeta => const TaskConstMeta(
+            debugName: "server_config_preset",
+            argNames: [],
+        );
+        
+
+@override Settings crateApiSettingsSettingsFromEnv({required Settings settings })  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_box_autoadd_settings(settings, serializer);
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 131)!;
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_settings,
+          decodeErrorData: sse_decode_AnyhowException,
+        )
+        ,
+            constMeta: kCrateApiSettingsSettingsFromEnvConstMeta,
+            argValues: [settings],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiSettingsSettingsFromEnvConstMeta => const TaskConstMeta(
+            debugName: "settings_from_env",
+            argNames: ["settings"],
+        );
+        
+
+@override Settings crateApiSettingsSettingsFromJson({required String json })  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_String(json, serializer);
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 132)!;
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_settings,
+          decodeErrorData: sse_decode_AnyhowException,
+        )
+        ,
+            constMeta: kCrateApiSettingsSettingsFromJsonConstMeta,
+            argValues: [json],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiSettingsSettingsFromJsonConstMeta => const TaskConstMeta(
+            debugName: "settings_from_json",
+            argNames: ["json"],
+        );
+        
+
+@override Future<SettingsPatch> crateApiEnigoSettingsPatchDefault()  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 133, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_settings_patch,
+          decodeErrorData: null,
+        )
+        ,
+            constMeta: kCrateApiEnigoSettingsPatchDefaultConstMeta,
+            argValues: [],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiEnigoSettingsPatchDefaultConstMeta => const TaskConstMeta(
+            debugName: "settings_patch_default",
+            argNames: [],
+        );
+        
+
+@override String crateApiSettingsSettingsToJson({required Settings settings })  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_box_autoadd_settings(settings, serializer);
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 134)!;
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_String,
+          decodeErrorData: sse_decode_AnyhowException,
+        )
+        ,
+            constMeta: kCrateApiSettingsSettingsToJsonConstMeta,
+            argValues: [settings],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiSettingsSettingsToJsonConstMeta => const TaskConstMeta(
+            debugName: "settings_to_json",
+            argNames: ["settings"],
+        );
+        
+
+@override void crateApiLoggingStopLogFile()  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 135)!;
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_unit,
+          decodeErrorData: null,
+        )
+        ,
+            constMeta: kCrateApiLoggingStopLogFileConstMeta,
+            argValues: [],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiLoggingStopLogFileConstMeta => const TaskConstMeta(
+            debugName: "stop_log_file",
+            argNames: [],
+        );
+        
+
+@override void crateApiLoggingStopLogStream()  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 136)!;
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_unit,
+          decodeErrorData: null,
+        )
+        ,
+            constMeta: kCrateApiLoggingStopLogStreamConstMeta,
+            argValues: [],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiLoggingStopLogStreamConstMeta => const TaskConstMeta(
+            debugName: "stop_log_stream",
+            argNames: [],
+        );
+        
+
+@override Future<TextOptions> crateApiEnigoTextOptionsDefault()  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 137, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_text_options,
+          decodeErrorData: null,
+        )
+        ,
+            constMeta: kCrateApiEnigoTextOptionsDefaultConstMeta,
+            argValues: [],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiEnigoTextOptionsDefaultConstMeta => const TaskConstMeta(
+            debugName: "text_options_default",
+            argNames: [],
+        );
+        
+
+@override void crateApiSettingsValidateSettings({required Settings settings })  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_box_autoadd_settings(settings, serializer);
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 138)!;
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_unit,
+          decodeErrorData: sse_decode_AnyhowException,
+        )
+        ,
+            constMeta: kCrateApiSettingsValidateSettingsConstMeta,
+            argValues: [settings],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiSettingsValidateSettingsConstMeta => const TaskConstMeta(
+            debugName: "validate_settings",
+            argNames: ["settings"],
+        );
+        
+
+@override BigInt crateApiAuditVerifyAuditLog({required String path })  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_String(path, serializer);
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 139)!;
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_u_64,
+          decodeErrorData: sse_decode_AnyhowException,
+        )
+        ,
+            constMeta: kCrateApiAuditVerifyAuditLogConstMeta,
+            argValues: [path],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiAuditVerifyAuditLogConstMeta => const TaskConstMeta(
+            debugName: "verify_audit_log",
+            argNames: ["path"],
+        );
+        
+
+RustArcIncrementStrongCountFnType get rust_arc_increment_strong_count_Enigo => wire.rust_arc_increment_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerEnigo;
+
+RustArcDecrementStrongCountFnType get rust_arc_decrement_strong_count_Enigo => wire.rust_arc_decrement_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerEnigo;
+
+RustArcIncrementStrongCountFnType get rust_arc_increment_strong_count_HotkeyManager => wire.rust_arc_increment_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerHotkeyManager;
+
+RustArcDecrementStrongCountFnType get rust_arc_decrement_strong_count_HotkeyManager => wire.rust_arc_decrement_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerHotkeyManager;
+
+RustArcIncrementStrongCountFnType get rust_arc_increment_strong_count_InputEvent => wire.rust_arc_increment_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerInputEvent;
+
+RustArcDecrementStrongCountFnType get rust_arc_decrement_strong_count_InputEvent => wire.rust_arc_decrement_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerInputEvent;
+
+RustArcIncrementStrongCountFnType get rust_arc_increment_strong_count_InputServer => wire.rust_arc_increment_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerInputServer;
+
+RustArcDecrementStrongCountFnType get rust_arc_decrement_strong_count_InputServer => wire.rust_arc_decrement_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerInputServer;
+
+RustArcIncrementStrongCountFnType get rust_arc_increment_strong_count_Listener => wire.rust_arc_increment_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerListener;
+
+RustArcDecrementStrongCountFnType get rust_arc_decrement_strong_count_Listener => wire.rust_arc_decrement_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerListener;
+
+RustArcIncrementStrongCountFnType get rust_arc_increment_strong_count_MockAction => wire.rust_arc_increment_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerMockAction;
+
+RustArcDecrementStrongCountFnType get rust_arc_decrement_strong_count_MockAction => wire.rust_arc_decrement_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerMockAction;
+
+RustArcIncrementStrongCountFnType get rust_arc_increment_strong_count_MockCall => wire.rust_arc_increment_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerMockCall;
+
+RustArcDecrementStrongCountFnType get rust_arc_decrement_strong_count_MockCall => wire.rust_arc_decrement_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerMockCall;
+
+RustArcIncrementStrongCountFnType get rust_arc_increment_strong_count_Recorder => wire.rust_arc_increment_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerRecorder;
+
+RustArcDecrementStrongCountFnType get rust_arc_decrement_strong_count_Recorder => wire.rust_arc_decrement_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerRecorder;
+
+RustArcIncrementStrongCountFnType get rust_arc_increment_strong_count_Recording => wire.rust_arc_increment_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerRecording;
+
+RustArcDecrementStrongCountFnType get rust_arc_decrement_strong_count_Recording => wire.rust_arc_decrement_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerRecording;
+
+RustArcIncrementStrongCountFnType get rust_arc_increment_strong_count_Remapper => wire.rust_arc_increment_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerRemapper;
+
+RustArcDecrementStrongCountFnType get rust_arc_decrement_strong_count_Remapper => wire.rust_arc_decrement_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerRemapper;
+
+RustArcIncrementStrongCountFnType get rust_arc_increment_strong_count_TraceEntry => wire.rust_arc_increment_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerTraceEntry;
+
+RustArcDecrementStrongCountFnType get rust_arc_decrement_strong_count_TraceEntry => wire.rust_arc_decrement_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerTraceEntry;
+
+RustArcIncrementStrongCountFnType get rust_arc_increment_strong_count_Button => wire.rust_arc_increment_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInner_Button;
+
+RustArcDecrementStrongCountFnType get rust_arc_decrement_strong_count_Button => wire.rust_arc_decrement_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInner_Button;
+
+
+
+                  @protected AnyhowException dco_decode_AnyhowException(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return AnyhowException(raw as String); }
+
+@protected Enigo dco_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerEnigo(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return EnigoImpl.frbInternalDcoDecode(raw as List<dynamic>); }
+
+@protected HotkeyManager dco_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerHotkeyManager(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return HotkeyManagerImpl.frbInternalDcoDecode(raw as List<dynamic>); }
+
+@protected InputEvent dco_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerInputEvent(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return InputEventImpl.frbInternalDcoDecode(raw as List<dynamic>); }
+
+@protected InputServer dco_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerInputServer(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return InputServerImpl.frbInternalDcoDecode(raw as List<dynamic>); }
+
+@protected Listener dco_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerListener(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return ListenerImpl.frbInternalDcoDecode(raw as List<dynamic>); }
+
+@protected MockAction dco_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerMockAction(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return MockActionImpl.frbInternalDcoDecode(raw as List<dynamic>); }
+
+@protected MockCall dco_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerMockCall(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return MockCallImpl.frbInternalDcoDecode(raw as List<dynamic>); }
+
+@protected Recorder dco_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerRecorder(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return RecorderImpl.frbInternalDcoDecode(raw as List<dynamic>); }
+
+@protected Recording dco_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerRecording(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return RecordingImpl.frbInternalDcoDecode(raw as List<dynamic>); }
+
+@protected Remapper dco_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerRemapper(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return RemapperImpl.frbInternalDcoDecode(raw as List<dynamic>); }
+
+@protected TraceEntry dco_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerTraceEntry(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return TraceEntryImpl.frbInternalDcoDecode(raw as List<dynamic>); }
+
+@protected Button dco_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInner_Button(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return ButtonImpl.frbInternalDcoDecode(raw as List<dynamic>); }
+
+@protected Enigo dco_decode_Auto_RefMut_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerEnigo(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return EnigoImpl.frbInternalDcoDecode(raw as List<dynamic>); }
+
+@protected MockCall dco_decode_Auto_RefMut_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerMockCall(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return MockCallImpl.frbInternalDcoDecode(raw as List<dynamic>); }
+
+@protected TraceEntry dco_decode_Auto_RefMut_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerTraceEntry(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return TraceEntryImpl.frbInternalDcoDecode(raw as List<dynamic>); }
+
+@protected Button dco_decode_Auto_RefMut_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInner_Button(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return ButtonImpl.frbInternalDcoDecode(raw as List<dynamic>); }
+
+@protected Enigo dco_decode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerEnigo(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return EnigoImpl.frbInternalDcoDecode(raw as List<dynamic>); }
+
+@protected HotkeyManager dco_decode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerHotkeyManager(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return HotkeyManagerImpl.frbInternalDcoDecode(raw as List<dynamic>); }
+
+@protected InputServer dco_decode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerInputServer(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return InputServerImpl.frbInternalDcoDecode(raw as List<dynamic>); }
+
+@protected Listener dco_decode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerListener(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return ListenerImpl.frbInternalDcoDecode(raw as List<dynamic>); }
+
+@protected MockCall dco_decode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerMockCall(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return MockCallImpl.frbInternalDcoDecode(raw as List<dynamic>); }
+
+@protected Recorder dco_decode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerRecorder(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return RecorderImpl.frbInternalDcoDecode(raw as List<dynamic>); }
+
+@protected Recording dco_decode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerRecording(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return RecordingImpl.frbInternalDcoDecode(raw as List<dynamic>); }
+
+@protected Remapper dco_decode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerRemapper(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return RemapperImpl.frbInternalDcoDecode(raw as List<dynamic>); }
+
+@protected TraceEntry dco_decode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerTraceEntry(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return TraceEntryImpl.frbInternalDcoDecode(raw as List<dynamic>); }
+
+@protected PhysicalKeyboardKey dco_decode_CustomSerializer_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerKey(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+throw UnimplementedError('Not implemented in this codec, please use the other one'); }
+
+@protected Enigo dco_decode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerEnigo(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return EnigoImpl.frbInternalDcoDecode(raw as List<dynamic>); }
+
+@protected HotkeyManager dco_decode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerHotkeyManager(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return HotkeyManagerImpl.frbInternalDcoDecode(raw as List<dynamic>); }
+
+@protected InputEvent dco_decode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerInputEvent(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return InputEventImpl.frbInternalDcoDecode(raw as List<dynamic>); }
+
+@protected InputServer dco_decode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerInputServer(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return InputServerImpl.frbInternalDcoDecode(raw as List<dynamic>); }
+
+@protected Listener dco_decode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerListener(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return ListenerImpl.frbInternalDcoDecode(raw as List<dynamic>); }
+
+@protected MockAction dco_decode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerMockAction(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return MockActionImpl.frbInternalDcoDecode(raw as List<dynamic>); }
+
+@protected MockCall dco_decode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerMockCall(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return MockCallImpl.frbInternalDcoDecode(raw as List<dynamic>); }
+
+@protected Recorder dco_decode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerRecorder(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return RecorderImpl.frbInternalDcoDecode(raw as List<dynamic>); }
+
+@protected Recording dco_decode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerRecording(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return RecordingImpl.frbInternalDcoDecode(raw as List<dynamic>); }
+
+@protected Remapper dco_decode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerRemapper(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return RemapperImpl.frbInternalDcoDecode(raw as List<dynamic>); }
+
+@protected TraceEntry dco_decode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerTraceEntry(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return TraceEntryImpl.frbInternalDcoDecode(raw as List<dynamic>); }
+
+@protected Button dco_decode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInner_Button(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return ButtonImpl.frbInternalDcoDecode(raw as List<dynamic>); }
+
+@protected RustStreamSink<InputEvent> dco_decode_StreamSink_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerInputEvent_Sse(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+throw UnimplementedError(); }
+
+@protected RustStreamSink<HotkeyEvent> dco_decode_StreamSink_hotkey_event_Sse(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+throw UnimplementedError(); }
+
+@protected RustStreamSink<LogRecord> dco_decode_StreamSink_log_record_Sse(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+throw UnimplementedError(); }
+
+@protected RustStreamSink<ReconnectEvent> dco_decode_StreamSink_reconnect_event_Sse(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+throw UnimplementedError(); }
+
+@protected String dco_decode_String(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return raw as String; }
+
+@protected Axis dco_decode_axis(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return Axis.values[raw as int]; }
+
+@protected Backend dco_decode_backend(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return Backend.values[raw as int]; }
+
+@protected BackendInfo dco_decode_backend_info(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+final arr = raw as List<dynamic>;
+                if (arr.length != 4) throw Exception('unexpected arr length: expect 4 but see ${arr.length}');
+                return BackendInfo(backend: dco_decode_backend(arr[0]),
+display: dco_decode_opt_String(arr[1]),
+compiled: dco_decode_list_backend(arr[2]),
+capabilities: dco_decode_capabilities(arr[3]),); }
+
+@protected bool dco_decode_bool(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return raw as bool; }
+
+@protected bool dco_decode_box_autoadd_bool(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return raw as bool; }
+
+@protected HeldInput dco_decode_box_autoadd_held_input(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return dco_decode_held_input(raw); }
+
+@protected PlatformInt64 dco_decode_box_autoadd_i_64(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return dco_decode_i_64(raw); }
+
+@protected Metrics dco_decode_box_autoadd_metrics(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return dco_decode_metrics(raw); }
+
+@protected PacerConfig dco_decode_box_autoadd_pacer_config(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return dco_decode_pacer_config(raw); }
+
+@protected PacerStats dco_decode_box_autoadd_pacer_stats(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return dco_decode_pacer_stats(raw); }
+
+@protected PlaybackOptions dco_decode_box_autoadd_playback_options(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return dco_decode_playback_options(raw); }
+
+@protected Policy dco_decode_box_autoadd_policy(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return dco_decode_policy(raw); }
+
+@protected RateLimit dco_decode_box_autoadd_rate_limit(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return dco_decode_rate_limit(raw); }
+
+@protected ReconnectPolicy dco_decode_box_autoadd_reconnect_policy(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return dco_decode_reconnect_policy(raw); }
+
+@protected (int,int) dco_decode_box_autoadd_record_i_32_i_32(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return raw as (int,int); }
+
+@protected RepeatPolicy dco_decode_box_autoadd_repeat_policy(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return dco_decode_repeat_policy(raw); }
+
+@protected ServerConfig dco_decode_box_autoadd_server_config(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return dco_decode_server_config(raw); }
+
+@protected Settings dco_decode_box_autoadd_settings(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return dco_decode_settings(raw); }
+
+@protected SettingsPatch dco_decode_box_autoadd_settings_patch(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return dco_decode_settings_patch(raw); }
+
+@protected TextOptions dco_decode_box_autoadd_text_options(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return dco_decode_text_options(raw); }
+
+@protected int dco_decode_box_autoadd_u_32(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return raw as int; }
+
+@protected BigInt dco_decode_box_autoadd_usize(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return dco_decode_usize(raw); }
+
+@protected Capabilities dco_decode_capabilities(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+final arr = raw as List<dynamic>;
+                if (arr.length != 6) throw Exception('unexpected arr length: expect 6 but see ${arr.length}');
+                return Capabilities(buttons: dco_decode_list_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInner_Button(arr[0]),
+absoluteMove: dco_decode_bool(arr[1]),
+location: dco_decode_bool(arr[2]),
+unicodeText: dco_decode_bool(arr[3]),
+rawKeycodes: dco_decode_bool(arr[4]),
+scrollResolution: dco_decode_u_32(arr[5]),); }
+
+@protected Coordinate dco_decode_coordinate(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return Coordinate.values[raw as int]; }
+
+@protected Direction dco_decode_direction(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return Direction.values[raw as int]; }
+
+@protected EventGaps dco_decode_event_gaps(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+final arr = raw as List<dynamic>;
+                if (arr.length != 5) throw Exception('unexpected arr length: expect 5 but see ${arr.length}');
+                return EventGaps(key: dco_decode_u_32(arr[0]),
+button: dco_decode_u_32(arr[1]),
+moveMouse: dco_decode_u_32(arr[2]),
+scroll: dco_decode_u_32(arr[3]),
+text: dco_decode_u_32(arr[4]),); }
+
+@protected double dco_decode_f_64(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return raw as double; }
+
+@protected HeldInput dco_decode_held_input(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+final arr = raw as List<dynamic>;
+                if (arr.length != 3) throw Exception('unexpected arr length: expect 3 but see ${arr.length}');
+                return HeldInput(keys: dco_decode_list_CustomSerializer_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerKey(arr[0]),
+buttons: dco_decode_list_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInner_Button(arr[1]),
+raw: dco_decode_list_prim_u_16_strict(arr[2]),); }
+
+@protected Histogram dco_decode_histogram(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+final arr = raw as List<dynamic>;
+                if (arr.length != 4) throw Exception('unexpected arr length: expect 4 but see ${arr.length}');
+                return Histogram(count: dco_decode_u_64(arr[0]),
+sumUs: dco_decode_u_64(arr[1]),
+maxUs: dco_decode_u_64(arr[2]),
+buckets: dco_decode_list_record_u_64_u_64(arr[3]),); }
+
+@protected HotkeyEvent dco_decode_hotkey_event(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+final arr = raw as List<dynamic>;
+                if (arr.length != 2) throw Exception('unexpected arr length: expect 2 but see ${arr.length}');
+                return HotkeyEvent(id: dco_decode_u_32(arr[0]),
+accelerator: dco_decode_String(arr[1]),); }
+
+@protected int dco_decode_i_32(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return raw as int; }
+
+@protected PlatformInt64 dco_decode_i_64(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return dcoDecodeI64(raw); }
+
+@protected List<MockCall> dco_decode_list_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerMockCall(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return (raw as List<dynamic>).map(dco_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerMockCall).toList(); }
+
+@protected List<TraceEntry> dco_decode_list_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerTraceEntry(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return (raw as List<dynamic>).map(dco_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerTraceEntry).toList(); }
+
+@protected List<Button> dco_decode_list_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInner_Button(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return (raw as List<dynamic>).map(dco_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInner_Button).toList(); }
+
+@protected List<PhysicalKeyboardKey> dco_decode_list_CustomSerializer_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerKey(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return (raw as List<dynamic>).map(dco_decode_CustomSerializer_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerKey).toList(); }
+
+@protected List<String> dco_decode_list_String(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return (raw as List<dynamic>).map(dco_decode_String).toList(); }
+
+@protected List<Backend> dco_decode_list_backend(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return (raw as List<dynamic>).map(dco_decode_backend).toList(); }
+
+@protected List<List<PhysicalKeyboardKey>> dco_decode_list_list_CustomSerializer_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerKey(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return (raw as List<dynamic>).map(dco_decode_list_CustomSerializer_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerKey).toList(); }
+
+@protected List<OperationMetrics> dco_decode_list_operation_metrics(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return (raw as List<dynamic>).map(dco_decode_operation_metrics).toList(); }
+
+@protected Uint16List dco_decode_list_prim_u_16_strict(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return raw as Uint16List; }
+
+@protected Uint8List dco_decode_list_prim_u_8_strict(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return raw as Uint8List; }
+
+@protected List<(String,String)> dco_decode_list_record_string_string(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return (raw as List<dynamic>).map(dco_decode_record_string_string).toList(); }
+
+@protected List<(String,BigInt)> dco_decode_list_record_string_u_64(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return (raw as List<dynamic>).map(dco_decode_record_string_u_64).toList(); }
+
+@protected List<(BigInt,BigInt)> dco_decode_list_record_u_64_u_64(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return (raw as List<dynamic>).map(dco_decode_record_u_64_u_64).toList(); }
+
+@protected List<Region> dco_decode_list_region(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return (raw as List<dynamic>).map(dco_decode_region).toList(); }
+
+@protected List<ServerToken> dco_decode_list_server_token(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return (raw as List<dynamic>).map(dco_decode_server_token).toList(); }
+
+@protected LockState dco_decode_lock_state(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+final arr = raw as List<dynamic>;
+                if (arr.length != 3) throw Exception('unexpected arr length: expect 3 but see ${arr.length}');
+                return LockState(capsLock: dco_decode_bool(arr[0]),
+numLock: dco_decode_bool(arr[1]),
+scrollLock: dco_decode_bool(arr[2]),); }
+
+@protected LogLevel dco_decode_log_level(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return LogLevel.values[raw as int]; }
+
+@protected LogRecord dco_decode_log_record(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+final arr = raw as List<dynamic>;
+                if (arr.length != 6) throw Exception('unexpected arr length: expect 6 but see ${arr.length}');
+                return LogRecord(timestampMs: dco_decode_u_64(arr[0]),
+level: dco_decode_log_level(arr[1]),
+target: dco_decode_String(arr[2]),
+spans: dco_decode_list_String(arr[3]),
+message: dco_decode_String(arr[4]),
+fields: dco_decode_list_record_string_string(arr[5]),); }
+
+@protected LogRotation dco_decode_log_rotation(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return LogRotation.values[raw as int]; }
+
+@protected Metrics dco_decode_metrics(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+final arr = raw as List<dynamic>;
+                if (arr.length != 1) throw Exception('unexpected arr length: expect 1 but see ${arr.length}');
+                return Metrics(operations: dco_decode_list_operation_metrics(arr[0]),); }
+
+@protected ModifierState dco_decode_modifier_state(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+final arr = raw as List<dynamic>;
+                if (arr.length != 4) throw Exception('unexpected arr length: expect 4 but see ${arr.length}');
+                return ModifierState(shift: dco_decode_bool(arr[0]),
+control: dco_decode_bool(arr[1]),
+alt: dco_decode_bool(arr[2]),
+meta: dco_decode_bool(arr[3]),); }
+
+@protected OperationMetrics dco_decode_operation_metrics(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+final arr = raw as List<dynamic>;
+                if (arr.length != 6) throw Exception('unexpected arr length: expect 6 but see ${arr.length}');
+                return OperationMetrics(operation: dco_decode_String(arr[0]),
+lockWait: dco_decode_histogram(arr[1]),
+backendCall: dco_decode_histogram(arr[2]),
+endToEnd: dco_decode_histogram(arr[3]),
+successes: dco_decode_u_64(arr[4]),
+failures: dco_decode_list_record_string_u_64(arr[5]),); }
+
+@protected String? dco_decode_opt_String(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return raw == null ? null : dco_decode_String(raw); }
+
+@protected bool? dco_decode_opt_box_autoadd_bool(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return raw == null ? null : dco_decode_box_autoadd_bool(raw); }
+
+@protected PlatformInt64? dco_decode_opt_box_autoadd_i_64(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return raw == null ? null : dco_decode_box_autoadd_i_64(raw); }
+
+@protected PacerConfig? dco_decode_opt_box_autoadd_pacer_config(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return raw == null ? null : dco_decode_box_autoadd_pacer_config(raw); }
+
+@protected PacerStats? dco_decode_opt_box_autoadd_pacer_stats(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return raw == null ? null : dco_decode_box_autoadd_pacer_stats(raw); }
+
+@protected Policy? dco_decode_opt_box_autoadd_policy(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return raw == null ? null : dco_decode_box_autoadd_policy(raw); }
+
+@protected RateLimit? dco_decode_opt_box_autoadd_rate_limit(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return raw == null ? null : dco_decode_box_autoadd_rate_limit(raw); }
+
+@protected (int,int)? dco_decode_opt_box_autoadd_record_i_32_i_32(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return raw == null ? null : dco_decode_box_autoadd_record_i_32_i_32(raw); }
+
+@protected int? dco_decode_opt_box_autoadd_u_32(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return raw == null ? null : dco_decode_box_autoadd_u_32(raw); }
+
+@protected BigInt? dco_decode_opt_box_autoadd_usize(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return raw == null ? null : dco_decode_box_autoadd_usize(raw); }
+
+@protected List<Backend>? dco_decode_opt_list_backend(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return raw == null ? null : dco_decode_list_backend(raw); }
+
+@protected PacerConfig dco_decode_pacer_config(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+final arr = raw as List<dynamic>;
+                if (arr.length != 3) throw Exception('unexpected arr length: expect 3 but see ${arr.length}');
+                return PacerConfig(eventsPerSecond: dco_decode_f_64(arr[0]),
+burst: dco_decode_u_32(arr[1]),
+minGapMs: dco_decode_event_gaps(arr[2]),); }
+
+@protected PacerStats dco_decode_pacer_stats(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+final arr = raw as List<dynamic>;
+                if (arr.length != 5) throw Exception('unexpected arr length: expect 5 but see ${arr.length}');
+                return PacerStats(events: dco_decode_u_64(arr[0]),
+delayed: dco_decode_u_64(arr[1]),
+totalWaitMs: dco_decode_u_64(arr[2]),
+maxWaitMs: dco_decode_u_64(arr[3]),
+tokens: dco_decode_f_64(arr[4]),); }
+
+@protected PlaybackOptions dco_decode_playback_options(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+final arr = raw as List<dynamic>;
+                if (arr.length != 3) throw Exception('unexpected arr length: expect 3 but see ${arr.length}');
+                return PlaybackOptions(speed: dco_decode_f_64(arr[0]),
+loops: dco_decode_u_32(arr[1]),
+maxGapMs: dco_decode_opt_box_autoadd_u_32(arr[2]),); }
+
+@protected Policy dco_decode_policy(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+final arr = raw as List<dynamic>;
+                if (arr.length != 7) throw Exception('unexpected arr length: expect 7 but see ${arr.length}');
+                return Policy(allowedKeys: dco_decode_list_CustomSerializer_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerKey(arr[0]),
+deniedKeys: dco_decode_list_CustomSerializer_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerKey(arr[1]),
+deniedChords: dco_decode_list_list_CustomSerializer_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerKey(arr[2]),
+allowRaw: dco_decode_bool(arr[3]),
+clickRegions: dco_decode_list_region(arr[4]),
+maxTextLen: dco_decode_opt_box_autoadd_u_32(arr[5]),
+rateLimit: dco_decode_opt_box_autoadd_rate_limit(arr[6]),); }
+
+@protected PolicyViolation dco_decode_policy_violation(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+switch (raw[0]) {
+                case 0: return PolicyViolation_KeyDenied(key: dco_decode_CustomSerializer_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerKey(raw[1]),);
+case 1: return PolicyViolation_ChordDenied(chord: dco_decode_list_CustomSerializer_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerKey(raw[1]),);
+case 2: return PolicyViolation_RawDenied(keycode: dco_decode_u_16(raw[1]),);
+case 3: return PolicyViolation_ClickOutsideRegions(x: dco_decode_i_32(raw[1]),y: dco_decode_i_32(raw[2]),);
+case 4: return PolicyViolation_TextTooLong(len: dco_decode_u_32(raw[1]),max: dco_decode_u_32(raw[2]),);
+case 5: return PolicyViolation_RateLimited(maxEvents: dco_decode_u_32(raw[1]),windowMs: dco_decode_u_32(raw[2]),);
+                default: throw Exception("unreachable");
+            } }
+
+@protected RateLimit dco_decode_rate_limit(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+final arr = raw as List<dynamic>;
+                if (arr.length != 2) throw Exception('unexpected arr length: expect 2 but see ${arr.length}');
+                return RateLimit(maxEvents: dco_decode_u_32(arr[0]),
+windowMs: dco_decode_u_32(arr[1]),); }
+
+@protected ReconnectEvent dco_decode_reconnect_event(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+final arr = raw as List<dynamic>;
+                if (arr.length != 3) throw Exception('unexpected arr length: expect 3 but see ${arr.length}');
+                return ReconnectEvent(backend: dco_decode_backend(arr[0]),
+attempts: dco_decode_u_32(arr[1]),
+error: dco_decode_String(arr[2]),); }
+
+@protected ReconnectPolicy dco_decode_reconnect_policy(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+switch (raw[0]) {
+                case 0: return ReconnectPolicy_Never();
+case 1: return ReconnectPolicy_Retry(maxAttempts: dco_decode_u_32(raw[1]),delayMs: dco_decode_u_32(raw[2]),);
+                default: throw Exception("unreachable");
+            } }
+
+@protected (int,int) dco_decode_record_i_32_i_32(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+final arr = raw as List<dynamic>;
+            if (arr.length != 2) {
+                throw Exception('Expected 2 elements, got ${arr.length}');
+            }
+            return (dco_decode_i_32(arr[0]),dco_decode_i_32(arr[1]),); }
+
+@protected (String,String) dco_decode_record_string_string(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+final arr = raw as List<dynamic>;
+            if (arr.length != 2) {
+                throw Exception('Expected 2 elements, got ${arr.length}');
+            }
+            return (dco_decode_String(arr[0]),dco_decode_String(arr[1]),); }
+
+@protected (String,BigInt) dco_decode_record_string_u_64(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+final arr = raw as List<dynamic>;
+            if (arr.length != 2) {
+                throw Exception('Expected 2 elements, got ${arr.length}');
+            }
+            return (dco_decode_String(arr[0]),dco_decode_u_64(arr[1]),); }
+
+@protected (BigInt,BigInt) dco_decode_record_u_64_u_64(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+final arr = raw as List<dynamic>;
+            if (arr.length != 2) {
+                throw Exception('Expected 2 elements, got ${arr.length}');
+            }
+            return (dco_decode_u_64(arr[0]),dco_decode_u_64(arr[1]),); }
+
+@protected Region dco_decode_region(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+final arr = raw as List<dynamic>;
+                if (arr.length != 4) throw Exception('unexpected arr length: expect 4 but see ${arr.length}');
+                return Region(x: dco_decode_i_32(arr[0]),
+y: dco_decode_i_32(arr[1]),
+width: dco_decode_i_32(arr[2]),
+height: dco_decode_i_32(arr[3]),); }
+
+@protected RepeatPolicy dco_decode_repeat_policy(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+switch (raw[0]) {
+                case 0: return RepeatPolicy_Silent();
+case 1: return RepeatPolicy_System();
+case 2: return RepeatPolicy_Custom(delayMs: dco_decode_u_32(raw[1]),intervalMs: dco_decode_u_32(raw[2]),);
+                default: throw Exception("unreachable");
+            } }
+
+@protected ServerConfig dco_decode_server_config(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+final arr = raw as List<dynamic>;
+                if (arr.length != 4) throw Exception('unexpected arr length: expect 4 but see ${arr.length}');
+                return ServerConfig(address: dco_decode_String(arr[0]),
+tokens: dco_decode_list_server_token(arr[1]),
+policy: dco_decode_opt_box_autoadd_policy(arr[2]),
+maxConnections: dco_decode_u_32(arr[3]),); }
+
+@protected ServerToken dco_decode_server_token(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+final arr = raw as List<dynamic>;
+                if (arr.length != 2) throw Exception('unexpected arr length: expect 2 but see ${arr.length}');
+                return ServerToken(token: dco_decode_String(arr[0]),
+policy: dco_decode_opt_box_autoadd_policy(arr[1]),); }
+
+@protected Settings dco_decode_settings(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+final arr = raw as List<dynamic>;
+                if (arr.length != 9) throw Exception('unexpected arr length: expect 9 but see ${arr.length}');
+                return Settings(linuxDelay: dco_decode_u_32(arr[0]),
+x11Display: dco_decode_opt_String(arr[1]),
+waylandDisplay: dco_decode_opt_String(arr[2]),
+windowsDwExtraInfo: dco_decode_opt_box_autoadd_usize(arr[3]),
+eventSourceUserData: dco_decode_opt_box_autoadd_i_64(arr[4]),
+releaseKeysWhenDropped: dco_decode_bool(arr[5]),
+openPromptToGetPermissions: dco_decode_bool(arr[6]),
+independentOfKeyboardState: dco_decode_bool(arr[7]),
+windowsSubjectToMouseSpeedAndAccelerationLevel: dco_decode_bool(arr[8]),); }
+
+@protected SettingsPatch dco_decode_settings_patch(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+final arr = raw as List<dynamic>;
+                if (arr.length != 10) throw Exception('unexpected arr length: expect 10 but see ${arr.length}');
+                return SettingsPatch(backend: dco_decode_opt_list_backend(arr[0]),
+linuxDelay: dco_decode_opt_box_autoadd_u_32(arr[1]),
+x11Display: dco_decode_opt_String(arr[2]),
+waylandDisplay: dco_decode_opt_String(arr[3]),
+windowsDwExtraInfo: dco_decode_opt_box_autoadd_usize(arr[4]),
+eventSourceUserData: dco_decode_opt_box_autoadd_i_64(arr[5]),
+releaseKeysWhenDropped: dco_decode_opt_box_autoadd_bool(arr[6]),
+openPromptToGetPermissions: dco_decode_opt_box_autoadd_bool(arr[7]),
+independentOfKeyboardState: dco_decode_opt_box_autoadd_bool(arr[8]),
+windowsSubjectToMouseSpeedAndAccelerationLevel: dco_decode_opt_box_autoadd_bool(arr[9]),); }
+
+@protected TextOptions dco_decode_text_options(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+final arr = raw as List<dynamic>;
+                if (arr.length != 1) throw Exception('unexpected arr length: expect 1 but see ${arr.length}');
+                return TextOptions(normalizeLockState: dco_decode_bool(arr[0]),); }
+
+@protected int dco_decode_u_16(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return raw as int; }
+
+@protected int dco_decode_u_32(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return raw as int; }
+
+@protected BigInt dco_decode_u_64(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return dcoDecodeU64(raw); }
+
+@protected int dco_decode_u_8(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return raw as int; }
+
+@protected void dco_decode_unit(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return; }
+
+@protected BigInt dco_decode_usize(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return dcoDecodeU64(raw); }
+
+@protected AnyhowException sse_decode_AnyhowException(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var inner = sse_decode_String(deserializer);
+        return AnyhowException(inner); }
+
+@protected Enigo sse_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerEnigo(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+return EnigoImpl.frbInternalSseDecode(sse_decode_usize(deserializer), sse_decode_i_32(deserializer)); }
+
+@protected HotkeyManager sse_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerHotkeyManager(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+return HotkeyManagerImpl.frbInternalSseDecode(sse_decode_usize(deserializer), sse_decode_i_32(deserializer)); }
+
+@protected InputEvent sse_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerInputEvent(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+return InputEventImpl.frbInternalSseDecode(sse_decode_usize(deserializer), sse_decode_i_32(deserializer)); }
+
+@protected InputServer sse_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerInputServer(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+return InputServerImpl.frbInternalSseDecode(sse_decode_usize(deserializer), sse_decode_i_32(deserializer)); }
+
+@protected Listener sse_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerListener(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+return ListenerImpl.frbInternalSseDecode(sse_decode_usize(deserializer), sse_decode_i_32(deserializer)); }
+
+@protected MockAction sse_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerMockAction(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+return MockActionImpl.frbInternalSseDecode(sse_decode_usize(deserializer), sse_decode_i_32(deserializer)); }
+
+@protected MockCall sse_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerMockCall(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+return MockCallImpl.frbInternalSseDecode(sse_decode_usize(deserializer), sse_decode_i_32(deserializer)); }
+
+@protected Recorder sse_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerRecorder(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+return RecorderImpl.frbInternalSseDecode(sse_decode_usize(deserializer), sse_decode_i_32(deserializer)); }
+
+@protected Recording sse_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerRecording(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+return RecordingImpl.frbInternalSseDecode(sse_decode_usize(deserializer), sse_decode_i_32(deserializer)); }
+
+@protected Remapper sse_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerRemapper(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+return RemapperImpl.frbInternalSseDecode(sse_decode_usize(deserializer), sse_decode_i_32(deserializer)); }
+
+@protected TraceEntry sse_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerTraceEntry(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+return TraceEntryImpl.frbInternalSseDecode(sse_decode_usize(deserializer), sse_decode_i_32(deserializer)); }
+
+@protected Button sse_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInner_Button(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+return ButtonImpl.frbInternalSseDecode(sse_decode_usize(deserializer), sse_decode_i_32(deserializer)); }
+
+@protected Enigo sse_decode_Auto_RefMut_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerEnigo(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+return EnigoImpl.frbInternalSseDecode(sse_decode_usize(deserializer), sse_decode_i_32(deserializer)); }
+
+@protected MockCall sse_decode_Auto_RefMut_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerMockCall(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+return MockCallImpl.frbInternalSseDecode(sse_decode_usize(deserializer), sse_decode_i_32(deserializer)); }
+
+@protected TraceEntry sse_decode_Auto_RefMut_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerTraceEntry(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+return TraceEntryImpl.frbInternalSseDecode(sse_decode_usize(deserializer), sse_decode_i_32(deserializer)); }
+
+@protected Button sse_decode_Auto_RefMut_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInner_Button(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+return ButtonImpl.frbInternalSseDecode(sse_decode_usize(deserializer), sse_decode_i_32(deserializer)); }
+
+@protected Enigo sse_decode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerEnigo(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+return EnigoImpl.frbInternalSseDecode(sse_decode_usize(deserializer), sse_decode_i_32(deserializer)); }
+
+@protected HotkeyManager sse_decode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerHotkeyManager(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+return HotkeyManagerImpl.frbInternalSseDecode(sse_decode_usize(deserializer), sse_decode_i_32(deserializer)); }
+
+@protected InputServer sse_decode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerInputServer(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+return InputServerImpl.frbInternalSseDecode(sse_decode_usize(deserializer), sse_decode_i_32(deserializer)); }
+
+@protected Listener sse_decode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerListener(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+return ListenerImpl.frbInternalSseDecode(sse_decode_usize(deserializer), sse_decode_i_32(deserializer)); }
+
+@protected MockCall sse_decode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerMockCall(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+return MockCallImpl.frbInternalSseDecode(sse_decode_usize(deserializer), sse_decode_i_32(deserializer)); }
+
+@protected Recorder sse_decode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerRecorder(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+return RecorderImpl.frbInternalSseDecode(sse_decode_usize(deserializer), sse_decode_i_32(deserializer)); }
+
+@protected Recording sse_decode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerRecording(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+return RecordingImpl.frbInternalSseDecode(sse_decode_usize(deserializer), sse_decode_i_32(deserializer)); }
+
+@protected Remapper sse_decode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerRemapper(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+return RemapperImpl.frbInternalSseDecode(sse_decode_usize(deserializer), sse_decode_i_32(deserializer)); }
+
+@protected TraceEntry sse_decode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerTraceEntry(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+return TraceEntryImpl.frbInternalSseDecode(sse_decode_usize(deserializer), sse_decode_i_32(deserializer)); }
+
+@protected PhysicalKeyboardKey sse_decode_CustomSerializer_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerKey(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var inner = sse_decode_u_32(deserializer);
+        return PhysicalKeyboardKey(inner); }
+
+@protected Enigo sse_decode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerEnigo(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+return EnigoImpl.frbInternalSseDecode(sse_decode_usize(deserializer), sse_decode_i_32(deserializer)); }
+
+@protected HotkeyManager sse_decode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerHotkeyManager(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+return HotkeyManagerImpl.frbInternalSseDecode(sse_decode_usize(deserializer), sse_decode_i_32(deserializer)); }
+
+@protected InputEvent sse_decode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerInputEvent(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+return InputEventImpl.frbInternalSseDecode(sse_decode_usize(deserializer), sse_decode_i_32(deserializer)); }
+
+@protected InputServer sse_decode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerInputServer(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+return InputServerImpl.frbInternalSseDecode(sse_decode_usize(deserializer), sse_decode_i_32(deserializer)); }
+
+@protected Listener sse_decode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerListener(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+return ListenerImpl.frbInternalSseDecode(sse_decode_usize(deserializer), sse_decode_i_32(deserializer)); }
+
+@protected MockAction sse_decode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerMockAction(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+return MockActionImpl.frbInternalSseDecode(sse_decode_usize(deserializer), sse_decode_i_32(deserializer)); }
+
+@protected MockCall sse_decode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerMockCall(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+return MockCallImpl.frbInternalSseDecode(sse_decode_usize(deserializer), sse_decode_i_32(deserializer)); }
+
+@protected Recorder sse_decode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerRecorder(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+return RecorderImpl.frbInternalSseDecode(sse_decode_usize(deserializer), sse_decode_i_32(deserializer)); }
+
+@protected Recording sse_decode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerRecording(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+return RecordingImpl.frbInternalSseDecode(sse_decode_usize(deserializer), sse_decode_i_32(deserializer)); }
+
+@protected Remapper sse_decode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerRemapper(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+return RemapperImpl.frbInternalSseDecode(sse_decode_usize(deserializer), sse_decode_i_32(deserializer)); }
+
+@protected TraceEntry sse_decode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerTraceEntry(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+return TraceEntryImpl.frbInternalSseDecode(sse_decode_usize(deserializer), sse_decode_i_32(deserializer)); }
+
+@protected Button sse_decode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInner_Button(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+return ButtonImpl.frbInternalSseDecode(sse_decode_usize(deserializer), sse_decode_i_32(deserializer)); }
+
+@protected RustStreamSink<InputEvent> sse_decode_StreamSink_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerInputEvent_Sse(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+throw UnimplementedError('Unreachable ()'); }
+
+@protected RustStreamSink<HotkeyEvent> sse_decode_StreamSink_hotkey_event_Sse(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+throw UnimplementedError('Unreachable ()'); }
+
+@protected RustStreamSink<LogRecord> sse_decode_StreamSink_log_record_Sse(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+throw UnimplementedError('Unreachable ()'); }
+
+@protected RustStreamSink<ReconnectEvent> sse_decode_StreamSink_reconnect_event_Sse(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+throw UnimplementedError('Unreachable ()'); }
+
+@protected String sse_decode_String(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var inner = sse_decode_list_prim_u_8_strict(deserializer);
+        return utf8.decoder.convert(inner); }
+
+@protected Axis sse_decode_axis(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var inner = sse_decode_i_32(deserializer);
+        return Axis.values[inner]; }
+
+@protected Backend sse_decode_backend(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var inner = sse_decode_i_32(deserializer);
+        return Backend.values[inner]; }
+
+@protected BackendInfo sse_decode_backend_info(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var var_backend = sse_decode_backend(deserializer);
+var var_display = sse_decode_opt_String(deserializer);
+var var_compiled = sse_decode_list_backend(deserializer);
+var var_capabilities = sse_decode_capabilities(deserializer);
+return BackendInfo(backend: var_backend, display: var_display, compiled: var_compiled, capabilities: var_capabilities); }
+
+@protected bool sse_decode_bool(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+return deserializer.buffer.getUint8() != 0; }
+
+@protected bool sse_decode_box_autoadd_bool(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+return (sse_decode_bool(deserializer)); }
+
+@protected HeldInput sse_decode_box_autoadd_held_input(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+return (sse_decode_held_input(deserializer)); }
+
+@protected PlatformInt64 sse_decode_box_autoadd_i_64(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+return (sse_decode_i_64(deserializer)); }
+
+@protected Metrics sse_decode_box_autoadd_metrics(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+return (sse_decode_metrics(deserializer)); }
+
+@protected PacerConfig sse_decode_box_autoadd_pacer_config(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+return (sse_decode_pacer_config(deserializer)); }
+
+@protected PacerStats sse_decode_box_autoadd_pacer_stats(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+return (sse_decode_pacer_stats(deserializer)); }
+
+@protected PlaybackOptions sse_decode_box_autoadd_playback_options(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+return (sse_decode_playback_options(deserializer)); }
+
+@protected Policy sse_decode_box_autoadd_policy(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+return (sse_decode_policy(deserializer)); }
+
+@protected RateLimit sse_decode_box_autoadd_rate_limit(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+return (sse_decode_rate_limit(deserializer)); }
+
+@protected ReconnectPolicy sse_decode_box_autoadd_reconnect_policy(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+return (sse_decode_reconnect_policy(deserializer)); }
+
+@protected (int,int) sse_decode_box_autoadd_record_i_32_i_32(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+return (sse_decode_record_i_32_i_32(deserializer)); }
+
+@protected RepeatPolicy sse_decode_box_autoadd_repeat_policy(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+return (sse_decode_repeat_policy(deserializer)); }
+
+@protected ServerConfig sse_decode_box_autoadd_server_config(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+return (sse_decode_server_config(deserializer)); }
+
+@protected Settings sse_decode_box_autoadd_settings(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+return (sse_decode_settings(deserializer)); }
+
+@protected SettingsPatch sse_decode_box_autoadd_settings_patch(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+return (sse_decode_settings_patch(deserializer)); }
+
+@protected TextOptions sse_decode_box_autoadd_text_options(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+return (sse_decode_text_options(deserializer)); }
+
+@protected int sse_decode_box_autoadd_u_32(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+return (sse_decode_u_32(deserializer)); }
+
+@protected BigInt sse_decode_box_autoadd_usize(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+return (sse_decode_usize(deserializer)); }
+
+@protected Capabilities sse_decode_capabilities(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var var_buttons = sse_decode_list_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInner_Button(deserializer);
+var var_absoluteMove = sse_decode_bool(deserializer);
+var var_location = sse_decode_bool(deserializer);
+var var_unicodeText = sse_decode_bool(deserializer);
+var var_rawKeycodes = sse_decode_bool(deserializer);
+var var_scrollResolution = sse_decode_u_32(deserializer);
+return Capabilities(buttons: var_buttons, absoluteMove: var_absoluteMove, location: var_location, unicodeText: var_unicodeText, rawKeycodes: var_rawKeycodes, scrollResolution: var_scrollResolution); }
+
+@protected Coordinate sse_decode_coordinate(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var inner = sse_decode_i_32(deserializer);
+        return Coordinate.values[inner]; }
+
+@protected Direction sse_decode_direction(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var inner = sse_decode_i_32(deserializer);
+        return Direction.values[inner]; }
+
+@protected EventGaps sse_decode_event_gaps(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var var_key = sse_decode_u_32(deserializer);
+var var_button = sse_decode_u_32(deserializer);
+var var_moveMouse = sse_decode_u_32(deserializer);
+var var_scroll = sse_decode_u_32(deserializer);
+var var_text = sse_decode_u_32(deserializer);
+return EventGaps(key: var_key, button: var_button, moveMouse: var_moveMouse, scroll: var_scroll, text: var_text); }
+
+@protected double sse_decode_f_64(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+return deserializer.buffer.getFloat64(); }
+
+@protected HeldInput sse_decode_held_input(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var var_keys = sse_decode_list_CustomSerializer_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerKey(deserializer);
+var var_buttons = sse_decode_list_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInner_Button(deserializer);
+var var_raw = sse_decode_list_prim_u_16_strict(deserializer);
+return HeldInput(keys: var_keys, buttons: var_buttons, raw: var_raw); }
+
+@protected Histogram sse_decode_histogram(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var var_count = sse_decode_u_64(deserializer);
+var var_sumUs = sse_decode_u_64(deserializer);
+var var_maxUs = sse_decode_u_64(deserializer);
+var var_buckets = sse_decode_list_record_u_64_u_64(deserializer);
+return Histogram(count: var_count, sumUs: var_sumUs, maxUs: var_maxUs, buckets: var_buckets); }
+
+@protected HotkeyEvent sse_decode_hotkey_event(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var var_id = sse_decode_u_32(deserializer);
+var var_accelerator = sse_decode_String(deserializer);
+return HotkeyEvent(id: var_id, accelerator: var_accelerator); }
+
+@protected int sse_decode_i_32(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+return deserializer.buffer.getInt32(); }
+
+@protected PlatformInt64 sse_decode_i_64(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+return deserializer.buffer.getPlatformInt64(); }
+
+@protected List<MockCall> sse_decode_list_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerMockCall(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+
+        var len_ = sse_decode_i_32(deserializer);
+        var ans_ = <MockCall>[];
+        for (var idx_ = 0; idx_ < len_; ++idx_) { ans_.add(sse_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerMockCall(deserializer)); }
+        return ans_;
+         }
+
+@protected List<TraceEntry> sse_decode_list_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerTraceEntry(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+
+        var len_ = sse_decode_i_32(deserializer);
+        var ans_ = <TraceEntry>[];
+        for (var idx_ = 0; idx_ < len_; ++idx_) { ans_.add(sse_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerTraceEntry(deserializer)); }
+        return ans_;
+         }
+
+@protected List<Button> sse_decode_list_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInner_Button(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+
+        var len_ = sse_decode_i_32(deserializer);
+        var ans_ = <Button>[];
+        for (var idx_ = 0; idx_ < len_; ++idx_) { ans_.add(sse_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInner_Button(deserializer)); }
+        return ans_;
+         }
+
+@protected List<PhysicalKeyboardKey> sse_decode_list_CustomSerializer_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerKey(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+
+        var len_ = sse_decode_i_32(deserializer);
+        var ans_ = <PhysicalKeyboardKey>[];
+        for (var idx_ = 0; idx_ < len_; ++idx_) { ans_.add(sse_decode_CustomSerializer_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerKey(deserializer)); }
+        return ans_;
+         }
+
+@protected List<String> sse_decode_list_String(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+
+        var len_ = sse_decode_i_32(deserializer);
+        var ans_ = <String>[];
+        for (var idx_ = 0; idx_ < len_; ++idx_) { ans_.add(sse_decode_String(deserializer)); }
+        return ans_;
+         }
+
+@protected List<Backend> sse_decode_list_backend(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+
+        var len_ = sse_decode_i_32(deserializer);
+        var ans_ = <Backend>[];
+        for (var idx_ = 0; idx_ < len_; ++idx_) { ans_.add(sse_decode_backend(deserializer)); }
+        return ans_;
+         }
+
+@protected List<List<PhysicalKeyboardKey>> sse_decode_list_list_CustomSerializer_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerKey(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+
+        var len_ = sse_decode_i_32(deserializer);
+        var ans_ = <List<PhysicalKeyboardKey>>[];
+        for (var idx_ = 0; idx_ < len_; ++idx_) { ans_.add(sse_decode_list_CustomSerializer_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerKey(deserializer)); }
+        return ans_;
+         }
+
+@protected List<OperationMetrics> sse_decode_list_operation_metrics(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+
+        var len_ = sse_decode_i_32(deserializer);
+        var ans_ = <OperationMetrics>[];
+        for (var idx_ = 0; idx_ < len_; ++idx_) { ans_.add(sse_decode_operation_metrics(deserializer)); }
+        return ans_;
+         }
+
+@protected Uint16List sse_decode_list_prim_u_16_strict(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var len_ = sse_decode_i_32(deserializer);
+                return deserializer.buffer.getUint16List(len_); }
+
+@protected Uint8List sse_decode_list_prim_u_8_strict(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var len_ = sse_decode_i_32(deserializer);
+                return deserializer.buffer.getUint8List(len_); }
+
+@protected List<(String,String)> sse_decode_list_record_string_string(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+
+        var len_ = sse_decode_i_32(deserializer);
+        var ans_ = <(String,String)>[];
+        for (var idx_ = 0; idx_ < len_; ++idx_) { ans_.add(sse_decode_record_string_string(deserializer)); }
+        return ans_;
+         }
+
+@protected List<(String,BigInt)> sse_decode_list_record_string_u_64(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+
+        var len_ = sse_decode_i_32(deserializer);
+        var ans_ = <(String,BigInt)>[];
+        for (var idx_ = 0; idx_ < len_; ++idx_) { ans_.add(sse_decode_record_string_u_64(deserializer)); }
+        return ans_;
+         }
+
+@protected List<(BigInt,BigInt)> sse_decode_list_record_u_64_u_64(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+
+        var len_ = sse_decode_i_32(deserializer);
+        var ans_ = <(BigInt,BigInt)>[];
+        for (var idx_ = 0; idx_ < len_; ++idx_) { ans_.add(sse_decode_record_u_64_u_64(deserializer)); }
+        return ans_;
+         }
+
+@protected List<Region> sse_decode_list_region(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+
+        var len_ = sse_decode_i_32(deserializer);
+        var ans_ = <Region>[];
+        for (var idx_ = 0; idx_ < len_; ++idx_) { ans_.add(sse_decode_region(deserializer)); }
+        return ans_;
+         }
+
+@protected List<ServerToken> sse_decode_list_server_token(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+
+        var len_ = sse_decode_i_32(deserializer);
+        var ans_ = <ServerToken>[];
+        for (var idx_ = 0; idx_ < len_; ++idx_) { ans_.add(sse_decode_server_token(deserializer)); }
+        return ans_;
+         }
+
+@protected LockState sse_decode_lock_state(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var var_capsLock = sse_decode_bool(deserializer);
+var var_numLock = sse_decode_bool(deserializer);
+var var_scrollLock = sse_decode_bool(deserializer);
+return LockState(capsLock: var_capsLock, numLock: var_numLock, scrollLock: var_scrollLock); }
+
+@protected LogLevel sse_decode_log_level(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var inner = sse_decode_i_32(deserializer);
+        return LogLevel.values[inner]; }
+
+@protected LogRecord sse_decode_log_record(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var var_timestampMs = sse_decode_u_64(deserializer);
+var var_level = sse_decode_log_level(deserializer);
+var var_target = sse_decode_String(deserializer);
+var var_spans = sse_decode_list_String(deserializer);
+var var_message = sse_decode_String(deserializer);
+var var_fields = sse_decode_list_record_string_string(deserializer);
+return LogRecord(timestampMs: var_timestampMs, level: var_level, target: var_target, spans: var_spans, message: var_message, fields: var_fields); }
+
+@protected LogRotation sse_decode_log_rotation(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var inner = sse_decode_i_32(deserializer);
+        return LogRotation.values[inner]; }
+
+@protected Metrics sse_decode_metrics(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var var_operations = sse_decode_list_operation_metrics(deserializer);
+return Metrics(operations: var_operations); }
+
+@protected ModifierState sse_decode_modifier_state(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var var_shift = sse_decode_bool(deserializer);
+var var_control = sse_decode_bool(deserializer);
+var var_alt = sse_decode_bool(deserializer);
+var var_meta = sse_decode_bool(deserializer);
+return ModifierState(shift: var_shift, control: var_control, alt: var_alt, meta: var_meta); }
+
+@protected OperationMetrics sse_decode_operation_metrics(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var var_operation = sse_decode_String(deserializer);
+var var_lockWait = sse_decode_histogram(deserializer);
+var var_backendCall = sse_decode_histogram(deserializer);
+var var_endToEnd = sse_decode_histogram(deserializer);
+var var_successes = sse_decode_u_64(deserializer);
+var var_failures = sse_decode_list_record_string_u_64(deserializer);
+return OperationMetrics(operation: var_operation, lockWait: var_lockWait, backendCall: var_backendCall, endToEnd: var_endToEnd, successes: var_successes, failures: var_failures); }
+
+@protected String? sse_decode_opt_String(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+
+            if (sse_decode_bool(deserializer)) {
+                return (sse_decode_String(deserializer));
+            } else {
+                return null;
+            }
+             }
+
+@protected bool? sse_decode_opt_box_autoadd_bool(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+
+            if (sse_decode_bool(deserializer)) {
+                return (sse_decode_box_autoadd_bool(deserializer));
+            } else {
+                return null;
+            }
+             }
+
+@protected PlatformInt64? sse_decode_opt_box_autoadd_i_64(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+
+            if (sse_decode_bool(deserializer)) {
+                return (sse_decode_box_autoadd_i_64(deserializer));
+            } else {
+                return null;
+            }
+             }
+
+@protected PacerConfig? sse_decode_opt_box_autoadd_pacer_config(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+
+            if (sse_decode_bool(deserializer)) {
+                return (sse_decode_box_autoadd_pacer_config(deserializer));
+            } else {
+                return null;
+            }
+             }
+
+@protected PacerStats? sse_decode_opt_box_autoadd_pacer_stats(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+
+            if (sse_decode_bool(deserializer)) {
+                return (sse_decode_box_autoadd_pacer_stats(deserializer));
+            } else {
+                return null;
+            }
+             }
+
+@protected Policy? sse_decode_opt_box_autoadd_policy(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+
+            if (sse_decode_bool(deserializer)) {
+                return (sse_decode_box_autoadd_policy(deserializer));
+            } else {
+                return null;
+            }
+             }
+
+@protected RateLimit? sse_decode_opt_box_autoadd_rate_limit(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+
+            if (sse_decode_bool(deserializer)) {
+                return (sse_decode_box_autoadd_rate_limit(deserializer));
+            } else {
+                return null;
+            }
+             }
+
+@protected (int,int)? sse_decode_opt_box_autoadd_record_i_32_i_32(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+
+            if (sse_decode_bool(deserializer)) {
+                return (sse_decode_box_autoadd_record_i_32_i_32(deserializer));
+            } else {
+                return null;
+            }
+             }
+
+@protected int? sse_decode_opt_box_autoadd_u_32(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+
+            if (sse_decode_bool(deserializer)) {
+                return (sse_decode_box_autoadd_u_32(deserializer));
+            } else {
+                return null;
+            }
+             }
+
+@protected BigInt? sse_decode_opt_box_autoadd_usize(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+
+            if (sse_decode_bool(deserializer)) {
+                return (sse_decode_box_autoadd_usize(deserializer));
+            } else {
+                return null;
+            }
+             }
+
+@protected List<Backend>? sse_decode_opt_list_backend(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+
+            if (sse_decode_bool(deserializer)) {
+                return (sse_decode_list_backend(deserializer));
+            } else {
+                return null;
+            }
+             }
+
+@protected PacerConfig sse_decode_pacer_config(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var var_eventsPerSecond = sse_decode_f_64(deserializer);
+var var_burst = sse_decode_u_32(deserializer);
+var var_minGapMs = sse_decode_event_gaps(deserializer);
+return PacerConfig(eventsPerSecond: var_eventsPerSecond, burst: var_burst, minGapMs: var_minGapMs); }
+
+@protected PacerStats sse_decode_pacer_stats(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var var_events = sse_decode_u_64(deserializer);
+var var_delayed = sse_decode_u_64(deserializer);
+var var_totalWaitMs = sse_decode_u_64(deserializer);
+var var_maxWaitMs = sse_decode_u_64(deserializer);
+var var_tokens = sse_decode_f_64(deserializer);
+return PacerStats(events: var_events, delayed: var_delayed, totalWaitMs: var_totalWaitMs, maxWaitMs: var_maxWaitMs, tokens: var_tokens); }
+
+@protected PlaybackOptions sse_decode_playback_options(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var var_speed = sse_decode_f_64(deserializer);
+var var_loops = sse_decode_u_32(deserializer);
+var var_maxGapMs = sse_decode_opt_box_autoadd_u_32(deserializer);
+return PlaybackOptions(speed: var_speed, loops: var_loops, maxGapMs: var_maxGapMs); }
+
+@protected Policy sse_decode_policy(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var var_allowedKeys = sse_decode_list_CustomSerializer_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerKey(deserializer);
+var var_deniedKeys = sse_decode_list_CustomSerializer_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerKey(deserializer);
+var var_deniedChords = sse_decode_list_list_CustomSerializer_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerKey(deserializer);
+var var_allowRaw = sse_decode_bool(deserializer);
+var var_clickRegions = sse_decode_list_region(deserializer);
+var var_maxTextLen = sse_decode_opt_box_autoadd_u_32(deserializer);
+var var_rateLimit = sse_decode_opt_box_autoadd_rate_limit(deserializer);
+return Policy(allowedKeys: var_allowedKeys, deniedKeys: var_deniedKeys, deniedChords: var_deniedChords, allowRaw: var_allowRaw, clickRegions: var_clickRegions, maxTextLen: var_maxTextLen, rateLimit: var_rateLimit); }
+
+@protected PolicyViolation sse_decode_policy_violation(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+
+            var tag_ = sse_decode_i_32(deserializer);
+            switch (tag_) { case 0: var var_key = sse_decode_CustomSerializer_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerKey(deserializer);
+return PolicyViolation_KeyDenied(key: var_key);case 1: var var_chord = sse_decode_list_CustomSerializer_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerKey(deserializer);
+return PolicyViolation_ChordDenied(chord: var_chord);case 2: var var_keycode = sse_decode_u_16(deserializer);
+return PolicyViolation_RawDenied(keycode: var_keycode);case 3: var var_x = sse_decode_i_32(deserializer);
+var var_y = sse_decode_i_32(deserializer);
+return PolicyViolation_ClickOutsideRegions(x: var_x, y: var_y);case 4: var var_len = sse_decode_u_32(deserializer);
+var var_max = sse_decode_u_32(deserializer);
+return PolicyViolation_TextTooLong(len: var_len, max: var_max);case 5: var var_maxEvents = sse_decode_u_32(deserializer);
+var var_windowMs = sse_decode_u_32(deserializer);
+return PolicyViolation_RateLimited(maxEvents: var_maxEvents, windowMs: var_windowMs); default: throw UnimplementedError(''); }
+             }
+
+@protected RateLimit sse_decode_rate_limit(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var var_maxEvents = sse_decode_u_32(deserializer);
+var var_windowMs = sse_decode_u_32(deserializer);
+return RateLimit(maxEvents: var_maxEvents, windowMs: var_windowMs); }
+
+@protected ReconnectEvent sse_decode_reconnect_event(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var var_backend = sse_decode_backend(deserializer);
+var var_attempts = sse_decode_u_32(deserializer);
+var var_error = sse_decode_String(deserializer);
+return ReconnectEvent(backend: var_backend, attempts: var_attempts, error: var_error); }
+
+@protected ReconnectPolicy sse_decode_reconnect_policy(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+
+            var tag_ = sse_decode_i_32(deserializer);
+            switch (tag_) { case 0: return ReconnectPolicy_Never();case 1: var var_maxAttempts = sse_decode_u_32(deserializer);
+var var_delayMs = sse_decode_u_32(deserializer);
+return ReconnectPolicy_Retry(maxAttempts: var_maxAttempts, delayMs: var_delayMs); default: throw UnimplementedError(''); }
+             }
+
+@protected (int,int) sse_decode_record_i_32_i_32(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var var_field0 = sse_decode_i_32(deserializer);
+var var_field1 = sse_decode_i_32(deserializer);
+return (var_field0, var_field1); }
+
+@protected (String,String) sse_decode_record_string_string(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var var_field0 = sse_decode_String(deserializer);
+var var_field1 = sse_decode_String(deserializer);
+return (var_field0, var_field1); }
+
+@protected (String,BigInt) sse_decode_record_string_u_64(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var var_field0 = sse_decode_String(deserializer);
+var var_field1 = sse_decode_u_64(deserializer);
+return (var_field0, var_field1); }
+
+@protected (BigInt,BigInt) sse_decode_record_u_64_u_64(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var var_field0 = sse_decode_u_64(deserializer);
+var var_field1 = sse_decode_u_64(deserializer);
+return (var_field0, var_field1); }
+
+@protected Region sse_decode_region(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var var_x = sse_decode_i_32(deserializer);
+var var_y = sse_decode_i_32(deserializer);
+var var_width = sse_decode_i_32(deserializer);
+var var_height = sse_decode_i_32(deserializer);
+return Region(x: var_x, y: var_y, width: var_width, height: var_height); }
+
+@protected RepeatPolicy sse_decode_repeat_policy(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+
+            var tag_ = sse_decode_i_32(deserializer);
+            switch (tag_) { case 0: return RepeatPolicy_Silent();case 1: return RepeatPolicy_System();case 2: var var_delayMs = sse_decode_u_32(deserializer);
+var var_intervalMs = sse_decode_u_32(deserializer);
+return RepeatPolicy_Custom(delayMs: var_delayMs, intervalMs: var_intervalMs); default: throw UnimplementedError(''); }
+             }
+
+@protected ServerConfig sse_decode_server_config(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var var_address = sse_decode_String(deserializer);
+var var_tokens = sse_decode_list_server_token(deserializer);
+var var_policy = sse_decode_opt_box_autoadd_policy(deserializer);
+var var_maxConnections = sse_decode_u_32(deserializer);
+return ServerConfig(address: var_address, tokens: var_tokens, policy: var_policy, maxConnections: var_maxConnections); }
+
+@protected ServerToken sse_decode_server_token(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var var_token = sse_decode_String(deserializer);
+var var_policy = sse_decode_opt_box_autoadd_policy(deserializer);
+return ServerToken(token: var_token, policy: var_policy); }
+
+@protected Settings sse_decode_settings(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var var_linuxDelay = sse_decode_u_32(deserializer);
+var var_x11Display = sse_decode_opt_String(deserializer);
+var var_waylandDisplay = sse_decode_opt_String(deserializer);
+var var_windowsDwExtraInfo = sse_decode_opt_box_autoadd_usize(deserializer);
+var var_eventSourceUserData = sse_decode_opt_box_autoadd_i_64(deserializer);
+var var_releaseKeysWhenDropped = sse_decode_bool(deserializer);
+var var_openPromptToGetPermissions = sse_decode_bool(deserializer);
+var var_independentOfKeyboardState = sse_decode_bool(deserializer);
+var var_windowsSubjectToMouseSpeedAndAccelerationLevel = sse_decode_bool(deserializer);
+return Settings(linuxDelay: var_linuxDelay, x11Display: var_x11Display, waylandDisplay: var_waylandDisplay, windowsDwExtraInfo: var_windowsDwExtraInfo, eventSourceUserData: var_eventSourceUserData, releaseKeysWhenDropped: var_releaseKeysWhenDropped, openPromptToGetPermissions: var_openPromptToGetPermissions, independentOfKeyboardState: var_independentOfKeyboardState, windowsSubjectToMouseSpeedAndAccelerationLevel: var_windowsSubjectToMouseSpeedAndAccelerationLevel); }
+
+@protected SettingsPatch sse_decode_settings_patch(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var var_backend = sse_decode_opt_list_backend(deserializer);
+var var_linuxDelay = sse_decode_opt_box_autoadd_u_32(deserializer);
+var var_x11Display = sse_decode_opt_String(deserializer);
+var var_waylandDisplay = sse_decode_opt_String(deserializer);
+var var_windowsDwExtraInfo = sse_decode_opt_box_autoadd_usize(deserializer);
+var var_eventSourceUserData = sse_decode_opt_box_autoadd_i_64(deserializer);
+var var_releaseKeysWhenDropped = sse_decode_opt_box_autoadd_bool(deserializer);
+var var_openPromptToGetPermissions = sse_decode_opt_box_autoadd_bool(deserializer);
+var var_independentOfKeyboardState = sse_decode_opt_box_autoadd_bool(deserializer);
+var var_windowsSubjectToMouseSpeedAndAccelerationLevel = sse_decode_opt_box_autoadd_bool(deserializer);
+return SettingsPatch(backend: var_backend, linuxDelay: var_linuxDelay, x11Display: var_x11Display, waylandDisplay: var_waylandDisplay, windowsDwExtraInfo: var_windowsDwExtraInfo, eventSourceUserData: var_eventSourceUserData, releaseKeysWhenDropped: var_releaseKeysWhenDropped, openPromptToGetPermissions: var_openPromptToGetPermissions, independentOfKeyboardState: var_independentOfKeyboardState, windowsSubjectToMouseSpeedAndAccelerationLevel: var_windowsSubjectToMouseSpeedAndAccelerationLevel); }
+
+@protected TextOptions sse_decode_text_options(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var var_normalizeLockState = sse_decode_bool(deserializer);
+return TextOptions(normalizeLockState: var_normalizeLockState); }
+
+@protected int sse_decode_u_16(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+return deserializer.buffer.getUint16(); }
+
+@protected int sse_decode_u_32(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+return deserializer.buffer.getUint32(); }
+
+@protected BigInt sse_decode_u_64(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+return deserializer.buffer.getBigUint64(); }
+
+@protected int sse_decode_u_8(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+return deserializer.buffer.getUint8(); }
+
+@protected void sse_decode_unit(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+ }
+
+@protected BigInt sse_decode_usize(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+return deserializer.buffer.getBigUint64(); }
+
+@protected void sse_encode_AnyhowException(AnyhowException self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_String(self.message, serializer); }
+
+@protected void sse_encode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerEnigo(Enigo self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_usize((self as EnigoImpl).frbInternalSseEncode(move: true), serializer); }
+
+@protected void sse_encode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerHotkeyManager(HotkeyManager self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_usize((self as HotkeyManagerImpl).frbInternalSseEncode(move: true), serializer); }
+
+@protected void sse_encode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerInputEvent(InputEvent self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_usize((self as InputEventImpl).frbInternalSseEncode(move: true), serializer); }
+
+@protected void sse_encode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerInputServer(InputServer self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_usize((self as InputServerImpl).frbInternalSseEncode(move: true), serializer); }
+
+@protected void sse_encode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerListener(Listener self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_usize((self as ListenerImpl).frbInternalSseEncode(move: true), serializer); }
+
+@protected void sse_encode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerMockAction(MockAction self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_usize((self as MockActionImpl).frbInternalSseEncode(move: true), serializer); }
+
+@protected void sse_encode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerMockCall(MockCall self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_usize((self as MockCallImpl).frbInternalSseEncode(move: true), serializer); }
+
+@protected void sse_encode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerRecorder(Recorder self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_usize((self as RecorderImpl).frbInternalSseEncode(move: true), serializer); }
+
+@protected void sse_encode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerRecording(Recording self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_usize((self as RecordingImpl).frbInternalSseEncode(move: true), serializer); }
+
+@protected void sse_encode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerRemapper(Remapper self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_usize((self as RemapperImpl).frbInternalSseEncode(move: true), serializer); }
+
+@protected void sse_encode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerTraceEntry(TraceEntry self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_usize((self as TraceEntryImpl).frbInternalSseEncode(move: true), serializer); }
+
+@protected void sse_encode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInner_Button(Button self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_usize((self as ButtonImpl).frbInternalSseEncode(move: true), serializer); }
+
+@protected void sse_encode_Auto_RefMut_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerEnigo(Enigo self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_usize((self as EnigoImpl).frbInternalSseEncode(move: false), serializer); }
+
+@protected void sse_encode_Auto_RefMut_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerMockCall(MockCall self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_usize((self as MockCallImpl).frbInternalSseEncode(move: false), serializer); }
+
+@protected void sse_encode_Auto_RefMut_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerTraceEntry(TraceEntry self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_usize((self as TraceEntryImpl).frbInternalSseEncode(move: false), serializer); }
+
+@protected void sse_encode_Auto_RefMut_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInner_Button(Button self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_usize((self as ButtonImpl).frbInternalSseEncode(move: false), serializer); }
+
+@protected void sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerEnigo(Enigo self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_usize((self as EnigoImpl).frbInternalSseEncode(move: false), serializer); }
+
+@protected void sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerHotkeyManager(HotkeyManager self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_usize((self as HotkeyManagerImpl).frbInternalSseEncode(move: false), serializer); }
+
+@protected void sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerInputServer(InputServer self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_usize((self as InputServerImpl).frbInternalSseEncode(move: false), serializer); }
+
+@protected void sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerListener(Listener self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_usize((self as ListenerImpl).frbInternalSseEncode(move: false), serializer); }
+
+@protected void sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerMockCall(MockCall self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_usize((self as MockCallImpl).frbInternalSseEncode(move: false), serializer); }
+
+@protected void sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerRecorder(Recorder self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_usize((self as RecorderImpl).frbInternalSseEncode(move: false), serializer); }
+
+@protected void sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerRecording(Recording self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_usize((self as RecordingImpl).frbInternalSseEncode(move: false), serializer); }
+
+@protected void sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerRemapper(Remapper self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_usize((self as RemapperImpl).frbInternalSseEncode(move: false), serializer); }
+
+@protected void sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerTraceEntry(TraceEntry self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_usize((self as TraceEntryImpl).frbInternalSseEncode(move: false), serializer); }
+
+@protected void sse_encode_CustomSerializer_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerKey(PhysicalKeyboardKey self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_u_32(self.usbHidUsage, serializer); }
+
+@protected void sse_encode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerEnigo(Enigo self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_usize((self as EnigoImpl).frbInternalSseEncode(move: null), serializer); }
+
+@protected void sse_encode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerHotkeyManager(HotkeyManager self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_usize((self as HotkeyManagerImpl).frbInternalSseEncode(move: null), serializer); }
+
+@protected void sse_encode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerInputEvent(InputEvent self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_usize((self as InputEventImpl).frbInternalSseEncode(move: null), serializer); }
+
+@protected void sse_encode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerInputServer(InputServer self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_usize((self as InputServerImpl).frbInternalSseEncode(move: null), serializer); }
+
+@protected void sse_encode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerListener(Listener self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_usize((self as ListenerImpl).frbInternalSseEncode(move: null), serializer); }
+
+@protected void sse_encode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerMockAction(MockAction self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_usize((self as MockActionImpl).frbInternalSseEncode(move: null), serializer); }
+
+@protected void sse_encode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerMockCall(MockCall self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_usize((self as MockCallImpl).frbInternalSseEncode(move: null), serializer); }
+
+@protected void sse_encode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerRecorder(Recorder self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_usize((self as RecorderImpl).frbInternalSseEncode(move: null), serializer); }
+
+@protected void sse_encode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerRecording(Recording self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_usize((self as RecordingImpl).frbInternalSseEncode(move: null), serializer); }
+
+@protected void sse_encode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerRemapper(Remapper self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_usize((self as RemapperImpl).frbInternalSseEncode(move: null), serializer); }
+
+@protected void sse_encode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerTraceEntry(TraceEntry self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_usize((self as TraceEntryImpl).frbInternalSseEncode(move: null), serializer); }
+
+@protected void sse_encode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInner_Button(Button self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_usize((self as ButtonImpl).frbInternalSseEncode(move: null), serializer); }
+
+@protected void sse_encode_StreamSink_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerInputEvent_Sse(RustStreamSink<InputEvent> self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_String(self.setupAndSerialize(codec: SseCodec(
+            decodeSuccessData: sse_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerInputEvent,
+            decodeErrorData: sse_decode_AnyhowException,
+        )), serializer); }
+
+@protected void sse_encode_StreamSink_hotkey_event_Sse(RustStreamSink<HotkeyEvent> self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_String(self.setupAndSerialize(codec: SseCodec(
+            decodeSuccessData: sse_decode_hotkey_event,
+            decodeErrorData: sse_decode_AnyhowException,
+        )), serializer); }
+
+@protected void sse_encode_StreamSink_log_record_Sse(RustStreamSink<LogRecord> self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_String(self.setupAndSerialize(codec: SseCodec(
+            decodeSuccessData: sse_decode_log_record,
+            decodeErrorData: sse_decode_AnyhowException,
+        )), serializer); }
+
+@protected void sse_encode_StreamSink_reconnect_event_Sse(RustStreamSink<ReconnectEvent> self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_String(self.setupAndSerialize(codec: SseCodec(
+            decodeSuccessData: sse_decode_reconnect_event,
+            decodeErrorData: sse_decode_AnyhowException,
+        )), serializer); }
+
+@protected void sse_encode_String(String self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_list_prim_u_8_strict(utf8.encoder.convert(self), serializer); }
+
+@protected void sse_encode_axis(Axis self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_i_32(self.index, serializer); }
+
+@protected void sse_encode_backend(Backend self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_i_32(self.index, serializer); }
+
+@protected void sse_encode_backend_info(BackendInfo self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_backend(self.backend, serializer);
+sse_encode_opt_String(self.display, serializer);
+sse_encode_list_backend(self.compiled, serializer);
+sse_encode_capabilities(self.capabilities, serializer);
+ }
+
+@protected void sse_encode_bool(bool self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+serializer.buffer.putUint8(self ? 1 : 0); }
+
+@protected void sse_encode_box_autoadd_bool(bool self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_bool(self, serializer); }
+
+@protected void sse_encode_box_autoadd_held_input(HeldInput self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_held_input(self, serializer); }
+
+@protected void sse_encode_box_autoadd_i_64(PlatformInt64 self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_i_64(self, serializer); }
+
+@protected void sse_encode_box_autoadd_metrics(Metrics self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_metrics(self, serializer); }
+
+@protected void sse_encode_box_autoadd_pacer_config(PacerConfig self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_pacer_config(self, serializer); }
+
+@protected void sse_encode_box_autoadd_pacer_stats(PacerStats self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_pacer_stats(self, serializer); }
+
+@protected void sse_encode_box_autoadd_playback_options(PlaybackOptions self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_playback_options(self, serializer); }
+
+@protected void sse_encode_box_autoadd_policy(Policy self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_policy(self, serializer); }
+
+@protected void sse_encode_box_autoadd_rate_limit(RateLimit self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_rate_limit(self, serializer); }
+
+@protected void sse_encode_box_autoadd_reconnect_policy(ReconnectPolicy self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_reconnect_policy(self, serializer); }
+
+@protected void sse_encode_box_autoadd_record_i_32_i_32((int,int) self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_record_i_32_i_32(self, serializer); }
+
+@protected void sse_encode_box_autoadd_repeat_policy(RepeatPolicy self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_repeat_policy(self, serializer); }
+
+@protected void sse_encode_box_autoadd_server_config(ServerConfig self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_server_config(self, serializer); }
+
+@protected void sse_encode_box_autoadd_settings(Settings self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_settings(self, serializer); }
+
+@protected void sse_encode_box_autoadd_settings_patch(SettingsPatch self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_settings_patch(self, serializer); }
+
+@protected void sse_encode_box_autoadd_text_options(TextOptions self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_text_options(self, serializer); }
+
+@protected void sse_encode_box_autoadd_u_32(int self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_u_32(self, serializer); }
+
+@protected void sse_encode_box_autoadd_usize(BigInt self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_usize(self, serializer); }
+
+@protected void sse_encode_capabilities(Capabilities self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_list_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInner_Button(self.buttons, serializer);
+sse_encode_bool(self.absoluteMove, serializer);
+sse_encode_bool(self.location, serializer);
+sse_encode_bool(self.unicodeText, serializer);
+sse_encode_bool(self.rawKeycodes, serializer);
+sse_encode_u_32(self.scrollResolution, serializer);
+ }
+
+@protected void sse_encode_coordinate(Coordinate self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_i_32(self.index, serializer); }
+
+@protected void sse_encode_direction(Direction self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_i_32(self.index, serializer); }
+
+@protected void sse_encode_event_gaps(EventGaps self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_u_32(self.key, serializer);
+sse_encode_u_32(self.button, serializer);
+sse_encode_u_32(self.moveMouse, serializer);
+sse_encode_u_32(self.scroll, serializer);
+sse_encode_u_32(self.text, serializer);
+ }
+
+@protected void sse_encode_f_64(double self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+serializer.buffer.putFloat64(self); }
+
+@protected void sse_encode_held_input(HeldInput self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_list_CustomSerializer_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerKey(self.keys, serializer);
+sse_encode_list_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInner_Button(self.buttons, serializer);
+sse_encode_list_prim_u_16_strict(self.raw, serializer);
+ }
+
+@protected void sse_encode_histogram(Histogram self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_u_64(self.count, serializer);
+sse_encode_u_64(self.sumUs, serializer);
+sse_encode_u_64(self.maxUs, serializer);
+sse_encode_list_record_u_64_u_64(self.buckets, serializer);
+ }
+
+@protected void sse_encode_hotkey_event(HotkeyEvent self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_u_32(self.id, serializer);
+sse_encode_String(self.accelerator, serializer);
+ }
+
+@protected void sse_encode_i_32(int self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+serializer.buffer.putInt32(self); }
+
+@protected void sse_encode_i_64(PlatformInt64 self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+serializer.buffer.putPlatformInt64(self); }
+
+@protected void sse_encode_list_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerMockCall(List<MockCall> self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_i_32(self.length, serializer);
+        for (final item in self) { sse_encode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerMockCall(item, serializer); } }
+
+@protected void sse_encode_list_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerTraceEntry(List<TraceEntry> self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_i_32(self.length, serializer);
+        for (final item in self) { sse_encode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerTraceEntry(item, serializer); } }
+
+@protected void sse_encode_list_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInner_Button(List<Button> self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_i_32(self.length, serializer);
+        for (final item in self) { sse_encode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInner_Button(item, serializer); } }
+
+@protected void sse_encode_list_CustomSerializer_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerKey(List<PhysicalKeyboardKey> self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_i_32(self.length, serializer);
+        for (final item in self) { sse_encode_CustomSerializer_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerKey(item, serializer); } }
+
+@protected void sse_encode_list_String(List<String> self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_i_32(self.length, serializer);
+        for (final item in self) { sse_encode_String(item, serializer); } }
+
+@protected void sse_encode_list_backend(List<Backend> self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_i_32(self.length, serializer);
+        for (final item in self) { sse_encode_backend(item, serializer); } }
+
+@protected void sse_encode_list_list_CustomSerializer_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerKey(List<List<PhysicalKeyboardKey>> self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_i_32(self.length, serializer);
+        for (final item in self) { sse_encode_list_CustomSerializer_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerKey(item, serializer); } }
+
+@protected void sse_encode_list_operation_metrics(List<OperationMetrics> self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_i_32(self.length, serializer);
+        for (final item in self) { sse_encode_operation_metrics(item, serializer); } }
+
+@protected void sse_encode_list_prim_u_16_strict(Uint16List self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_i_32(self.length, serializer);
+                    serializer.buffer.putUint16List(self); }
+
+@protected void sse_encode_list_prim_u_8_strict(Uint8List self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_i_32(self.length, serializer);
+                    serializer.buffer.putUint8List(self); }
+
+@protected void sse_encode_list_record_string_string(List<(String,String)> self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_i_32(self.length, serializer);
+        for (final item in self) { sse_encode_record_string_string(item, serializer); } }
+
+@protected void sse_encode_list_record_string_u_64(List<(String,BigInt)> self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_i_32(self.length, serializer);
+        for (final item in self) { sse_encode_record_string_u_64(item, serializer); } }
+
+@protected void sse_encode_list_record_u_64_u_64(List<(BigInt,BigInt)> self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_i_32(self.length, serializer);
+        for (final item in self) { sse_encode_record_u_64_u_64(item, serializer); } }
+
+@protected void sse_encode_list_region(List<Region> self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_i_32(self.length, serializer);
+        for (final item in self) { sse_encode_region(item, serializer); } }
+
+@protected void sse_encode_list_server_token(List<ServerToken> self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_i_32(self.length, serializer);
+        for (final item in self) { sse_encode_server_token(item, serializer); } }
+
+@protected void sse_encode_lock_state(LockState self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_bool(self.capsLock, serializer);
+sse_encode_bool(self.numLock, serializer);
+sse_encode_bool(self.scrollLock, serializer);
+ }
+
+@protected void sse_encode_log_level(LogLevel self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_i_32(self.index, serializer); }
+
+@protected void sse_encode_log_record(LogRecord self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_u_64(self.timestampMs, serializer);
+sse_encode_log_level(self.level, serializer);
+sse_encode_String(self.target, serializer);
+sse_encode_list_String(self.spans, serializer);
+sse_encode_String(self.message, serializer);
+sse_encode_list_record_string_string(self.fields, serializer);
+ }
+
+@protected void sse_encode_log_rotation(LogRotation self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_i_32(self.index, serializer); }
+
+@protected void sse_encode_metrics(Metrics self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_list_operation_metrics(self.operations, serializer);
+ }
+
+@protected void sse_encode_modifier_state(ModifierState self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_bool(self.shift, serializer);
+sse_encode_bool(self.control, serializer);
+sse_encode_bool(self.alt, serializer);
+sse_encode_bool(self.meta, serializer);
+ }
+
+@protected void sse_encode_operation_metrics(OperationMetrics self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_String(self.operation, serializer);
+sse_encode_histogram(self.lockWait, serializer);
+sse_encode_histogram(self.backendCall, serializer);
+sse_encode_histogram(self.endToEnd, serializer);
+sse_encode_u_64(self.successes, serializer);
+sse_encode_list_record_string_u_64(self.failures, serializer);
+ }
+
+@protected void sse_encode_opt_String(String? self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+
+                sse_encode_bool(self != null, serializer);
+                if (self != null) {
+                    sse_encode_String(self, serializer);
+                }
+                 }
+
+@protected void sse_encode_opt_box_autoadd_bool(bool? self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+
+                sse_encode_bool(self != null, serializer);
+                if (self != null) {
+                    sse_encode_box_autoadd_bool(self, serializer);
+                }
+                 }
+
+@protected void sse_encode_opt_box_autoadd_i_64(PlatformInt64? self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+
+                sse_encode_bool(self != null, serializer);
+                if (self != null) {
+                    sse_encode_box_autoadd_i_64(self, serializer);
+                }
+                 }
+
+@protected void sse_encode_opt_box_autoadd_pacer_config(PacerConfig? self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+
+                sse_encode_bool(self != null, serializer);
+                if (self != null) {
+                    sse_encode_box_autoadd_pacer_config(self, serializer);
+                }
+                 }
+
+@protected void sse_encode_opt_box_autoadd_pacer_stats(PacerStats? self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+
+                sse_encode_bool(self != null, serializer);
+                if (self != null) {
+                    sse_encode_box_autoadd_pacer_stats(self, serializer);
+                }
+                 }
+
+@protected void sse_encode_opt_box_autoadd_policy(Policy? self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+
+                sse_encode_bool(self != null, serializer);
+                if (self != null) {
+                    sse_encode_box_autoadd_policy(self, serializer);
+                }
+                 }
+
+@protected void sse_encode_opt_box_autoadd_rate_limit(RateLimit? self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+
+                sse_encode_bool(self != null, serializer);
+                if (self != null) {
+                    sse_encode_box_autoadd_rate_limit(self, serializer);
+                }
+                 }
+
+@protected void sse_encode_opt_box_autoadd_record_i_32_i_32((int,int)? self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+
+                sse_encode_bool(self != null, serializer);
+                if (self != null) {
+                    sse_encode_box_autoadd_record_i_32_i_32(self, serializer);
+                }
+                 }
+
+@protected void sse_encode_opt_box_autoadd_u_32(int? self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+
+                sse_encode_bool(self != null, serializer);
+                if (self != null) {
+                    sse_encode_box_autoadd_u_32(self, serializer);
+                }
+                 }
+
+@protected void sse_encode_opt_box_autoadd_usize(BigInt? self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+
+                sse_encode_bool(self != null, serializer);
+                if (self != null) {
+                    sse_encode_box_autoadd_usize(self, serializer);
+                }
+                 }
+
+@protected void sse_encode_opt_list_backend(List<Backend>? self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+
+                sse_encode_bool(self != null, serializer);
+                if (self != null) {
+                    sse_encode_list_backend(self, serializer);
+                }
+                 }
+
+@protected void sse_encode_pacer_config(PacerConfig self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_f_64(self.eventsPerSecond, serializer);
+sse_encode_u_32(self.burst, serializer);
+sse_encode_event_gaps(self.minGapMs, serializer);
+ }
+
+@protected void sse_encode_pacer_stats(PacerStats self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_u_64(self.events, serializer);
+sse_encode_u_64(self.delayed, serializer);
+sse_encode_u_64(self.totalWaitMs, serializer);
+sse_encode_u_64(self.maxWaitMs, serializer);
+sse_encode_f_64(self.tokens, serializer);
+ }
+
+@protected void sse_encode_playback_options(PlaybackOptions self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_f_64(self.speed, serializer);
+sse_encode_u_32(self.loops, serializer);
+sse_encode_opt_box_autoadd_u_32(self.maxGapMs, serializer);
+ }
+
+@protected void sse_encode_policy(Policy self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_list_CustomSerializer_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerKey(self.allowedKeys, serializer);
+sse_encode_list_CustomSerializer_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerKey(self.deniedKeys, serializer);
+sse_encode_list_list_CustomSerializer_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerKey(self.deniedChords, serializer);
+sse_encode_bool(self.allowRaw, serializer);
+sse_encode_list_region(self.clickRegions, serializer);
+sse_encode_opt_box_autoadd_u_32(self.maxTextLen, serializer);
+sse_encode_opt_box_autoadd_rate_limit(self.rateLimit, serializer);
+ }
+
+@protected void sse_encode_policy_violation(PolicyViolation self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+switch (self) { case PolicyViolation_KeyDenied(key: final key): sse_encode_i_32(0, serializer); sse_encode_CustomSerializer_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerKey(key, serializer);
+case PolicyViolation_ChordDenied(chord: final chord): sse_encode_i_32(1, serializer); sse_encode_list_CustomSerializer_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerKey(chord, serializer);
+case PolicyViolation_RawDenied(keycode: final keycode): sse_encode_i_32(2, serializer); sse_encode_u_16(keycode, serializer);
+case PolicyViolation_ClickOutsideRegions(x: final x,y: final y): sse_encode_i_32(3, serializer); sse_encode_i_32(x, serializer);
+sse_encode_i_32(y, serializer);
+case PolicyViolation_TextTooLong(len: final len,max: final max): sse_encode_i_32(4, serializer); sse_encode_u_32(len, serializer);
+sse_encode_u_32(max, serializer);
+case PolicyViolation_RateLimited(maxEvents: final maxEvents,windowMs: final windowMs): sse_encode_i_32(5, serializer); sse_encode_u_32(maxEvents, serializer);
+sse_encode_u_32(windowMs, serializer);
+  } }
+
+@protected void sse_encode_rate_limit(RateLimit self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_u_32(self.maxEvents, serializer);
+sse_encode_u_32(self.windowMs, serializer);
+ }
+
+@protected void sse_encode_reconnect_event(ReconnectEvent self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_backend(self.backend, serializer);
+sse_encode_u_32(self.attempts, serializer);
+sse_encode_String(self.error, serializer);
+ }
+
+@protected void sse_encode_reconnect_policy(ReconnectPolicy self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+switch (self) { case ReconnectPolicy_Never(): sse_encode_i_32(0, serializer); case ReconnectPolicy_Retry(maxAttempts: final maxAttempts,delayMs: final delayMs): sse_encode_i_32(1, serializer); sse_encode_u_32(maxAttempts, serializer);
+sse_encode_u_32(delayMs, serializer);
+  } }
+
+@protected void sse_encode_record_i_32_i_32((int,int) self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_i_32(self.$1, serializer);
+sse_encode_i_32(self.$2, serializer);
+ }
+
+@protected void sse_encode_record_string_string((String,String) self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_String(self.$1, serializer);
+sse_encode_String(self.$2, serializer);
+ }
+
+@protected void sse_encode_record_string_u_64((String,BigInt) self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_String(self.$1, serializer);
+sse_encode_u_64(self.$2, serializer);
+ }
+
+@protected void sse_encode_record_u_64_u_64((BigInt,BigInt) self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_u_64(self.$1, serializer);
+sse_encode_u_64(self.$2, serializer);
+ }
+
+@protected void sse_encode_region(Region self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_i_32(self.x, serializer);
+sse_encode_i_32(self.y, serializer);
+sse_encode_i_32(self.width, serializer);
+sse_encode_i_32(self.height, serializer);
+ }
+
+@protected void sse_encode_repeat_policy(RepeatPolicy self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+switch (self) { case RepeatPolicy_Silent(): sse_encode_i_32(0, serializer); case RepeatPolicy_System(): sse_encode_i_32(1, serializer); case RepeatPolicy_Custom(delayMs: final delayMs,intervalMs: final intervalMs): sse_encode_i_32(2, serializer); sse_encode_u_32(delayMs, serializer);
+sse_encode_u_32(intervalMs, serializer);
+  } }
+
+@protected void sse_encode_server_config(ServerConfig self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_String(self.address, serializer);
+sse_encode_list_server_token(self.tokens, serializer);
+sse_encode_opt_box_autoadd_policy(self.policy, serializer);
+sse_encode_u_32(self.maxConnections, serializer);
+ }
+
+@protected void sse_encode_server_token(ServerToken self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_String(self.token, serializer);
+sse_encode_opt_box_autoadd_policy(self.policy, serializer);
+ }
+
+@protected void sse_encode_settings(Settings self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_u_32(self.linuxDelay, serializer);
+sse_encode_opt_String(self.x11Display, serializer);
+sse_encode_opt_String(self.waylandDisplay, serializer);
+sse_encode_opt_box_autoadd_usize(self.windowsDwExtraInfo, serializer);
+sse_encode_opt_box_autoadd_i_64(self.eventSourceUserData, serializer);
+sse_encode_bool(self.releaseKeysWhenDropped, serializer);
+sse_encode_bool(self.openPromptToGetPermissions, serializer);
+sse_encode_bool(self.independentOfKeyboardState, serializer);
+sse_encode_bool(self.windowsSubjectToMouseSpeedAndAccelerationLevel, serializer);
+ }
+
+@protected void sse_encode_settings_patch(SettingsPatch self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_opt_list_backend(self.backend, serializer);
+sse_encode_opt_box_autoadd_u_32(self.linuxDelay, serializer);
+sse_encode_opt_String(self.x11Display, serializer);
+sse_encode_opt_String(self.waylandDisplay, serializer);
+sse_encode_opt_box_autoadd_usize(self.windowsDwExtraInfo, serializer);
+sse_encode_opt_box_autoadd_i_64(self.eventSourceUserData, serializer);
+sse_encode_opt_box_autoadd_bool(self.releaseKeysWhenDropped, serializer);
+sse_encode_opt_box_autoadd_bool(self.openPromptToGetPermissions, serializer);
+sse_encode_opt_box_autoadd_bool(self.independentOfKeyboardState, serializer);
+sse_encode_opt_box_autoadd_bool(self.windowsSubjectToMouseSpeedAndAccelerationLevel, serializer);
+ }
+
+@protected void sse_encode_text_options(TextOptions self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_bool(self.normalizeLockState, serializer);
+ }
+
+@protected void sse_encode_u_16(int self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+serializer.buffer.putUint16(self); }
+
+@protected void sse_encode_u_32(int self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+serializer.buffer.putUint32(self); }
+
+@protected void sse_encode_u_64(BigInt self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+serializer.buffer.putBigUint64(self); }
+
+@protected void sse_encode_u_8(int self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+serializer.buffer.putUint8(self); }
+
+@protected void sse_encode_unit(void self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+ }
+
+@protected void sse_encode_usize(BigInt self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+serializer.buffer.putBigUint64(self); }
+                }
+                
+
+            @sealed class ButtonImpl extends RustOpaque implements Button {
+                // Not to be used by end users
+                ButtonImpl.frbInternalDcoDecode(List<dynamic> wire):
+                    super.frbInternalDcoDecode(wire, _kStaticData);
+
+                // Not to be used by end users
+                ButtonImpl.frbInternalSseDecode(BigInt ptr, int externalSizeOnNative):
+                    super.frbInternalSseDecode(ptr, externalSizeOnNative, _kStaticData);
+
+                static final _kStaticData = RustArcStaticData(
+                    rustArcIncrementStrongCount: RustLib.instance.api.rust_arc_increment_strong_count_Button,
+                    rustArcDecrementStrongCount: RustLib.instance.api.rust_arc_decrement_strong_count_Button,
+                    rustArcDecrementStrongCountPtr: RustLib.instance.api.rust_arc_decrement_strong_count_ButtonPtr,
+                );
+
+                 String  toString()=>RustLib.instance.api.crateApiEnigoButtonToString(that: this, );
+
+
+            }
+            @sealed class EnigoImpl extends RustOpaque implements Enigo {
+                // Not to be used by end users
+                EnigoImpl.frbInternalDcoDecode(List<dynamic> wire):
+                    super.frbInternalDcoDecode(wire, _kStaticData);
+
+                // Not to be used by end users
+                EnigoImpl.frbInternalSseDecode(BigInt ptr, int externalSizeOnNative):
+                    super.frbInternalSseDecode(ptr, externalSizeOnNative, _kStaticData);
+
+                static final _kStaticData = RustArcStaticData(
+                    rustArcIncrementStrongCount: RustLib.instance.api.rust_arc_increment_strong_count_Enigo,
+                    rustArcDecrementStrongCount: RustLib.instance.api.rust_arc_decrement_strong_count_Enigo,
+                    rustArcDecrementStrongCountPtr: RustLib.instance.api.rust_arc_decrement_strong_count_EnigoPtr,
+                );
+
+                /// Which backend this instance ended up with, and what it can do.
+ BackendInfo  backendInfo()=>RustLib.instance.api.crateApiEnigoEnigoBackendInfo(that: this, );
+
+
+ void  button({required Button button , required Direction direction })=>RustLib.instance.api.crateApiEnigoEnigoButton(that: this, button: button, direction: direction);
+
+
+/// What this instance's backend supports; shorthand for
+/// `backend_info().capabilities`.
+ Capabilities  capabilities()=>RustLib.instance.api.crateApiEnigoEnigoCapabilities(that: this, );
+
+
+ void  clearMockCalls()=>RustLib.instance.api.crateApiEnigoEnigoClearMockCalls(that: this, );
+
+
+/// Click `button` `count` times natively, so FFI jitter can't stretch the
+/// gap between clicks past the double-click threshold.
+///
+/// Without `interval_ms` the clicks are spaced at a quarter of the system
+/// double-click time, or of [`DEFAULT_DOUBLE_CLICK_TIME_MS`] if that
+/// can't be read.
+ Future<void>  click({required Button button , required int count , int? intervalMs })=>RustLib.instance.api.crateApiEnigoEnigoClick(that: this, button: button, count: count, intervalMs: intervalMs);
+
+
+/// The longest gap, in milliseconds, the system accepts between the clicks
+/// of a double-click.
+ int  doubleClickTime()=>RustLib.instance.api.crateApiEnigoEnigoDoubleClickTime(that: this, );
+
+
+/// What this instance would have sent since dry-run mode was switched
+/// on; empty when it is off.
+ List<TraceEntry>  dryRunTrace()=>RustLib.instance.api.crateApiEnigoEnigoDryRunTrace(that: this, );
+
+
+/// [`Enigo::dry_run_trace`] as a JSON array.
+ String  dryRunTraceJson()=>RustLib.instance.api.crateApiEnigoEnigoDryRunTraceJson(that: this, );
+
+
+/// [`Enigo::dry_run_trace`] with one readable line per call.
+ String  dryRunTraceText()=>RustLib.instance.api.crateApiEnigoEnigoDryRunTraceText(that: this, );
+
+
+/// Keys, buttons and raw keycodes pressed through this instance and not
+/// released yet.
+ HeldInput  held()=>RustLib.instance.api.crateApiEnigoEnigoHeld(that: this, );
+
+
+/// Hold `key` down for `duration_ms`, optionally emitting auto-repeat
+/// presses the way a physically held key would.
+///
+/// Fails with the [`PolicyViolation`] if the policy refuses a press, and
+/// before pressing anything if `repeat` is [`RepeatPolicy::System`] and
+/// the system's repeat settings can't be read.
+///
+/// Not `sync`: the hold is timed on a Rust worker thread instead of
+/// blocking the Dart isolate.
+ Future<void>  holdKey({required PhysicalKeyboardKey key , required int durationMs , required RepeatPolicy repeat })=>RustLib.instance.api.crateApiEnigoEnigoHoldKey(that: this, key: key, durationMs: durationMs, repeat: repeat);
+
+
+/// Whether the backend's connection still works: X11 is asked for the
+/// cursor location and an input server is pinged. The other backends
+/// have no connection to lose, or none that can be checked, and are
+/// always alive.
+ bool  isAlive()=>RustLib.instance.api.crateApiEnigoEnigoIsAlive(that: this, );
+
+
+ bool get isDryRun=>RustLib.instance.api.crateApiEnigoEnigoIsDryRun(that: this, );
+
+
+ void  key({required PhysicalKeyboardKey key , required Direction direction })=>RustLib.instance.api.crateApiEnigoEnigoKey(that: this, key: key, direction: direction);
+
+
+ (int,int)  location()=>RustLib.instance.api.crateApiEnigoEnigoLocation(that: this, );
+
+
+/// Whether each lock key is on, as the backend sees it: the system's
+/// for local backends, the server's for a remote one and the simulated
+/// one for a mock.
+ LockState  lockState()=>RustLib.instance.api.crateApiEnigoEnigoLockState(that: this, );
+
+
+ (int,int)  mainDisplay()=>RustLib.instance.api.crateApiEnigoEnigoMainDisplay(that: this, );
+
+
+/// Timings and outcomes of this instance's calls since it was created
+/// or [`Enigo::reset_metrics`] was called.
+ Metrics  metrics()=>RustLib.instance.api.crateApiEnigoEnigoMetrics(that: this, );
+
+
+/// Every call a [`Backend::Mock`] instance received, oldest first.
+ List<MockCall>  mockCalls()=>RustLib.instance.api.crateApiEnigoEnigoMockCalls(that: this, );
+
+
+/// Which modifiers are held, as the backend sees it; see
+/// [`Enigo::lock_state`].
+ ModifierState  modifierState()=>RustLib.instance.api.crateApiEnigoEnigoModifierState(that: this, );
+
+
+ void  moveMouse({required int x , required int y , required Coordinate coordinate })=>RustLib.instance.api.crateApiEnigoEnigoMoveMouse(that: this, x: x, y: y, coordinate: coordinate);
+
+
+/// How much the pacer has held events back since it was set.
+ PacerStats?  pacerStats()=>RustLib.instance.api.crateApiEnigoEnigoPacerStats(that: this, );
+
+
+/// Replay a [`Recording`] with its original timing, adjusted by
+/// `options`.
+ Future<void>  play({required Recording recording , required PlaybackOptions options })=>RustLib.instance.api.crateApiEnigoEnigoPlay(that: this, recording: recording, options: options);
+
+
+ Policy? get policy=>RustLib.instance.api.crateApiEnigoEnigoPolicy(that: this, );
+
+
+ void  raw({required int keycode , required Direction direction })=>RustLib.instance.api.crateApiEnigoEnigoRaw(that: this, keycode: keycode, direction: direction);
+
+
+/// Recreate the backend from the settings and backend preference this
+/// instance was created with.
+ void  reconnect()=>RustLib.instance.api.crateApiEnigoEnigoReconnect(that: this, );
+
+
+/// Receive a [`ReconnectEvent`] whenever the reconnect policy kicks in.
+ Stream<ReconnectEvent>  reconnects()=>RustLib.instance.api.crateApiEnigoEnigoReconnects(that: this, );
+
+
+/// Release everything reported by [`Enigo::held`], most recent first.
+ void  releaseAll()=>RustLib.instance.api.crateApiEnigoEnigoReleaseAll(that: this, );
+
+
+ void  resetMetrics()=>RustLib.instance.api.crateApiEnigoEnigoResetMetrics(that: this, );
+
+
+ void  scroll({required int length , required Axis axis })=>RustLib.instance.api.crateApiEnigoEnigoScroll(that: this, length: length, axis: axis);
+
+
+/// Scroll by `dx`/`dy` notches, which may be fractional, spread over
+/// `duration_ms` in many small steps.
+///
+/// Partial notches are sent as-is where the backend supports high
+/// resolution wheel events and are accumulated into whole notches
+/// elsewhere.
+ Future<void>  scrollSmooth({required double dx , required double dy , required int durationMs })=>RustLib.instance.api.crateApiEnigoEnigoScrollSmooth(that: this, dx: dx, dy: dy, durationMs: durationMs);
+
+
+/// Identifies this instance in audit log records.
+ String get sessionId=>RustLib.instance.api.crateApiEnigoEnigoSessionId(that: this, );
+
+
+/// Append a record of every event sent from now on to the JSON-lines
+/// file at `path`, or stop with `None`. Check the file with
+/// [`verify_audit_log`].
+///
+/// [`verify_audit_log`]: super::audit::verify_audit_log
+ void  setAuditLog({String? path })=>RustLib.instance.api.crateApiEnigoEnigoSetAuditLog(that: this, path: path);
+
+
+/// Switch dry-run mode on or off. While it is on, every call goes
+/// through the same key resolution, coordinate conversion and bounds
+/// checks as usual but is only added to the trace instead of being sent.
+///
+/// Switching it on starts a new trace. Input held before stays held and
+/// is tracked again once dry-run mode ends.
+ void  setDryRun({required bool enabled })=>RustLib.instance.api.crateApiEnigoEnigoSetDryRun(that: this, enabled: enabled);
+
+
+/// Space out events as `config` says, or send them as they come with
+/// `None`. Calls block while their event waits, after the events of
+/// earlier calls; other calls on this instance go ahead meanwhile.
+///
+/// The wait is on the calling thread, which for the `sync` methods is
+/// the Dart isolate that calls them: with a low rate, or text longer
+/// than the burst, call them from a background isolate, or the UI
+/// freezes until the events are sent.
+///
+/// Fails if `config` has a rate that can't work; see
+/// [`PacerConfig::events_per_second`].
+ void  setPacer({PacerConfig? config })=>RustLib.instance.api.crateApiEnigoEnigoSetPacer(that: this, config: config);
+
+
+/// Refuse calls that `policy` doesn't allow with a [`PolicyViolation`],
+/// or allow everything again with `None`.
+ void  setPolicy({Policy? policy })=>RustLib.instance.api.crateApiEnigoEnigoSetPolicy(that: this, policy: policy);
+
+
+/// What to do when a call fails because the connection went away, for
+/// example because the X server restarted. Defaults to
+/// [`ReconnectPolicy::Never`].
+ void  setReconnectPolicy({required ReconnectPolicy policy })=>RustLib.instance.api.crateApiEnigoEnigoSetReconnectPolicy(that: this, policy: policy);
+
+
+ Settings  settings()=>RustLib.instance.api.crateApiEnigoEnigoSettings(that: this, );
+
+
+ void  text({required String text })=>RustLib.instance.api.crateApiEnigoEnigoText(that: this, text: text);
+
+
+ void  textWithOptions({required String text , required TextOptions options })=>RustLib.instance.api.crateApiEnigoEnigoTextWithOptions(that: this, text: text, options: options);
+
+
+/// Change some settings, including the backend preference. The backend
+/// is rebuilt if anything changed, and input held down through this
+/// instance stays held. Settings [`validate_settings`] rejects are
+/// rejected here too.
+///
+/// [`validate_settings`]: super::settings::validate_settings
+ void  updateSettings({required SettingsPatch patch })=>RustLib.instance.api.crateApiEnigoEnigoUpdateSettings(that: this, patch: patch);
+
+
+            }
+            @sealed class HotkeyManagerImpl extends RustOpaque implements HotkeyManager {
+                // Not to be used by end users
+                HotkeyManagerImpl.frbInternalDcoDecode(List<dynamic> wire):
+                    super.frbInternalDcoDecode(wire, _kStaticData);
+
+                // Not to be used by end users
+                HotkeyManagerImpl.frbInternalSseDecode(BigInt ptr, int externalSizeOnNative):
+                    super.frbInternalSseDecode(ptr, externalSizeOnNative, _kStaticData);
+
+                static final _kStaticData = RustArcStaticData(
+                    rustArcIncrementStrongCount: RustLib.instance.api.rust_arc_increment_strong_count_HotkeyManager,
+                    rustArcDecrementStrongCount: RustLib.instance.api.rust_arc_decrement_strong_count_HotkeyManager,
+                    rustArcDecrementStrongCountPtr: RustLib.instance.api.rust_arc_decrement_strong_count_HotkeyManagerPtr,
+                );
+
+                /// Stream every activation of a registered hotkey. A new stream replaces
+/// the previous one.
+ Stream<HotkeyEvent>  activations()=>RustLib.instance.api.crateApiHotkeyHotkeyManagerActivations(that: this, );
+
+
+/// Register `accelerator`, e.g. `ctrl+alt+f9`, and return the id its
+/// activations are reported with.
+ int  register({required String accelerator })=>RustLib.instance.api.crateApiHotkeyHotkeyManagerRegister(that: this, accelerator: accelerator);
+
+
+ void  unregister({required int id })=>RustLib.instance.api.crateApiHotkeyHotkeyManagerUnregister(that: this, id: id);
+
+
+ void  unregisterAll()=>RustLib.instance.api.crateApiHotkeyHotkeyManagerUnregisterAll(that: this, );
+
+
+            }
+            @sealed class InputEventImpl extends RustOpaque implements InputEvent {
+                // Not to be used by end users
+                InputEventImpl.frbInternalDcoDecode(List<dynamic> wire):
+                    super.frbInternalDcoDecode(wire, _kStaticData);
+
+                // Not to be used by end users
+                InputEventImpl.frbInternalSseDecode(BigInt ptr, int externalSizeOnNative):
+                    super.frbInternalSseDecode(ptr, externalSizeOnNative, _kStaticData);
+
+                static final _kStaticData = RustArcStaticData(
+                    rustArcIncrementStrongCount: RustLib.instance.api.rust_arc_increment_strong_count_InputEvent,
+                    rustArcDecrementStrongCount: RustLib.instance.api.rust_arc_decrement_strong_count_InputEvent,
+                    rustArcDecrementStrongCountPtr: RustLib.instance.api.rust_arc_decrement_strong_count_InputEventPtr,
+                );
+
+                
+            }
+            @sealed class InputServerImpl extends RustOpaque implements InputServer {
+                // Not to be used by end users
+                InputServerImpl.frbInternalDcoDecode(List<dynamic> wire):
+                    super.frbInternalDcoDecode(wire, _kStaticData);
+
+                // Not to be used by end users
+                InputServerImpl.frbInternalSseDecode(BigInt ptr, int externalSizeOnNative):
+                    super.frbInternalSseDecode(ptr, externalSizeOnNative, _kStaticData);
+
+                static final _kStaticData = RustArcStaticData(
+                    rustArcIncrementStrongCount: RustLib.instance.api.rust_arc_increment_strong_count_InputServer,
+                    rustArcDecrementStrongCount: RustLib.instance.api.rust_arc_decrement_strong_count_InputServer,
+                    rustArcDecrementStrongCountPtr: RustLib.instance.api.rust_arc_decrement_strong_count_InputServerPtr,
+                );
+
+                /// The address the server listens on, with the port it got.
+ String get address=>RustLib.instance.api.crateApiServerInputServerAddress(that: this, );
+
+
+ int get connections=>RustLib.instance.api.crateApiServerInputServerConnections(that: this, );
+
+
+ bool get isRunning=>RustLib.instance.api.crateApiServerInputServerIsRunning(that: this, );
+
+
+/// Stop accepting and close every connection, releasing what their
+/// clients held.
+ void  stop()=>RustLib.instance.api.crateApiServerInputServerStop(that: this, );
+
+
+            }
+            @sealed class ListenerImpl extends RustOpaque implements Listener {
+                // Not to be used by end users
+                ListenerImpl.frbInternalDcoDecode(List<dynamic> wire):
+                    super.frbInternalDcoDecode(wire, _kStaticData);
+
+                // Not to be used by end users
+                ListenerImpl.frbInternalSseDecode(BigInt ptr, int externalSizeOnNative):
+                    super.frbInternalSseDecode(ptr, externalSizeOnNative, _kStaticData);
+
+                static final _kStaticData = RustArcStaticData(
+                    rustArcIncrementStrongCount: RustLib.instance.api.rust_arc_increment_strong_count_Listener,
+                    rustArcDecrementStrongCount: RustLib.instance.api.rust_arc_decrement_strong_count_Listener,
+                    rustArcDecrementStrongCountPtr: RustLib.instance.api.rust_arc_decrement_strong_count_ListenerPtr,
+                );
+
+                 bool get isListening=>RustLib.instance.api.crateApiListenerListenerIsListening(that: this, );
+
+
+/// Start capturing and push every event into `sink`, replacing any
+/// capture already running. Capturing stops when the Dart stream is
+/// cancelled or [`Listener::stop`] is called.
+ Stream<InputEvent>  listen()=>RustLib.instance.api.crateApiListenerListenerListen(that: this, );
+
+
+ void  stop()=>RustLib.instance.api.crateApiListenerListenerStop(that: this, );
+
+
+            }
+            @sealed class MockActionImpl extends RustOpaque implements MockAction {
+                // Not to be used by end users
+                MockActionImpl.frbInternalDcoDecode(List<dynamic> wire):
+                    super.frbInternalDcoDecode(wire, _kStaticData);
+
+                // Not to be used by end users
+                MockActionImpl.frbInternalSseDecode(BigInt ptr, int externalSizeOnNative):
+                    super.frbInternalSseDecode(ptr, externalSizeOnNative, _kStaticData);
+
+                static final _kStaticData = RustArcStaticData(
+                    rustArcIncrementStrongCount: RustLib.instance.api.rust_arc_increment_strong_count_MockAction,
+                    rustArcDecrementStrongCount: RustLib.instance.api.rust_arc_decrement_strong_count_MockAction,
+                    rustArcDecrementStrongCountPtr: RustLib.instance.api.rust_arc_decrement_strong_count_MockActionPtr,
+                );
+
+                
+            }
+            @sealed class MockCallImpl extends RustOpaque implements MockCall {
+                // Not to be used by end users
+                MockCallImpl.frbInternalDcoDecode(List<dynamic> wire):
+                    super.frbInternalDcoDecode(wire, _kStaticData);
+
+                // Not to be used by end users
+                MockCallImpl.frbInternalSseDecode(BigInt ptr, int externalSizeOnNative):
+                    super.frbInternalSseDecode(ptr, externalSizeOnNative, _kStaticData);
+
+                static final _kStaticData = RustArcStaticData(
+                    rustArcIncrementStrongCount: RustLib.instance.api.rust_arc_increment_strong_count_MockCall,
+                    rustArcDecrementStrongCount: RustLib.instance.api.rust_arc_decrement_strong_count_MockCall,
+                    rustArcDecrementStrongCountPtr: RustLib.instance.api.rust_arc_decrement_strong_count_MockCallPtr,
+                );
+
+                 MockAction get action=>RustLib.instance.api.crateApiMockMockCallAutoAccessorGetAction(that: this, );
+
+
+ BigInt get timestampUs=>RustLib.instance.api.crateApiMockMockCallAutoAccessorGetTimestampUs(that: this, );
+
+
+  set action(MockAction action)=>RustLib.instance.api.crateApiMockMockCallAutoAccessorSetAction(that: this, action: action);
+
+
+  set timestampUs(BigInt timestampUs)=>RustLib.instance.api.crateApiMockMockCallAutoAccessorSetTimestampUs(that: this, timestampUs: timestampUs);
+
+
+            }
+            @sealed class RecorderImpl extends RustOpaque implements Recorder {
+                // Not to be used by end users
+                RecorderImpl.frbInternalDcoDecode(List<dynamic> wire):
+                    super.frbInternalDcoDecode(wire, _kStaticData);
+
+                // Not to be used by end users
+                RecorderImpl.frbInternalSseDecode(BigInt ptr, int externalSizeOnNative):
+                    super.frbInternalSseDecode(ptr, externalSizeOnNative, _kStaticData);
+
+                static final _kStaticData = RustArcStaticData(
+                    rustArcIncrementStrongCount: RustLib.instance.api.rust_arc_increment_strong_count_Recorder,
+                    rustArcDecrementStrongCount: RustLib.instance.api.rust_arc_decrement_strong_count_Recorder,
+                    rustArcDecrementStrongCountPtr: RustLib.instance.api.rust_arc_decrement_strong_count_RecorderPtr,
+                );
+
+                 bool get isRecording=>RustLib.instance.api.crateApiRecorderRecorderIsRecording(that: this, );
+
+
+/// Start a new recording, discarding anything recorded so far.
+ void  start()=>RustLib.instance.api.crateApiRecorderRecorderStart(that: this, );
+
+
+/// Stop recording and return what was captured.
+ Recording  stop()=>RustLib.instance.api.crateApiRecorderRecorderStop(that: this, );
+
+
+            }
+            @sealed class RecordingImpl extends RustOpaque implements Recording {
+                // Not to be used by end users
+                RecordingImpl.frbInternalDcoDecode(List<dynamic> wire):
+                    super.frbInternalDcoDecode(wire, _kStaticData);
+
+                // Not to be used by end users
+                RecordingImpl.frbInternalSseDecode(BigInt ptr, int externalSizeOnNative):
+                    super.frbInternalSseDecode(ptr, externalSizeOnNative, _kStaticData);
+
+                static final _kStaticData = RustArcStaticData(
+                    rustArcIncrementStrongCount: RustLib.instance.api.rust_arc_increment_strong_count_Recording,
+                    rustArcDecrementStrongCount: RustLib.instance.api.rust_arc_decrement_strong_count_Recording,
+                    rustArcDecrementStrongCountPtr: RustLib.instance.api.rust_arc_decrement_strong_count_RecordingPtr,
+                );
+
+                 BigInt get durationMs=>RustLib.instance.api.crateApiRecorderRecordingDurationMs(that: this, );
+
+
+ bool get isEmpty=>RustLib.instance.api.crateApiRecorderRecordingIsEmpty(that: this, );
+
+
+ BigInt get len=>RustLib.instance.api.crateApiRecorderRecordingLen(that: this, );
+
+
+ void  save({required String path })=>RustLib.instance.api.crateApiRecorderRecordingSave(that: this, path: path);
+
+
+ String  toJson()=>RustLib.instance.api.crateApiRecorderRecordingToJson(that: this, );
+
+
+            }
+            @sealed class RemapperImpl extends RustOpaque implements Remapper {
+                // Not to be used by end users
+                RemapperImpl.frbInternalDcoDecode(List<dynamic> wire):
+                    super.frbInternalDcoDecode(wire, _kStaticData);
+
+                // Not to be used by end users
+                RemapperImpl.frbInternalSseDecode(BigInt ptr, int externalSizeOnNative):
+                    super.frbInternalSseDecode(ptr, externalSizeOnNative, _kStaticData);
+
+                static final _kStaticData = RustArcStaticData(
+                    rustArcIncrementStrongCount: RustLib.instance.api.rust_arc_increment_strong_count_Remapper,
+                    rustArcDecrementStrongCount: RustLib.instance.api.rust_arc_decrement_strong_count_Remapper,
+                    rustArcDecrementStrongCountPtr: RustLib.instance.api.rust_arc_decrement_strong_count_RemapperPtr,
+                );
+
+                 bool get enabled=>RustLib.instance.api.crateApiRemapRemapperEnabled(that: this, );
+
+
+ bool get isRunning=>RustLib.instance.api.crateApiRemapRemapperIsRunning(that: this, );
+
+
+/// Replace the rules; takes effect immediately if the remapper runs.
+ void  loadJson({required String config })=>RustLib.instance.api.crateApiRemapRemapperLoadJson(that: this, config: config);
+
+
+/// While disabled the keyboards stay grabbed but events pass through
+/// unchanged.
+ void  setEnabled({required bool enabled })=>RustLib.instance.api.crateApiRemapRemapperSetEnabled(that: this, enabled: enabled);
+
+
+/// Grab the keyboards and start remapping.
+ void  start()=>RustLib.instance.api.crateApiRemapRemapperStart(that: this, );
+
+
+/// Release the keyboards.
+ void  stop()=>RustLib.instance.api.crateApiRemapRemapperStop(that: this, );
+
+
+            }
+            @sealed class TraceEntryImpl extends RustOpaque implements TraceEntry {
+                // Not to be used by end users
+                TraceEntryImpl.frbInternalDcoDecode(List<dynamic> wire):
+                    super.frbInternalDcoDecode(wire, _kStaticData);
+
+                // Not to be used by end users
+                TraceEntryImpl.frbInternalSseDecode(BigInt ptr, int externalSizeOnNative):
+                    super.frbInternalSseDecode(ptr, externalSizeOnNative, _kStaticData);
+
+                static final _kStaticData = RustArcStaticData(
+                    rustArcIncrementStrongCount: RustLib.instance.api.rust_arc_increment_strong_count_TraceEntry,
+                    rustArcDecrementStrongCount: RustLib.instance.api.rust_arc_decrement_strong_count_TraceEntry,
+                    rustArcDecrementStrongCountPtr: RustLib.instance.api.rust_arc_decrement_strong_count_TraceEntryPtr,
+                );
+
+                 MockAction get action=>RustLib.instance.api.crateApiDryRunTraceEntryAutoAccessorGetAction(that: this, );
+
+
+ BigInt get offsetMs=>RustLib.instance.api.crateApiDryRunTraceEntryAutoAccessorGetOffsetMs(that: this, );
+
+
+ (int,int)? get target=>RustLib.instance.api.crateApiDryRunTraceEntryAutoAccessorGetTarget(that: this, );
+
+
+ String? get warning=>RustLib.instance.api.crateApiDryRunTraceEntryAutoAccessorGetWarning(that: this, );
+
+
+  set action(MockAction action)=>RustLib.instance.api.crateApiDryRunTraceEntryAutoAccessorSetAction(that: this, action: action);
+
+
+  set offsetMs(BigInt offsetMs)=>RustLib.instance.api.crateApiDryRunTraceEntryAutoAccessorSetOffsetMs(that: this, offsetMs: offsetMs);
+
+
+  set target((int,int)? target)=>RustLib.instance.api.crateApiDryRunTraceEntryAutoAccessorSetTarget(that: this, target: target);
+
+
+  set warning(String? warning)=>RustLib.instance.api.crateApiDryRunTraceEntryAutoAccessorSetWarning(that: this, warning: warning);
+
+
+/// One line such as `+120 ms  move to (10, 20)`.
+ String  describe()=>RustLib.instance.api.crateApiDryRunTraceEntryDescribe(that: this, );
+
+
+            }
\ No newline at end of file
@@ -0,0 +1,343 @@
+use enigo::{Axis, Coordinate, Direction, InputResult, Key, Keyboard, Mouse, Settings};
+use flutter_rust_bridge::*;
+use serde_json::{json, Map, Value};
+use sha2::{Digest, Sha256};
+use std::fs::{File, OpenOptions};
+use std::io::{BufRead, BufReader, Write as _};
+use std::path::PathBuf;
+use std::sync::{Arc, Mutex, PoisonError, Weak};
+use std::time::{SystemTime, UNIX_EPOCH};
+
+use super::backend::InputBackend;
+
+/// `prev` of the first record in a file.
+const GENESIS: &str = "0000000000000000000000000000000000000000000000000000000000000000";
+
+/// Audit files in use, so instances logging to the same file extend one
+/// chain instead of each forking it.
+static OPEN: Mutex<Vec<(PathBuf, Weak<Mutex<Chain>>)>> = Mutex::new(Vec::new());
+
+/// Check the hash chain of an audit log written by [`Enigo::set_audit_log`]
+/// and return how many records it has.
+///
+/// Fails on the first record that was changed, removed or inserted after it
+/// was written.
+///
+/// [`Enigo::set_audit_log`]: super::enigo::Enigo::set_audit_log
+#[frb(sync)]
+pub fn verify_audit_log(path: String) -> anyhow::Result<u64> {
+    let mut prev = GENESIS.to_owned();
+    let mut count = 0;
+    for (index, line) in BufReader::new(File::open(&path)?).lines().enumerate() {
+        let line = line?;
+        let number = index + 1;
+        let mut record: Map<String, Value> = serde_json::from_str(&line)
+            .map_err(|error| anyhow::anyhow!("line {number} is not a record: {error}"))?;
+        let Some(Value::String(hash)) = record.remove("hash") else {
+            anyhow::bail!("line {number} has no hash");
+        };
+        if record.get("prev").and_then(Value::as_str) != Some(&prev) {
+            anyhow::bail!("line {number} doesn't follow the record before it");
+        }
+        if chain_hash(&prev, &Value::Object(record).to_string()) != hash {
+            anyhow::bail!("line {number} was changed after it was written");
+        }
+        prev = hash;
+        count += 1;
+    }
+    Ok(count)
+}
+
+/// An append-only JSON-lines file of every event sent to the backend.
+///
+/// Each record holds the hash of the one before it and its own hash over
+/// that and its content, so editing, dropping or reordering records breaks
+/// the chain. Typed text is recorded by length only.
+#[frb(ignore)]
+pub(crate) struct AuditLog {
+    chain: Arc<Mutex<Chain>>,
+    session: String,
+}
+
+/// An audit file and where its chain ends.
+struct Chain {
+    file: File,
+    seq: u64,
+    prev: String,
+}
+
+impl AuditLog {
+    /// Log to `path` for `session`, sharing the file with other instances
+    /// logging there.
+    pub fn open(path: &str, session: String) -> anyhow::Result<Self> {
+        let mut open = OPEN.lock().unwrap_or_else(PoisonError::into_inner);
+        open.retain(|(_, chain)| chain.strong_count() > 0);
+        let shared = std::fs::canonicalize(path).ok().and_then(|key| {
+            open.iter()
+                .find(|(path, _)| *path == key)
+                .and_then(|(_, chain)| chain.upgrade())
+        });
+        let chain = match shared {
+            Some(chain) => chain,
+            None => {
+                let chain = Arc::new(Mutex::new(Chain::open(path)?));
+                open.push((std::fs::canonicalize(path)?, Arc::downgrade(&chain)));
+                chain
+            }
+        };
+        Ok(Self { chain, session })
+    }
+
+    fn record(&mut self, mut record: Value, result: &InputResult<()>) {
+        record["session"] = json!(self.session);
+        if let Err(error) = result {
+            record["error"] = json!(error.to_string());
+        }
+        let mut chain = self.chain.lock().unwrap_or_else(PoisonError::into_inner);
+        chain.append(record);
+    }
+}
+
+impl Chain {
+    /// Open `path` for appending, continuing the chain of the records
+    /// already in it.
+    fn open(path: &str) -> anyhow::Result<Self> {
+        let mut seq = 0;
+        let mut prev = GENESIS.to_owned();
+        if let Ok(existing) = File::open(path) {
+            if let Some(last) = BufReader::new(existing)
+                .lines()
+                .map_while(Result::ok)
+                .last()
+            {
+                let last: Value = serde_json::from_str(&last)?;
+                let (Some(last_seq), Some(hash)) = (last["seq"].as_u64(), last["hash"].as_str())
+                else {
+                    anyhow::bail!("{path} doesn't end with an audit record");
+                };
+                seq = last_seq + 1;
+                prev = hash.to_owned();
+            }
+        }
+        let file = OpenOptions::new().create(true).append(true).open(path)?;
+        Ok(Self { file, seq, prev })
+    }
+
+    fn append(&mut self, mut record: Value) {
+        record["seq"] = json!(self.seq);
+        record["timestamp_ms"] = json!(SystemTime::now()
+            .duration_since(UNIX_EPOCH)
+            .unwrap_or_default()
+            .as_millis() as u64);
+        record["prev"] = json!(self.prev);
+        let hash = chain_hash(&self.prev, &record.to_string());
+        record["hash"] = json!(hash);
+        // A whole line per write, so a failed write can't leave half a
+        // record that the next one is appended to.
+        if let Err(error) = self.file.write_all(format!("{record}\n").as_bytes()) {
+            tracing::error!(%error, "couldn't write the audit log");
+            return;
+        }
+        self.seq += 1;
+        self.prev = hash;
+    }
+}
+
+/// Hex SHA-256 of the previous record's hash followed by `record`.
+fn chain_hash(prev: &str, record: &str) -> String {
+    let mut hasher = Sha256::new();
+    hasher.update(prev.as_bytes());
+    hasher.update(record.as_bytes());
+    format!("{:x}", hasher.finalize())
+}
+
+/// A backend that writes every call made through it to an [`AuditLog`].
+#[frb(ignore)]
+pub(crate) struct Audited<'a> {
+    pub backend: &'a mut dyn InputBackend,
+    pub log: &'a mut AuditLog,
+}
+
+impl InputBackend for Audited<'_> {
+    fn scroll_hi_res(&mut self, settings: &Settings, delta: i32, axis: Axis) -> InputResult<()> {
+        let result = self.backend.scroll_hi_res(settings, delta, axis);
+        let record = json!({ "action": "scroll_hi_res", "delta": delta, "axis": axis });
+        self.log.record(record, &result);
+        result
+    }
+}
+
+impl Keyboard for Audited<'_> {
+    fn fast_text(&mut self, text: &str) -> InputResult<Option<()>> {
+        let result = self.backend.fast_text(text);
+        let logged = match &result {
+            Ok(Some(())) => Ok(()),
+            // Nothing was sent; `text` falls back to single keys.
+            Ok(None) => return result,
+            Err(error) => Err(error.clone()),
+        };
+        let record = json!({ "action": "text", "length": text.chars().count() });
+        self.log.record(record, &logged);
+        result
+    }
+
+    /// Sent as one call, so text the backend types key by key is still
+    /// recorded by length instead of key by key.
+    fn text(&mut self, text: &str) -> InputResult<()> {
+        let result = self.backend.text(text);
+        let record = json!({ "action": "text", "length": text.chars().count() });
+        self.log.record(record, &result);
+        result
+    }
+
+    fn key(&mut self, key: Key, direction: Direction) -> InputResult<()> {
+        let result = self.backend.key(key, direction);
+        let record = json!({ "action": "key", "key": key, "direction": direction });
+        self.log.record(record, &result);
+        result
+    }
+
+    fn raw(&mut self, keycode: u16, direction: Direction) -> InputResult<()> {
+        let result = self.backend.raw(keycode, direction);
+        let record = json!({ "action": "raw", "keycode": keycode, "direction": direction });
+        self.log.record(record, &result);
+        result
+    }
+}
+
+impl Mouse for Audited<'_> {
+    fn button(&mut self, button: enigo::Button, direction: Direction) -> InputResult<()> {
+        let result = self.backend.button(button, direction);
+        let mut record = json!({ "action": "button", "button": button, "direction": direction });
+        if let Ok((x, y)) = self.backend.location() {
+            record["target"] = json!([x, y]);
+        }
+        self.log.record(record, &result);
+        result
+    }
+
+    fn move_mouse(&mut self, x: i32, y: i32, coordinate: Coordinate) -> InputResult<()> {
+        let result = self.backend.move_mouse(x, y, coordinate);
+        let mut record =
+            json!({ "action": "move_mouse", "x": x, "y": y, "coordinate": coordinate });
+        if let Ok((x, y)) = self.backend.location() {
+            record["target"] = json!([x, y]);
+        }
+        self.log.record(record, &result);
+        result
+    }
+
+    fn scroll(&mut self, length: i32, axis: Axis) -> InputResult<()> {
+        let result = self.backend.scroll(length, axis);
+        let record = json!({ "action": "scroll", "length": length, "axis": axis });
+        self.log.record(record, &result);
+        result
+    }
+
+    fn main_display(&self) -> InputResult<(i32, i32)> {
+        self.backend.main_display()
+    }
+
+    fn location(&self) -> InputResult<(i32, i32)> {
+        self.backend.location()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::api::mock::{MockBackend, DEFAULT_DISPLAY};
+
+    fn scratch(name: &str) -> String {
+        let path = std::env::temp_dir().join(format!("{}-audit-{name}", std::process::id()));
+        let _ = std::fs::remove_file(&path);
+        path.to_str().unwrap().to_owned()
+    }
+
+    /// Log a few calls to `path` and return its lines.
+    fn write_log(path: &str) -> Vec<String> {
+        let mut mock = MockBackend::new(DEFAULT_DISPLAY);
+        let mut log = AuditLog::open(path, "one".to_owned()).unwrap();
+        let mut audited = Audited {
+            backend: &mut mock,
+            log: &mut log,
+        };
+        audited.text("secret").unwrap();
+        audited.key(Key::Return, Direction::Click).unwrap();
+        audited.move_mouse(10, 20, Coordinate::Abs).unwrap();
+        drop(log);
+
+        // A second instance continues the chain.
+        let mut log = AuditLog::open(path, "two".to_owned()).unwrap();
+        let mut audited = Audited {
+            backend: &mut mock,
+            log: &mut log,
+        };
+        audited.scroll(1, Axis::Vertical).unwrap();
+        drop(log);
+        std::fs::read_to_string(path)
+            .unwrap()
+            .lines()
+            .map(str::to_owned)
+            .collect()
+    }
+
+    fn rewrite(path: &str, lines: &[String]) {
+        std::fs::write(path, lines.join("\n") + "\n").unwrap();
+    }
+
+    #[test]
+    fn intact_chain_verifies() {
+        let path = scratch("intact");
+        let lines = write_log(&path);
+        assert_eq!(verify_audit_log(path.clone()).unwrap(), 4);
+        std::fs::remove_file(&path).unwrap();
+
+        let records: Vec<Value> = lines
+            .iter()
+            .map(|line| serde_json::from_str(line).unwrap())
+            .collect();
+        assert_eq!(records[0]["length"], 6);
+        assert!(!lines[0].contains("secret"));
+        assert_eq!(records[2]["target"], json!([10, 20]));
+        assert_eq!(records[3]["seq"], 3);
+        assert_eq!(records[3]["prev"], records[2]["hash"]);
+        assert_eq!(records[0]["session"], "one");
+        assert_eq!(records[3]["session"], "two");
+    }
+
+    #[test]
+    fn tampering_breaks_the_chain() {
+        let path = scratch("tampered");
+        let lines = write_log(&path);
+
+        let mut edited = lines.clone();
+        edited[1] = edited[1].replace("\"Click\"", "\"Press\"");
+        assert_ne!(edited, lines);
+        rewrite(&path, &edited);
+        let error = verify_audit_log(path.clone()).unwrap_err();
+        assert!(error.to_string().contains("line 2 was changed"), "{error}");
+
+        let mut dropped = lines.clone();
+        dropped.remove(1);
+        rewrite(&path, &dropped);
+        let error = verify_audit_log(path.clone()).unwrap_err();
+        assert!(
+            error.to_string().contains("line 2 doesn't follow"),
+            "{error}"
+        );
+
+        let mut swapped = lines.clone();
+        swapped.swap(2, 3);
+        rewrite(&path, &swapped);
+        let error = verify_audit_log(path.clone()).unwrap_err();
+        assert!(
+            error.to_string().contains("line 3 doesn't follow"),
+            "{error}"
+        );
+
+        rewrite(&path, &["not json".to_owned()]);
+        assert!(verify_audit_log(path.clone()).is_err());
+        std::fs::remove_file(&path).unwrap();
+    }
+}
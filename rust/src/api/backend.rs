@@ -0,0 +1,898 @@
+use enigo::{Axis, Button, Coordinate, Direction, InputResult, Key, Keyboard, Mouse, Settings};
+use flutter_rust_bridge::*;
+use serde::{Deserialize, Serialize};
+
+#[cfg(feature = "client")]
+use super::client::Remote;
+use super::enigo::_Button;
+use super::mock::{self, MockBackend};
+use super::policy::PolicyViolation;
+use super::system::{self, LockState, ModifierState};
+
+/// Where an [`Enigo`] sends its input.
+///
+/// In settings files and `ENIGO_BACKEND` the names are lower case, e.g.
+/// `"uinput"`.
+///
+/// [`Enigo`]: super::enigo::Enigo
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum Backend {
+    /// Whatever enigo uses on this platform: SendInput on Windows, CGEvent on
+    /// macOS and, on Linux, the first of [`Backend::X11`],
+    /// [`Backend::Wayland`] and [`Backend::Libei`] this build includes that
+    /// connects, which [`BackendInfo::backend`] then reports.
+    #[default]
+    Native,
+    /// Linux only, with the `x11rb` or `xdo` cargo feature.
+    X11,
+    /// Linux only, with the `wayland` cargo feature. Needs a compositor that
+    /// implements the virtual keyboard and pointer protocols.
+    Wayland,
+    /// Linux only, with the `libei` cargo feature.
+    Libei,
+    /// A virtual keyboard and mouse created through `/dev/uinput`, which also
+    /// works on a bare TTY, in compositors without the virtual input
+    /// protocols and for applications that read evdev directly.
+    ///
+    /// Linux only, and only when built with the `uinput` cargo feature. The
+    /// process needs write access to `/dev/uinput`.
+    Uinput,
+    /// Sends nothing and records every call instead, for tests that run
+    /// without a display. See [`Enigo::mock`].
+    ///
+    /// [`Enigo::mock`]: super::enigo::Enigo::mock
+    Mock,
+    /// An [`InputServer`] on another machine, which sends the input with its
+    /// own backend. See [`Enigo::remote`].
+    ///
+    /// Only with the `client` cargo feature; the README shows how to turn it
+    /// on through cargokit.
+    ///
+    /// [`InputServer`]: super::server::InputServer
+    /// [`Enigo::remote`]: super::enigo::Enigo::remote
+    Remote,
+}
+
+impl Backend {
+    /// Whether this build can use the backend at all.
+    #[frb(sync, getter)]
+    pub fn is_compiled(&self) -> bool {
+        match self {
+            Backend::Native => true,
+            Backend::X11 => cfg!(all(
+                target_os = "linux",
+                any(feature = "x11rb", feature = "xdo")
+            )),
+            Backend::Wayland => cfg!(all(target_os = "linux", feature = "wayland")),
+            Backend::Libei => cfg!(all(target_os = "linux", feature = "libei")),
+            Backend::Uinput => cfg!(all(target_os = "linux", feature = "uinput")),
+            Backend::Mock => true,
+            Backend::Remote => cfg!(feature = "client"),
+        }
+    }
+
+    /// Every backend this build can use.
+    #[frb(sync)]
+    pub fn compiled() -> Vec<Backend> {
+        [
+            Backend::Native,
+            Backend::X11,
+            Backend::Wayland,
+            Backend::Libei,
+            Backend::Uinput,
+            Backend::Mock,
+            Backend::Remote,
+        ]
+        .into_iter()
+        .filter(Backend::is_compiled)
+        .collect()
+    }
+}
+
+/// What [`Enigo::backend_info`] reports.
+///
+/// [`Enigo::backend_info`]: super::enigo::Enigo::backend_info
+#[derive(Clone)]
+pub struct BackendInfo {
+    /// The backend that connected.
+    pub backend: Backend,
+    /// The X11 or Wayland display it talks to, or the address of the input
+    /// server for [`Backend::Remote`].
+    pub display: Option<String>,
+    /// Every backend this build can use.
+    pub compiled: Vec<Backend>,
+    pub capabilities: Capabilities,
+}
+
+/// Operations that don't work with every backend, so a UI can hide them
+/// instead of failing.
+#[derive(Clone)]
+pub struct Capabilities {
+    /// Buttons `button` and `click` accept.
+    pub buttons: Vec<_Button>,
+    /// `move_mouse` with [`Coordinate::Abs`](enigo::Coordinate::Abs).
+    pub absolute_move: bool,
+    /// `location`.
+    pub location: bool,
+    /// `text` with arbitrary Unicode rather than what the keyboard layout
+    /// can type.
+    pub unicode_text: bool,
+    /// `raw`. Its keycodes are scan codes on Windows, virtual keycodes on
+    /// macOS and X11 keycodes on Linux.
+    pub raw_keycodes: bool,
+    /// Steps per wheel notch that `scroll_smooth` can send; 1 means whole
+    /// notches only.
+    pub scroll_resolution: u32,
+}
+
+/// Units of [`InputBackend::scroll_hi_res`] per wheel notch, as in Windows'
+/// `WHEEL_DELTA` and Linux's `REL_WHEEL_HI_RES`.
+pub(crate) const HI_RES_PER_NOTCH: i32 = 120;
+
+/// Everything the wrapper needs from a backend.
+#[frb(ignore)]
+pub(crate) trait InputBackend: Keyboard + Mouse {
+    #[frb(ignore)]
+    fn mock(&mut self) -> Option<&mut MockBackend> {
+        None
+    }
+
+    /// Reconnect in place, for backends that can't be recreated from the
+    /// settings; `None` recreates the backend instead.
+    #[frb(ignore)]
+    fn reconnect(&mut self) -> Option<anyhow::Result<()>> {
+        None
+    }
+
+    /// Why the last call was refused by a policy the backend enforces
+    /// itself, if that's why it failed.
+    #[frb(ignore)]
+    fn take_violation(&mut self) -> Option<PolicyViolation> {
+        None
+    }
+
+    /// Scroll by `delta` 120ths of a notch; by default rounded to whole
+    /// notches, for backends whose [`Capabilities::scroll_resolution`] is 1.
+    #[frb(ignore)]
+    fn scroll_hi_res(&mut self, _settings: &Settings, delta: i32, axis: Axis) -> InputResult<()> {
+        let notches = (f64::from(delta) / f64::from(HI_RES_PER_NOTCH)).round() as i32;
+        if notches == 0 {
+            return Ok(());
+        }
+        self.scroll(notches, axis)
+    }
+
+    /// Whether the connection still works; backends that can't lose theirs
+    /// are always alive.
+    #[frb(ignore)]
+    fn is_alive(&self) -> bool {
+        true
+    }
+
+    /// Whether each lock key is on; by default as the system reports it for
+    /// the display in `settings`.
+    #[frb(ignore)]
+    fn lock_state(&self, settings: &Settings) -> anyhow::Result<LockState> {
+        system::imp::lock_state(settings.x11_display.as_deref())
+            .map_err(|error| anyhow::anyhow!("couldn't read the lock keys: {error}"))
+    }
+
+    /// Which modifiers are held; by default as the system reports it for
+    /// the display in `settings`.
+    #[frb(ignore)]
+    fn modifier_state(&self, settings: &Settings) -> anyhow::Result<ModifierState> {
+        system::imp::modifier_state(settings.x11_display.as_deref())
+            .map_err(|error| anyhow::anyhow!("couldn't read the modifiers: {error}"))
+    }
+}
+
+/// enigo's own backends, and what the wrapper knows about the one it
+/// connected to.
+#[frb(ignore)]
+struct Native {
+    enigo: enigo::Enigo,
+    /// Whether `location` works, so it can tell if the connection still does.
+    /// Only X11 can lose its connection and report the cursor.
+    probe: bool,
+}
+
+impl Native {
+    fn new(settings: &Settings, backend: Backend) -> anyhow::Result<Self> {
+        Ok(Self {
+            enigo: enigo::Enigo::new(settings)?,
+            probe: backend == Backend::X11,
+        })
+    }
+}
+
+impl InputBackend for Native {
+    #[cfg(target_os = "windows")]
+    fn scroll_hi_res(&mut self, settings: &Settings, delta: i32, axis: Axis) -> InputResult<()> {
+        system::imp::scroll_hi_res(settings, delta, axis)
+    }
+
+    fn is_alive(&self) -> bool {
+        !self.probe || self.enigo.location().is_ok()
+    }
+}
+
+impl Keyboard for Native {
+    fn fast_text(&mut self, text: &str) -> InputResult<Option<()>> {
+        self.enigo.fast_text(text)
+    }
+
+    fn text(&mut self, text: &str) -> InputResult<()> {
+        self.enigo.text(text)
+    }
+
+    fn key(&mut self, key: Key, direction: Direction) -> InputResult<()> {
+        self.enigo.key(key, direction)
+    }
+
+    fn raw(&mut self, keycode: u16, direction: Direction) -> InputResult<()> {
+        self.enigo.raw(keycode, direction)
+    }
+}
+
+impl Mouse for Native {
+    fn button(&mut self, button: Button, direction: Direction) -> InputResult<()> {
+        self.enigo.button(button, direction)
+    }
+
+    fn move_mouse(&mut self, x: i32, y: i32, coordinate: Coordinate) -> InputResult<()> {
+        self.enigo.move_mouse(x, y, coordinate)
+    }
+
+    fn scroll(&mut self, length: i32, axis: Axis) -> InputResult<()> {
+        self.enigo.scroll(length, axis)
+    }
+
+    fn main_display(&self) -> InputResult<(i32, i32)> {
+        self.enigo.main_display()
+    }
+
+    fn location(&self) -> InputResult<(i32, i32)> {
+        self.enigo.location()
+    }
+}
+
+/// Connect to the first backend in `preference` that works; an empty
+/// preference means [`Backend::Native`].
+pub(crate) fn connect(
+    settings: &Settings,
+    preference: &[Backend],
+) -> anyhow::Result<(Box<dyn InputBackend>, BackendInfo)> {
+    let preference = if preference.is_empty() {
+        &[Backend::Native]
+    } else {
+        preference
+    };
+    let mut errors = Vec::new();
+    for &backend in preference {
+        match connect_to(settings, backend) {
+            Ok((connected, backend)) => return Ok((connected, info(settings, backend))),
+            Err(error) => errors.push(format!("{backend:?}: {error}")),
+        }
+    }
+    anyhow::bail!("no backend could connect ({})", errors.join("; "))
+}
+
+/// Connect to the input server at `address`; see [`Enigo::remote`].
+///
+/// [`Enigo::remote`]: super::enigo::Enigo::remote
+pub(crate) fn connect_remote(
+    address: &str,
+    token: Option<&str>,
+) -> anyhow::Result<(Box<dyn InputBackend>, BackendInfo)> {
+    #[cfg(feature = "client")]
+    {
+        let remote = Remote::connect(address, token)?;
+        let mut info = info(&Settings::default(), Backend::Remote);
+        info.display = Some(address.to_owned());
+        Ok((Box::new(remote), info))
+    }
+    #[cfg(not(feature = "client"))]
+    {
+        let _ = (address, token);
+        anyhow::bail!("Remote: not included in this build")
+    }
+}
+
+/// Connect to `backend`, and say which one that turned out to be where
+/// [`Backend::Native`] stands for several.
+fn connect_to(
+    settings: &Settings,
+    backend: Backend,
+) -> anyhow::Result<(Box<dyn InputBackend>, Backend)> {
+    if !backend.is_compiled() {
+        anyhow::bail!("not included in this build");
+    }
+    let connected: Box<dyn InputBackend> = match backend {
+        #[cfg(target_os = "linux")]
+        Backend::Native => {
+            let mut errors = Vec::new();
+            for backend in [Backend::X11, Backend::Wayland, Backend::Libei] {
+                if !backend.is_compiled() {
+                    continue;
+                }
+                match connect_to(settings, backend) {
+                    Ok(connected) => return Ok(connected),
+                    Err(error) => errors.push(format!("{backend:?}: {error}")),
+                }
+            }
+            anyhow::bail!("{}", errors.join("; "))
+        }
+        #[cfg(not(target_os = "linux"))]
+        Backend::Native => Box::new(Native::new(settings, backend)?),
+        Backend::Mock => Box::new(MockBackend::new(mock::DEFAULT_DISPLAY)),
+        Backend::Remote => anyhow::bail!("needs an address; see `Enigo::remote`"),
+        #[cfg(all(target_os = "linux", feature = "uinput"))]
+        Backend::Uinput => Box::new(uinput::Uinput::new(settings)?),
+        #[cfg(target_os = "linux")]
+        Backend::X11 | Backend::Wayland | Backend::Libei => {
+            Box::new(Native::new(&only(settings, backend), backend)?)
+        }
+        #[cfg(not(all(target_os = "linux", feature = "uinput")))]
+        _ => unreachable!("checked by is_compiled"),
+    };
+    Ok((connected, backend))
+}
+
+/// Settings that keep enigo from connecting to anything but `backend`.
+///
+/// enigo sends every event through each backend it connected to, so under
+/// XWayland an unrestricted instance types everything twice. X11 and
+/// Wayland are ruled out through a display name that can't exist; libei
+/// can't be, so it is always used when the `libei` feature is on.
+#[cfg(target_os = "linux")]
+fn only(settings: &Settings, backend: Backend) -> Settings {
+    const UNREACHABLE: &str = "enigo-flutter-unreachable";
+
+    let mut settings = settings.clone();
+    if backend != Backend::X11 {
+        settings.x11_display = Some(UNREACHABLE.to_string());
+    }
+    if backend != Backend::Wayland {
+        settings.wayland_display = Some(UNREACHABLE.to_string());
+    }
+    settings
+}
+
+fn info(settings: &Settings, backend: Backend) -> BackendInfo {
+    let x11 = || {
+        settings
+            .x11_display
+            .clone()
+            .or_else(|| std::env::var("DISPLAY").ok())
+    };
+    let wayland = || {
+        settings
+            .wayland_display
+            .clone()
+            .or_else(|| std::env::var("WAYLAND_DISPLAY").ok())
+    };
+    let display = match backend {
+        Backend::X11 => x11(),
+        Backend::Wayland => wayland(),
+        Backend::Native | Backend::Libei | Backend::Uinput | Backend::Mock | Backend::Remote => {
+            None
+        }
+    };
+
+    let full = Capabilities {
+        buttons: [
+            Button::Left,
+            Button::Middle,
+            Button::Right,
+            // macOS has no events for the side buttons.
+            #[cfg(not(target_os = "macos"))]
+            Button::Back,
+            #[cfg(not(target_os = "macos"))]
+            Button::Forward,
+            Button::ScrollUp,
+            Button::ScrollDown,
+            Button::ScrollLeft,
+            Button::ScrollRight,
+        ]
+        .into_iter()
+        .map(|button| _Button { value: button })
+        .collect(),
+        absolute_move: true,
+        location: true,
+        unicode_text: true,
+        raw_keycodes: true,
+        scroll_resolution: system::imp::SCROLL_RESOLUTION as u32,
+    };
+    let capabilities = match backend {
+        Backend::Native | Backend::X11 => full,
+        // Records whole notches.
+        Backend::Mock => Capabilities {
+            scroll_resolution: 1,
+            ..full
+        },
+        // What the server's backend supports isn't known here, so scroll
+        // in whole notches, which every backend can.
+        Backend::Remote => Capabilities {
+            scroll_resolution: 1,
+            ..full
+        },
+        Backend::Wayland => Capabilities {
+            location: false,
+            ..full
+        },
+        Backend::Libei => Capabilities {
+            location: false,
+            raw_keycodes: false,
+            ..full
+        },
+        // Only tracked after an absolute move, and typed on a US layout.
+        Backend::Uinput => Capabilities {
+            location: false,
+            unicode_text: false,
+            scroll_resolution: HI_RES_PER_NOTCH as u32,
+            ..full
+        },
+    };
+
+    BackendInfo {
+        backend,
+        display,
+        compiled: Backend::compiled(),
+        capabilities,
+    }
+}
+
+#[cfg(all(target_os = "linux", feature = "uinput"))]
+#[frb(ignore)]
+mod uinput {
+    use enigo::{
+        Axis, Button, Coordinate, Direction, InputError, InputResult, Key, Keyboard, Mouse,
+        Settings,
+    };
+    use evdev::uinput::VirtualDevice;
+    use evdev::{
+        AbsInfo, AbsoluteAxisCode, AttributeSet, EventType, InputEvent, KeyCode, RelativeAxisCode,
+        UinputAbsSetup,
+    };
+    use std::time::Duration;
+
+    use super::{InputBackend, HI_RES_PER_NOTCH};
+    use crate::api::listener::imp::evdev::X11_KEYCODE_OFFSET;
+
+    /// Compositors pick new devices up asynchronously; anything sent before
+    /// that is lost.
+    const SETTLE: Duration = Duration::from_millis(200);
+
+    /// US layout, unshifted and shifted, in the order of `LAYOUT_CODES`.
+    const LAYOUT: &str = "`1234567890-=qwertyuiop[]\\asdfghjkl;'zxcvbnm,./";
+    const LAYOUT_SHIFTED: &str = "~!@#$%^&*()_+QWERTYUIOP{}|ASDFGHJKL:\"ZXCVBNM<>?";
+    const LAYOUT_CODES: [KeyCode; 47] = [
+        KeyCode::KEY_GRAVE,
+        KeyCode::KEY_1,
+        KeyCode::KEY_2,
+        KeyCode::KEY_3,
+        KeyCode::KEY_4,
+        KeyCode::KEY_5,
+        KeyCode::KEY_6,
+        KeyCode::KEY_7,
+        KeyCode::KEY_8,
+        KeyCode::KEY_9,
+        KeyCode::KEY_0,
+        KeyCode::KEY_MINUS,
+        KeyCode::KEY_EQUAL,
+        KeyCode::KEY_Q,
+        KeyCode::KEY_W,
+        KeyCode::KEY_E,
+        KeyCode::KEY_R,
+        KeyCode::KEY_T,
+        KeyCode::KEY_Y,
+        KeyCode::KEY_U,
+        KeyCode::KEY_I,
+        KeyCode::KEY_O,
+        KeyCode::KEY_P,
+        KeyCode::KEY_LEFTBRACE,
+        KeyCode::KEY_RIGHTBRACE,
+        KeyCode::KEY_BACKSLASH,
+        KeyCode::KEY_A,
+        KeyCode::KEY_S,
+        KeyCode::KEY_D,
+        KeyCode::KEY_F,
+        KeyCode::KEY_G,
+        KeyCode::KEY_H,
+        KeyCode::KEY_J,
+        KeyCode::KEY_K,
+        KeyCode::KEY_L,
+        KeyCode::KEY_SEMICOLON,
+        KeyCode::KEY_APOSTROPHE,
+        KeyCode::KEY_Z,
+        KeyCode::KEY_X,
+        KeyCode::KEY_C,
+        KeyCode::KEY_V,
+        KeyCode::KEY_B,
+        KeyCode::KEY_N,
+        KeyCode::KEY_M,
+        KeyCode::KEY_COMMA,
+        KeyCode::KEY_DOT,
+        KeyCode::KEY_SLASH,
+    ];
+
+    /// Keys outside the 1..=255 range that `keycode` can produce.
+    const EXTRA_KEYS: [KeyCode; 3] = [KeyCode::KEY_SELECT, KeyCode::KEY_CLEAR, KeyCode::KEY_BREAK];
+
+    const BUTTONS: [KeyCode; 5] = [
+        KeyCode::BTN_LEFT,
+        KeyCode::BTN_RIGHT,
+        KeyCode::BTN_MIDDLE,
+        KeyCode::BTN_SIDE,
+        KeyCode::BTN_EXTRA,
+    ];
+
+    pub struct Uinput {
+        /// Keys, buttons, relative motion and wheels.
+        device: VirtualDevice,
+        /// Absolute motion lives on its own device, because libinput treats
+        /// a device with both relative and absolute axes as neither.
+        /// `None` when the display size is unknown.
+        pointer: Option<VirtualDevice>,
+        display: Option<(i32, i32)>,
+        /// uinput can't be queried, so this is where the cursor was last put.
+        location: Option<(i32, i32)>,
+        /// High-resolution wheel movement not yet sent as a whole notch, per
+        /// axis, horizontal first.
+        wheel_remainders: [i32; 2],
+    }
+
+    impl Uinput {
+        pub fn new(settings: &Settings) -> std::io::Result<Self> {
+            let mut keys = AttributeSet::<KeyCode>::new();
+            for code in 1..=255 {
+                keys.insert(KeyCode::new(code));
+            }
+            for key in EXTRA_KEYS.into_iter().chain(BUTTONS) {
+                keys.insert(key);
+            }
+            let mut axes = AttributeSet::<RelativeAxisCode>::new();
+            for axis in [
+                RelativeAxisCode::REL_X,
+                RelativeAxisCode::REL_Y,
+                RelativeAxisCode::REL_WHEEL,
+                RelativeAxisCode::REL_HWHEEL,
+                RelativeAxisCode::REL_WHEEL_HI_RES,
+                RelativeAxisCode::REL_HWHEEL_HI_RES,
+            ] {
+                axes.insert(axis);
+            }
+            let device = VirtualDevice::builder()?
+                .name("enigo_flutter virtual input")
+                .with_keys(&keys)?
+                .with_relative_axes(&axes)?
+                .build()?;
+
+            let display = display_size(settings);
+            let pointer = match display {
+                Some((width, height)) => {
+                    let mut buttons = AttributeSet::<KeyCode>::new();
+                    buttons.insert(KeyCode::BTN_LEFT);
+                    let axis =
+                        |code, max| UinputAbsSetup::new(code, AbsInfo::new(0, 0, max, 0, 0, 0));
+                    Some(
+                        VirtualDevice::builder()?
+                            .name("enigo_flutter virtual pointer")
+                            .with_keys(&buttons)?
+                            .with_absolute_axis(&axis(AbsoluteAxisCode::ABS_X, width - 1))?
+                            .with_absolute_axis(&axis(AbsoluteAxisCode::ABS_Y, height - 1))?
+                            .build()?,
+                    )
+                }
+                None => None,
+            };
+
+            std::thread::sleep(SETTLE);
+            Ok(Self {
+                device,
+                pointer,
+                display,
+                location: None,
+                wheel_remainders: [0; 2],
+            })
+        }
+
+        fn emit(&mut self, events: &[InputEvent]) -> InputResult<()> {
+            self.device
+                .emit(events)
+                .map_err(|_| InputError::Simulate("writing to /dev/uinput failed"))
+        }
+
+        fn press_release(&mut self, code: KeyCode, direction: Direction) -> InputResult<()> {
+            let key = |value| InputEvent::new(EventType::KEY.0, code.code(), value);
+            if matches!(direction, Direction::Press | Direction::Click) {
+                self.emit(&[key(1)])?;
+            }
+            if matches!(direction, Direction::Release | Direction::Click) {
+                self.emit(&[key(0)])?;
+            }
+            Ok(())
+        }
+    }
+
+    impl InputBackend for Uinput {
+        /// Sends `delta` as is on the high-resolution axis and, like a real
+        /// high-resolution wheel, a notch on the legacy one whenever the
+        /// movement adds up to a whole notch.
+        fn scroll_hi_res(
+            &mut self,
+            _settings: &Settings,
+            delta: i32,
+            axis: Axis,
+        ) -> InputResult<()> {
+            let (notches, hi_res, delta) = wheel(delta, axis);
+            let remainder = &mut self.wheel_remainders[(axis == Axis::Vertical) as usize];
+            *remainder += delta;
+            let whole = *remainder / HI_RES_PER_NOTCH;
+            *remainder -= whole * HI_RES_PER_NOTCH;
+            let mut events = vec![InputEvent::new(EventType::RELATIVE.0, hi_res.0, delta)];
+            if whole != 0 {
+                events.push(InputEvent::new(EventType::RELATIVE.0, notches.0, whole));
+            }
+            self.emit(&events)
+        }
+    }
+
+    impl Keyboard for Uinput {
+        fn fast_text(&mut self, _text: &str) -> InputResult<Option<()>> {
+            // Typed key by key through `key`.
+            Ok(None)
+        }
+
+        fn key(&mut self, key: Key, direction: Direction) -> InputResult<()> {
+            let (code, shift) = keycode(key).ok_or(InputError::InvalidInput(
+                "the uinput backend can't type this key",
+            ))?;
+            if shift && direction != Direction::Release {
+                self.press_release(KeyCode::KEY_LEFTSHIFT, Direction::Press)?;
+                let result = self.press_release(code, direction);
+                self.press_release(KeyCode::KEY_LEFTSHIFT, Direction::Release)?;
+                result
+            } else {
+                self.press_release(code, direction)
+            }
+        }
+
+        fn raw(&mut self, keycode: u16, direction: Direction) -> InputResult<()> {
+            // Like the X11 backend, raw keycodes are X11 keycodes.
+            let code = keycode
+                .checked_sub(X11_KEYCODE_OFFSET)
+                .ok_or(InputError::InvalidInput("X11 keycodes start at 8"))?;
+            self.press_release(KeyCode::new(code), direction)
+        }
+    }
+
+    impl Mouse for Uinput {
+        fn button(&mut self, button: Button, direction: Direction) -> InputResult<()> {
+            let code = match button {
+                Button::Left => KeyCode::BTN_LEFT,
+                Button::Middle => KeyCode::BTN_MIDDLE,
+                Button::Right => KeyCode::BTN_RIGHT,
+                Button::Back => KeyCode::BTN_SIDE,
+                Button::Forward => KeyCode::BTN_EXTRA,
+                // Wheel "buttons" scroll on press, like on X11.
+                Button::ScrollUp
+                | Button::ScrollDown
+                | Button::ScrollLeft
+                | Button::ScrollRight => {
+                    if direction == Direction::Release {
+                        return Ok(());
+                    }
+                    let (length, axis) = match button {
+                        Button::ScrollUp => (-1, Axis::Vertical),
+                        Button::ScrollDown => (1, Axis::Vertical),
+                        Button::ScrollLeft => (-1, Axis::Horizontal),
+                        _ => (1, Axis::Horizontal),
+                    };
+                    return self.scroll(length, axis);
+                }
+            };
+            self.press_release(code, direction)
+        }
+
+        fn move_mouse(&mut self, x: i32, y: i32, coordinate: Coordinate) -> InputResult<()> {
+            match coordinate {
+                Coordinate::Abs => {
+                    let pointer = self.pointer.as_mut().ok_or(InputError::Simulate(
+                        "absolute moves need the display size, which is unknown",
+                    ))?;
+                    pointer
+                        .emit(&[
+                            InputEvent::new(EventType::ABSOLUTE.0, AbsoluteAxisCode::ABS_X.0, x),
+                            InputEvent::new(EventType::ABSOLUTE.0, AbsoluteAxisCode::ABS_Y.0, y),
+                        ])
+                        .map_err(|_| InputError::Simulate("writing to /dev/uinput failed"))?;
+                    self.location = Some((x, y));
+                }
+                Coordinate::Rel => {
+                    self.emit(&[
+                        InputEvent::new(EventType::RELATIVE.0, RelativeAxisCode::REL_X.0, x),
+                        InputEvent::new(EventType::RELATIVE.0, RelativeAxisCode::REL_Y.0, y),
+                    ])?;
+                    if let (Some((last_x, last_y)), Some((width, height))) =
+                        (self.location, self.display)
+                    {
+                        self.location = Some((
+                            (last_x + x).clamp(0, width - 1),
+                            (last_y + y).clamp(0, height - 1),
+                        ));
+                    }
+                }
+            }
+            Ok(())
+        }
+
+        fn scroll(&mut self, length: i32, axis: Axis) -> InputResult<()> {
+            let (notches, hi_res, length) = wheel(length, axis);
+            self.emit(&[
+                InputEvent::new(EventType::RELATIVE.0, notches.0, length),
+                InputEvent::new(EventType::RELATIVE.0, hi_res.0, length * HI_RES_PER_NOTCH),
+            ])
+        }
+
+        fn main_display(&self) -> InputResult<(i32, i32)> {
+            self.display
+                .ok_or(InputError::Simulate("the display size is unknown"))
+        }
+
+        fn location(&self) -> InputResult<(i32, i32)> {
+            self.location.ok_or(InputError::Simulate(
+                "the uinput backend only knows the location after an absolute move",
+            ))
+        }
+    }
+
+    /// The legacy and high-resolution wheel axes for `axis`, and `length`
+    /// in their direction: positive means down for enigo, but up for the
+    /// vertical wheel.
+    fn wheel(length: i32, axis: Axis) -> (RelativeAxisCode, RelativeAxisCode, i32) {
+        match axis {
+            Axis::Horizontal => (
+                RelativeAxisCode::REL_HWHEEL,
+                RelativeAxisCode::REL_HWHEEL_HI_RES,
+                length,
+            ),
+            Axis::Vertical => (
+                RelativeAxisCode::REL_WHEEL,
+                RelativeAxisCode::REL_WHEEL_HI_RES,
+                -length,
+            ),
+        }
+    }
+
+    /// The evdev keycode for `key`, and whether Shift has to be held for it.
+    fn keycode(key: Key) -> Option<(KeyCode, bool)> {
+        let code = match key {
+            Key::Unicode(c) => {
+                return match c {
+                    ' ' => Some((KeyCode::KEY_SPACE, false)),
+                    '\n' | '\r' => Some((KeyCode::KEY_ENTER, false)),
+                    '\t' => Some((KeyCode::KEY_TAB, false)),
+                    _ => LAYOUT
+                        .chars()
+                        .position(|l| l == c)
+                        .map(|i| (LAYOUT_CODES[i], false))
+                        .or_else(|| {
+                            LAYOUT_SHIFTED
+                                .chars()
+                                .position(|l| l == c)
+                                .map(|i| (LAYOUT_CODES[i], true))
+                        }),
+                };
+            }
+            Key::Alt => KeyCode::KEY_LEFTALT,
+            Key::Backspace => KeyCode::KEY_BACKSPACE,
+            Key::Break => KeyCode::KEY_BREAK,
+            Key::Cancel => KeyCode::KEY_CANCEL,
+            Key::CapsLock => KeyCode::KEY_CAPSLOCK,
+            Key::Clear => KeyCode::KEY_CLEAR,
+            Key::Meta => KeyCode::KEY_LEFTMETA,
+            Key::Control | Key::LControl => KeyCode::KEY_LEFTCTRL,
+            Key::Delete => KeyCode::KEY_DELETE,
+            Key::DownArrow => KeyCode::KEY_DOWN,
+            Key::End => KeyCode::KEY_END,
+            Key::Escape => KeyCode::KEY_ESC,
+            Key::F1 => KeyCode::KEY_F1,
+            Key::F2 => KeyCode::KEY_F2,
+            Key::F3 => KeyCode::KEY_F3,
+            Key::F4 => KeyCode::KEY_F4,
+            Key::F5 => KeyCode::KEY_F5,
+            Key::F6 => KeyCode::KEY_F6,
+            Key::F7 => KeyCode::KEY_F7,
+            Key::F8 => KeyCode::KEY_F8,
+            Key::F9 => KeyCode::KEY_F9,
+            Key::F10 => KeyCode::KEY_F10,
+            Key::F11 => KeyCode::KEY_F11,
+            Key::F12 => KeyCode::KEY_F12,
+            Key::F13 => KeyCode::KEY_F13,
+            Key::F14 => KeyCode::KEY_F14,
+            Key::F15 => KeyCode::KEY_F15,
+            Key::F16 => KeyCode::KEY_F16,
+            Key::F17 => KeyCode::KEY_F17,
+            Key::F18 => KeyCode::KEY_F18,
+            Key::F19 => KeyCode::KEY_F19,
+            Key::F20 => KeyCode::KEY_F20,
+            Key::F21 => KeyCode::KEY_F21,
+            Key::F22 => KeyCode::KEY_F22,
+            Key::F23 => KeyCode::KEY_F23,
+            Key::F24 => KeyCode::KEY_F24,
+            Key::Find => KeyCode::KEY_FIND,
+            Key::Hangul => KeyCode::KEY_HANGEUL,
+            Key::Hanja => KeyCode::KEY_HANJA,
+            Key::Help => KeyCode::KEY_HELP,
+            Key::Home => KeyCode::KEY_HOME,
+            Key::Insert => KeyCode::KEY_INSERT,
+            Key::LeftArrow => KeyCode::KEY_LEFT,
+            Key::Linefeed => KeyCode::KEY_LINEFEED,
+            Key::LMenu => KeyCode::KEY_COMPOSE,
+            Key::LShift | Key::Shift => KeyCode::KEY_LEFTSHIFT,
+            Key::MediaNextTrack => KeyCode::KEY_NEXTSONG,
+            Key::MediaPlayPause => KeyCode::KEY_PLAYPAUSE,
+            Key::MediaPrevTrack => KeyCode::KEY_PREVIOUSSONG,
+            Key::MediaStop => KeyCode::KEY_STOPCD,
+            Key::MicMute => KeyCode::KEY_MICMUTE,
+            Key::Numlock => KeyCode::KEY_NUMLOCK,
+            Key::PageDown => KeyCode::KEY_PAGEDOWN,
+            Key::PageUp => KeyCode::KEY_PAGEUP,
+            Key::Pause => KeyCode::KEY_PAUSE,
+            Key::PrintScr | Key::SysReq => KeyCode::KEY_SYSRQ,
+            Key::RControl => KeyCode::KEY_RIGHTCTRL,
+            Key::Redo => KeyCode::KEY_REDO,
+            Key::Return => KeyCode::KEY_ENTER,
+            Key::RightArrow => KeyCode::KEY_RIGHT,
+            Key::RShift => KeyCode::KEY_RIGHTSHIFT,
+            Key::ScrollLock => KeyCode::KEY_SCROLLLOCK,
+            Key::Select => KeyCode::KEY_SELECT,
+            Key::Space => KeyCode::KEY_SPACE,
+            Key::Tab => KeyCode::KEY_TAB,
+            Key::Undo => KeyCode::KEY_UNDO,
+            Key::UpArrow => KeyCode::KEY_UP,
+            Key::VolumeDown => KeyCode::KEY_VOLUMEDOWN,
+            Key::VolumeMute => KeyCode::KEY_MUTE,
+            Key::VolumeUp => KeyCode::KEY_VOLUMEUP,
+            _ => return None,
+        };
+        Some((code, false))
+    }
+
+    /// The X11 root window size if there is an X server, otherwise the mode of
+    /// the first connected DRM output.
+    fn display_size(settings: &Settings) -> Option<(i32, i32)> {
+        x11_display_size(settings.x11_display.as_deref()).or_else(drm_display_size)
+    }
+
+    fn x11_display_size(display: Option<&str>) -> Option<(i32, i32)> {
+        use x11rb::connection::Connection;
+
+        let (conn, screen) = x11rb::connect(display).ok()?;
+        let screen = &conn.setup().roots[screen];
+        Some((
+            screen.width_in_pixels.into(),
+            screen.height_in_pixels.into(),
+        ))
+    }
+
+    fn drm_display_size() -> Option<(i32, i32)> {
+        let mut outputs: Vec<_> = std::fs::read_dir("/sys/class/drm")
+            .ok()?
+            .flatten()
+            .map(|entry| entry.path())
+            .collect();
+        outputs.sort();
+        outputs.into_iter().find_map(|output| {
+            let status = std::fs::read_to_string(output.join("status")).ok()?;
+            if status.trim() != "connected" {
+                return None;
+            }
+            let modes = std::fs::read_to_string(output.join("modes")).ok()?;
+            let (width, height) = modes.lines().next()?.split_once('x')?;
+            Some((width.parse().ok()?, height.parse().ok()?))
+        })
+    }
+}
@@ -0,0 +1,412 @@
+use enigo::{
+    Axis, Button, Coordinate, Direction, InputError, InputResult, Key, Keyboard, Mouse, Settings,
+};
+use flutter_rust_bridge::*;
+use std::cell::{Cell, RefCell};
+use std::net::{TcpStream, ToSocketAddrs};
+use std::time::Duration;
+use tungstenite::http::Uri;
+use tungstenite::{Message, WebSocket};
+
+use super::backend::InputBackend;
+use super::policy::PolicyViolation;
+use super::protocol::{self, Command, RemoteError, Reply, Request, RequestId};
+use super::system::{LockState, ModifierState};
+
+const CONNECT_TIMEOUT: Duration = Duration::from_secs(5);
+
+/// Generous, since the server's pacer may hold long text back.
+const REPLY_TIMEOUT: Duration = Duration::from_secs(60);
+
+/// Sends every call to an [`InputServer`] as a JSON command and waits for
+/// its reply; see [`Enigo::remote`].
+///
+/// [`InputServer`]: super::server::InputServer
+/// [`Enigo::remote`]: super::enigo::Enigo::remote
+#[frb(ignore)]
+pub(crate) struct Remote {
+    address: String,
+    token: Option<String>,
+    /// `None` once the connection failed, until it is reconnected.
+    connection: RefCell<Option<Connection>>,
+    /// Set when the server's policy refused the last command.
+    violation: RefCell<Option<PolicyViolation>>,
+    /// Sent with every command, so the server recognizes one sent again
+    /// after a reconnect and doesn't carry it out twice.
+    client: String,
+    next_seq: Cell<u64>,
+    /// The last command and its seq if the connection was lost before its
+    /// reply came, to send it again under the same seq.
+    unanswered: RefCell<Option<(u64, Command)>>,
+}
+
+enum Connection {
+    /// Length-prefixed frames.
+    Tcp(TcpStream),
+    WebSocket(Box<WebSocket<TcpStream>>),
+}
+
+impl Remote {
+    /// Connect to `address`, a `host:port` for plain TCP or a `ws://` URL,
+    /// and authenticate with `token` if there is one.
+    pub fn connect(address: &str, token: Option<&str>) -> anyhow::Result<Self> {
+        let connection = Connection::open(address, token)?;
+        tracing::info!(address, "connected to the input server");
+        Ok(Self {
+            address: address.to_owned(),
+            token: token.map(str::to_owned),
+            connection: RefCell::new(Some(connection)),
+            violation: RefCell::new(None),
+            client: uuid::Uuid::new_v4().to_string(),
+            next_seq: Cell::new(0),
+            unanswered: RefCell::new(None),
+        })
+    }
+
+    fn request(&self, command: &Command) -> InputResult<Reply> {
+        let mut connection = self.connection.borrow_mut();
+        let Some(open) = connection.as_mut() else {
+            return Err(InputError::Simulate("not connected to the input server"));
+        };
+        let seq = match &*self.unanswered.borrow() {
+            Some((seq, unanswered)) if unanswered == command => *seq,
+            _ => self.next_seq.replace(self.next_seq.get() + 1),
+        };
+        let request = Request {
+            command: command.clone(),
+            id: Some(RequestId {
+                client: self.client.clone(),
+                seq,
+            }),
+        };
+        let reply = open.request(&request);
+        *self.unanswered.borrow_mut() = reply.is_err().then(|| (seq, command.clone()));
+        match reply {
+            Ok(Reply::Error(error)) => Err(self.remote_error(error)),
+            Ok(reply) => Ok(reply),
+            Err(error) => {
+                tracing::warn!(address = self.address, %error, "lost the input server");
+                *connection = None;
+                Err(InputError::Simulate(
+                    "lost the connection to the input server",
+                ))
+            }
+        }
+    }
+
+    fn send(&self, command: Command) -> InputResult<()> {
+        // Queries leave it alone: `Enigo` checks the connection with a ping
+        // after a failed call, before it takes the violation.
+        self.violation.take();
+        match self.request(&command)? {
+            Reply::Ok => Ok(()),
+            _ => Err(InputError::Simulate(
+                "unexpected reply from the input server",
+            )),
+        }
+    }
+
+    fn point(&self, command: Command) -> InputResult<(i32, i32)> {
+        match self.request(&command)? {
+            Reply::Point { x, y } => Ok((x, y)),
+            _ => Err(InputError::Simulate(
+                "unexpected reply from the input server",
+            )),
+        }
+    }
+
+    /// The error the server's backend returned, as far as it can be
+    /// rebuilt; the server's message is logged for the ones that can't.
+    fn remote_error(&self, error: RemoteError) -> InputError {
+        match error {
+            RemoteError::Policy { violation } => {
+                *self.violation.borrow_mut() = Some(violation);
+                InputError::Simulate("refused by the input server's policy")
+            }
+            RemoteError::Input { kind, message } => match kind.as_str() {
+                "mapping" => InputError::Mapping(message),
+                "unmapping" => InputError::Unmapping(message),
+                "no_empty_keycodes" => InputError::NoEmptyKeycodes,
+                _ => {
+                    tracing::warn!(kind, message, "the input server failed");
+                    if kind == "invalid_input" {
+                        InputError::InvalidInput("rejected by the input server")
+                    } else {
+                        InputError::Simulate("failed on the input server")
+                    }
+                }
+            },
+            RemoteError::Unauthorized => InputError::Simulate("not authorized by the input server"),
+            RemoteError::BadCommand { message } => {
+                tracing::warn!(message, "the input server couldn't parse a command");
+                InputError::InvalidInput("not understood by the input server")
+            }
+        }
+    }
+}
+
+impl Connection {
+    fn open(address: &str, token: Option<&str>) -> anyhow::Result<Self> {
+        let mut connection = if address.starts_with("ws://") {
+            let uri: Uri = address.parse()?;
+            let host = uri
+                .host()
+                .ok_or_else(|| anyhow::anyhow!("{address} has no host"))?;
+            let stream = tcp(&format!("{host}:{}", uri.port_u16().unwrap_or(80)))?;
+            let (socket, _) = tungstenite::client(address, stream)
+                .map_err(|error| anyhow::anyhow!("WebSocket handshake failed: {error}"))?;
+            Connection::WebSocket(Box::new(socket))
+        } else if address.contains("://") {
+            anyhow::bail!("{address}: only ws:// URLs and host:port are supported");
+        } else {
+            Connection::Tcp(tcp(address)?)
+        };
+        if let Some(token) = token {
+            let auth = Request {
+                command: Command::Auth {
+                    token: token.to_owned(),
+                },
+                id: None,
+            };
+            match connection.request(&auth)? {
+                Reply::Ok => {}
+                Reply::Error(RemoteError::Unauthorized) => {
+                    anyhow::bail!("the input server refused the token")
+                }
+                reply => anyhow::bail!("unexpected reply to the token: {reply:?}"),
+            }
+        }
+        Ok(connection)
+    }
+
+    fn request(&mut self, request: &Request) -> anyhow::Result<Reply> {
+        let json = serde_json::to_string(request)?;
+        let reply = match self {
+            Connection::Tcp(stream) => {
+                protocol::write_frame(stream, json.as_bytes())?;
+                protocol::read_frame(stream)?
+            }
+            Connection::WebSocket(socket) => {
+                socket.send(Message::Text(json))?;
+                loop {
+                    match socket.read()? {
+                        Message::Text(text) => break text.into_bytes(),
+                        Message::Binary(bytes) => break bytes,
+                        Message::Close(_) => {
+                            anyhow::bail!("the input server closed the connection")
+                        }
+                        // Pings are answered on the next write.
+                        _ => {}
+                    }
+                }
+            }
+        };
+        Ok(serde_json::from_slice(&reply)?)
+    }
+}
+
+impl Drop for Connection {
+    fn drop(&mut self) {
+        // Say goodbye, so the server doesn't take this for a failure.
+        if let Connection::WebSocket(socket) = self {
+            let _ = socket.close(None);
+            let _ = socket.flush();
+        }
+    }
+}
+
+fn tcp(address: &str) -> anyhow::Result<TcpStream> {
+    let mut error = None;
+    for candidate in address.to_socket_addrs()? {
+        match TcpStream::connect_timeout(&candidate, CONNECT_TIMEOUT) {
+            Ok(stream) => {
+                stream.set_nodelay(true)?;
+                stream.set_read_timeout(Some(REPLY_TIMEOUT))?;
+                return Ok(stream);
+            }
+            Err(candidate_error) => error = Some(candidate_error),
+        }
+    }
+    match error {
+        Some(error) => Err(error.into()),
+        None => anyhow::bail!("{address} didn't resolve to any address"),
+    }
+}
+
+impl InputBackend for Remote {
+    /// The server releases what a connection held when it closes, so
+    /// nothing is pressed again.
+    fn reconnect(&mut self) -> Option<anyhow::Result<()>> {
+        self.connection.take();
+        let connection = Connection::open(&self.address, self.token.as_deref());
+        Some(connection.map(|connection| *self.connection.get_mut() = Some(connection)))
+    }
+
+    fn take_violation(&mut self) -> Option<PolicyViolation> {
+        self.violation.take()
+    }
+
+    fn is_alive(&self) -> bool {
+        self.request(&Command::Ping).is_ok()
+    }
+
+    fn lock_state(&self, _settings: &Settings) -> anyhow::Result<LockState> {
+        match self.request(&Command::LockState)? {
+            Reply::LockState(state) => Ok(state),
+            reply => anyhow::bail!("unexpected reply from the input server: {reply:?}"),
+        }
+    }
+
+    fn modifier_state(&self, _settings: &Settings) -> anyhow::Result<ModifierState> {
+        match self.request(&Command::ModifierState)? {
+            Reply::ModifierState(state) => Ok(state),
+            reply => anyhow::bail!("unexpected reply from the input server: {reply:?}"),
+        }
+    }
+}
+
+impl Keyboard for Remote {
+    fn fast_text(&mut self, text: &str) -> InputResult<Option<()>> {
+        self.send(Command::Text {
+            text: text.to_owned(),
+        })
+        .map(Some)
+    }
+
+    fn key(&mut self, key: Key, direction: Direction) -> InputResult<()> {
+        self.send(Command::Key { key, direction })
+    }
+
+    fn raw(&mut self, keycode: u16, direction: Direction) -> InputResult<()> {
+        self.send(Command::Raw { keycode, direction })
+    }
+}
+
+impl Mouse for Remote {
+    fn button(&mut self, button: Button, direction: Direction) -> InputResult<()> {
+        self.send(Command::Button { button, direction })
+    }
+
+    fn move_mouse(&mut self, x: i32, y: i32, coordinate: Coordinate) -> InputResult<()> {
+        self.send(Command::MoveMouse { x, y, coordinate })
+    }
+
+    fn scroll(&mut self, length: i32, axis: Axis) -> InputResult<()> {
+        self.send(Command::Scroll { length, axis })
+    }
+
+    fn main_display(&self) -> InputResult<(i32, i32)> {
+        self.point(Command::MainDisplay)
+    }
+
+    fn location(&self) -> InputResult<(i32, i32)> {
+        self.point(Command::Location)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::net::TcpListener;
+    use std::thread;
+
+    /// A server that drops the first connection without answering and
+    /// answers everything on the next one, returning the requests it got.
+    fn flaky_server() -> (String, thread::JoinHandle<Vec<Request>>) {
+        let listener = TcpListener::bind("127.0.0.1:0").unwrap();
+        let address = listener.local_addr().unwrap().to_string();
+        let server = thread::spawn(move || {
+            let mut requests = Vec::new();
+            let mut receive = |stream: &mut TcpStream| {
+                let frame = protocol::read_frame(stream).ok()?;
+                requests.push(serde_json::from_slice(&frame).unwrap());
+                Some(())
+            };
+            let (mut first, _) = listener.accept().unwrap();
+            receive(&mut first);
+            drop(first);
+            let (mut second, _) = listener.accept().unwrap();
+            while receive(&mut second).is_some() {
+                protocol::write_frame(&mut second, br#"{"status":"ok"}"#).unwrap();
+            }
+            requests
+        });
+        (address, server)
+    }
+
+    #[test]
+    fn commands_sent_again_keep_their_seq() {
+        let (address, server) = flaky_server();
+        let mut remote = Remote::connect(&address, None).unwrap();
+        let a = Key::Unicode('a');
+        assert!(remote.key(a, Direction::Click).is_err());
+        remote.reconnect().unwrap().unwrap();
+        remote.key(a, Direction::Click).unwrap();
+        remote.key(a, Direction::Click).unwrap();
+        remote.text("b").unwrap();
+        drop(remote);
+
+        let requests = server.join().unwrap();
+        let ids: Vec<_> = requests
+            .iter()
+            .map(|request| request.id.clone().unwrap())
+            .collect();
+        let seqs: Vec<_> = ids.iter().map(|id| id.seq).collect();
+        assert_eq!(seqs, [0, 0, 1, 2]);
+        assert!(ids.iter().all(|id| id.client == ids[0].client));
+    }
+
+    #[cfg(feature = "server")]
+    #[test]
+    fn enigo_sends_through_a_loopback_server() {
+        use crate::api::enigo::Enigo;
+        use crate::api::mock::MockAction;
+        use crate::api::policy::Policy;
+        use crate::api::server::{InputServer, ServerConfig, ServerToken};
+
+        let target = Enigo::mock(100, 50).unwrap();
+        let config = ServerConfig {
+            address: "127.0.0.1:0".to_owned(),
+            tokens: vec![ServerToken {
+                token: "secret".to_owned(),
+                policy: Some(Policy {
+                    denied_keys: vec![Key::Delete],
+                    ..Policy::default()
+                }),
+            }],
+            ..ServerConfig::default()
+        };
+        let server = InputServer::start(&target, config).unwrap();
+        assert!(Enigo::remote(server.address(), Some("guess".to_owned())).is_err());
+
+        let mut enigo = Enigo::remote(server.address(), Some("secret".to_owned())).unwrap();
+        enigo.key(Key::Unicode('a'), Direction::Click).unwrap();
+        enigo.move_mouse(10, 20, Coordinate::Abs).unwrap();
+        assert_eq!(enigo.location(), (10, 20));
+        assert_eq!(enigo.main_display(), (100, 50));
+        assert_eq!(
+            enigo.key(Key::Delete, Direction::Click),
+            Err(PolicyViolation::KeyDenied { key: Key::Delete })
+        );
+        let actions: Vec<_> = target
+            .mock_calls()
+            .unwrap()
+            .into_iter()
+            .map(|call| call.action)
+            .collect();
+        assert_eq!(
+            actions,
+            [
+                MockAction::Key {
+                    key: Key::Unicode('a'),
+                    direction: Direction::Click
+                },
+                MockAction::MoveMouse {
+                    x: 10,
+                    y: 20,
+                    coordinate: Coordinate::Abs
+                },
+            ]
+        );
+    }
+}
@@ -0,0 +1,404 @@
+use enigo::{Axis, Coordinate, Direction, InputResult, Key, Keyboard, Mouse, Settings};
+use flutter_rust_bridge::*;
+use serde_json::{json, Value};
+use std::fmt::Write as _;
+use std::time::Instant;
+
+use super::backend::{InputBackend, HI_RES_PER_NOTCH};
+use super::enigo::{_Button, HeldInput};
+use super::mock::MockAction;
+
+/// Keycodes `raw` accepts on this platform.
+#[cfg(target_os = "linux")]
+const RAW_KEYCODES: std::ops::RangeInclusive<u16> = 8..=255;
+#[cfg(target_os = "windows")]
+const RAW_KEYCODES: std::ops::RangeInclusive<u16> = 1..=254;
+#[cfg(target_os = "macos")]
+const RAW_KEYCODES: std::ops::RangeInclusive<u16> = 0..=127;
+
+/// What an [`Enigo`] in dry-run mode would have sent.
+///
+/// [`Enigo`]: super::enigo::Enigo
+#[derive(Clone)]
+pub struct TraceEntry {
+    /// Milliseconds since dry-run mode was switched on.
+    pub offset_ms: u64,
+    pub action: MockAction,
+    /// Where the cursor would end up, for mouse moves.
+    pub target: Option<(i32, i32)>,
+    /// Why the call would not have done what was probably intended.
+    pub warning: Option<String>,
+}
+
+impl TraceEntry {
+    /// One line such as `+120 ms  move to (10, 20)`.
+    #[frb(sync)]
+    pub fn describe(&self) -> String {
+        let mut line = format!("+{} ms  ", self.offset_ms);
+        let _ = match &self.action {
+            MockAction::Key { key, direction } => write!(line, "key {key:?} {direction:?}"),
+            MockAction::Raw { keycode, direction } => {
+                write!(line, "raw keycode {keycode} {direction:?}")
+            }
+            MockAction::Button { button, direction } => {
+                write!(line, "button {:?} {direction:?}", button.value)
+            }
+            MockAction::MoveMouse { x, y, coordinate } => match (coordinate, self.target) {
+                (Coordinate::Abs, _) => write!(line, "move to ({x}, {y})"),
+                (Coordinate::Rel, Some((target_x, target_y))) => {
+                    write!(line, "move by ({x}, {y}) to ({target_x}, {target_y})")
+                }
+                (Coordinate::Rel, None) => write!(line, "move by ({x}, {y})"),
+            },
+            MockAction::Scroll { length, axis } => write!(line, "scroll {length} {axis:?}"),
+            MockAction::Text { text } => write!(line, "text {text:?}"),
+        };
+        if let Some(warning) = &self.warning {
+            let _ = write!(line, "  [warning: {warning}]");
+        }
+        line
+    }
+
+    fn to_json(&self) -> Value {
+        let mut value = match &self.action {
+            MockAction::Key { key, direction } => {
+                json!({ "action": "key", "key": key, "direction": direction })
+            }
+            MockAction::Raw { keycode, direction } => {
+                json!({ "action": "raw", "keycode": keycode, "direction": direction })
+            }
+            MockAction::Button { button, direction } => {
+                json!({ "action": "button", "button": button.value, "direction": direction })
+            }
+            MockAction::MoveMouse { x, y, coordinate } => {
+                json!({ "action": "move_mouse", "x": x, "y": y, "coordinate": coordinate })
+            }
+            MockAction::Scroll { length, axis } => {
+                json!({ "action": "scroll", "length": length, "axis": axis })
+            }
+            MockAction::Text { text } => json!({ "action": "text", "text": text }),
+        };
+        value["offset_ms"] = json!(self.offset_ms);
+        if let Some((x, y)) = self.target {
+            value["target"] = json!([x, y]);
+        }
+        if let Some(warning) = &self.warning {
+            value["warning"] = json!(warning);
+        }
+        value
+    }
+}
+
+pub(crate) fn trace_to_text(trace: &[TraceEntry]) -> String {
+    trace.iter().map(|entry| entry.describe() + "\n").collect()
+}
+
+pub(crate) fn trace_to_json(trace: &[TraceEntry]) -> anyhow::Result<String> {
+    let entries: Vec<Value> = trace.iter().map(TraceEntry::to_json).collect();
+    Ok(serde_json::to_string_pretty(&entries)?)
+}
+
+/// Stands in for the backend while dry-run mode is on: checks and traces
+/// every call, and keeps a simulated cursor on a display the size of the real
+/// one.
+#[frb(ignore)]
+pub(crate) struct DryRun {
+    pub trace: Vec<TraceEntry>,
+    /// What the real backend holds, restored when dry-run mode ends.
+    pub held: HeldInput,
+    display: (i32, i32),
+    location: (i32, i32),
+    /// High-resolution wheel movement not yet traced as a whole notch, per
+    /// axis, horizontal first.
+    wheel_remainders: [i32; 2],
+    start: Instant,
+}
+
+impl DryRun {
+    /// Start from the real backend's display size and cursor location,
+    /// where it can report them.
+    pub fn new(backend: &dyn InputBackend, held: HeldInput) -> Self {
+        let display = backend
+            .main_display()
+            .unwrap_or(super::mock::DEFAULT_DISPLAY);
+        let location = backend.location().unwrap_or((display.0 / 2, display.1 / 2));
+        Self {
+            trace: Vec::new(),
+            held,
+            display,
+            location,
+            wheel_remainders: [0; 2],
+            start: Instant::now(),
+        }
+    }
+
+    fn record(&mut self, action: MockAction, target: Option<(i32, i32)>, warning: Option<String>) {
+        self.trace.push(TraceEntry {
+            offset_ms: self.start.elapsed().as_millis() as u64,
+            action,
+            target,
+            warning,
+        });
+    }
+}
+
+impl InputBackend for DryRun {
+    /// Traced in whole notches, like the mock, once the movement adds up to
+    /// one.
+    fn scroll_hi_res(&mut self, _settings: &Settings, delta: i32, axis: Axis) -> InputResult<()> {
+        let remainder = &mut self.wheel_remainders[(axis == Axis::Vertical) as usize];
+        *remainder += delta;
+        let length = *remainder / HI_RES_PER_NOTCH;
+        *remainder -= length * HI_RES_PER_NOTCH;
+        if length != 0 {
+            self.record(MockAction::Scroll { length, axis }, None, None);
+        }
+        Ok(())
+    }
+}
+
+impl Keyboard for DryRun {
+    fn fast_text(&mut self, text: &str) -> InputResult<Option<()>> {
+        let warning = text
+            .contains('\0')
+            .then(|| "null characters are skipped".to_string());
+        self.record(
+            MockAction::Text {
+                text: text.to_owned(),
+            },
+            None,
+            warning,
+        );
+        Ok(Some(()))
+    }
+
+    fn key(&mut self, key: Key, direction: Direction) -> InputResult<()> {
+        let warning = match key {
+            Key::Unicode('\0') => Some("a null character is skipped".to_string()),
+            Key::Other(0) => Some("resolves to no key".to_string()),
+            _ => None,
+        };
+        self.record(MockAction::Key { key, direction }, None, warning);
+        Ok(())
+    }
+
+    fn raw(&mut self, keycode: u16, direction: Direction) -> InputResult<()> {
+        let warning = (!RAW_KEYCODES.contains(&keycode)).then(|| {
+            format!(
+                "keycodes on this platform are {}..={}",
+                RAW_KEYCODES.start(),
+                RAW_KEYCODES.end()
+            )
+        });
+        self.record(MockAction::Raw { keycode, direction }, None, warning);
+        Ok(())
+    }
+}
+
+impl Mouse for DryRun {
+    fn button(&mut self, button: enigo::Button, direction: Direction) -> InputResult<()> {
+        let button = _Button { value: button };
+        self.record(MockAction::Button { button, direction }, None, None);
+        Ok(())
+    }
+
+    fn move_mouse(&mut self, x: i32, y: i32, coordinate: Coordinate) -> InputResult<()> {
+        let target = match coordinate {
+            Coordinate::Abs => (x, y),
+            Coordinate::Rel => (self.location.0 + x, self.location.1 + y),
+        };
+        let (width, height) = self.display;
+        let clamped = (target.0.clamp(0, width - 1), target.1.clamp(0, height - 1));
+        let warning = (clamped != target).then(|| {
+            format!(
+                "({}, {}) is outside the {width}x{height} display",
+                target.0, target.1
+            )
+        });
+        self.location = clamped;
+        self.record(
+            MockAction::MoveMouse { x, y, coordinate },
+            Some(clamped),
+            warning,
+        );
+        Ok(())
+    }
+
+    fn scroll(&mut self, length: i32, axis: Axis) -> InputResult<()> {
+        self.record(MockAction::Scroll { length, axis }, None, None);
+        Ok(())
+    }
+
+    fn main_display(&self) -> InputResult<(i32, i32)> {
+        Ok(self.display)
+    }
+
+    fn location(&self) -> InputResult<(i32, i32)> {
+        Ok(self.location)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::api::mock::MockBackend;
+
+    fn dry_run() -> DryRun {
+        DryRun::new(&MockBackend::new((100, 50)), HeldInput::default())
+    }
+
+    fn entry(action: MockAction) -> TraceEntry {
+        TraceEntry {
+            offset_ms: 120,
+            action,
+            target: None,
+            warning: None,
+        }
+    }
+
+    #[test]
+    fn starts_where_the_backend_is() {
+        let dry_run = dry_run();
+        assert_eq!(dry_run.main_display().unwrap(), (100, 50));
+        assert_eq!(dry_run.location().unwrap(), (50, 25));
+    }
+
+    #[test]
+    fn moves_are_clamped_to_the_display() {
+        let mut dry_run = dry_run();
+        dry_run.move_mouse(10, 20, Coordinate::Abs).unwrap();
+        dry_run.move_mouse(200, 0, Coordinate::Rel).unwrap();
+        dry_run.move_mouse(-5, 60, Coordinate::Abs).unwrap();
+        assert_eq!(dry_run.location().unwrap(), (0, 49));
+        let traced: Vec<_> = dry_run
+            .trace
+            .iter()
+            .map(|entry| (entry.target, entry.warning.as_deref()))
+            .collect();
+        assert_eq!(
+            traced,
+            [
+                (Some((10, 20)), None),
+                (
+                    Some((99, 20)),
+                    Some("(210, 20) is outside the 100x50 display")
+                ),
+                (
+                    Some((0, 49)),
+                    Some("(-5, 60) is outside the 100x50 display")
+                ),
+            ]
+        );
+    }
+
+    #[test]
+    fn suspicious_calls_are_traced_with_a_warning() {
+        let mut dry_run = dry_run();
+        dry_run.key(Key::Unicode('\0'), Direction::Click).unwrap();
+        dry_run.key(Key::Other(0), Direction::Click).unwrap();
+        dry_run.key(Key::Return, Direction::Click).unwrap();
+        dry_run.fast_text("a\0b").unwrap();
+        dry_run
+            .raw(*RAW_KEYCODES.start(), Direction::Press)
+            .unwrap();
+        dry_run.raw(u16::MAX, Direction::Press).unwrap();
+        let warnings: Vec<_> = dry_run
+            .trace
+            .iter()
+            .map(|entry| entry.warning.is_some())
+            .collect();
+        assert_eq!(warnings, [true, true, false, true, false, true]);
+        let raw = dry_run.trace[5].warning.as_deref().unwrap();
+        assert!(raw.starts_with("keycodes on this platform are"), "{raw}");
+    }
+
+    #[test]
+    fn hi_res_scrolling_is_traced_in_whole_notches() {
+        let mut dry_run = dry_run();
+        let settings = Settings::default();
+        for _ in 0..5 {
+            dry_run
+                .scroll_hi_res(&settings, HI_RES_PER_NOTCH / 2, Axis::Vertical)
+                .unwrap();
+        }
+        dry_run
+            .scroll_hi_res(&settings, -HI_RES_PER_NOTCH, Axis::Horizontal)
+            .unwrap();
+        let actions: Vec<_> = dry_run.trace.into_iter().map(|e| e.action).collect();
+        let scroll = |length, axis| MockAction::Scroll { length, axis };
+        assert_eq!(
+            actions,
+            [
+                scroll(1, Axis::Vertical),
+                scroll(1, Axis::Vertical),
+                scroll(-1, Axis::Horizontal),
+            ]
+        );
+    }
+
+    #[test]
+    fn entries_describe_themselves() {
+        let mut moved = entry(MockAction::MoveMouse {
+            x: -5,
+            y: 3,
+            coordinate: Coordinate::Rel,
+        });
+        assert_eq!(moved.describe(), "+120 ms  move by (-5, 3)");
+        moved.target = Some((45, 28));
+        moved.warning = Some("careful".to_owned());
+        assert_eq!(
+            moved.describe(),
+            "+120 ms  move by (-5, 3) to (45, 28)  [warning: careful]"
+        );
+        let key = entry(MockAction::Key {
+            key: Key::Return,
+            direction: Direction::Click,
+        });
+        assert_eq!(key.describe(), "+120 ms  key Return Click");
+        let text = entry(MockAction::Text {
+            text: "a\"b".to_owned(),
+        });
+        assert_eq!(text.describe(), r#"+120 ms  text "a\"b""#);
+        assert_eq!(
+            trace_to_text(&[key, text]),
+            "+120 ms  key Return Click\n+120 ms  text \"a\\\"b\"\n"
+        );
+    }
+
+    #[test]
+    fn entries_as_json() {
+        let mut moved = entry(MockAction::MoveMouse {
+            x: 1,
+            y: 2,
+            coordinate: Coordinate::Abs,
+        });
+        moved.target = Some((1, 2));
+        assert_eq!(
+            moved.to_json(),
+            json!({
+                "action": "move_mouse",
+                "x": 1,
+                "y": 2,
+                "coordinate": "Abs",
+                "offset_ms": 120,
+                "target": [1, 2],
+            })
+        );
+        let mut raw = entry(MockAction::Raw {
+            keycode: 4,
+            direction: Direction::Press,
+        });
+        raw.warning = Some("careful".to_owned());
+        let json: Value = serde_json::from_str(&trace_to_json(&[raw]).unwrap()).unwrap();
+        assert_eq!(
+            json,
+            json!([{
+                "action": "raw",
+                "keycode": 4,
+                "direction": "Press",
+                "offset_ms": 120,
+                "warning": "careful",
+            }])
+        );
+    }
+}
@@ -1,7 +1,24 @@
+use crate::frb_generated::StreamSink;
 pub use enigo::Settings;
-use enigo::{Key, Keyboard, Mouse};
+use enigo::{InputError, InputResult, Key};
 use flutter_rust_bridge::*;
-use std::sync::Mutex;
+use serde::Deserialize;
+use std::panic::{self, AssertUnwindSafe};
+use std::sync::mpsc::{self, Receiver, Sender};
+use std::sync::{Arc, Mutex, MutexGuard, PoisonError, Weak};
+use std::thread::{self, ThreadId};
+use std::time::{Duration, Instant};
+
+use super::audit::{AuditLog, Audited};
+use super::backend::{self, Backend, BackendInfo, Capabilities, InputBackend};
+use super::dry_run::{self, DryRun, TraceEntry};
+use super::metrics::{self, Metrics, Registry, Stage};
+use super::mock::{MockAction, MockBackend, MockCall};
+use super::pacer::{Pacer, PacerConfig, PacerStats};
+use super::policy::{Enforcer, Policy, PolicyViolation};
+use super::recorder::{PlaybackOptions, Recording};
+use super::settings;
+use super::system::{self, LockState, ModifierState, RepeatInfo};
 
 pub use enigo::{Axis, Coordinate, Direction};
 
@@ -55,30 +72,383 @@ mod permission {
         true
     }
 }
+#[cfg(not(any(target_os = "macos", target_os = "windows")))]
+#[frb(ignore)]
+mod permission {
+    pub fn has_permission(_open_prompt_to_get_permissions: bool) -> bool {
+        true
+    }
+}
 
 #[frb]
+#[derive(Clone)]
 pub struct Enigo {
     #[frb(ignore)]
-    enigo: Mutex<enigo::Enigo>,
+    worker: Arc<Worker>,
+}
+
+/// Owns the backend on a thread of its own and runs jobs sent to it, so the
+/// backend's connection is only ever used from the thread that opened it.
+#[frb(ignore)]
+struct Worker {
+    jobs: Sender<Job>,
+    thread: ThreadId,
+    metrics: Arc<Mutex<Registry>>,
+    /// Shared with [`State`], but waited on by callers, so a paced call
+    /// doesn't hold up the worker.
+    pacer: Arc<Mutex<Option<Pacer>>>,
+}
+
+type Job = Box<dyn FnOnce(&mut State) + Send>;
+
+#[frb(ignore)]
+struct State {
+    backend: Box<dyn InputBackend>,
+    info: BackendInfo,
+    /// What `backend` was picked from, to pick again on reconnect.
+    preference: Vec<Backend>,
+    settings: Settings,
+    held: HeldInput,
+    reconnect_policy: ReconnectPolicy,
+    reconnect_sink: Option<StreamSink<ReconnectEvent>>,
+    /// Takes the backend's place while dry-run mode is on.
+    dry_run: Option<DryRun>,
+    /// Identifies this instance in audit records.
+    session_id: String,
+    audit: Option<AuditLog>,
+    policy: Option<Enforcer>,
+    pacer: Arc<Mutex<Option<Pacer>>>,
+    metrics: Arc<Mutex<Registry>>,
+    /// What [`Worker::run_op`] is running, for the metrics.
+    operation: &'static str,
 }
 
-unsafe impl Send for Enigo {}
+/// Every live `Enigo`, so the panic hook can release what they hold.
+static INSTANCES: Mutex<Vec<Weak<Worker>>> = Mutex::new(Vec::new());
 
-unsafe impl Sync for Enigo {}
+/// [`State::operation`] outside of [`Worker::run_op`].
+const OTHER_OPERATION: &str = "other";
+
+/// What [`Enigo::click`] assumes when the system's double-click time can't
+/// be read; Windows' default.
+pub const DEFAULT_DOUBLE_CLICK_TIME_MS: u32 = 500;
 
 impl Enigo {
+    /// An instance on the backends `ENIGO_BACKEND` lists in order of
+    /// preference, separated by commas, or [`Backend::Native`] if it isn't
+    /// set. A value that doesn't parse, or whose backends can't connect, is
+    /// logged and Native used instead.
     #[frb(sync)]
     pub fn new(settings: &Settings) -> Self {
-        Self {
-            enigo: Mutex::new(enigo::Enigo::new(settings).unwrap()),
+        let preference = settings::backend_from_env().unwrap_or_else(|error| {
+            tracing::warn!(%error, "ignoring ENIGO_BACKEND");
+            None
+        });
+        if let Some(preference) = preference {
+            match Self::with_backends(settings, preference) {
+                Ok(enigo) => return enigo,
+                Err(error) => tracing::warn!(%error, "using Native instead of ENIGO_BACKEND"),
+            }
         }
+        Self::with_backends(settings, vec![Backend::Native]).unwrap()
+    }
+
+    /// Like [`Enigo::new`], but sending input through `backend`.
+    #[frb(sync)]
+    pub fn with_backend(settings: &Settings, backend: Backend) -> anyhow::Result<Self> {
+        Self::with_backends(settings, vec![backend])
+    }
+
+    /// Like [`Enigo::new`], but sending input through the first backend in
+    /// `preference` that connects.
+    #[frb(sync)]
+    #[tracing::instrument]
+    pub fn with_backends(settings: &Settings, preference: Vec<Backend>) -> anyhow::Result<Self> {
+        let worker = Worker::spawn(settings.clone(), preference, backend::connect)?;
+        Ok(Self::register(worker))
+    }
+
+    /// An instance that sends its input to the [`InputServer`] at `address`
+    /// on another machine, authenticating with `token` if there is one.
+    ///
+    /// `address` is `host:port` for plain TCP or a `ws://` URL for
+    /// WebSocket. Errors the server reports surface as they would locally:
+    /// a refusal by its policy as a [`PolicyViolation`], anything else as a
+    /// panic with the backend's error. If the connection is lost, the call
+    /// reconnects up to 3 times, half a second apart; see
+    /// [`Enigo::set_reconnect_policy`]. The server releases what was held
+    /// when the connection went away, and recognizes a call sent again
+    /// after a reconnect, so it isn't carried out twice.
+    ///
+    /// Needs the `client` cargo feature, which the README shows how to turn
+    /// on through cargokit; without it this fails.
+    ///
+    /// Not `sync`: connecting waits on the network, which would block the
+    /// Dart isolate.
+    ///
+    /// [`InputServer`]: super::server::InputServer
+    #[tracing::instrument(skip(token))]
+    pub fn remote(address: String, token: Option<String>) -> anyhow::Result<Self> {
+        let worker = Worker::spawn(Settings::default(), vec![Backend::Remote], move |_, _| {
+            backend::connect_remote(&address, token.as_deref())
+        })?;
+        let enigo = Self::register(worker);
+        enigo.set_reconnect_policy(ReconnectPolicy::Retry {
+            max_attempts: 3,
+            delay_ms: 500,
+        });
+        Ok(enigo)
+    }
+
+    fn register(worker: Worker) -> Self {
+        let worker = Arc::new(worker);
+        let mut instances = INSTANCES.lock().unwrap_or_else(PoisonError::into_inner);
+        instances.retain(|weak| weak.strong_count() > 0);
+        instances.push(Arc::downgrade(&worker));
+        Self { worker }
     }
 
     #[frb(sync)]
     pub fn preset() -> Self {
-        Self {
-            enigo: Mutex::new(enigo::Enigo::new(&Settings::default()).unwrap()),
+        Self::new(&Settings::default())
+    }
+
+    /// An instance on [`Backend::Mock`] with a display of the given size and
+    /// the cursor in its center.
+    #[frb(sync)]
+    pub fn mock(display_width: i32, display_height: i32) -> anyhow::Result<Self> {
+        if display_width <= 0 || display_height <= 0 {
+            anyhow::bail!("the display size must be positive");
         }
+        let enigo = Self::with_backend(&Settings::default(), Backend::Mock)?;
+        enigo.worker.run(move |state| {
+            if let Some(mock) = state.backend.mock() {
+                *mock = MockBackend::new((display_width, display_height));
+            }
+        });
+        Ok(enigo)
+    }
+
+    /// Every call a [`Backend::Mock`] instance received, oldest first.
+    #[frb(sync)]
+    pub fn mock_calls(&self) -> anyhow::Result<Vec<MockCall>> {
+        self.worker.run(|state| match state.backend.mock() {
+            Some(mock) => Ok(mock.calls.clone()),
+            None => Err(anyhow::anyhow!("not using the mock backend")),
+        })
+    }
+
+    #[frb(sync)]
+    pub fn clear_mock_calls(&self) -> anyhow::Result<()> {
+        self.worker.run(|state| match state.backend.mock() {
+            Some(mock) => {
+                mock.calls.clear();
+                Ok(())
+            }
+            None => Err(anyhow::anyhow!("not using the mock backend")),
+        })
+    }
+
+    /// Whether the backend's connection still works: X11 is asked for the
+    /// cursor location and an input server is pinged. The other backends
+    /// have no connection to lose, or none that can be checked, and are
+    /// always alive.
+    #[frb(sync)]
+    pub fn is_alive(&self) -> bool {
+        self.worker.run(|state| state.is_alive())
+    }
+
+    /// Recreate the backend from the settings and backend preference this
+    /// instance was created with.
+    #[frb(sync)]
+    #[tracing::instrument(skip(self))]
+    pub fn reconnect(&self) -> anyhow::Result<()> {
+        self.worker.run(State::reconnect)
+    }
+
+    /// What to do when a call fails because the connection went away, for
+    /// example because the X server restarted. Defaults to
+    /// [`ReconnectPolicy::Never`].
+    #[frb(sync)]
+    #[tracing::instrument(skip(self))]
+    pub fn set_reconnect_policy(&self, policy: ReconnectPolicy) {
+        self.worker
+            .run(move |state| state.reconnect_policy = policy);
+    }
+
+    /// Receive a [`ReconnectEvent`] whenever the reconnect policy kicks in.
+    #[frb(sync)]
+    pub fn reconnects(&self, sink: StreamSink<ReconnectEvent>) {
+        self.worker
+            .run(move |state| state.reconnect_sink = Some(sink));
+    }
+
+    /// Switch dry-run mode on or off. While it is on, every call goes
+    /// through the same key resolution, coordinate conversion and bounds
+    /// checks as usual but is only added to the trace instead of being sent.
+    ///
+    /// Switching it on starts a new trace. Input held before stays held and
+    /// is tracked again once dry-run mode ends.
+    #[frb(sync)]
+    #[tracing::instrument(skip(self))]
+    pub fn set_dry_run(&self, enabled: bool) {
+        self.worker.run(move |state| {
+            if enabled {
+                state.end_dry_run();
+                let held = std::mem::take(&mut state.held);
+                state.dry_run = Some(DryRun::new(&*state.backend, held));
+            } else {
+                state.end_dry_run();
+            }
+        });
+    }
+
+    #[frb(sync, getter)]
+    pub fn is_dry_run(&self) -> bool {
+        self.worker.run(|state| state.dry_run.is_some())
+    }
+
+    /// What this instance would have sent since dry-run mode was switched
+    /// on; empty when it is off.
+    #[frb(sync)]
+    pub fn dry_run_trace(&self) -> Vec<TraceEntry> {
+        self.worker.run(|state| state.trace().to_vec())
+    }
+
+    /// [`Enigo::dry_run_trace`] with one readable line per call.
+    #[frb(sync)]
+    pub fn dry_run_trace_text(&self) -> String {
+        self.worker
+            .run(|state| dry_run::trace_to_text(state.trace()))
+    }
+
+    /// [`Enigo::dry_run_trace`] as a JSON array.
+    #[frb(sync)]
+    pub fn dry_run_trace_json(&self) -> anyhow::Result<String> {
+        self.worker
+            .run(|state| dry_run::trace_to_json(state.trace()))
+    }
+
+    /// Identifies this instance in audit log records.
+    #[frb(sync, getter)]
+    pub fn session_id(&self) -> String {
+        self.worker.run(|state| state.session_id.clone())
+    }
+
+    /// Append a record of every event sent from now on to the JSON-lines
+    /// file at `path`, or stop with `None`. Check the file with
+    /// [`verify_audit_log`].
+    ///
+    /// [`verify_audit_log`]: super::audit::verify_audit_log
+    #[frb(sync)]
+    #[tracing::instrument(skip(self))]
+    pub fn set_audit_log(&self, path: Option<String>) -> anyhow::Result<()> {
+        self.worker.run(move |state| {
+            state.audit = match path {
+                Some(path) => Some(AuditLog::open(&path, state.session_id.clone())?),
+                None => None,
+            };
+            Ok(())
+        })
+    }
+
+    /// Refuse calls that `policy` doesn't allow with a [`PolicyViolation`],
+    /// or allow everything again with `None`.
+    #[frb(sync)]
+    #[tracing::instrument(skip(self))]
+    pub fn set_policy(&self, policy: Option<Policy>) {
+        self.worker
+            .run(move |state| state.policy = policy.map(Enforcer::new));
+    }
+
+    #[frb(sync, getter)]
+    pub fn policy(&self) -> Option<Policy> {
+        self.worker.run(|state| {
+            state
+                .policy
+                .as_ref()
+                .map(|enforcer| enforcer.policy.clone())
+        })
+    }
+
+    /// Space out events as `config` says, or send them as they come with
+    /// `None`. Calls block while their event waits, after the events of
+    /// earlier calls; other calls on this instance go ahead meanwhile.
+    ///
+    /// The wait is on the calling thread, which for the `sync` methods is
+    /// the Dart isolate that calls them: with a low rate, or text longer
+    /// than the burst, call them from a background isolate, or the UI
+    /// freezes until the events are sent.
+    ///
+    /// Fails if `config` has a rate that can't work; see
+    /// [`PacerConfig::events_per_second`].
+    #[frb(sync)]
+    #[tracing::instrument(skip(self))]
+    pub fn set_pacer(&self, config: Option<PacerConfig>) -> anyhow::Result<()> {
+        if let Some(config) = &config {
+            config.validate()?;
+        }
+        *self.worker.pacer() = config.map(Pacer::new);
+        Ok(())
+    }
+
+    /// How much the pacer has held events back since it was set.
+    #[frb(sync)]
+    pub fn pacer_stats(&self) -> Option<PacerStats> {
+        self.worker.pacer().as_mut().map(Pacer::current_stats)
+    }
+
+    /// Timings and outcomes of this instance's calls since it was created
+    /// or [`Enigo::reset_metrics`] was called.
+    #[frb(sync)]
+    pub fn metrics(&self) -> Metrics {
+        self.worker.metrics().snapshot()
+    }
+
+    #[frb(sync)]
+    pub fn reset_metrics(&self) {
+        *self.worker.metrics() = Registry::default();
+    }
+
+    #[frb(sync)]
+    pub fn settings(&self) -> Settings {
+        self.worker.run(|state| state.settings.clone())
+    }
+
+    /// Change some settings, including the backend preference. The backend
+    /// is rebuilt if anything changed, and input held down through this
+    /// instance stays held. Settings [`validate_settings`] rejects are
+    /// rejected here too.
+    ///
+    /// [`validate_settings`]: super::settings::validate_settings
+    #[frb(sync)]
+    #[tracing::instrument(skip(self))]
+    pub fn update_settings(&self, patch: SettingsPatch) -> anyhow::Result<()> {
+        self.worker.run(move |state| {
+            let preference = patch
+                .backend
+                .clone()
+                .unwrap_or_else(|| state.preference.clone());
+            let mut settings = state.settings.clone();
+            patch.apply(&mut settings);
+            if settings == state.settings && preference == state.preference {
+                return Ok(());
+            }
+            state.rebuild(settings, preference)
+        })
+    }
+
+    /// Which backend this instance ended up with, and what it can do.
+    #[frb(sync)]
+    pub fn backend_info(&self) -> BackendInfo {
+        self.worker.run(|state| state.info.clone())
+    }
+
+    /// What this instance's backend supports; shorthand for
+    /// `backend_info().capabilities`.
+    #[frb(sync)]
+    pub fn capabilities(&self) -> Capabilities {
+        self.worker.run(|state| state.info.capabilities.clone())
     }
 
     #[frb(sync)]
@@ -87,54 +457,1010 @@ impl Enigo {
     }
 
     #[frb(sync)]
-    pub fn button(&mut self, button: _Button, direction: Direction) {
-        self.enigo
-            .lock()
-            .unwrap()
-            .button(button.value, direction)
-            .unwrap();
+    #[tracing::instrument(level = "debug", skip(self))]
+    pub fn button(&mut self, button: _Button, direction: Direction) -> Result<(), PolicyViolation> {
+        let action = MockAction::Button { button, direction };
+        let result = self.worker.run_paced("button", &[action], move |state| {
+            state.permit(&MockAction::Button { button, direction })?;
+            let result = state.call(|backend| backend.button(button.value, direction));
+            if result.is_ok() {
+                state.held.track_button(button.value, direction);
+            }
+            state.remote_policy(result)
+        });
+        result?.unwrap();
+        Ok(())
+    }
+
+    /// Click `button` `count` times natively, so FFI jitter can't stretch the
+    /// gap between clicks past the double-click threshold.
+    ///
+    /// Without `interval_ms` the clicks are spaced at a quarter of the system
+    /// double-click time, or of [`DEFAULT_DOUBLE_CLICK_TIME_MS`] if that
+    /// can't be read.
+    #[tracing::instrument(level = "debug", skip(self))]
+    pub fn click(
+        &self,
+        button: _Button,
+        count: u32,
+        interval_ms: Option<u32>,
+    ) -> Result<(), PolicyViolation> {
+        let interval_ms = match interval_ms {
+            Some(interval_ms) => interval_ms,
+            None => {
+                let double_click_time = self.double_click_time().unwrap_or_else(|error| {
+                    tracing::warn!(%error, "using the default double-click time");
+                    DEFAULT_DOUBLE_CLICK_TIME_MS
+                });
+                double_click_time / 4
+            }
+        };
+        for i in 0..count {
+            if i > 0 {
+                thread::sleep(Duration::from_millis(interval_ms.into()));
+            }
+            let direction = Direction::Click;
+            let action = MockAction::Button { button, direction };
+            let result = self.worker.run_paced("click", &[action], move |state| {
+                state.permit(&MockAction::Button { button, direction })?;
+                let result = state.call(|backend| backend.button(button.value, direction));
+                state.remote_policy(result)
+            });
+            result?.unwrap();
+        }
+        Ok(())
     }
 
+    /// The longest gap, in milliseconds, the system accepts between the clicks
+    /// of a double-click.
     #[frb(sync)]
-    pub fn move_mouse(&mut self, x: i32, y: i32, coordinate: Coordinate) {
-        self.enigo
-            .lock()
-            .unwrap()
-            .move_mouse(x, y, coordinate)
-            .unwrap();
+    pub fn double_click_time(&self) -> anyhow::Result<u32> {
+        system::imp::double_click_time(self.x11_display().as_deref())
+            .map_err(|error| anyhow::anyhow!("couldn't read the double-click time: {error}"))
     }
 
     #[frb(sync)]
-    pub fn scroll(&mut self, length: i32, axis: Axis) {
-        self.enigo.lock().unwrap().scroll(length, axis).unwrap();
+    #[tracing::instrument(level = "debug", skip(self))]
+    pub fn move_mouse(
+        &mut self,
+        x: i32,
+        y: i32,
+        coordinate: Coordinate,
+    ) -> Result<(), PolicyViolation> {
+        let action = MockAction::MoveMouse { x, y, coordinate };
+        let result = self
+            .worker
+            .run_paced("move_mouse", &[action], move |state| {
+                state.permit(&MockAction::MoveMouse { x, y, coordinate })?;
+                let result = state.call(|backend| backend.move_mouse(x, y, coordinate));
+                state.remote_policy(result)
+            });
+        result?.unwrap();
+        Ok(())
     }
 
     #[frb(sync)]
+    #[tracing::instrument(level = "debug", skip(self))]
+    pub fn scroll(&mut self, length: i32, axis: Axis) -> Result<(), PolicyViolation> {
+        let action = MockAction::Scroll { length, axis };
+        let result = self.worker.run_paced("scroll", &[action], move |state| {
+            state.permit(&MockAction::Scroll { length, axis })?;
+            let result = state.call(|backend| backend.scroll(length, axis));
+            state.remote_policy(result)
+        });
+        result?.unwrap();
+        Ok(())
+    }
+
+    /// Scroll by `dx`/`dy` notches, which may be fractional, spread over
+    /// `duration_ms` in many small steps.
+    ///
+    /// Partial notches are sent as-is where the backend supports high
+    /// resolution wheel events and are accumulated into whole notches
+    /// elsewhere.
+    #[tracing::instrument(level = "debug", skip(self))]
+    pub fn scroll_smooth(&self, dx: f64, dy: f64, duration_ms: u32) -> Result<(), PolicyViolation> {
+        const STEP_MS: u32 = 8;
+
+        let resolution = self.capabilities().scroll_resolution.max(1) as i32;
+        let steps = (duration_ms / STEP_MS).max(1);
+        let (mut sent_x, mut sent_y) = (0, 0);
+        let start = Instant::now();
+        for step in 1..=steps {
+            let progress = f64::from(step) / f64::from(steps);
+            let target_x = (dx * f64::from(resolution) * progress).round() as i32;
+            let target_y = (dy * f64::from(resolution) * progress).round() as i32;
+            let (delta_x, delta_y) = (target_x - sent_x, target_y - sent_y);
+            for (length, axis) in [(delta_x, Axis::Horizontal), (delta_y, Axis::Vertical)] {
+                if length == 0 {
+                    continue;
+                }
+                let action = MockAction::Scroll { length, axis };
+                let result = self
+                    .worker
+                    .run_paced("scroll_smooth", &[action], move |state| {
+                        state.permit(&MockAction::Scroll { length, axis })?;
+                        let settings = state.settings.clone();
+                        let delta = length * backend::HI_RES_PER_NOTCH / resolution;
+                        let result =
+                            state.call(|backend| backend.scroll_hi_res(&settings, delta, axis));
+                        state.remote_policy(result)
+                    });
+                result?.unwrap();
+            }
+            (sent_x, sent_y) = (target_x, target_y);
+            if step < steps {
+                let next = start + Duration::from_millis((STEP_MS * step).into());
+                thread::sleep(next.saturating_duration_since(Instant::now()));
+            }
+        }
+        Ok(())
+    }
+
+    #[frb(sync)]
+    #[tracing::instrument(level = "debug", skip(self))]
     pub fn main_display(&self) -> (i32, i32) {
-        self.enigo.lock().unwrap().main_display().unwrap()
+        self.try_main_display().unwrap()
     }
 
     #[frb(sync)]
+    #[tracing::instrument(level = "debug", skip(self))]
     pub fn location(&self) -> (i32, i32) {
-        self.enigo.lock().unwrap().location().unwrap()
+        self.try_location().unwrap()
+    }
+
+    #[frb(sync)]
+    #[tracing::instrument(level = "debug", skip(self, text), fields(len = text.len()))]
+    pub fn text(&mut self, text: &str) -> Result<(), PolicyViolation> {
+        let action = MockAction::Text {
+            text: text.to_owned(),
+        };
+        let text = text.to_owned();
+        let result = self.worker.run_paced("text", &[action], move |state| {
+            state.permit(&MockAction::Text { text: text.clone() })?;
+            let result = state.call(|backend| backend.text(&text));
+            state.remote_policy(result)
+        });
+        result?.unwrap();
+        Ok(())
     }
 
     #[frb(sync)]
-    pub fn text(&mut self, text: &str) {
-        self.enigo.lock().unwrap().text(text).unwrap();
+    #[tracing::instrument(level = "debug", skip(self, text), fields(len = text.len()))]
+    pub fn text_with_options(
+        &mut self,
+        text: &str,
+        options: TextOptions,
+    ) -> Result<(), PolicyViolation> {
+        // Caps Lock is only known on the worker; see `State::type_text`.
+        let action = MockAction::Text {
+            text: text.to_owned(),
+        };
+        let text = text.to_owned();
+        let result = self
+            .worker
+            .run_paced("text_with_options", &[action], move |state| {
+                state.type_text(&text, options.normalize_lock_state)
+            });
+        result?.unwrap();
+        Ok(())
+    }
+
+    #[frb(sync)]
+    #[tracing::instrument(level = "debug", skip(self))]
+    pub fn key(&mut self, key: Key, direction: Direction) -> Result<(), PolicyViolation> {
+        self.send_key(key, direction)?.unwrap();
+        Ok(())
+    }
+
+    /// Hold `key` down for `duration_ms`, optionally emitting auto-repeat
+    /// presses the way a physically held key would.
+    ///
+    /// Fails with the [`PolicyViolation`] if the policy refuses a press, and
+    /// before pressing anything if `repeat` is [`RepeatPolicy::System`] and
+    /// the system's repeat settings can't be read.
+    ///
+    /// Not `sync`: the hold is timed on a Rust worker thread instead of
+    /// blocking the Dart isolate.
+    #[tracing::instrument(level = "debug", skip(self))]
+    pub fn hold_key(&self, key: Key, duration_ms: u32, repeat: RepeatPolicy) -> anyhow::Result<()> {
+        let repeat = match repeat {
+            RepeatPolicy::Silent => None,
+            RepeatPolicy::System => {
+                let result = system::imp::repeat_info(self.x11_display().as_deref());
+                let info = result.map_err(|error| {
+                    anyhow::anyhow!("couldn't read the key repeat settings: {error}")
+                })?;
+                Some(info)
+            }
+            RepeatPolicy::Custom {
+                delay_ms,
+                interval_ms,
+            } => Some(RepeatInfo {
+                delay: delay_ms,
+                interval: interval_ms,
+            }),
+        };
+
+        let start = Instant::now();
+        let end = start + Duration::from_millis(duration_ms.into());
+        self.send_key(key, Direction::Press)?.unwrap();
+
+        let mut result = Ok(Ok(()));
+        if let Some(repeat) = repeat {
+            let interval = Duration::from_millis(repeat.interval.max(1).into());
+            let mut next = start + Duration::from_millis(repeat.delay.into());
+            while matches!(result, Ok(Ok(()))) && next < end {
+                thread::sleep(next.saturating_duration_since(Instant::now()));
+                result = self.send_key(key, Direction::Press);
+                next += interval;
+            }
+        }
+        if matches!(result, Ok(Ok(()))) {
+            thread::sleep(end.saturating_duration_since(Instant::now()));
+        }
+
+        // Releases are never refused.
+        let release = self.send_key(key, Direction::Release);
+        result?.and(release?).unwrap();
+        Ok(())
     }
 
     #[frb(sync)]
-    pub fn key(&mut self, key: Key, direction: Direction) {
-        self.enigo.lock().unwrap().key(key, direction).unwrap();
+    #[tracing::instrument(level = "debug", skip(self))]
+    pub fn raw(&mut self, keycode: u16, direction: Direction) -> Result<(), PolicyViolation> {
+        let action = MockAction::Raw { keycode, direction };
+        let result = self.worker.run_paced("raw", &[action], move |state| {
+            state.permit(&MockAction::Raw { keycode, direction })?;
+            let result = state.call(|backend| backend.raw(keycode, direction));
+            if result.is_ok() {
+                state.held.track_raw(keycode, direction);
+            }
+            state.remote_policy(result)
+        });
+        result?.unwrap();
+        Ok(())
+    }
+
+    /// Replay a [`Recording`] with its original timing, adjusted by
+    /// `options`.
+    #[tracing::instrument(level = "debug", skip(self, recording), fields(events = recording.events.len()))]
+    pub fn play(
+        &self,
+        recording: &Recording,
+        options: PlaybackOptions,
+    ) -> Result<(), PolicyViolation> {
+        let speed = if options.speed > 0.0 {
+            options.speed
+        } else {
+            1.0
+        };
+        for _ in 0..options.loops.max(1) {
+            let start = Instant::now();
+            let mut elapsed_ms = 0.0;
+            let mut previous_offset = 0;
+            for event in &recording.events {
+                let mut gap = event.offset_ms.saturating_sub(previous_offset);
+                if let Some(max_gap_ms) = options.max_gap_ms {
+                    gap = gap.min(max_gap_ms.into());
+                }
+                previous_offset = event.offset_ms;
+                elapsed_ms += gap as f64 / speed;
+                let due = start + Duration::from_secs_f64(elapsed_ms / 1000.0);
+                thread::sleep(due.saturating_duration_since(Instant::now()));
+
+                let action = MockAction::from(&event.action);
+                let paced = [action.clone()];
+                let result = self.worker.run_paced("play", &paced, move |state| {
+                    state.permit(&action)?;
+                    let result = state.send_action(&action);
+                    state.remote_policy(result)
+                });
+                result?.unwrap();
+            }
+        }
+        Ok(())
     }
 
+    /// Whether each lock key is on, as the backend sees it: the system's
+    /// for local backends, the server's for a remote one and the simulated
+    /// one for a mock.
     #[frb(sync)]
-    pub fn raw(&mut self, keycode: u16, direction: Direction) {
-        self.enigo.lock().unwrap().raw(keycode, direction).unwrap()
+    pub fn lock_state(&self) -> anyhow::Result<LockState> {
+        self.worker
+            .run(|state| state.backend.lock_state(&state.settings))
+    }
+
+    /// Which modifiers are held, as the backend sees it; see
+    /// [`Enigo::lock_state`].
+    #[frb(sync)]
+    pub fn modifier_state(&self) -> anyhow::Result<ModifierState> {
+        self.worker
+            .run(|state| state.backend.modifier_state(&state.settings))
+    }
+
+    /// Keys, buttons and raw keycodes pressed through this instance and not
+    /// released yet.
+    #[frb(sync)]
+    pub fn held(&self) -> HeldInput {
+        self.worker.run(|state| state.held.clone())
+    }
+
+    /// Release everything reported by [`Enigo::held`], most recent first.
+    #[frb(sync)]
+    #[tracing::instrument(level = "debug", skip(self))]
+    pub fn release_all(&mut self) {
+        self.worker.run(State::release_all);
+    }
+
+    #[frb(ignore)]
+    pub(crate) fn try_main_display(&self) -> InputResult<(i32, i32)> {
+        self.worker.run_op("main_display", |state| {
+            state.call(|backend| backend.main_display())
+        })
+    }
+
+    #[frb(ignore)]
+    pub(crate) fn try_location(&self) -> InputResult<(i32, i32)> {
+        self.worker
+            .run_op("location", |state| state.call(|backend| backend.location()))
+    }
+
+    /// Send `action` through this instance's policy, pacer and backend,
+    /// returning the backend's error instead of panicking on it.
+    #[cfg(feature = "server")]
+    #[frb(ignore)]
+    pub(crate) fn send_action(
+        &self,
+        action: MockAction,
+    ) -> Result<InputResult<()>, PolicyViolation> {
+        let paced = [action.clone()];
+        self.worker
+            .run_paced(action.operation(), &paced, move |state| {
+                state.permit(&action)?;
+                let result = state.send_action(&action);
+                state.remote_policy(result)
+            })
+    }
+
+    fn send_key(&self, key: Key, direction: Direction) -> Result<InputResult<()>, PolicyViolation> {
+        let action = MockAction::Key { key, direction };
+        self.worker.run_paced("key", &[action], move |state| {
+            state.permit(&MockAction::Key { key, direction })?;
+            let result = state.call(|backend| backend.key(key, direction));
+            if result.is_ok() {
+                state.held.track_key(key, direction);
+            }
+            state.remote_policy(result)
+        })
+    }
+
+    fn x11_display(&self) -> Option<String> {
+        self.worker.run(|state| state.settings.x11_display.clone())
+    }
+}
+
+impl Worker {
+    /// Start the thread and connect the backend on it with `connect`.
+    fn spawn(
+        settings: Settings,
+        preference: Vec<Backend>,
+        connect: impl FnOnce(&Settings, &[Backend]) -> anyhow::Result<(Box<dyn InputBackend>, BackendInfo)>
+            + Send
+            + 'static,
+    ) -> anyhow::Result<Self> {
+        let (jobs, receiver) = mpsc::channel::<Job>();
+        let (connected, connection) = mpsc::sync_channel(1);
+        let metrics = Arc::new(Mutex::new(Registry::default()));
+        let worker_metrics = Arc::clone(&metrics);
+        let pacer = Arc::new(Mutex::new(None));
+        let worker_pacer = Arc::clone(&pacer);
+        let thread = thread::Builder::new()
+            .name("enigo".to_string())
+            .spawn(move || {
+                let (backend, info) = match connect(&settings, &preference) {
+                    Ok(connected) => connected,
+                    Err(error) => {
+                        let _ = connected.send(Err(error));
+                        return;
+                    }
+                };
+                tracing::info!(backend = ?info.backend, display = ?info.display, "connected");
+                let _ = connected.send(Ok(()));
+                let mut state = State {
+                    backend,
+                    info,
+                    preference,
+                    settings,
+                    held: HeldInput::default(),
+                    reconnect_policy: ReconnectPolicy::Never,
+                    reconnect_sink: None,
+                    dry_run: None,
+                    session_id: uuid::Uuid::new_v4().to_string(),
+                    audit: None,
+                    policy: None,
+                    pacer: worker_pacer,
+                    metrics: worker_metrics,
+                    operation: OTHER_OPERATION,
+                };
+                // Ends once the last `Enigo` for this worker is dropped.
+                for job in receiver {
+                    // The caller sees the panic; keep serving the others.
+                    if panic::catch_unwind(AssertUnwindSafe(|| job(&mut state))).is_err() {
+                        state.release_after_panic();
+                    }
+                }
+            })?;
+        connection
+            .recv()
+            .map_err(|_| anyhow::anyhow!("the enigo worker panicked while connecting"))??;
+        Ok(Self {
+            jobs,
+            thread: thread.thread().id(),
+            metrics,
+            pacer,
+        })
+    }
+
+    /// [`Worker::run`] for the `Enigo` method `operation`, timing how long
+    /// the job waited for the worker and how long the whole call took.
+    fn run_op<T: Send + 'static>(
+        &self,
+        operation: &'static str,
+        job: impl FnOnce(&mut State) -> T + Send + 'static,
+    ) -> T {
+        self.run_paced(operation, &[], job)
+    }
+
+    /// [`Worker::run_op`] for a job that sends `actions`, after waiting on
+    /// the caller's thread until the pacer lets them through. The wait
+    /// counts towards the whole call, not towards waiting for the worker.
+    fn run_paced<T: Send + 'static>(
+        &self,
+        operation: &'static str,
+        actions: &[MockAction],
+        job: impl FnOnce(&mut State) -> T + Send + 'static,
+    ) -> T {
+        let start = Instant::now();
+        let wait = match self.pacer().as_mut() {
+            Some(pacer) => actions
+                .iter()
+                .map(|action| pacer.reserve(action))
+                .max()
+                .unwrap_or_default(),
+            None => Duration::ZERO,
+        };
+        thread::sleep(wait);
+        let queued = Instant::now();
+        let result = self.run(move |state| {
+            state
+                .metrics()
+                .observe(operation, Stage::LockWait, queued.elapsed());
+            state.operation = operation;
+            let result = job(state);
+            state.operation = OTHER_OPERATION;
+            result
+        });
+        self.metrics()
+            .observe(operation, Stage::EndToEnd, start.elapsed());
+        result
+    }
+
+    fn metrics(&self) -> MutexGuard<'_, Registry> {
+        self.metrics.lock().unwrap_or_else(PoisonError::into_inner)
+    }
+
+    fn pacer(&self) -> MutexGuard<'_, Option<Pacer>> {
+        self.pacer.lock().unwrap_or_else(PoisonError::into_inner)
+    }
+
+    /// Run `job` on the worker thread and wait for its result.
+    fn run<T: Send + 'static>(&self, job: impl FnOnce(&mut State) -> T + Send + 'static) -> T {
+        self.send(job).recv().expect("the enigo worker panicked")
+    }
+
+    fn send<T: Send + 'static>(
+        &self,
+        job: impl FnOnce(&mut State) -> T + Send + 'static,
+    ) -> Receiver<T> {
+        let (reply, result) = mpsc::sync_channel(1);
+        // Keep the caller's span, so what the job logs belongs to its call.
+        let span = tracing::Span::current();
+        let job: Job = Box::new(move |state| {
+            let _entered = span.enter();
+            let _ = reply.send(job(state));
+        });
+        self.jobs.send(job).expect("the enigo worker stopped");
+        result
+    }
+}
+
+impl State {
+    /// Run `f` on where input goes: the dry run while there is one, else
+    /// the backend, through the audit log if there is one.
+    fn with_input<T>(&mut self, f: impl FnOnce(&mut dyn InputBackend) -> T) -> T {
+        match (&mut self.dry_run, &mut self.audit) {
+            (Some(dry_run), _) => f(dry_run),
+            (None, Some(log)) => f(&mut Audited {
+                backend: &mut *self.backend,
+                log,
+            }),
+            (None, None) => f(&mut *self.backend),
+        }
+    }
+
+    /// What the backend holds, which is not [`State::held`] during a dry run.
+    fn real_held(&mut self) -> &mut HeldInput {
+        match &mut self.dry_run {
+            Some(dry_run) => &mut dry_run.held,
+            None => &mut self.held,
+        }
+    }
+
+    fn trace(&self) -> &[TraceEntry] {
+        self.dry_run
+            .as_ref()
+            .map_or(&[], |dry_run| dry_run.trace.as_slice())
+    }
+
+    fn end_dry_run(&mut self) {
+        if let Some(dry_run) = self.dry_run.take() {
+            self.held = dry_run.held;
+        }
+    }
+
+    fn metrics(&self) -> MutexGuard<'_, Registry> {
+        self.metrics.lock().unwrap_or_else(PoisonError::into_inner)
+    }
+
+    fn pacer(&self) -> MutexGuard<'_, Option<Pacer>> {
+        self.pacer.lock().unwrap_or_else(PoisonError::into_inner)
+    }
+
+    fn is_alive(&self) -> bool {
+        self.backend.is_alive()
+    }
+
+    /// Switch to a backend built from `settings`, pressing what was held on
+    /// the old one again: dropping it may have released everything.
+    fn rebuild(&mut self, settings: Settings, preference: Vec<Backend>) -> anyhow::Result<()> {
+        settings::validate(&settings)?;
+        // The server sends the input with its own settings.
+        if self.info.backend == Backend::Remote {
+            if preference != self.preference {
+                anyhow::bail!("the backend of a remote instance can't be changed");
+            }
+            self.settings = settings;
+            return Ok(());
+        }
+        let (mut backend, info) = backend::connect(&settings, &preference)?;
+        // A mock keeps its display, call log and what it holds.
+        let carried_over = match (self.backend.mock(), backend.mock()) {
+            (Some(old), Some(new)) => {
+                std::mem::swap(old, new);
+                true
+            }
+            _ => false,
+        };
+        self.backend = backend;
+        self.info = info;
+        self.settings = settings;
+        self.preference = preference;
+        if carried_over {
+            return Ok(());
+        }
+        let held = self.real_held().clone();
+        for key in held.keys {
+            let _ = self.backend.key(key, Direction::Press);
+        }
+        for keycode in held.raw {
+            let _ = self.backend.raw(keycode, Direction::Press);
+        }
+        for button in held.buttons {
+            let _ = self.backend.button(button.value, Direction::Press);
+        }
+        Ok(())
+    }
+
+    fn reconnect(&mut self) -> anyhow::Result<()> {
+        match self.backend.reconnect() {
+            Some(result) => result?,
+            None => {
+                let (backend, info) = backend::connect(&self.settings, &self.preference)?;
+                self.backend = backend;
+                self.info = info;
+            }
+        }
+        // Whatever was held went away with the old connection.
+        *self.real_held() = HeldInput::default();
+        Ok(())
+    }
+
+    /// Run `f` against the backend like [`State::call_backend`], logging the
+    /// backend, latency and result.
+    fn call<T>(
+        &mut self,
+        f: impl FnMut(&mut dyn InputBackend) -> InputResult<T>,
+    ) -> InputResult<T> {
+        let start = Instant::now();
+        let result = self.call_backend(f);
+        let latency = start.elapsed();
+        let latency_us = latency.as_micros() as u64;
+        let backend = self.info.backend;
+        let dry_run = self.dry_run.is_some();
+        let mut metrics = self.metrics();
+        metrics.observe(self.operation, Stage::BackendCall, latency);
+        match &result {
+            Ok(_) => {
+                tracing::debug!(?backend, dry_run, latency_us, "ok");
+                metrics.succeeded(self.operation);
+            }
+            Err(error) => {
+                tracing::warn!(?backend, dry_run, latency_us, %error, "failed");
+                metrics.failed(self.operation, metrics::input_error_kind(error));
+            }
+        }
+        result
+    }
+
+    /// Run `f` against the backend and, if it fails because the connection
+    /// is gone, reconnect as the policy allows and run it once more.
+    fn call_backend<T>(
+        &mut self,
+        mut f: impl FnMut(&mut dyn InputBackend) -> InputResult<T>,
+    ) -> InputResult<T> {
+        let error = match self.with_input(&mut f) {
+            Err(error) if self.dry_run.is_none() && !self.is_alive() => error,
+            result => return result,
+        };
+        let ReconnectPolicy::Retry {
+            max_attempts,
+            delay_ms,
+        } = self.reconnect_policy
+        else {
+            return Err(error);
+        };
+        for attempt in 1..=max_attempts {
+            if attempt > 1 {
+                thread::sleep(Duration::from_millis(delay_ms.into()));
+            }
+            if self.reconnect().is_ok() {
+                tracing::info!(backend = ?self.info.backend, attempt, %error, "reconnected");
+                if let Some(sink) = &self.reconnect_sink {
+                    let _ = sink.add(ReconnectEvent {
+                        backend: self.info.backend,
+                        attempts: attempt,
+                        error: error.to_string(),
+                    });
+                }
+                return self.with_input(f);
+            }
+        }
+        Err(error)
+    }
+
+    /// Refuse `action` if the policy doesn't allow it. The caller waited for
+    /// the pacer already, in [`Worker::run_paced`].
+    fn permit(&mut self, action: &MockAction) -> Result<(), PolicyViolation> {
+        if let Some(enforcer) = &mut self.policy {
+            let input: &dyn InputBackend = match &self.dry_run {
+                Some(dry_run) => dry_run,
+                None => &*self.backend,
+            };
+            if let Err(violation) = enforcer.check(action, &self.held, || input.location().ok()) {
+                tracing::warn!(%violation, "refused by the policy");
+                self.metrics().failed(self.operation, "policy");
+                return Err(violation);
+            }
+        }
+        Ok(())
+    }
+
+    /// Report a call refused by the policy of the server a remote backend
+    /// sends to like one refused by [`State::permit`].
+    fn remote_policy<T>(
+        &mut self,
+        result: InputResult<T>,
+    ) -> Result<InputResult<T>, PolicyViolation> {
+        match self.backend.take_violation() {
+            Some(violation) if result.is_err() => {
+                tracing::warn!(%violation, "refused by the server's policy");
+                Err(violation)
+            }
+            _ => Ok(result),
+        }
+    }
+
+    /// Type `text`, switching Caps Lock off around it if `normalize_lock_state`
+    /// and it is on: Caps Lock inverts the case of what enigo types on some
+    /// backends. The policy sees both Caps Lock presses, before anything is
+    /// sent.
+    fn type_text(
+        &mut self,
+        text: &str,
+        normalize_lock_state: bool,
+    ) -> Result<InputResult<()>, PolicyViolation> {
+        let toggle_caps = if normalize_lock_state {
+            match self.backend.lock_state(&self.settings) {
+                Ok(lock_state) => lock_state.caps_lock,
+                Err(error) => {
+                    tracing::warn!(%error, "couldn't read Caps Lock");
+                    return Ok(Err(InputError::Simulate(
+                        "couldn't read the Caps Lock state",
+                    )));
+                }
+            }
+        } else {
+            false
+        };
+        let caps = MockAction::Key {
+            key: Key::CapsLock,
+            direction: Direction::Click,
+        };
+        if toggle_caps {
+            self.permit(&caps)?;
+        }
+        self.permit(&MockAction::Text {
+            text: text.to_owned(),
+        })?;
+        if toggle_caps {
+            self.permit(&caps)?;
+            // Only the text was waited for, so as not to hold up the
+            // worker; the Caps Lock presses still take their tokens.
+            if let Some(pacer) = self.pacer().as_mut() {
+                pacer.reserve(&caps);
+                pacer.reserve(&caps);
+            }
+        }
+
+        let result = (|| {
+            if toggle_caps {
+                self.call(|backend| backend.key(Key::CapsLock, Direction::Click))?;
+            }
+            let result = self.call(|backend| backend.text(text));
+            if toggle_caps {
+                self.call(|backend| backend.key(Key::CapsLock, Direction::Click))?;
+            }
+            result
+        })();
+        self.remote_policy(result)
+    }
+
+    fn send_action(&mut self, action: &MockAction) -> InputResult<()> {
+        match *action {
+            MockAction::Key { key, direction } => {
+                self.call(|backend| backend.key(key, direction))?
+            }
+            MockAction::Raw { keycode, direction } => {
+                self.call(|backend| backend.raw(keycode, direction))?
+            }
+            MockAction::Button { button, direction } => {
+                self.call(|backend| backend.button(button.value, direction))?
+            }
+            MockAction::MoveMouse { x, y, coordinate } => {
+                self.call(|backend| backend.move_mouse(x, y, coordinate))?
+            }
+            MockAction::Scroll { length, axis } => {
+                self.call(|backend| backend.scroll(length, axis))?
+            }
+            MockAction::Text { ref text } => self.call(|backend| backend.text(text))?,
+        }
+        self.held.track(action);
+        Ok(())
+    }
+
+    fn release_all(&mut self) {
+        let held = std::mem::take(&mut self.held);
+        // Best effort: one failing release must not keep the rest held down.
+        self.with_input(|input| {
+            for key in held.keys.into_iter().rev() {
+                let _ = input.key(key, Direction::Release);
+            }
+            for keycode in held.raw.into_iter().rev() {
+                let _ = input.raw(keycode, Direction::Release);
+            }
+            for button in held.buttons.into_iter().rev() {
+                let _ = input.button(button.value, Direction::Release);
+            }
+        });
+    }
+
+    /// [`State::release_all`] on the real backend, leaving dry-run mode:
+    /// what a dry run holds was never pressed.
+    fn release_after_panic(&mut self) {
+        self.end_dry_run();
+        self.release_all();
+    }
+}
+
+/// How [`Enigo::hold_key`] emits auto-repeat while the key is held.
+#[derive(Debug)]
+pub enum RepeatPolicy {
+    /// Press once and stay silent until the release.
+    Silent,
+    /// Repeat at the delay and rate configured in the system settings.
+    System,
+    Custom {
+        delay_ms: u32,
+        interval_ms: u32,
+    },
+}
+
+/// What [`Enigo`] does when its connection goes away.
+#[derive(Debug, Clone, Copy)]
+pub enum ReconnectPolicy {
+    /// Fail the call.
+    Never,
+    /// Recreate the backend, up to `max_attempts` times with `delay_ms`
+    /// between attempts, and repeat the call once it is back.
+    Retry { max_attempts: u32, delay_ms: u32 },
+}
+
+/// Sent to [`Enigo::reconnects`] after a successful reconnect.
+#[derive(Debug, Clone)]
+pub struct ReconnectEvent {
+    /// The backend now in use, which may differ from the one before.
+    pub backend: Backend,
+    pub attempts: u32,
+    /// Why the call that triggered the reconnect failed.
+    pub error: String,
+}
+
+/// Changes for [`Enigo::update_settings`]; `None` leaves a setting as it is.
+///
+/// Settings files have the same shape.
+#[derive(Debug, Clone, Default, Deserialize)]
+#[serde(default, deny_unknown_fields)]
+pub struct SettingsPatch {
+    /// Backends in order of preference, as for [`Enigo::with_backends`].
+    /// Not part of enigo's `Settings`, so it only takes effect through
+    /// [`Enigo::update_settings`].
+    pub backend: Option<Vec<Backend>>,
+    pub linux_delay: Option<u32>,
+    /// An empty name goes back to the default display.
+    pub x11_display: Option<String>,
+    /// An empty name goes back to the default display.
+    pub wayland_display: Option<String>,
+    pub windows_dw_extra_info: Option<usize>,
+    pub event_source_user_data: Option<i64>,
+    pub release_keys_when_dropped: Option<bool>,
+    pub open_prompt_to_get_permissions: Option<bool>,
+    pub independent_of_keyboard_state: Option<bool>,
+    pub windows_subject_to_mouse_speed_and_acceleration_level: Option<bool>,
+}
+
+impl SettingsPatch {
+    pub(crate) fn apply(self, settings: &mut Settings) {
+        let display = |name: String| (!name.is_empty()).then_some(name);
+        if let Some(linux_delay) = self.linux_delay {
+            settings.linux_delay = linux_delay;
+        }
+        if let Some(x11_display) = self.x11_display {
+            settings.x11_display = display(x11_display);
+        }
+        if let Some(wayland_display) = self.wayland_display {
+            settings.wayland_display = display(wayland_display);
+        }
+        if let Some(extra_info) = self.windows_dw_extra_info {
+            settings.windows_dw_extra_info = Some(extra_info);
+        }
+        if let Some(user_data) = self.event_source_user_data {
+            settings.event_source_user_data = Some(user_data);
+        }
+        if let Some(release) = self.release_keys_when_dropped {
+            settings.release_keys_when_dropped = release;
+        }
+        if let Some(open_prompt) = self.open_prompt_to_get_permissions {
+            settings.open_prompt_to_get_permissions = open_prompt;
+        }
+        if let Some(independent) = self.independent_of_keyboard_state {
+            settings.independent_of_keyboard_state = independent;
+        }
+        if let Some(subject) = self.windows_subject_to_mouse_speed_and_acceleration_level {
+            settings.windows_subject_to_mouse_speed_and_acceleration_level = subject;
+        }
+    }
+}
+
+/// Options for [`Enigo::text_with_options`].
+#[derive(Debug, Clone, Copy, Default)]
+pub struct TextOptions {
+    /// Switch Caps Lock off while typing and restore it afterwards.
+    pub normalize_lock_state: bool,
+}
+
+/// Input currently held down by an [`Enigo`], in press order.
+#[derive(Clone, Default)]
+pub struct HeldInput {
+    pub keys: Vec<Key>,
+    pub buttons: Vec<_Button>,
+    pub raw: Vec<u16>,
+}
+
+impl HeldInput {
+    #[frb(sync, getter)]
+    pub fn is_empty(&self) -> bool {
+        self.keys.is_empty() && self.buttons.is_empty() && self.raw.is_empty()
+    }
+
+    fn track_key(&mut self, key: Key, direction: Direction) {
+        track(&mut self.keys, key, direction);
+    }
+
+    fn track_button(&mut self, button: enigo::Button, direction: Direction) {
+        track(&mut self.buttons, _Button { value: button }, direction);
+    }
+
+    fn track_raw(&mut self, keycode: u16, direction: Direction) {
+        track(&mut self.raw, keycode, direction);
+    }
+
+    /// Note what `action` pressed or released; other actions change nothing.
+    pub(crate) fn track(&mut self, action: &MockAction) {
+        match *action {
+            MockAction::Key { key, direction } => self.track_key(key, direction),
+            MockAction::Raw { keycode, direction } => self.track_raw(keycode, direction),
+            MockAction::Button { button, direction } => self.track_button(button.value, direction),
+            MockAction::MoveMouse { .. } | MockAction::Scroll { .. } | MockAction::Text { .. } => {}
+        }
+    }
+
+    /// Releases for everything held, most recent first.
+    #[cfg(feature = "server")]
+    pub(crate) fn releases(&self) -> Vec<MockAction> {
+        let direction = Direction::Release;
+        let keys = self
+            .keys
+            .iter()
+            .rev()
+            .map(|&key| MockAction::Key { key, direction });
+        let raw = self
+            .raw
+            .iter()
+            .rev()
+            .map(|&keycode| MockAction::Raw { keycode, direction });
+        let buttons = self
+            .buttons
+            .iter()
+            .rev()
+            .map(|&button| MockAction::Button { button, direction });
+        keys.chain(raw).chain(buttons).collect()
+    }
+}
+
+fn track<T: PartialEq>(held: &mut Vec<T>, value: T, direction: Direction) {
+    match direction {
+        Direction::Press => {
+            if !held.contains(&value) {
+                held.push(value);
+            }
+        }
+        Direction::Release => held.retain(|v| *v != value),
+        Direction::Click => {}
     }
 }
 
+/// Release everything held by every live `Enigo` before a panic unwinds back
+/// across the FFI boundary.
+fn install_release_on_panic_hook() {
+    const RELEASE_TIMEOUT: Duration = Duration::from_secs(1);
+
+    let previous = panic::take_hook();
+    panic::set_hook(Box::new(move |info| {
+        if let Ok(instances) = INSTANCES.try_lock() {
+            for worker in instances.iter().filter_map(Weak::upgrade) {
+                let released = worker.send(State::release_after_panic);
+                // A worker that panicked itself releases once the job unwinds
+                // and can't be waited for here.
+                if thread::current().id() != worker.thread {
+                    let _ = released.recv_timeout(RELEASE_TIMEOUT);
+                }
+            }
+        }
+        previous(info);
+    }));
+}
+
 #[frb(mirror(Direction))]
 pub enum _Direction {
     Press,
@@ -155,6 +1481,7 @@ pub enum _Axis {
 }
 
 #[frb(name = "Button")]
+#[derive(Debug, Clone, Copy, PartialEq)]
 pub struct _Button {
     #[frb(ignore)]
     pub(crate) value: enigo::Button,
@@ -969,480 +2296,674 @@ pub fn decode_physical_keyboard_key_type(raw: u32) -> Key {
         0x00070004 => Key::Other(0),
         #[cfg(target_os = "windows")]
         0x00070004 => Key::Other(65),
+        #[cfg(target_os = "linux")]
+        0x00070004 => Key::Other(0x61),
         // KeyB
         #[cfg(target_os = "macos")]
         0x00070005 => Key::Other(11),
         #[cfg(target_os = "windows")]
         0x00070005 => Key::Other(66),
+        #[cfg(target_os = "linux")]
+        0x00070005 => Key::Other(0x62),
         // KeyC
         #[cfg(target_os = "macos")]
         0x00070006 => Key::Other(8),
         #[cfg(target_os = "windows")]
         0x00070006 => Key::Other(67),
+        #[cfg(target_os = "linux")]
+        0x00070006 => Key::Other(0x63),
         // KeyD
         #[cfg(target_os = "macos")]
         0x00070007 => Key::Other(2),
         #[cfg(target_os = "windows")]
         0x00070007 => Key::Other(68),
+        #[cfg(target_os = "linux")]
+        0x00070007 => Key::Other(0x64),
         // KeyE
         #[cfg(target_os = "macos")]
         0x00070008 => Key::Other(14),
         #[cfg(target_os = "windows")]
         0x00070008 => Key::Other(69),
+        #[cfg(target_os = "linux")]
+        0x00070008 => Key::Other(0x65),
         // KeyF
         #[cfg(target_os = "macos")]
         0x00070009 => Key::Other(3),
         #[cfg(target_os = "windows")]
         0x00070009 => Key::Other(70),
+        #[cfg(target_os = "linux")]
+        0x00070009 => Key::Other(0x66),
         // KeyG
         #[cfg(target_os = "macos")]
         0x0007000a => Key::Other(5),
         #[cfg(target_os = "windows")]
         0x0007000a => Key::Other(71),
+        #[cfg(target_os = "linux")]
+        0x0007000a => Key::Other(0x67),
         // KeyH
         #[cfg(target_os = "macos")]
         0x0007000b => Key::Other(4),
         #[cfg(target_os = "windows")]
         0x0007000b => Key::Other(72),
+        #[cfg(target_os = "linux")]
+        0x0007000b => Key::Other(0x68),
         // KeyI
         #[cfg(target_os = "macos")]
         0x0007000c => Key::Other(34),
         #[cfg(target_os = "windows")]
         0x0007000c => Key::Other(73),
+        #[cfg(target_os = "linux")]
+        0x0007000c => Key::Other(0x69),
         // KeyJ
         #[cfg(target_os = "macos")]
         0x0007000d => Key::Other(38),
         #[cfg(target_os = "windows")]
         0x0007000d => Key::Other(74),
+        #[cfg(target_os = "linux")]
+        0x0007000d => Key::Other(0x6a),
         // KeyK
         #[cfg(target_os = "macos")]
         0x0007000e => Key::Other(40),
         #[cfg(target_os = "windows")]
         0x0007000e => Key::Other(75),
+        #[cfg(target_os = "linux")]
+        0x0007000e => Key::Other(0x6b),
         // KeyL
         #[cfg(target_os = "macos")]
         0x0007000f => Key::Other(37),
         #[cfg(target_os = "windows")]
         0x0007000f => Key::Other(76),
+        #[cfg(target_os = "linux")]
+        0x0007000f => Key::Other(0x6c),
         // KeyM
         #[cfg(target_os = "macos")]
         0x00070010 => Key::Other(46),
         #[cfg(target_os = "windows")]
         0x00070010 => Key::Other(77),
+        #[cfg(target_os = "linux")]
+        0x00070010 => Key::Other(0x6d),
         // KeyN
         #[cfg(target_os = "macos")]
         0x00070011 => Key::Other(45),
         #[cfg(target_os = "windows")]
         0x00070011 => Key::Other(78),
+        #[cfg(target_os = "linux")]
+        0x00070011 => Key::Other(0x6e),
         // KeyO
         #[cfg(target_os = "macos")]
         0x00070012 => Key::Other(31),
         #[cfg(target_os = "windows")]
         0x00070012 => Key::Other(79),
+        #[cfg(target_os = "linux")]
+        0x00070012 => Key::Other(0x6f),
         // KeyP
         #[cfg(target_os = "macos")]
         0x00070013 => Key::Other(35),
         #[cfg(target_os = "windows")]
         0x00070013 => Key::Other(80),
+        #[cfg(target_os = "linux")]
+        0x00070013 => Key::Other(0x70),
         // KeyQ
         #[cfg(target_os = "macos")]
         0x00070014 => Key::Other(12),
         #[cfg(target_os = "windows")]
         0x00070014 => Key::Other(81),
+        #[cfg(target_os = "linux")]
+        0x00070014 => Key::Other(0x71),
         // KeyR
         #[cfg(target_os = "macos")]
         0x00070015 => Key::Other(15),
         #[cfg(target_os = "windows")]
         0x00070015 => Key::Other(82),
+        #[cfg(target_os = "linux")]
+        0x00070015 => Key::Other(0x72),
         // KeyS
         #[cfg(target_os = "macos")]
         0x00070016 => Key::Other(1),
         #[cfg(target_os = "windows")]
         0x00070016 => Key::Other(83),
+        #[cfg(target_os = "linux")]
+        0x00070016 => Key::Other(0x73),
         // KeyT
         #[cfg(target_os = "macos")]
         0x00070017 => Key::Other(17),
         #[cfg(target_os = "windows")]
         0x00070017 => Key::Other(84),
+        #[cfg(target_os = "linux")]
+        0x00070017 => Key::Other(0x74),
         // KeyU
         #[cfg(target_os = "macos")]
         0x00070018 => Key::Other(32),
         #[cfg(target_os = "windows")]
         0x00070018 => Key::Other(85),
+        #[cfg(target_os = "linux")]
+        0x00070018 => Key::Other(0x75),
         // KeyV
         #[cfg(target_os = "macos")]
         0x00070019 => Key::Other(9),
         #[cfg(target_os = "windows")]
         0x00070019 => Key::Other(86),
+        #[cfg(target_os = "linux")]
+        0x00070019 => Key::Other(0x76),
         // KeyW
         #[cfg(target_os = "macos")]
         0x0007001a => Key::Other(13),
         #[cfg(target_os = "windows")]
         0x0007001a => Key::Other(87),
+        #[cfg(target_os = "linux")]
+        0x0007001a => Key::Other(0x77),
         // KeyX
         #[cfg(target_os = "macos")]
         0x0007001b => Key::Other(7),
         #[cfg(target_os = "windows")]
         0x0007001b => Key::Other(88),
+        #[cfg(target_os = "linux")]
+        0x0007001b => Key::Other(0x78),
         // KeyY
         #[cfg(target_os = "macos")]
         0x0007001c => Key::Other(16),
         #[cfg(target_os = "windows")]
         0x0007001c => Key::Other(89),
+        #[cfg(target_os = "linux")]
+        0x0007001c => Key::Other(0x79),
         // KeyZ
         #[cfg(target_os = "macos")]
         0x0007001d => Key::Other(6),
         #[cfg(target_os = "windows")]
         0x0007001d => Key::Other(90),
+        #[cfg(target_os = "linux")]
+        0x0007001d => Key::Other(0x7a),
         // Digit1
         #[cfg(target_os = "macos")]
         0x0007001e => Key::Other(18),
         #[cfg(target_os = "windows")]
         0x0007001e => Key::Other(49),
+        #[cfg(target_os = "linux")]
+        0x0007001e => Key::Other(0x31),
         // Digit2
         #[cfg(target_os = "macos")]
         0x0007001f => Key::Other(19),
         #[cfg(target_os = "windows")]
         0x0007001f => Key::Other(50),
+        #[cfg(target_os = "linux")]
+        0x0007001f => Key::Other(0x32),
         // Digit3
         #[cfg(target_os = "macos")]
         0x00070020 => Key::Other(20),
         #[cfg(target_os = "windows")]
         0x00070020 => Key::Other(51),
+        #[cfg(target_os = "linux")]
+        0x00070020 => Key::Other(0x33),
         // Digit4
         #[cfg(target_os = "macos")]
         0x00070021 => Key::Other(21),
         #[cfg(target_os = "windows")]
         0x00070021 => Key::Other(52),
+        #[cfg(target_os = "linux")]
+        0x00070021 => Key::Other(0x34),
         // Digit5
         #[cfg(target_os = "macos")]
         0x00070022 => Key::Other(23),
         #[cfg(target_os = "windows")]
         0x00070022 => Key::Other(53),
+        #[cfg(target_os = "linux")]
+        0x00070022 => Key::Other(0x35),
         // Digit6
         #[cfg(target_os = "macos")]
         0x00070023 => Key::Other(22),
         #[cfg(target_os = "windows")]
         0x00070023 => Key::Other(54),
+        #[cfg(target_os = "linux")]
+        0x00070023 => Key::Other(0x36),
         // Digit7
         #[cfg(target_os = "macos")]
         0x00070024 => Key::Other(26),
         #[cfg(target_os = "windows")]
         0x00070024 => Key::Other(55),
+        #[cfg(target_os = "linux")]
+        0x00070024 => Key::Other(0x37),
         // Digit8
         #[cfg(target_os = "macos")]
         0x00070025 => Key::Other(28),
         #[cfg(target_os = "windows")]
         0x00070025 => Key::Other(56),
+        #[cfg(target_os = "linux")]
+        0x00070025 => Key::Other(0x38),
         // Digit9
         #[cfg(target_os = "macos")]
         0x00070026 => Key::Other(25),
         #[cfg(target_os = "windows")]
         0x00070026 => Key::Other(57),
+        #[cfg(target_os = "linux")]
+        0x00070026 => Key::Other(0x39),
         // Digit0
         #[cfg(target_os = "macos")]
         0x00070027 => Key::Other(29),
         #[cfg(target_os = "windows")]
         0x00070027 => Key::Other(48),
+        #[cfg(target_os = "linux")]
+        0x00070027 => Key::Other(0x30),
         // Enter
         #[cfg(target_os = "macos")]
         0x00070028 => Key::Other(36),
         #[cfg(target_os = "windows")]
         0x00070028 => Key::Other(13),
+        #[cfg(target_os = "linux")]
+        0x00070028 => Key::Other(0xff0d),
         // Escape
         #[cfg(target_os = "macos")]
         0x00070029 => Key::Other(53),
         #[cfg(target_os = "windows")]
         0x00070029 => Key::Other(27),
+        #[cfg(target_os = "linux")]
+        0x00070029 => Key::Other(0xff1b),
         // Backspace
         #[cfg(target_os = "macos")]
         0x0007002a => Key::Other(51),
         #[cfg(target_os = "windows")]
         0x0007002a => Key::Other(8),
+        #[cfg(target_os = "linux")]
+        0x0007002a => Key::Other(0xff08),
         // Tab
         #[cfg(target_os = "macos")]
         0x0007002b => Key::Other(48),
         #[cfg(target_os = "windows")]
         0x0007002b => Key::Other(9),
+        #[cfg(target_os = "linux")]
+        0x0007002b => Key::Other(0xff09),
         // Space
         #[cfg(target_os = "macos")]
         0x0007002c => Key::Other(49),
         #[cfg(target_os = "windows")]
         0x0007002c => Key::Other(32),
+        #[cfg(target_os = "linux")]
+        0x0007002c => Key::Other(0x20),
         // Minus
         #[cfg(target_os = "macos")]
         0x0007002d => Key::Other(27),
         #[cfg(target_os = "windows")]
         0x0007002d => Key::Other(189),
+        #[cfg(target_os = "linux")]
+        0x0007002d => Key::Other(0x2d),
         // Equal
         #[cfg(target_os = "macos")]
         0x0007002e => Key::Other(24),
         #[cfg(target_os = "windows")]
         0x0007002e => Key::Other(187),
+        #[cfg(target_os = "linux")]
+        0x0007002e => Key::Other(0x3d),
         // BracketLeft
         #[cfg(target_os = "macos")]
         0x0007002f => Key::Other(33),
         #[cfg(target_os = "windows")]
         0x0007002f => Key::Other(219),
+        #[cfg(target_os = "linux")]
+        0x0007002f => Key::Other(0x5b),
         // BracketRight
         #[cfg(target_os = "macos")]
         0x00070030 => Key::Other(30),
         #[cfg(target_os = "windows")]
         0x00070030 => Key::Other(221),
+        #[cfg(target_os = "linux")]
+        0x00070030 => Key::Other(0x5d),
         // Backslash
         #[cfg(target_os = "macos")]
         0x00070031 => Key::Other(42),
         #[cfg(target_os = "windows")]
         0x00070031 => Key::Other(220),
+        #[cfg(target_os = "linux")]
+        0x00070031 => Key::Other(0x5c),
         // Semicolon
         #[cfg(target_os = "macos")]
         0x00070033 => Key::Other(41),
         #[cfg(target_os = "windows")]
         0x00070033 => Key::Other(186),
+        #[cfg(target_os = "linux")]
+        0x00070033 => Key::Other(0x3b),
         // Quote
         #[cfg(target_os = "macos")]
         0x00070034 => Key::Other(39),
         #[cfg(target_os = "windows")]
         0x00070034 => Key::Other(222),
+        #[cfg(target_os = "linux")]
+        0x00070034 => Key::Other(0x27),
         // Backquote
         #[cfg(target_os = "macos")]
         0x00070035 => Key::Other(50),
         #[cfg(target_os = "windows")]
         0x00070035 => Key::Other(192),
+        #[cfg(target_os = "linux")]
+        0x00070035 => Key::Other(0x60),
         // Comma
         #[cfg(target_os = "macos")]
         0x00070036 => Key::Other(43),
         #[cfg(target_os = "windows")]
         0x00070036 => Key::Other(188),
+        #[cfg(target_os = "linux")]
+        0x00070036 => Key::Other(0x2c),
         // Period
         #[cfg(target_os = "macos")]
         0x00070037 => Key::Other(47),
         #[cfg(target_os = "windows")]
         0x00070037 => Key::Other(190),
+        #[cfg(target_os = "linux")]
+        0x00070037 => Key::Other(0x2e),
         // Slash
         #[cfg(target_os = "macos")]
         0x00070038 => Key::Other(44),
         #[cfg(target_os = "windows")]
         0x00070038 => Key::Other(191),
+        #[cfg(target_os = "linux")]
+        0x00070038 => Key::Other(0x2f),
         // CapsLock
         #[cfg(target_os = "macos")]
         0x00070039 => Key::Other(57),
         #[cfg(target_os = "windows")]
         0x00070039 => Key::Other(20),
+        #[cfg(target_os = "linux")]
+        0x00070039 => Key::Other(0xffe5),
         // F1
         #[cfg(target_os = "macos")]
         0x0007003a => Key::Other(122),
         #[cfg(target_os = "windows")]
         0x0007003a => Key::Other(112),
+        #[cfg(target_os = "linux")]
+        0x0007003a => Key::Other(0xffbe),
         // F2
         #[cfg(target_os = "macos")]
         0x0007003b => Key::Other(120),
         #[cfg(target_os = "windows")]
         0x0007003b => Key::Other(113),
+        #[cfg(target_os = "linux")]
+        0x0007003b => Key::Other(0xffbf),
         // F3
         #[cfg(target_os = "macos")]
         0x0007003c => Key::Other(99),
         #[cfg(target_os = "windows")]
         0x0007003c => Key::Other(114),
+        #[cfg(target_os = "linux")]
+        0x0007003c => Key::Other(0xffc0),
         // F4
         #[cfg(target_os = "macos")]
         0x0007003d => Key::Other(118),
         #[cfg(target_os = "windows")]
         0x0007003d => Key::Other(115),
+        #[cfg(target_os = "linux")]
+        0x0007003d => Key::Other(0xffc1),
         // F5
         #[cfg(target_os = "macos")]
         0x0007003e => Key::Other(96),
         #[cfg(target_os = "windows")]
         0x0007003e => Key::Other(116),
+        #[cfg(target_os = "linux")]
+        0x0007003e => Key::Other(0xffc2),
         // F6
         #[cfg(target_os = "macos")]
         0x0007003f => Key::Other(97),
         #[cfg(target_os = "windows")]
         0x0007003f => Key::Other(117),
+        #[cfg(target_os = "linux")]
+        0x0007003f => Key::Other(0xffc3),
         // F7
         #[cfg(target_os = "macos")]
         0x00070040 => Key::Other(98),
         #[cfg(target_os = "windows")]
         0x00070040 => Key::Other(118),
+        #[cfg(target_os = "linux")]
+        0x00070040 => Key::Other(0xffc4),
         // F8
         #[cfg(target_os = "macos")]
         0x00070041 => Key::Other(100),
         #[cfg(target_os = "windows")]
         0x00070041 => Key::Other(119),
+        #[cfg(target_os = "linux")]
+        0x00070041 => Key::Other(0xffc5),
         // F9
         #[cfg(target_os = "macos")]
         0x00070042 => Key::Other(101),
         #[cfg(target_os = "windows")]
         0x00070042 => Key::Other(120),
+        #[cfg(target_os = "linux")]
+        0x00070042 => Key::Other(0xffc6),
         // F10
         #[cfg(target_os = "macos")]
         0x00070043 => Key::Other(109),
         #[cfg(target_os = "windows")]
         0x00070043 => Key::Other(121),
+        #[cfg(target_os = "linux")]
+        0x00070043 => Key::Other(0xffc7),
         // F11
         #[cfg(target_os = "macos")]
         0x00070044 => Key::Other(103),
         #[cfg(target_os = "windows")]
         0x00070044 => Key::Other(122),
+        #[cfg(target_os = "linux")]
+        0x00070044 => Key::Other(0xffc8),
         // F12
         #[cfg(target_os = "macos")]
         0x00070045 => Key::Other(111),
         #[cfg(target_os = "windows")]
         0x00070045 => Key::Other(123),
+        #[cfg(target_os = "linux")]
+        0x00070045 => Key::Other(0xffc9),
         // PrintScreen
         #[cfg(target_os = "windows")]
         0x00070046 => Key::Other(44),
+        #[cfg(target_os = "linux")]
+        0x00070046 => Key::Other(0xff61),
         // ScrollLock
         #[cfg(target_os = "windows")]
         0x00070047 => Key::Other(3),
+        #[cfg(target_os = "linux")]
+        0x00070047 => Key::Other(0xff14),
         // Pause
         #[cfg(target_os = "windows")]
         0x00070048 => Key::Other(19),
+        #[cfg(target_os = "linux")]
+        0x00070048 => Key::Other(0xff13),
         // Insert
         #[cfg(target_os = "macos")]
         0x00070049 => Key::Other(114),
         #[cfg(target_os = "windows")]
         0x00070049 => Key::Other(45),
+        #[cfg(target_os = "linux")]
+        0x00070049 => Key::Other(0xff63),
         // Home
         #[cfg(target_os = "macos")]
         0x0007004a => Key::Other(115),
         #[cfg(target_os = "windows")]
         0x0007004a => Key::Other(36),
+        #[cfg(target_os = "linux")]
+        0x0007004a => Key::Other(0xff50),
         // PageUp
         #[cfg(target_os = "macos")]
         0x0007004b => Key::Other(116),
         #[cfg(target_os = "windows")]
         0x0007004b => Key::Other(33),
+        #[cfg(target_os = "linux")]
+        0x0007004b => Key::Other(0xff55),
         // Delete
         #[cfg(target_os = "macos")]
         0x0007004c => Key::Other(117),
         #[cfg(target_os = "windows")]
         0x0007004c => Key::Other(46),
+        #[cfg(target_os = "linux")]
+        0x0007004c => Key::Other(0xffff),
         // End
         #[cfg(target_os = "macos")]
         0x0007004d => Key::Other(119),
         #[cfg(target_os = "windows")]
         0x0007004d => Key::Other(35),
+        #[cfg(target_os = "linux")]
+        0x0007004d => Key::Other(0xff57),
         // PageDown
         #[cfg(target_os = "macos")]
         0x0007004e => Key::Other(121),
         #[cfg(target_os = "windows")]
         0x0007004e => Key::Other(34),
+        #[cfg(target_os = "linux")]
+        0x0007004e => Key::Other(0xff56),
         // ArrowRight
         #[cfg(target_os = "macos")]
         0x0007004f => Key::Other(124),
         #[cfg(target_os = "windows")]
         0x0007004f => Key::Other(39),
+        #[cfg(target_os = "linux")]
+        0x0007004f => Key::Other(0xff53),
         // ArrowLeft
         #[cfg(target_os = "macos")]
         0x00070050 => Key::Other(123),
         #[cfg(target_os = "windows")]
         0x00070050 => Key::Other(37),
+        #[cfg(target_os = "linux")]
+        0x00070050 => Key::Other(0xff51),
         // ArrowDown
         #[cfg(target_os = "macos")]
         0x00070051 => Key::Other(125),
         #[cfg(target_os = "windows")]
         0x00070051 => Key::Other(40),
+        #[cfg(target_os = "linux")]
+        0x00070051 => Key::Other(0xff54),
         // ArrowUp
         #[cfg(target_os = "macos")]
         0x00070052 => Key::Other(126),
         #[cfg(target_os = "windows")]
         0x00070052 => Key::Other(38),
+        #[cfg(target_os = "linux")]
+        0x00070052 => Key::Other(0xff52),
         // NumLock
         #[cfg(target_os = "macos")]
         0x00070053 => Key::Other(71),
         #[cfg(target_os = "windows")]
         0x00070053 => Key::Other(144),
+        #[cfg(target_os = "linux")]
+        0x00070053 => Key::Other(0xff7f),
         // NumpadDivide
         #[cfg(target_os = "macos")]
         0x00070054 => Key::Other(75),
         #[cfg(target_os = "windows")]
         0x00070054 => Key::Other(111),
+        #[cfg(target_os = "linux")]
+        0x00070054 => Key::Other(0xffaf),
         // NumpadMultiply
         #[cfg(target_os = "macos")]
         0x00070055 => Key::Other(67),
         #[cfg(target_os = "windows")]
         0x00070055 => Key::Other(106),
+        #[cfg(target_os = "linux")]
+        0x00070055 => Key::Other(0xffaa),
         // NumpadSubtract
         #[cfg(target_os = "macos")]
         0x00070056 => Key::Other(78),
         #[cfg(target_os = "windows")]
         0x00070056 => Key::Other(109),
+        #[cfg(target_os = "linux")]
+        0x00070056 => Key::Other(0xffad),
         // NumpadAdd
         #[cfg(target_os = "macos")]
         0x00070057 => Key::Other(69),
         #[cfg(target_os = "windows")]
         0x00070057 => Key::Other(107),
+        #[cfg(target_os = "linux")]
+        0x00070057 => Key::Other(0xffab),
         // NumpadEnter
         #[cfg(target_os = "macos")]
         0x00070058 => Key::Other(76),
         #[cfg(target_os = "windows")]
         0x00070058 => Key::Other(13),
+        #[cfg(target_os = "linux")]
+        0x00070058 => Key::Other(0xff8d),
         // Numpad1
         #[cfg(target_os = "macos")]
         0x00070059 => Key::Other(83),
         #[cfg(target_os = "windows")]
         0x00070059 => Key::Other(97),
+        #[cfg(target_os = "linux")]
+        0x00070059 => Key::Other(0xff9c),
         // Numpad2
         #[cfg(target_os = "macos")]
         0x0007005a => Key::Other(84),
         #[cfg(target_os = "windows")]
         0x0007005a => Key::Other(98),
+        #[cfg(target_os = "linux")]
+        0x0007005a => Key::Other(0xff99),
         // Numpad3
         #[cfg(target_os = "macos")]
         0x0007005b => Key::Other(85),
         #[cfg(target_os = "windows")]
         0x0007005b => Key::Other(99),
+        #[cfg(target_os = "linux")]
+        0x0007005b => Key::Other(0xff9b),
         // Numpad4
         #[cfg(target_os = "macos")]
         0x0007005c => Key::Other(86),
         #[cfg(target_os = "windows")]
         0x0007005c => Key::Other(100),
+        #[cfg(target_os = "linux")]
+        0x0007005c => Key::Other(0xff96),
         // Numpad5
         #[cfg(target_os = "macos")]
         0x0007005d => Key::Other(87),
         #[cfg(target_os = "windows")]
         0x0007005d => Key::Other(101),
+        #[cfg(target_os = "linux")]
+        0x0007005d => Key::Other(0xff9d),
         // Numpad6
         #[cfg(target_os = "macos")]
         0x0007005e => Key::Other(88),
         #[cfg(target_os = "windows")]
         0x0007005e => Key::Other(102),
+        #[cfg(target_os = "linux")]
+        0x0007005e => Key::Other(0xff98),
         // Numpad7
         #[cfg(target_os = "macos")]
         0x0007005f => Key::Other(89),
         #[cfg(target_os = "windows")]
         0x0007005f => Key::Other(103),
+        #[cfg(target_os = "linux")]
+        0x0007005f => Key::Other(0xff95),
         // Numpad8
         #[cfg(target_os = "macos")]
         0x00070060 => Key::Other(91),
         #[cfg(target_os = "windows")]
         0x00070060 => Key::Other(104),
+        #[cfg(target_os = "linux")]
+        0x00070060 => Key::Other(0xff97),
         // Numpad9
         #[cfg(target_os = "macos")]
         0x00070061 => Key::Other(92),
         #[cfg(target_os = "windows")]
         0x00070061 => Key::Other(105),
+        #[cfg(target_os = "linux")]
+        0x00070061 => Key::Other(0xff9a),
         // Numpad0
         #[cfg(target_os = "macos")]
         0x00070062 => Key::Other(82),
         #[cfg(target_os = "windows")]
         0x00070062 => Key::Other(96),
+        #[cfg(target_os = "linux")]
+        0x00070062 => Key::Other(0xff9e),
         // NumpadDecimal
         #[cfg(target_os = "macos")]
         0x00070063 => Key::Other(65),
         #[cfg(target_os = "windows")]
         0x00070063 => Key::Other(110),
+        #[cfg(target_os = "linux")]
+        0x00070063 => Key::Other(0xff9f),
         // IntlBackslash
         #[cfg(target_os = "macos")]
         0x00070064 => Key::Other(10),
         #[cfg(target_os = "windows")]
         0x00070064 => Key::Other(226),
+        #[cfg(target_os = "linux")]
+        0x00070064 => Key::Other(0x3c),
         // ContextMenu
         #[cfg(target_os = "macos")]
         0x00070065 => Key::Other(110),
         #[cfg(target_os = "windows")]
         0x00070065 => Key::Other(93),
+        #[cfg(target_os = "linux")]
+        0x00070065 => Key::Other(0xff67),
         // Power
         #[cfg(target_os = "windows")]
         0x00070066 => Key::Other(229),
@@ -1456,53 +2977,77 @@ pub fn decode_physical_keyboard_key_type(raw: u32) -> Key {
         0x00070068 => Key::Other(105),
         #[cfg(target_os = "windows")]
         0x00070068 => Key::Other(124),
+        #[cfg(target_os = "linux")]
+        0x00070068 => Key::Other(0xffca),
         // F14
         #[cfg(target_os = "macos")]
         0x00070069 => Key::Other(107),
         #[cfg(target_os = "windows")]
         0x00070069 => Key::Other(125),
+        #[cfg(target_os = "linux")]
+        0x00070069 => Key::Other(0xffcb),
         // F15
         #[cfg(target_os = "macos")]
         0x0007006a => Key::Other(113),
         #[cfg(target_os = "windows")]
         0x0007006a => Key::Other(126),
+        #[cfg(target_os = "linux")]
+        0x0007006a => Key::Other(0xffcc),
         // F16
         #[cfg(target_os = "macos")]
         0x0007006b => Key::Other(106),
         #[cfg(target_os = "windows")]
         0x0007006b => Key::Other(127),
+        #[cfg(target_os = "linux")]
+        0x0007006b => Key::Other(0xffcd),
         // F17
         #[cfg(target_os = "macos")]
         0x0007006c => Key::Other(64),
         #[cfg(target_os = "windows")]
         0x0007006c => Key::Other(128),
+        #[cfg(target_os = "linux")]
+        0x0007006c => Key::Other(0xffce),
         // F18
         #[cfg(target_os = "macos")]
         0x0007006d => Key::Other(79),
         #[cfg(target_os = "windows")]
         0x0007006d => Key::Other(129),
+        #[cfg(target_os = "linux")]
+        0x0007006d => Key::Other(0xffcf),
         // F19
         #[cfg(target_os = "macos")]
         0x0007006e => Key::Other(80),
         #[cfg(target_os = "windows")]
         0x0007006e => Key::Other(130),
+        #[cfg(target_os = "linux")]
+        0x0007006e => Key::Other(0xffd0),
         // F20
         #[cfg(target_os = "macos")]
         0x0007006f => Key::Other(90),
         #[cfg(target_os = "windows")]
         0x0007006f => Key::Other(131),
+        #[cfg(target_os = "linux")]
+        0x0007006f => Key::Other(0xffd1),
         // F21
         #[cfg(target_os = "windows")]
         0x00070070 => Key::Other(132),
+        #[cfg(target_os = "linux")]
+        0x00070070 => Key::Other(0xffd2),
         // F22
         #[cfg(target_os = "windows")]
         0x00070071 => Key::Other(133),
+        #[cfg(target_os = "linux")]
+        0x00070071 => Key::Other(0xffd3),
         // F23
         #[cfg(target_os = "windows")]
         0x00070072 => Key::Other(134),
+        #[cfg(target_os = "linux")]
+        0x00070072 => Key::Other(0xffd4),
         // F24
         #[cfg(target_os = "windows")]
         0x00070073 => Key::Other(135),
+        #[cfg(target_os = "linux")]
+        0x00070073 => Key::Other(0xffd5),
         // Help
         #[cfg(target_os = "windows")]
         0x00070075 => Key::Other(47),
@@ -1551,41 +3096,57 @@ pub fn decode_physical_keyboard_key_type(raw: u32) -> Key {
         0x000700e0 => Key::Other(59),
         #[cfg(target_os = "windows")]
         0x000700e0 => Key::Other(17),
+        #[cfg(target_os = "linux")]
+        0x000700e0 => Key::Other(0xffe3),
         // ShiftLeft
         #[cfg(target_os = "macos")]
         0x000700e1 => Key::Other(56),
         #[cfg(target_os = "windows")]
         0x000700e1 => Key::Other(16),
+        #[cfg(target_os = "linux")]
+        0x000700e1 => Key::Other(0xffe1),
         // AltLeft
         #[cfg(target_os = "macos")]
         0x000700e2 => Key::Other(58),
         #[cfg(target_os = "windows")]
         0x000700e2 => Key::Other(18),
+        #[cfg(target_os = "linux")]
+        0x000700e2 => Key::Other(0xffe9),
         // MetaLeft
         #[cfg(target_os = "macos")]
         0x000700e3 => Key::Other(55),
         #[cfg(target_os = "windows")]
         0x000700e3 => Key::Other(91),
+        #[cfg(target_os = "linux")]
+        0x000700e3 => Key::Other(0xffeb),
         // ControlRight
         #[cfg(target_os = "macos")]
         0x000700e4 => Key::Other(62),
         #[cfg(target_os = "windows")]
         0x000700e4 => Key::Other(163),
+        #[cfg(target_os = "linux")]
+        0x000700e4 => Key::Other(0xffe4),
         // ShiftRight
         #[cfg(target_os = "macos")]
         0x000700e5 => Key::Other(60),
         #[cfg(target_os = "windows")]
         0x000700e5 => Key::Other(161),
+        #[cfg(target_os = "linux")]
+        0x000700e5 => Key::Other(0xffe2),
         // AltRight
         #[cfg(target_os = "macos")]
         0x000700e6 => Key::Other(61),
         #[cfg(target_os = "windows")]
         0x000700e6 => Key::Other(165),
+        #[cfg(target_os = "linux")]
+        0x000700e6 => Key::Other(0xffea),
         // MetaRight
         #[cfg(target_os = "macos")]
         0x000700e7 => Key::Other(54),
         #[cfg(target_os = "windows")]
         0x000700e7 => Key::Other(92),
+        #[cfg(target_os = "linux")]
+        0x000700e7 => Key::Other(0xffec),
         // MediaStop
         #[cfg(target_os = "windows")]
         0x000c00b7 => Key::Other(233),
@@ -1664,4 +3225,186 @@ pub fn decode_physical_keyboard_key_type(raw: u32) -> Key {
 #[frb(init)]
 pub fn init_app() {
     setup_default_user_utils();
+    install_release_on_panic_hook();
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn actions(enigo: &Enigo) -> Vec<MockAction> {
+        enigo
+            .mock_calls()
+            .unwrap()
+            .into_iter()
+            .map(|call| call.action)
+            .collect()
+    }
+
+    fn key(key: Key, direction: Direction) -> MockAction {
+        MockAction::Key { key, direction }
+    }
+
+    #[test]
+    fn calls_reach_the_mock_in_order() {
+        let mut enigo = Enigo::mock(100, 50).unwrap();
+        enigo.key(Key::Unicode('a'), Direction::Click).unwrap();
+        enigo.text("hi").unwrap();
+        enigo.move_mouse(10, 20, Coordinate::Abs).unwrap();
+        enigo.scroll(2, Axis::Vertical).unwrap();
+        assert_eq!(
+            actions(&enigo),
+            [
+                key(Key::Unicode('a'), Direction::Click),
+                MockAction::Text {
+                    text: "hi".to_owned()
+                },
+                MockAction::MoveMouse {
+                    x: 10,
+                    y: 20,
+                    coordinate: Coordinate::Abs
+                },
+                MockAction::Scroll {
+                    length: 2,
+                    axis: Axis::Vertical
+                },
+            ]
+        );
+        assert_eq!(enigo.location(), (10, 20));
+        assert_eq!(enigo.main_display(), (100, 50));
+    }
+
+    #[test]
+    fn text_switches_caps_lock_off_while_typing() {
+        let mut enigo = Enigo::mock(100, 50).unwrap();
+        enigo.key(Key::CapsLock, Direction::Click).unwrap();
+        enigo.clear_mock_calls().unwrap();
+        let options = TextOptions {
+            normalize_lock_state: true,
+        };
+        enigo.text_with_options("ab", options).unwrap();
+        assert_eq!(
+            actions(&enigo),
+            [
+                key(Key::CapsLock, Direction::Click),
+                MockAction::Text {
+                    text: "ab".to_owned()
+                },
+                key(Key::CapsLock, Direction::Click),
+            ]
+        );
+        assert!(enigo.lock_state().unwrap().caps_lock);
+
+        enigo.key(Key::CapsLock, Direction::Click).unwrap();
+        enigo.clear_mock_calls().unwrap();
+        enigo.text_with_options("ab", options).unwrap();
+        assert_eq!(actions(&enigo).len(), 1);
+    }
+
+    #[test]
+    fn held_input_is_tracked_and_released() {
+        let mut enigo = Enigo::mock(100, 50).unwrap();
+        enigo.key(Key::Shift, Direction::Press).unwrap();
+        enigo.raw(50, Direction::Press).unwrap();
+        enigo.button(_Button::left(), Direction::Press).unwrap();
+        enigo.key(Key::Unicode('a'), Direction::Click).unwrap();
+        let held = enigo.held();
+        assert_eq!(held.keys, [Key::Shift]);
+        assert_eq!(held.raw, [50]);
+        assert_eq!(held.buttons, [_Button::left()]);
+        assert!(enigo.modifier_state().unwrap().shift);
+
+        enigo.clear_mock_calls().unwrap();
+        enigo.release_all();
+        assert!(enigo.held().is_empty());
+        let released = actions(&enigo);
+        assert_eq!(released.len(), 3);
+        assert!(released.contains(&key(Key::Shift, Direction::Release)));
+        assert!(!enigo.modifier_state().unwrap().shift);
+    }
+
+    #[test]
+    fn settings_updates_keep_the_mock() {
+        let mut enigo = Enigo::mock(100, 50).unwrap();
+        enigo.key(Key::Shift, Direction::Press).unwrap();
+        let patch = SettingsPatch {
+            linux_delay: Some(5),
+            ..SettingsPatch::default()
+        };
+        enigo.update_settings(patch).unwrap();
+        assert_eq!(enigo.settings().linux_delay, 5);
+        assert_eq!(enigo.main_display(), (100, 50));
+        // Nothing is pressed again: the mock still holds it.
+        assert_eq!(actions(&enigo), [key(Key::Shift, Direction::Press)]);
+        assert!(enigo.modifier_state().unwrap().shift);
+
+        let invalid = SettingsPatch {
+            linux_delay: Some(5000),
+            ..SettingsPatch::default()
+        };
+        assert!(enigo.update_settings(invalid).is_err());
+        assert_eq!(enigo.settings().linux_delay, 5);
+    }
+
+    #[test]
+    fn dry_run_sends_nothing() {
+        let mut enigo = Enigo::mock(100, 50).unwrap();
+        enigo.set_dry_run(true);
+        enigo.key(Key::Unicode('a'), Direction::Click).unwrap();
+        enigo.move_mouse(5, 5, Coordinate::Rel).unwrap();
+        assert_eq!(enigo.dry_run_trace().len(), 2);
+        assert_eq!(enigo.location(), (55, 30));
+        enigo.set_dry_run(false);
+        assert!(actions(&enigo).is_empty());
+        assert_eq!(enigo.location(), (50, 25));
+    }
+
+    #[test]
+    fn paced_calls_wait_without_holding_up_others() {
+        let enigo = Enigo::mock(100, 50).unwrap();
+        enigo
+            .set_pacer(Some(PacerConfig {
+                events_per_second: 10.0,
+                burst: 1,
+                ..PacerConfig::default()
+            }))
+            .unwrap();
+        let typing = {
+            let mut enigo = enigo.clone();
+            thread::spawn(move || {
+                for _ in 0..3 {
+                    enigo.key(Key::Unicode('a'), Direction::Click).unwrap();
+                }
+            })
+        };
+        thread::sleep(Duration::from_millis(20));
+        let start = Instant::now();
+        enigo.location();
+        assert!(start.elapsed() < Duration::from_millis(50));
+        typing.join().unwrap();
+        assert_eq!(actions(&enigo).len(), 3);
+        let stats = enigo.pacer_stats().unwrap();
+        assert_eq!((stats.events, stats.delayed), (3, 2));
+    }
+
+    #[test]
+    fn smooth_scrolling_adds_up_to_the_distance() {
+        let enigo = Enigo::mock(100, 50).unwrap();
+        enigo.scroll_smooth(-1.0, 3.0, 40).unwrap();
+        let (mut x, mut y) = (0, 0);
+        for action in actions(&enigo) {
+            match action {
+                MockAction::Scroll {
+                    length,
+                    axis: Axis::Horizontal,
+                } => x += length,
+                MockAction::Scroll {
+                    length,
+                    axis: Axis::Vertical,
+                } => y += length,
+                action => panic!("unexpected {action:?}"),
+            }
+        }
+        assert_eq!((x, y), (-1, 3));
+    }
 }
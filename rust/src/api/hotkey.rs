@@ -0,0 +1,765 @@
+use crate::frb_generated::StreamSink;
+use enigo::Settings;
+use flutter_rust_bridge::*;
+use std::collections::HashMap;
+use std::sync::{Arc, Mutex, MutexGuard, PoisonError};
+
+/// Modifiers a hotkey requires; left and right variants are not told apart.
+#[frb(ignore)]
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub(crate) struct Modifiers {
+    pub control: bool,
+    pub shift: bool,
+    pub alt: bool,
+    pub meta: bool,
+}
+
+/// A parsed accelerator. The key is kept as a USB HID usage so it can go
+/// through the same physical key tables the `Key` codec uses.
+#[frb(ignore)]
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub(crate) struct Hotkey {
+    pub accelerator: String,
+    pub modifiers: Modifiers,
+    pub usage: u32,
+}
+
+const KEYBOARD_PAGE: u32 = 0x0007_0000;
+
+const LEFT_CONTROL: u32 = KEYBOARD_PAGE | 0xe0;
+const LEFT_SHIFT: u32 = KEYBOARD_PAGE | 0xe1;
+const LEFT_ALT: u32 = KEYBOARD_PAGE | 0xe2;
+const LEFT_META: u32 = KEYBOARD_PAGE | 0xe3;
+const RIGHT_CONTROL: u32 = KEYBOARD_PAGE | 0xe4;
+const RIGHT_SHIFT: u32 = KEYBOARD_PAGE | 0xe5;
+const RIGHT_ALT: u32 = KEYBOARD_PAGE | 0xe6;
+const RIGHT_META: u32 = KEYBOARD_PAGE | 0xe7;
+
+impl Hotkey {
+    /// Parse accelerators such as `ctrl+alt+f9` or `Meta+Shift+K`.
+    pub fn parse(accelerator: &str) -> anyhow::Result<Self> {
+        let mut modifiers = Modifiers::default();
+        let mut parts: Vec<String> = accelerator
+            .split('+')
+            .map(|part| part.trim().to_ascii_lowercase())
+            .collect();
+        let key = parts.pop().unwrap_or_default();
+        for part in parts {
+            match part.as_str() {
+                "ctrl" | "control" => modifiers.control = true,
+                "shift" => modifiers.shift = true,
+                "alt" | "option" => modifiers.alt = true,
+                "meta" | "super" | "win" | "cmd" | "command" => modifiers.meta = true,
+                _ => anyhow::bail!("`{part}` in `{accelerator}` is not a modifier"),
+            }
+        }
+        let usage = usage(&key)
+            .ok_or_else(|| anyhow::anyhow!("`{key}` in `{accelerator}` is not a known key"))?;
+        Ok(Self {
+            accelerator: accelerator.to_string(),
+            modifiers,
+            usage,
+        })
+    }
+}
+
+/// Key names, mostly as in Flutter's `PhysicalKeyboardKey`, mapped to their
+/// USB HID usage.
+pub(crate) fn usage(name: &str) -> Option<u32> {
+    let mut chars = name.chars();
+    if let (Some(c), None) = (chars.next(), chars.next()) {
+        match c {
+            'a'..='z' => return Some(KEYBOARD_PAGE | (0x04 + c as u32 - 'a' as u32)),
+            '1'..='9' => return Some(KEYBOARD_PAGE | (0x1e + c as u32 - '1' as u32)),
+            '0' => return Some(KEYBOARD_PAGE | 0x27),
+            _ => {}
+        }
+    }
+    if let Some(n) = name.strip_prefix('f').and_then(|n| n.parse::<u32>().ok()) {
+        if (1..=12).contains(&n) {
+            return Some(KEYBOARD_PAGE | (0x3a + n - 1));
+        }
+    }
+    let usage = match name {
+        "enter" | "return" => 0x28,
+        "escape" | "esc" => 0x29,
+        "backspace" => 0x2a,
+        "tab" => 0x2b,
+        "space" => 0x2c,
+        "minus" => 0x2d,
+        "equal" => 0x2e,
+        "bracketleft" => 0x2f,
+        "bracketright" => 0x30,
+        "backslash" => 0x31,
+        "semicolon" => 0x33,
+        "quote" => 0x34,
+        "backquote" => 0x35,
+        "comma" => 0x36,
+        "period" => 0x37,
+        "slash" => 0x38,
+        "capslock" => 0x39,
+        "printscreen" => 0x46,
+        "scrolllock" => 0x47,
+        "pause" => 0x48,
+        "insert" => 0x49,
+        "home" => 0x4a,
+        "pageup" => 0x4b,
+        "delete" => 0x4c,
+        "end" => 0x4d,
+        "pagedown" => 0x4e,
+        "right" | "arrowright" => 0x4f,
+        "left" | "arrowleft" => 0x50,
+        "down" | "arrowdown" => 0x51,
+        "up" | "arrowup" => 0x52,
+        "numlock" => 0x53,
+        "controlleft" | "ctrl" | "control" => 0xe0,
+        "shiftleft" | "shift" => 0xe1,
+        "altleft" | "alt" => 0xe2,
+        "metaleft" | "meta" | "super" => 0xe3,
+        "controlright" => 0xe4,
+        "shiftright" => 0xe5,
+        "altright" => 0xe6,
+        "metaright" => 0xe7,
+        _ => return None,
+    };
+    Some(KEYBOARD_PAGE | usage)
+}
+
+/// The keycode the global listener reports as `raw` for a HID usage.
+#[cfg(any(target_os = "linux", target_os = "macos"))]
+pub(crate) fn raw_keycode(usage: u32) -> Option<u16> {
+    imp::raw_keycode(usage)
+}
+
+/// A registered hotkey was pressed.
+#[derive(Debug, Clone)]
+pub struct HotkeyEvent {
+    pub id: u32,
+    pub accelerator: String,
+}
+
+/// State shared between the manager and its platform backend.
+#[frb(ignore)]
+#[derive(Default)]
+pub(crate) struct Shared {
+    hotkeys: Mutex<HashMap<u32, Hotkey>>,
+    sink: Mutex<Option<StreamSink<HotkeyEvent>>>,
+}
+
+impl Shared {
+    pub fn hotkeys(&self) -> MutexGuard<'_, HashMap<u32, Hotkey>> {
+        self.hotkeys.lock().unwrap_or_else(PoisonError::into_inner)
+    }
+
+    pub fn activate(&self, id: u32) {
+        let Some(accelerator) = self.hotkeys().get(&id).map(|h| h.accelerator.clone()) else {
+            return;
+        };
+        let mut sink = self.sink.lock().unwrap_or_else(PoisonError::into_inner);
+        if let Some(stream) = sink.as_ref() {
+            if stream.add(HotkeyEvent { id, accelerator }).is_err() {
+                *sink = None;
+            }
+        }
+    }
+
+    /// Activate every hotkey `matches` accepts.
+    pub fn activate_matching(&self, matches: impl Fn(&Hotkey) -> bool) {
+        let ids: Vec<u32> = self
+            .hotkeys()
+            .iter()
+            .filter(|(_, hotkey)| matches(hotkey))
+            .map(|(id, _)| *id)
+            .collect();
+        for id in ids {
+            self.activate(id);
+        }
+    }
+}
+
+#[frb(ignore)]
+pub(crate) trait Backend: Send + Sync {
+    fn grab(&self, id: u32, hotkey: &Hotkey) -> anyhow::Result<()>;
+    fn ungrab(&self, id: u32, hotkey: &Hotkey) -> anyhow::Result<()>;
+}
+
+/// System-wide hotkeys that fire while other applications have focus.
+#[frb(opaque)]
+pub struct HotkeyManager {
+    shared: Arc<Shared>,
+    backend: Box<dyn Backend>,
+    next_id: Mutex<u32>,
+}
+
+impl HotkeyManager {
+    #[frb(sync)]
+    pub fn new(settings: &Settings) -> anyhow::Result<Self> {
+        let shared = Arc::new(Shared::default());
+        let backend = imp::backend(settings.x11_display.as_deref(), shared.clone())?;
+        Ok(Self {
+            shared,
+            backend,
+            next_id: Mutex::new(1),
+        })
+    }
+
+    /// Stream every activation of a registered hotkey. A new stream replaces
+    /// the previous one.
+    pub fn activations(&self, sink: StreamSink<HotkeyEvent>) -> anyhow::Result<()> {
+        *self
+            .shared
+            .sink
+            .lock()
+            .unwrap_or_else(PoisonError::into_inner) = Some(sink);
+        Ok(())
+    }
+
+    /// Register `accelerator`, e.g. `ctrl+alt+f9`, and return the id its
+    /// activations are reported with.
+    #[frb(sync)]
+    pub fn register(&self, accelerator: String) -> anyhow::Result<u32> {
+        let hotkey = Hotkey::parse(&accelerator)?;
+        let id = {
+            let mut next_id = self.next_id.lock().unwrap_or_else(PoisonError::into_inner);
+            let id = *next_id;
+            *next_id += 1;
+            id
+        };
+        self.backend.grab(id, &hotkey)?;
+        self.shared.hotkeys().insert(id, hotkey);
+        Ok(id)
+    }
+
+    #[frb(sync)]
+    pub fn unregister(&self, id: u32) -> anyhow::Result<()> {
+        let hotkey = self.shared.hotkeys().remove(&id);
+        match hotkey {
+            Some(hotkey) => self.backend.ungrab(id, &hotkey),
+            None => anyhow::bail!("no hotkey is registered with id {id}"),
+        }
+    }
+
+    #[frb(sync)]
+    pub fn unregister_all(&self) -> anyhow::Result<()> {
+        let hotkeys: Vec<(u32, Hotkey)> = self.shared.hotkeys().drain().collect();
+        for (id, hotkey) in hotkeys {
+            self.backend.ungrab(id, &hotkey)?;
+        }
+        Ok(())
+    }
+}
+
+impl Drop for HotkeyManager {
+    fn drop(&mut self) {
+        let _ = self.unregister_all();
+    }
+}
+
+/// Passive fallback for platforms without a grab API: match hotkeys against
+/// the global listener. The keys still reach the focused application.
+#[cfg(any(target_os = "linux", target_os = "macos"))]
+#[frb(ignore)]
+mod listening {
+    use super::{Backend, Hotkey, Modifiers, Shared};
+    use super::{
+        LEFT_ALT, LEFT_CONTROL, LEFT_META, LEFT_SHIFT, RIGHT_ALT, RIGHT_CONTROL, RIGHT_META,
+        RIGHT_SHIFT,
+    };
+    use crate::api::listener::{self, InputEvent, ListenerHandle};
+    use enigo::Direction;
+    use std::sync::{Arc, Mutex};
+
+    pub struct ListeningBackend {
+        _handle: Mutex<ListenerHandle>,
+    }
+
+    impl ListeningBackend {
+        pub fn new(display: Option<&str>, shared: Arc<Shared>) -> anyhow::Result<Self> {
+            let raw = super::raw_keycode;
+            let modifier_keys = [
+                (raw(LEFT_CONTROL), raw(RIGHT_CONTROL)),
+                (raw(LEFT_SHIFT), raw(RIGHT_SHIFT)),
+                (raw(LEFT_ALT), raw(RIGHT_ALT)),
+                (raw(LEFT_META), raw(RIGHT_META)),
+            ];
+            let mut held = [false; 8];
+            let handle = listener::spawn(
+                display,
+                Box::new(move |event| {
+                    let InputEvent::Key { raw, direction, .. } = event else {
+                        return true;
+                    };
+                    let pressed = direction != Direction::Release;
+                    let mut is_modifier = false;
+                    for (i, (left, right)) in modifier_keys.iter().enumerate() {
+                        if Some(raw) == *left {
+                            held[i * 2] = pressed;
+                            is_modifier = true;
+                        } else if Some(raw) == *right {
+                            held[i * 2 + 1] = pressed;
+                            is_modifier = true;
+                        }
+                    }
+                    if pressed && !is_modifier {
+                        let modifiers = Modifiers {
+                            control: held[0] || held[1],
+                            shift: held[2] || held[3],
+                            alt: held[4] || held[5],
+                            meta: held[6] || held[7],
+                        };
+                        shared.activate_matching(|hotkey| {
+                            hotkey.modifiers == modifiers
+                                && super::raw_keycode(hotkey.usage) == Some(raw)
+                        });
+                    }
+                    true
+                }),
+            )?;
+            Ok(Self {
+                _handle: Mutex::new(handle),
+            })
+        }
+    }
+
+    impl Backend for ListeningBackend {
+        fn grab(&self, _id: u32, hotkey: &Hotkey) -> anyhow::Result<()> {
+            if super::raw_keycode(hotkey.usage).is_none() {
+                anyhow::bail!("`{}` has no keycode on this platform", hotkey.accelerator);
+            }
+            Ok(())
+        }
+
+        fn ungrab(&self, _id: u32, _hotkey: &Hotkey) -> anyhow::Result<()> {
+            Ok(())
+        }
+    }
+}
+
+#[cfg(target_os = "linux")]
+#[frb(ignore)]
+mod imp {
+    use super::listening::ListeningBackend;
+    use super::{Backend, Hotkey, Shared};
+    use crate::api::enigo::decode_physical_keyboard_key_type;
+    use crate::api::listener::imp::x11::Keymap;
+    use enigo::Key;
+    use std::sync::Arc;
+    use std::thread::JoinHandle;
+    use x11rb::connection::Connection;
+    use x11rb::protocol::xproto::{
+        ClientMessageEvent, ConnectionExt as _, CreateWindowAux, EventMask, GrabMode, ModMask,
+        Window, WindowClass,
+    };
+    use x11rb::protocol::Event;
+    use x11rb::rust_connection::RustConnection;
+    use x11rb::COPY_FROM_PARENT;
+
+    /// The keysym a HID usage decodes to, which is how enigo names keys on
+    /// Linux.
+    fn keysym(usage: u32) -> Option<u32> {
+        match decode_physical_keyboard_key_type(usage) {
+            Key::Other(keysym) if keysym != 0 => Some(keysym),
+            _ => None,
+        }
+    }
+
+    /// The key the evdev listener gives that keysym, as the X11 keycode it
+    /// reports as `raw`. Without an X server there is no keymap to ask, so
+    /// this goes by the listener's own US-layout table.
+    #[cfg(feature = "uinput")]
+    pub fn raw_keycode(usage: u32) -> Option<u16> {
+        use crate::api::listener::imp::evdev::{keysym as evdev_keysym, X11_KEYCODE_OFFSET};
+        use evdev::KeyCode;
+
+        let keysym = keysym(usage)?;
+        // X11 keycodes are a byte.
+        (0..=u16::from(u8::MAX) - X11_KEYCODE_OFFSET)
+            .find(|&code| evdev_keysym(KeyCode::new(code)) == keysym)
+            .map(|code| code + X11_KEYCODE_OFFSET)
+    }
+
+    /// Listening without an X server needs the `uinput` cargo feature, so
+    /// there is no `raw` to match.
+    #[cfg(not(feature = "uinput"))]
+    pub fn raw_keycode(_usage: u32) -> Option<u16> {
+        None
+    }
+
+    /// `XGrabKey` when an X server is reachable, the passive listener otherwise.
+    pub fn backend(display: Option<&str>, shared: Arc<Shared>) -> anyhow::Result<Box<dyn Backend>> {
+        match x11rb::connect(display) {
+            Ok((conn, screen)) => Ok(Box::new(X11Backend::new(conn, screen, shared)?)),
+            Err(_) => Ok(Box::new(ListeningBackend::new(display, shared)?)),
+        }
+    }
+
+    fn relevant_modifiers() -> u16 {
+        u16::from(ModMask::CONTROL | ModMask::SHIFT | ModMask::M1 | ModMask::M4)
+    }
+
+    fn mod_mask(hotkey: &Hotkey) -> u16 {
+        let modifiers = hotkey.modifiers;
+        let mut mask = 0;
+        for (on, bit) in [
+            (modifiers.control, ModMask::CONTROL),
+            (modifiers.shift, ModMask::SHIFT),
+            (modifiers.alt, ModMask::M1),
+            (modifiers.meta, ModMask::M4),
+        ] {
+            if on {
+                mask |= u16::from(bit);
+            }
+        }
+        mask
+    }
+
+    fn keycode(keymap: &Keymap, hotkey: &Hotkey) -> anyhow::Result<u8> {
+        keysym(hotkey.usage)
+            .and_then(|keysym| keymap.keycode(keysym))
+            .ok_or_else(|| anyhow::anyhow!("`{}` has no X11 keycode", hotkey.accelerator))
+    }
+
+    /// Caps Lock and Num Lock must not stop a hotkey from firing, so every
+    /// grab is repeated with each combination of them.
+    fn lock_variants() -> [u16; 4] {
+        let (caps, num) = (u16::from(ModMask::LOCK), u16::from(ModMask::M2));
+        [0, caps, num, caps | num]
+    }
+
+    struct X11Backend {
+        conn: Arc<RustConnection>,
+        keymap: Arc<Keymap>,
+        root: Window,
+        /// Unmapped window the event thread is woken through on drop.
+        wake: Window,
+        thread: Option<JoinHandle<()>>,
+    }
+
+    impl X11Backend {
+        fn new(conn: RustConnection, screen: usize, shared: Arc<Shared>) -> anyhow::Result<Self> {
+            let conn = Arc::new(conn);
+            let keymap = Arc::new(Keymap::load(&conn)?);
+            let root = conn.setup().roots[screen].root;
+            let wake = conn.generate_id()?;
+            conn.create_window(
+                COPY_FROM_PARENT as u8,
+                wake,
+                root,
+                0,
+                0,
+                1,
+                1,
+                0,
+                WindowClass::INPUT_ONLY,
+                COPY_FROM_PARENT,
+                &CreateWindowAux::new().event_mask(EventMask::NO_EVENT),
+            )?
+            .check()?;
+
+            let thread = {
+                let conn = conn.clone();
+                let keymap = keymap.clone();
+                std::thread::spawn(move || loop {
+                    match conn.wait_for_event() {
+                        Ok(Event::KeyPress(event)) => {
+                            let state = u16::from(event.state) & relevant_modifiers();
+                            shared.activate_matching(|hotkey| {
+                                keycode(&keymap, hotkey).ok() == Some(event.detail)
+                                    && mod_mask(hotkey) == state
+                            });
+                        }
+                        Ok(Event::ClientMessage(event)) if event.window == wake => break,
+                        Ok(_) => {}
+                        Err(_) => break,
+                    }
+                })
+            };
+
+            Ok(Self {
+                conn,
+                keymap,
+                root,
+                wake,
+                thread: Some(thread),
+            })
+        }
+    }
+
+    impl Backend for X11Backend {
+        fn grab(&self, id: u32, hotkey: &Hotkey) -> anyhow::Result<()> {
+            let keycode = keycode(&self.keymap, hotkey)?;
+            let mask = mod_mask(hotkey);
+            for lock in lock_variants() {
+                let grabbed = self
+                    .conn
+                    .grab_key(
+                        false,
+                        self.root,
+                        ModMask::from(mask | lock),
+                        keycode,
+                        GrabMode::ASYNC,
+                        GrabMode::ASYNC,
+                    )?
+                    .check();
+                if grabbed.is_err() {
+                    let _ = self.ungrab(id, hotkey);
+                    anyhow::bail!(
+                        "`{}` is already grabbed by another client",
+                        hotkey.accelerator
+                    );
+                }
+            }
+            Ok(())
+        }
+
+        fn ungrab(&self, _id: u32, hotkey: &Hotkey) -> anyhow::Result<()> {
+            let keycode = keycode(&self.keymap, hotkey)?;
+            let mask = mod_mask(hotkey);
+            for lock in lock_variants() {
+                self.conn
+                    .ungrab_key(keycode, self.root, ModMask::from(mask | lock))?;
+            }
+            self.conn.flush()?;
+            Ok(())
+        }
+    }
+
+    impl Drop for X11Backend {
+        fn drop(&mut self) {
+            let event = ClientMessageEvent::new(32, self.wake, x11rb::NONE, [0u32; 5]);
+            let _ = self
+                .conn
+                .send_event(false, self.wake, EventMask::NO_EVENT, event);
+            let _ = self.conn.flush();
+            if let Some(thread) = self.thread.take() {
+                let _ = thread.join();
+            }
+            let _ = self.conn.destroy_window(self.wake);
+            let _ = self.conn.flush();
+        }
+    }
+}
+
+#[cfg(target_os = "macos")]
+#[frb(ignore)]
+mod imp {
+    use super::listening::ListeningBackend;
+    use super::{Backend, Shared};
+    use crate::api::enigo::decode_physical_keyboard_key_type;
+    use enigo::Key;
+    use std::sync::Arc;
+
+    pub fn raw_keycode(usage: u32) -> Option<u16> {
+        match decode_physical_keyboard_key_type(usage) {
+            Key::Other(keycode) => u16::try_from(keycode).ok(),
+            _ => None,
+        }
+    }
+
+    pub fn backend(display: Option<&str>, shared: Arc<Shared>) -> anyhow::Result<Box<dyn Backend>> {
+        Ok(Box::new(ListeningBackend::new(display, shared)?))
+    }
+}
+
+#[cfg(target_os = "windows")]
+#[frb(ignore)]
+mod imp {
+    use super::{Backend, Hotkey, Shared};
+    use crate::api::enigo::decode_physical_keyboard_key_type;
+    use enigo::Key;
+    use std::sync::mpsc::{self, Receiver, Sender};
+    use std::sync::{Arc, Mutex, PoisonError};
+    use std::thread::JoinHandle;
+    use windows::Win32::Foundation::{HWND, LPARAM, WPARAM};
+    use windows::Win32::System::Threading::GetCurrentThreadId;
+    use windows::Win32::UI::Input::KeyboardAndMouse::{
+        RegisterHotKey, UnregisterHotKey, HOT_KEY_MODIFIERS, MOD_ALT, MOD_CONTROL, MOD_NOREPEAT,
+        MOD_SHIFT, MOD_WIN,
+    };
+    use windows::Win32::UI::WindowsAndMessaging::{
+        GetMessageW, PeekMessageW, PostThreadMessageW, MSG, PM_NOREMOVE, WM_APP, WM_HOTKEY,
+        WM_QUIT, WM_USER,
+    };
+
+    enum Request {
+        Grab(u32, HOT_KEY_MODIFIERS, u32),
+        Ungrab(u32),
+    }
+
+    type Reply = windows::core::Result<()>;
+
+    /// `RegisterHotKey` posts `WM_HOTKEY` to the registering thread, so all
+    /// registrations go through one thread running a message loop.
+    struct WindowsBackend {
+        thread_id: u32,
+        requests: Mutex<Sender<(Request, Sender<Reply>)>>,
+        thread: Option<JoinHandle<()>>,
+    }
+
+    pub fn backend(
+        _display: Option<&str>,
+        shared: Arc<Shared>,
+    ) -> anyhow::Result<Box<dyn Backend>> {
+        let (requests, incoming) = mpsc::channel();
+        let (started, ready) = mpsc::channel();
+        let thread = std::thread::spawn(move || run(shared, incoming, started));
+        Ok(Box::new(WindowsBackend {
+            thread_id: ready.recv()?,
+            requests: Mutex::new(requests),
+            thread: Some(thread),
+        }))
+    }
+
+    fn run(
+        shared: Arc<Shared>,
+        incoming: Receiver<(Request, Sender<Reply>)>,
+        started: Sender<u32>,
+    ) {
+        let mut message = MSG::default();
+        // Make sure the thread has a message queue before anyone posts to it.
+        unsafe {
+            let _ = PeekMessageW(&mut message, HWND::default(), WM_USER, WM_USER, PM_NOREMOVE);
+        }
+        let _ = started.send(unsafe { GetCurrentThreadId() });
+
+        while unsafe { GetMessageW(&mut message, HWND::default(), 0, 0) }.as_bool() {
+            match message.message {
+                WM_HOTKEY => shared.activate(message.wParam.0 as u32),
+                WM_APP => {
+                    for (request, reply) in incoming.try_iter() {
+                        let result = unsafe {
+                            match request {
+                                Request::Grab(id, modifiers, vk) => {
+                                    RegisterHotKey(HWND::default(), id as i32, modifiers, vk)
+                                }
+                                Request::Ungrab(id) => UnregisterHotKey(HWND::default(), id as i32),
+                            }
+                        };
+                        let _ = reply.send(result);
+                    }
+                }
+                _ => {}
+            }
+        }
+    }
+
+    impl WindowsBackend {
+        fn request(&self, request: Request) -> anyhow::Result<()> {
+            let (reply, result) = mpsc::channel();
+            self.requests
+                .lock()
+                .unwrap_or_else(PoisonError::into_inner)
+                .send((request, reply))?;
+            unsafe { PostThreadMessageW(self.thread_id, WM_APP, WPARAM(0), LPARAM(0))? };
+            Ok(result.recv()??)
+        }
+    }
+
+    impl Backend for WindowsBackend {
+        fn grab(&self, id: u32, hotkey: &Hotkey) -> anyhow::Result<()> {
+            let Key::Other(vk) = decode_physical_keyboard_key_type(hotkey.usage) else {
+                anyhow::bail!("`{}` has no virtual key", hotkey.accelerator);
+            };
+            let mut modifiers = MOD_NOREPEAT;
+            for (on, flag) in [
+                (hotkey.modifiers.control, MOD_CONTROL),
+                (hotkey.modifiers.shift, MOD_SHIFT),
+                (hotkey.modifiers.alt, MOD_ALT),
+                (hotkey.modifiers.meta, MOD_WIN),
+            ] {
+                if on {
+                    modifiers |= flag;
+                }
+            }
+            self.request(Request::Grab(id, modifiers, vk))
+        }
+
+        fn ungrab(&self, id: u32, _hotkey: &Hotkey) -> anyhow::Result<()> {
+            self.request(Request::Ungrab(id))
+        }
+    }
+
+    impl Drop for WindowsBackend {
+        fn drop(&mut self) {
+            unsafe {
+                let _ = PostThreadMessageW(self.thread_id, WM_QUIT, WPARAM(0), LPARAM(0));
+            }
+            if let Some(thread) = self.thread.take() {
+                let _ = thread.join();
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn accelerators_split_into_modifiers_and_a_key() {
+        let hotkey = Hotkey::parse("Ctrl + Alt+F9").unwrap();
+        assert_eq!(hotkey.accelerator, "Ctrl + Alt+F9");
+        assert_eq!(
+            hotkey.modifiers,
+            Modifiers {
+                control: true,
+                alt: true,
+                ..Modifiers::default()
+            }
+        );
+        assert_eq!(hotkey.usage, KEYBOARD_PAGE | 0x42);
+
+        let hotkey = Hotkey::parse("cmd+option+shift+k").unwrap();
+        assert_eq!(
+            hotkey.modifiers,
+            Modifiers {
+                control: false,
+                shift: true,
+                alt: true,
+                meta: true,
+            }
+        );
+        assert_eq!(hotkey.usage, KEYBOARD_PAGE | 0x0e);
+    }
+
+    #[test]
+    fn a_modifier_alone_is_the_key() {
+        let hotkey = Hotkey::parse("shift").unwrap();
+        assert_eq!(hotkey.modifiers, Modifiers::default());
+        assert_eq!(hotkey.usage, LEFT_SHIFT);
+    }
+
+    #[test]
+    fn bad_accelerators_are_refused() {
+        for (accelerator, error) in [
+            ("", "`` in `` is not a known key"),
+            ("ctrl+", "`` in `ctrl+` is not a known key"),
+            ("ctrl+nokey", "`nokey` in `ctrl+nokey` is not a known key"),
+            ("f13", "`f13` in `f13` is not a known key"),
+            ("hyper+a", "`hyper` in `hyper+a` is not a modifier"),
+            ("a+ctrl", "`a` in `a+ctrl` is not a modifier"),
+        ] {
+            let parsed = Hotkey::parse(accelerator);
+            assert_eq!(parsed.unwrap_err().to_string(), error, "{accelerator:?}");
+        }
+    }
+
+    #[cfg(all(target_os = "linux", feature = "uinput"))]
+    #[test]
+    fn usages_map_to_the_keycodes_the_listener_reports() {
+        for (name, keycode) in [
+            ("a", 38),
+            ("0", 19),
+            ("enter", 36),
+            ("space", 65),
+            ("f1", 67),
+            ("left", 113),
+            ("numlock", 77),
+            ("controlleft", 37),
+            ("metaright", 134),
+        ] {
+            assert_eq!(raw_keycode(usage(name).unwrap()), Some(keycode), "{name}");
+        }
+        assert_eq!(raw_keycode(KEYBOARD_PAGE | 0xff), None);
+    }
+}
@@ -0,0 +1,1294 @@
+use crate::frb_generated::StreamSink;
+use enigo::{Axis, Coordinate, Direction, Key, Settings};
+use flutter_rust_bridge::*;
+use std::sync::{Mutex, PoisonError};
+use std::thread::JoinHandle;
+
+use super::enigo::_Button;
+
+/// A global input event, described with the same types the [`Enigo`] methods
+/// accept so it can be replayed as-is.
+///
+/// [`Enigo`]: super::enigo::Enigo
+#[derive(Clone)]
+pub enum InputEvent {
+    /// `key` is the named key where enigo has one, the character for keys
+    /// that type one, and `Key::Other` with the platform's key value
+    /// (keysym, virtual-key code, or keycode on macOS) otherwise. `raw` is
+    /// the platform keycode [`Enigo::raw`] accepts.
+    ///
+    /// [`Enigo::raw`]: super::enigo::Enigo::raw
+    Key {
+        key: Key,
+        raw: u16,
+        direction: Direction,
+    },
+    Button {
+        button: _Button,
+        direction: Direction,
+    },
+    /// Relative only where the backend can't see the cursor (evdev).
+    MouseMove {
+        x: i32,
+        y: i32,
+        coordinate: Coordinate,
+    },
+    Scroll {
+        length: i32,
+        axis: Axis,
+    },
+}
+
+/// Observes keyboard and mouse input system-wide.
+#[frb(opaque)]
+pub struct Listener {
+    display: Option<String>,
+    handle: Mutex<Option<ListenerHandle>>,
+}
+
+impl Listener {
+    #[frb(sync)]
+    pub fn new(settings: &Settings) -> Self {
+        Self {
+            display: settings.x11_display.clone(),
+            handle: Mutex::new(None),
+        }
+    }
+
+    /// Start capturing and push every event into `sink`, replacing any
+    /// capture already running. Capturing stops when the Dart stream is
+    /// cancelled or [`Listener::stop`] is called.
+    pub fn listen(&self, sink: StreamSink<InputEvent>) -> anyhow::Result<()> {
+        let handle = spawn(
+            self.display.as_deref(),
+            Box::new(move |event| sink.add(event).is_ok()),
+        )?;
+        // Dropping the previous handle stops its capture.
+        self.lock().replace(handle);
+        Ok(())
+    }
+
+    #[frb(sync)]
+    pub fn stop(&self) {
+        self.lock().take();
+    }
+
+    #[frb(sync, getter)]
+    pub fn is_listening(&self) -> bool {
+        self.lock().as_ref().is_some_and(ListenerHandle::is_running)
+    }
+
+    fn lock(&self) -> std::sync::MutexGuard<'_, Option<ListenerHandle>> {
+        self.handle.lock().unwrap_or_else(PoisonError::into_inner)
+    }
+}
+
+/// Called on the capture thread for every event; returning `false` ends the
+/// capture.
+pub(crate) type Callback = Box<dyn FnMut(InputEvent) -> bool + Send>;
+
+/// A running capture. Dropping it stops the capture.
+#[frb(ignore)]
+pub(crate) struct ListenerHandle {
+    interrupt: Option<Box<dyn FnOnce() + Send>>,
+    thread: Option<JoinHandle<()>>,
+}
+
+impl ListenerHandle {
+    pub fn is_running(&self) -> bool {
+        self.thread
+            .as_ref()
+            .is_some_and(|thread| !thread.is_finished())
+    }
+}
+
+impl Drop for ListenerHandle {
+    fn drop(&mut self) {
+        if let Some(interrupt) = self.interrupt.take() {
+            interrupt();
+        }
+        if let Some(thread) = self.thread.take() {
+            let _ = thread.join();
+        }
+    }
+}
+
+/// Start a capture on a dedicated thread, for Rust-side consumers such as the
+/// recorder.
+pub(crate) fn spawn(display: Option<&str>, callback: Callback) -> anyhow::Result<ListenerHandle> {
+    imp::spawn(display, callback)
+}
+
+#[cfg(target_os = "linux")]
+#[frb(ignore)]
+pub(crate) mod imp {
+    use super::{Callback, ListenerHandle};
+    use enigo::Key;
+
+    /// XRecord when an X server is reachable, evdev otherwise (Wayland, TTY)
+    /// if the `uinput` cargo feature is on.
+    pub fn spawn(display: Option<&str>, callback: Callback) -> anyhow::Result<ListenerHandle> {
+        match x11::connect(display) {
+            Ok(connections) => x11::spawn(connections, callback),
+            #[cfg(feature = "uinput")]
+            Err(_) => evdev::spawn(callback),
+            #[cfg(not(feature = "uinput"))]
+            Err(error) => Err(error.context(
+                "no X server to listen to, and listening through evdev needs the `uinput` cargo feature",
+            )),
+        }
+    }
+
+    /// The named key for an X11 keysym, the character for a printable
+    /// Latin-1 one, and `Key::Other(keysym)`, which enigo takes on Linux,
+    /// otherwise. Both capture paths describe keys this way.
+    ///
+    /// enigo has no numpad keys on Linux, so keypad keysyms become the key
+    /// or character they type, as Windows reports the keypad with Num Lock
+    /// off; the raw keycode still tells them apart.
+    pub(crate) fn key(keysym: u32) -> Key {
+        const FUNCTION_KEYS: [Key; 35] = [
+            Key::F1,
+            Key::F2,
+            Key::F3,
+            Key::F4,
+            Key::F5,
+            Key::F6,
+            Key::F7,
+            Key::F8,
+            Key::F9,
+            Key::F10,
+            Key::F11,
+            Key::F12,
+            Key::F13,
+            Key::F14,
+            Key::F15,
+            Key::F16,
+            Key::F17,
+            Key::F18,
+            Key::F19,
+            Key::F20,
+            Key::F21,
+            Key::F22,
+            Key::F23,
+            Key::F24,
+            Key::F25,
+            Key::F26,
+            Key::F27,
+            Key::F28,
+            Key::F29,
+            Key::F30,
+            Key::F31,
+            Key::F32,
+            Key::F33,
+            Key::F34,
+            Key::F35,
+        ];
+        match keysym {
+            0x20 | 0xff80 => Key::Space,
+            0x21..=0x7e | 0xa0..=0xff => {
+                char::from_u32(keysym).map_or(Key::Other(keysym), Key::Unicode)
+            }
+            0xff08 => Key::Backspace,
+            0xff09 | 0xff89 => Key::Tab,
+            0xff0d | 0xff8d => Key::Return,
+            0xff13 => Key::Pause,
+            0xff14 => Key::ScrollLock,
+            0xff15 => Key::SysReq,
+            0xff1b => Key::Escape,
+            0xff50 | 0xff95 => Key::Home,
+            0xff51 | 0xff96 => Key::LeftArrow,
+            0xff52 | 0xff97 => Key::UpArrow,
+            0xff53 | 0xff98 => Key::RightArrow,
+            0xff54 | 0xff99 => Key::DownArrow,
+            0xff55 | 0xff9a => Key::PageUp,
+            0xff56 | 0xff9b => Key::PageDown,
+            0xff57 | 0xff9c => Key::End,
+            0xff58 | 0xff9d => Key::Begin,
+            0xff60 => Key::Select,
+            0xff61 => Key::PrintScr,
+            0xff62 => Key::Execute,
+            0xff63 | 0xff9e => Key::Insert,
+            0xff65 => Key::Undo,
+            0xff66 => Key::Redo,
+            0xff68 => Key::Find,
+            0xff69 => Key::Cancel,
+            0xff6a => Key::Help,
+            0xff6b => Key::Break,
+            0xff7e => Key::ModeChange,
+            0xff7f => Key::Numlock,
+            // KP_Multiply to KP_9 and KP_Equal are their ASCII characters
+            // with the keypad bit set.
+            0xffaa..=0xffb9 | 0xffbd => Key::Unicode(char::from((keysym - 0xff80) as u8)),
+            0xffbe..=0xffe0 => FUNCTION_KEYS[(keysym - 0xffbe) as usize],
+            0xffe1 => Key::LShift,
+            0xffe2 => Key::RShift,
+            0xffe3 => Key::LControl,
+            0xffe4 => Key::RControl,
+            0xffe5 => Key::CapsLock,
+            0xffe6 => Key::ShiftLock,
+            0xffe7 | 0xffe8 | 0xffeb | 0xffec => Key::Meta,
+            0xffe9 | 0xffea => Key::Alt,
+            0xffff | 0xff9f => Key::Delete,
+            0x1008_ff11 => Key::VolumeDown,
+            0x1008_ff12 => Key::VolumeMute,
+            0x1008_ff13 => Key::VolumeUp,
+            0x1008_ff14 => Key::MediaPlayPause,
+            0x1008_ff15 => Key::MediaStop,
+            0x1008_ff16 => Key::MediaPrevTrack,
+            0x1008_ff17 => Key::MediaNextTrack,
+            0x1008_ffb2 => Key::MicMute,
+            _ => Key::Other(keysym),
+        }
+    }
+
+    pub(crate) mod x11 {
+        use super::super::{Callback, InputEvent, ListenerHandle};
+        use crate::api::enigo::_Button;
+        use enigo::{Axis, Coordinate, Direction, Key};
+        use x11rb::connection::{Connection, RequestConnection};
+        use x11rb::protocol::record::{self, ConnectionExt as _, Range, Range8, CS};
+        use x11rb::protocol::xproto::{
+            ButtonPressEvent, ConnectionExt as _, KeyPressEvent, MotionNotifyEvent,
+            BUTTON_PRESS_EVENT, BUTTON_RELEASE_EVENT, KEY_PRESS_EVENT, KEY_RELEASE_EVENT,
+            MOTION_NOTIFY_EVENT,
+        };
+        use x11rb::rust_connection::RustConnection;
+        use x11rb::x11_utils::TryParse;
+
+        /// Recording blocks the connection it runs on, so it needs a second
+        /// one to control it.
+        pub struct Connections {
+            control: RustConnection,
+            data: RustConnection,
+        }
+
+        pub fn connect(display: Option<&str>) -> anyhow::Result<Connections> {
+            let (control, _) = x11rb::connect(display)?;
+            let (data, _) = x11rb::connect(display)?;
+            if control
+                .extension_information(record::X11_EXTENSION_NAME)?
+                .is_none()
+            {
+                anyhow::bail!("the X server does not support the RECORD extension");
+            }
+            Ok(Connections { control, data })
+        }
+
+        /// Keysyms at the first shift level, indexed by keycode.
+        pub(crate) struct Keymap {
+            min_keycode: u8,
+            per_keycode: usize,
+            keysyms: Vec<u32>,
+        }
+
+        impl Keymap {
+            pub fn load(conn: &RustConnection) -> anyhow::Result<Self> {
+                let setup = conn.setup();
+                let (min_keycode, max_keycode) = (setup.min_keycode, setup.max_keycode);
+                let reply = conn
+                    .get_keyboard_mapping(min_keycode, max_keycode - min_keycode + 1)?
+                    .reply()?;
+                Ok(Self {
+                    min_keycode,
+                    per_keycode: reply.keysyms_per_keycode.into(),
+                    keysyms: reply.keysyms,
+                })
+            }
+
+            pub fn key(&self, keycode: u8) -> Key {
+                let index =
+                    usize::from(keycode.saturating_sub(self.min_keycode)) * self.per_keycode;
+                super::key(self.keysyms.get(index).copied().unwrap_or_default())
+            }
+
+            /// The first keycode with `keysym` at the first shift level.
+            pub fn keycode(&self, keysym: u32) -> Option<u8> {
+                let index = self
+                    .keysyms
+                    .chunks(self.per_keycode.max(1))
+                    .position(|keysyms| keysyms.first() == Some(&keysym))?;
+                u8::try_from(usize::from(self.min_keycode) + index).ok()
+            }
+        }
+
+        pub fn spawn(
+            connections: Connections,
+            mut callback: Callback,
+        ) -> anyhow::Result<ListenerHandle> {
+            let Connections { control, data } = connections;
+            let keymap = Keymap::load(&control)?;
+            let context = control.generate_id()?;
+            let range = Range {
+                device_events: Range8 {
+                    first: KEY_PRESS_EVENT,
+                    last: MOTION_NOTIFY_EVENT,
+                },
+                ..Default::default()
+            };
+            control
+                .record_create_context(context, 0, &[CS::ALL_CLIENTS.into()], &[range])?
+                .check()?;
+
+            let thread = std::thread::spawn(move || {
+                let Ok(replies) = data.record_enable_context(context) else {
+                    return;
+                };
+                for reply in replies {
+                    let Ok(reply) = reply else {
+                        break;
+                    };
+                    // Category 0 is "from server"; the rest is bookkeeping.
+                    if reply.category != 0 {
+                        continue;
+                    }
+                    for raw in reply.data.chunks_exact(32) {
+                        if let Some(event) = parse(raw, &keymap) {
+                            if !callback(event) {
+                                return;
+                            }
+                        }
+                    }
+                }
+            });
+
+            Ok(ListenerHandle {
+                interrupt: Some(Box::new(move || {
+                    let _ = control.record_disable_context(context);
+                    let _ = control.record_free_context(context);
+                    let _ = control.flush();
+                })),
+                thread: Some(thread),
+            })
+        }
+
+        fn parse(raw: &[u8], keymap: &Keymap) -> Option<InputEvent> {
+            let direction = |pressed: bool| {
+                if pressed {
+                    Direction::Press
+                } else {
+                    Direction::Release
+                }
+            };
+            match raw[0] & 0x7f {
+                kind @ (KEY_PRESS_EVENT | KEY_RELEASE_EVENT) => {
+                    let event = KeyPressEvent::try_parse(raw).ok()?.0;
+                    Some(InputEvent::Key {
+                        key: keymap.key(event.detail),
+                        raw: event.detail.into(),
+                        direction: direction(kind == KEY_PRESS_EVENT),
+                    })
+                }
+                kind @ (BUTTON_PRESS_EVENT | BUTTON_RELEASE_EVENT) => {
+                    let event = ButtonPressEvent::try_parse(raw).ok()?.0;
+                    let pressed = kind == BUTTON_PRESS_EVENT;
+                    let scroll = |length, axis| {
+                        // Wheel "buttons" are clicked; report each notch once.
+                        pressed.then_some(InputEvent::Scroll { length, axis })
+                    };
+                    let button = match event.detail {
+                        1 => enigo::Button::Left,
+                        2 => enigo::Button::Middle,
+                        3 => enigo::Button::Right,
+                        4 => return scroll(-1, Axis::Vertical),
+                        5 => return scroll(1, Axis::Vertical),
+                        6 => return scroll(-1, Axis::Horizontal),
+                        7 => return scroll(1, Axis::Horizontal),
+                        8 => enigo::Button::Back,
+                        9 => enigo::Button::Forward,
+                        _ => return None,
+                    };
+                    Some(InputEvent::Button {
+                        button: _Button { value: button },
+                        direction: direction(pressed),
+                    })
+                }
+                MOTION_NOTIFY_EVENT => {
+                    let event = MotionNotifyEvent::try_parse(raw).ok()?.0;
+                    Some(InputEvent::MouseMove {
+                        x: event.root_x.into(),
+                        y: event.root_y.into(),
+                        coordinate: Coordinate::Abs,
+                    })
+                }
+                _ => None,
+            }
+        }
+    }
+
+    #[cfg(feature = "uinput")]
+    pub(crate) mod evdev {
+        use super::super::{Callback, InputEvent, ListenerHandle};
+        use crate::api::enigo::_Button;
+        use enigo::{Axis, Coordinate, Direction};
+        use evdev::{Device, EventSummary, KeyCode, RelativeAxisCode};
+        use std::sync::atomic::{AtomicBool, Ordering};
+        use std::sync::Arc;
+        use std::time::Duration;
+
+        /// Offset between evdev and X11 keycodes, which is what `Enigo::raw`
+        /// takes on Linux.
+        pub const X11_KEYCODE_OFFSET: u16 = 8;
+
+        pub fn open_devices() -> anyhow::Result<Vec<Device>> {
+            let devices: Vec<Device> = evdev::enumerate()
+                .map(|(_, device)| device)
+                .filter(|device| {
+                    device.supported_keys().is_some() || device.supported_relative_axes().is_some()
+                })
+                .collect();
+            if devices.is_empty() {
+                anyhow::bail!("no readable input devices under /dev/input");
+            }
+            Ok(devices)
+        }
+
+        pub fn spawn(mut callback: Callback) -> anyhow::Result<ListenerHandle> {
+            let mut devices = open_devices()?;
+            for device in &devices {
+                device.set_nonblocking(true)?;
+            }
+
+            let running = Arc::new(AtomicBool::new(true));
+            let thread = {
+                let running = running.clone();
+                std::thread::spawn(move || {
+                    let mut motion = (0, 0);
+                    while running.load(Ordering::Relaxed) {
+                        for device in &mut devices {
+                            let Ok(events) = device.fetch_events() else {
+                                continue;
+                            };
+                            for event in events {
+                                for event in translate(event.destructure(), &mut motion) {
+                                    if !callback(event) {
+                                        return;
+                                    }
+                                }
+                            }
+                        }
+                        std::thread::sleep(Duration::from_millis(4));
+                    }
+                })
+            };
+
+            Ok(ListenerHandle {
+                interrupt: Some(Box::new(move || running.store(false, Ordering::Relaxed))),
+                thread: Some(thread),
+            })
+        }
+
+        /// Relative motion arrives one axis at a time; it is buffered in
+        /// `motion` and reported on the next sync.
+        pub fn translate(event: EventSummary, motion: &mut (i32, i32)) -> Vec<InputEvent> {
+            let mut events = Vec::new();
+            match event {
+                EventSummary::Key(_, code, value) => {
+                    // 2 is an auto-repeat, which a real listener would see as
+                    // another press.
+                    let direction = if value == 0 {
+                        Direction::Release
+                    } else {
+                        Direction::Press
+                    };
+                    let button = match code {
+                        KeyCode::BTN_LEFT => Some(enigo::Button::Left),
+                        KeyCode::BTN_MIDDLE => Some(enigo::Button::Middle),
+                        KeyCode::BTN_RIGHT => Some(enigo::Button::Right),
+                        KeyCode::BTN_SIDE => Some(enigo::Button::Back),
+                        KeyCode::BTN_EXTRA => Some(enigo::Button::Forward),
+                        _ => None,
+                    };
+                    events.push(match button {
+                        Some(button) => InputEvent::Button {
+                            button: _Button { value: button },
+                            direction,
+                        },
+                        None => InputEvent::Key {
+                            key: super::key(keysym(code)),
+                            raw: code.code() + X11_KEYCODE_OFFSET,
+                            direction,
+                        },
+                    });
+                }
+                EventSummary::RelativeAxis(_, axis, value) => match axis {
+                    RelativeAxisCode::REL_X => motion.0 += value,
+                    RelativeAxisCode::REL_Y => motion.1 += value,
+                    // The wheel counts up as positive, enigo counts down.
+                    RelativeAxisCode::REL_WHEEL => events.push(InputEvent::Scroll {
+                        length: -value,
+                        axis: Axis::Vertical,
+                    }),
+                    RelativeAxisCode::REL_HWHEEL => events.push(InputEvent::Scroll {
+                        length: value,
+                        axis: Axis::Horizontal,
+                    }),
+                    _ => {}
+                },
+                EventSummary::Synchronization(..) if *motion != (0, 0) => {
+                    let (x, y) = std::mem::take(motion);
+                    events.push(InputEvent::MouseMove {
+                        x,
+                        y,
+                        coordinate: Coordinate::Rel,
+                    });
+                }
+                _ => {}
+            }
+            events
+        }
+
+        /// The keysym a US layout gives `code` at the first shift level, as
+        /// the X11 path would see it; without a keymap that is the best
+        /// guess. 0 (`NoSymbol`) for codes no layout assigns a keysym to,
+        /// which are still identified by their raw keycode.
+        pub fn keysym(code: KeyCode) -> u32 {
+            match code {
+                KeyCode::KEY_ESC => 0xff1b,
+                KeyCode::KEY_BACKSPACE => 0xff08,
+                KeyCode::KEY_TAB => 0xff09,
+                KeyCode::KEY_ENTER => 0xff0d,
+                KeyCode::KEY_CAPSLOCK => 0xffe5,
+                KeyCode::KEY_LEFTCTRL => 0xffe3,
+                KeyCode::KEY_RIGHTCTRL => 0xffe4,
+                KeyCode::KEY_LEFTSHIFT => 0xffe1,
+                KeyCode::KEY_RIGHTSHIFT => 0xffe2,
+                KeyCode::KEY_LEFTALT => 0xffe9,
+                KeyCode::KEY_RIGHTALT => 0xffea,
+                KeyCode::KEY_LEFTMETA => 0xffeb,
+                KeyCode::KEY_RIGHTMETA => 0xffec,
+                KeyCode::KEY_COMPOSE => 0xff67,
+                KeyCode::KEY_UP => 0xff52,
+                KeyCode::KEY_DOWN => 0xff54,
+                KeyCode::KEY_LEFT => 0xff51,
+                KeyCode::KEY_RIGHT => 0xff53,
+                KeyCode::KEY_HOME => 0xff50,
+                KeyCode::KEY_END => 0xff57,
+                KeyCode::KEY_PAGEUP => 0xff55,
+                KeyCode::KEY_PAGEDOWN => 0xff56,
+                KeyCode::KEY_INSERT => 0xff63,
+                KeyCode::KEY_DELETE => 0xffff,
+                KeyCode::KEY_SYSRQ => 0xff61,
+                KeyCode::KEY_SCROLLLOCK => 0xff14,
+                KeyCode::KEY_PAUSE => 0xff13,
+                KeyCode::KEY_NUMLOCK => 0xff7f,
+                KeyCode::KEY_F1 => 0xffbe,
+                KeyCode::KEY_F2 => 0xffbf,
+                KeyCode::KEY_F3 => 0xffc0,
+                KeyCode::KEY_F4 => 0xffc1,
+                KeyCode::KEY_F5 => 0xffc2,
+                KeyCode::KEY_F6 => 0xffc3,
+                KeyCode::KEY_F7 => 0xffc4,
+                KeyCode::KEY_F8 => 0xffc5,
+                KeyCode::KEY_F9 => 0xffc6,
+                KeyCode::KEY_F10 => 0xffc7,
+                KeyCode::KEY_F11 => 0xffc8,
+                KeyCode::KEY_F12 => 0xffc9,
+                KeyCode::KEY_F13 => 0xffca,
+                KeyCode::KEY_F14 => 0xffcb,
+                KeyCode::KEY_F15 => 0xffcc,
+                KeyCode::KEY_F16 => 0xffcd,
+                KeyCode::KEY_F17 => 0xffce,
+                KeyCode::KEY_F18 => 0xffcf,
+                KeyCode::KEY_F19 => 0xffd0,
+                KeyCode::KEY_F20 => 0xffd1,
+                KeyCode::KEY_F21 => 0xffd2,
+                KeyCode::KEY_F22 => 0xffd3,
+                KeyCode::KEY_F23 => 0xffd4,
+                KeyCode::KEY_F24 => 0xffd5,
+                KeyCode::KEY_KP0 => 0xff9e,
+                KeyCode::KEY_KP1 => 0xff9c,
+                KeyCode::KEY_KP2 => 0xff99,
+                KeyCode::KEY_KP3 => 0xff9b,
+                KeyCode::KEY_KP4 => 0xff96,
+                KeyCode::KEY_KP5 => 0xff9d,
+                KeyCode::KEY_KP6 => 0xff98,
+                KeyCode::KEY_KP7 => 0xff95,
+                KeyCode::KEY_KP8 => 0xff97,
+                KeyCode::KEY_KP9 => 0xff9a,
+                KeyCode::KEY_KPDOT => 0xff9f,
+                KeyCode::KEY_KPENTER => 0xff8d,
+                KeyCode::KEY_KPPLUS => 0xffab,
+                KeyCode::KEY_KPMINUS => 0xffad,
+                KeyCode::KEY_KPASTERISK => 0xffaa,
+                KeyCode::KEY_KPSLASH => 0xffaf,
+                KeyCode::KEY_MUTE => 0x1008_ff12,
+                KeyCode::KEY_VOLUMEDOWN => 0x1008_ff11,
+                KeyCode::KEY_VOLUMEUP => 0x1008_ff13,
+                KeyCode::KEY_PLAYPAUSE => 0x1008_ff14,
+                KeyCode::KEY_STOPCD => 0x1008_ff15,
+                KeyCode::KEY_PREVIOUSSONG => 0x1008_ff16,
+                KeyCode::KEY_NEXTSONG => 0x1008_ff17,
+                KeyCode::KEY_MICMUTE => 0x1008_ffb2,
+                KeyCode::KEY_SPACE => u32::from(' '),
+                KeyCode::KEY_MINUS => u32::from('-'),
+                KeyCode::KEY_EQUAL => u32::from('='),
+                KeyCode::KEY_LEFTBRACE => u32::from('['),
+                KeyCode::KEY_RIGHTBRACE => u32::from(']'),
+                KeyCode::KEY_SEMICOLON => u32::from(';'),
+                KeyCode::KEY_APOSTROPHE => u32::from('\''),
+                KeyCode::KEY_GRAVE => u32::from('`'),
+                KeyCode::KEY_BACKSLASH => u32::from('\\'),
+                KeyCode::KEY_COMMA => u32::from(','),
+                KeyCode::KEY_DOT => u32::from('.'),
+                KeyCode::KEY_SLASH => u32::from('/'),
+                KeyCode::KEY_102ND => u32::from('<'),
+                KeyCode::KEY_1 => u32::from('1'),
+                KeyCode::KEY_2 => u32::from('2'),
+                KeyCode::KEY_3 => u32::from('3'),
+                KeyCode::KEY_4 => u32::from('4'),
+                KeyCode::KEY_5 => u32::from('5'),
+                KeyCode::KEY_6 => u32::from('6'),
+                KeyCode::KEY_7 => u32::from('7'),
+                KeyCode::KEY_8 => u32::from('8'),
+                KeyCode::KEY_9 => u32::from('9'),
+                KeyCode::KEY_0 => u32::from('0'),
+                KeyCode::KEY_A => u32::from('a'),
+                KeyCode::KEY_B => u32::from('b'),
+                KeyCode::KEY_C => u32::from('c'),
+                KeyCode::KEY_D => u32::from('d'),
+                KeyCode::KEY_E => u32::from('e'),
+                KeyCode::KEY_F => u32::from('f'),
+                KeyCode::KEY_G => u32::from('g'),
+                KeyCode::KEY_H => u32::from('h'),
+                KeyCode::KEY_I => u32::from('i'),
+                KeyCode::KEY_J => u32::from('j'),
+                KeyCode::KEY_K => u32::from('k'),
+                KeyCode::KEY_L => u32::from('l'),
+                KeyCode::KEY_M => u32::from('m'),
+                KeyCode::KEY_N => u32::from('n'),
+                KeyCode::KEY_O => u32::from('o'),
+                KeyCode::KEY_P => u32::from('p'),
+                KeyCode::KEY_Q => u32::from('q'),
+                KeyCode::KEY_R => u32::from('r'),
+                KeyCode::KEY_S => u32::from('s'),
+                KeyCode::KEY_T => u32::from('t'),
+                KeyCode::KEY_U => u32::from('u'),
+                KeyCode::KEY_V => u32::from('v'),
+                KeyCode::KEY_W => u32::from('w'),
+                KeyCode::KEY_X => u32::from('x'),
+                KeyCode::KEY_Y => u32::from('y'),
+                KeyCode::KEY_Z => u32::from('z'),
+                _ => 0,
+            }
+        }
+    }
+}
+
+#[cfg(target_os = "windows")]
+#[frb(ignore)]
+mod imp {
+    use super::{Callback, InputEvent, ListenerHandle};
+    use crate::api::enigo::_Button;
+    use enigo::{Axis, Coordinate, Direction, Key};
+    use std::cell::{Cell, RefCell};
+    use std::sync::mpsc;
+    use windows::Win32::Foundation::{HINSTANCE, HWND, LPARAM, LRESULT, WPARAM};
+    use windows::Win32::System::Threading::GetCurrentThreadId;
+    use windows::Win32::UI::Input::KeyboardAndMouse::{MapVirtualKeyW, MAPVK_VK_TO_CHAR};
+    use windows::Win32::UI::WindowsAndMessaging::{
+        CallNextHookEx, DispatchMessageW, GetMessageW, PostThreadMessageW, SetWindowsHookExW,
+        UnhookWindowsHookEx, HHOOK, KBDLLHOOKSTRUCT, LLKHF_EXTENDED, MSG, MSLLHOOKSTRUCT,
+        WH_KEYBOARD_LL, WH_MOUSE_LL, WM_KEYDOWN, WM_KEYUP, WM_LBUTTONDOWN, WM_LBUTTONUP,
+        WM_MBUTTONDOWN, WM_MBUTTONUP, WM_MOUSEHWHEEL, WM_MOUSEMOVE, WM_MOUSEWHEEL, WM_QUIT,
+        WM_RBUTTONDOWN, WM_RBUTTONUP, WM_SYSKEYDOWN, WM_SYSKEYUP, WM_XBUTTONDOWN, WM_XBUTTONUP,
+    };
+
+    thread_local! {
+        // Low-level hooks run on the thread that installed them and carry no
+        // user data, so the callback lives here.
+        static CALLBACK: RefCell<Option<Callback>> = RefCell::new(None);
+        // Wheel movement not yet reported as a whole notch, horizontal first.
+        static WHEEL_REMAINDERS: Cell<[i32; 2]> = const { Cell::new([0; 2]) };
+    }
+
+    /// A notch is `WHEEL_DELTA`; high-resolution wheels send less at a time.
+    const WHEEL_DELTA: i32 = 120;
+
+    /// Adds `delta` to what the wheel has moved and reports the whole notches
+    /// that makes, if any.
+    fn wheel(delta: i32, axis: Axis) -> Option<InputEvent> {
+        WHEEL_REMAINDERS.with(|remainders| {
+            let mut all = remainders.get();
+            let remainder = &mut all[(axis == Axis::Vertical) as usize];
+            *remainder += delta;
+            let length = *remainder / WHEEL_DELTA;
+            *remainder -= length * WHEEL_DELTA;
+            remainders.set(all);
+            (length != 0).then_some(InputEvent::Scroll { length, axis })
+        })
+    }
+
+    /// The named key for a virtual-key code, the character the current layout
+    /// gives it, or `Key::Other(vk)`, which enigo takes on Windows.
+    fn key(vk: u32) -> Key {
+        const FUNCTION_KEYS: [Key; 24] = [
+            Key::F1,
+            Key::F2,
+            Key::F3,
+            Key::F4,
+            Key::F5,
+            Key::F6,
+            Key::F7,
+            Key::F8,
+            Key::F9,
+            Key::F10,
+            Key::F11,
+            Key::F12,
+            Key::F13,
+            Key::F14,
+            Key::F15,
+            Key::F16,
+            Key::F17,
+            Key::F18,
+            Key::F19,
+            Key::F20,
+            Key::F21,
+            Key::F22,
+            Key::F23,
+            Key::F24,
+        ];
+        match vk {
+            0x08 => Key::Backspace,
+            0x09 => Key::Tab,
+            0x0d => Key::Return,
+            0x13 => Key::Pause,
+            0x14 => Key::CapsLock,
+            0x1b => Key::Escape,
+            0x20 => Key::Space,
+            0x21 => Key::PageUp,
+            0x22 => Key::PageDown,
+            0x23 => Key::End,
+            0x24 => Key::Home,
+            0x25 => Key::LeftArrow,
+            0x26 => Key::UpArrow,
+            0x27 => Key::RightArrow,
+            0x28 => Key::DownArrow,
+            0x2c => Key::PrintScr,
+            0x2d => Key::Insert,
+            0x2e => Key::Delete,
+            0x2f => Key::Help,
+            0x5b | 0x5c => Key::Meta,
+            0x5d => Key::Apps,
+            0x60 => Key::Numpad0,
+            0x61 => Key::Numpad1,
+            0x62 => Key::Numpad2,
+            0x63 => Key::Numpad3,
+            0x64 => Key::Numpad4,
+            0x65 => Key::Numpad5,
+            0x66 => Key::Numpad6,
+            0x67 => Key::Numpad7,
+            0x68 => Key::Numpad8,
+            0x69 => Key::Numpad9,
+            0x6a => Key::Multiply,
+            0x6b => Key::Add,
+            0x6d => Key::Subtract,
+            0x6e => Key::Decimal,
+            0x6f => Key::Divide,
+            0x70..=0x87 => FUNCTION_KEYS[(vk - 0x70) as usize],
+            0x90 => Key::Numlock,
+            0x91 => Key::Scroll,
+            0xa0 => Key::LShift,
+            0xa1 => Key::RShift,
+            0xa2 => Key::LControl,
+            0xa3 => Key::RControl,
+            0xa4 | 0xa5 => Key::Alt,
+            0xad => Key::VolumeMute,
+            0xae => Key::VolumeDown,
+            0xaf => Key::VolumeUp,
+            0xb0 => Key::MediaNextTrack,
+            0xb1 => Key::MediaPrevTrack,
+            0xb2 => Key::MediaStop,
+            0xb3 => Key::MediaPlayPause,
+            _ => {
+                // Letters come back upper case; the top bit marks dead keys.
+                let character = unsafe { MapVirtualKeyW(vk, MAPVK_VK_TO_CHAR) } & 0x7fff_ffff;
+                char::from_u32(character)
+                    .filter(|character| *character != '\0')
+                    .map_or(Key::Other(vk), |character| {
+                        Key::Unicode(character.to_ascii_lowercase())
+                    })
+            }
+        }
+    }
+
+    fn emit(event: InputEvent) {
+        CALLBACK.with(|callback| {
+            let mut callback = callback.borrow_mut();
+            if let Some(f) = callback.as_mut() {
+                if !f(event) {
+                    *callback = None;
+                    unsafe {
+                        let _ =
+                            PostThreadMessageW(GetCurrentThreadId(), WM_QUIT, WPARAM(0), LPARAM(0));
+                    }
+                }
+            }
+        });
+    }
+
+    unsafe extern "system" fn keyboard_proc(code: i32, wparam: WPARAM, lparam: LPARAM) -> LRESULT {
+        if code >= 0 {
+            let info = &*(lparam.0 as *const KBDLLHOOKSTRUCT);
+            let direction = match wparam.0 as u32 {
+                WM_KEYDOWN | WM_SYSKEYDOWN => Some(Direction::Press),
+                WM_KEYUP | WM_SYSKEYUP => Some(Direction::Release),
+                _ => None,
+            };
+            if let Some(direction) = direction {
+                // `Enigo::raw` takes scan codes, with extended ones prefixed
+                // by 0xE0.
+                let prefix = if info.flags.contains(LLKHF_EXTENDED) {
+                    0xe000
+                } else {
+                    0
+                };
+                emit(InputEvent::Key {
+                    key: key(info.vkCode),
+                    raw: prefix | info.scanCode as u16,
+                    direction,
+                });
+            }
+        }
+        CallNextHookEx(HHOOK::default(), code, wparam, lparam)
+    }
+
+    unsafe extern "system" fn mouse_proc(code: i32, wparam: WPARAM, lparam: LPARAM) -> LRESULT {
+        if code >= 0 {
+            let info = &*(lparam.0 as *const MSLLHOOKSTRUCT);
+            let high_word = (info.mouseData >> 16) as u16;
+            let button = |value, direction| {
+                Some(InputEvent::Button {
+                    button: _Button { value },
+                    direction,
+                })
+            };
+            let event = match wparam.0 as u32 {
+                WM_MOUSEMOVE => Some(InputEvent::MouseMove {
+                    x: info.pt.x,
+                    y: info.pt.y,
+                    coordinate: Coordinate::Abs,
+                }),
+                WM_LBUTTONDOWN => button(enigo::Button::Left, Direction::Press),
+                WM_LBUTTONUP => button(enigo::Button::Left, Direction::Release),
+                WM_MBUTTONDOWN => button(enigo::Button::Middle, Direction::Press),
+                WM_MBUTTONUP => button(enigo::Button::Middle, Direction::Release),
+                WM_RBUTTONDOWN => button(enigo::Button::Right, Direction::Press),
+                WM_RBUTTONUP => button(enigo::Button::Right, Direction::Release),
+                message @ (WM_XBUTTONDOWN | WM_XBUTTONUP) => {
+                    let value = if high_word == 1 {
+                        enigo::Button::Back
+                    } else {
+                        enigo::Button::Forward
+                    };
+                    let direction = if message == WM_XBUTTONDOWN {
+                        Direction::Press
+                    } else {
+                        Direction::Release
+                    };
+                    button(value, direction)
+                }
+                // The vertical wheel counts up as positive.
+                WM_MOUSEWHEEL => wheel(-i32::from(high_word as i16), Axis::Vertical),
+                WM_MOUSEHWHEEL => wheel(i32::from(high_word as i16), Axis::Horizontal),
+                _ => None,
+            };
+            if let Some(event) = event {
+                emit(event);
+            }
+        }
+        CallNextHookEx(HHOOK::default(), code, wparam, lparam)
+    }
+
+    pub fn spawn(_display: Option<&str>, callback: Callback) -> anyhow::Result<ListenerHandle> {
+        let (started, ready) = mpsc::channel();
+        let thread = std::thread::spawn(move || {
+            CALLBACK.with(|slot| *slot.borrow_mut() = Some(callback));
+            let hooks = unsafe {
+                SetWindowsHookExW(WH_KEYBOARD_LL, Some(keyboard_proc), HINSTANCE::default(), 0)
+                    .and_then(|keyboard| {
+                        SetWindowsHookExW(WH_MOUSE_LL, Some(mouse_proc), HINSTANCE::default(), 0)
+                            .map(|mouse| (keyboard, mouse))
+                            .inspect_err(|_| {
+                                let _ = UnhookWindowsHookEx(keyboard);
+                            })
+                    })
+            };
+            let (keyboard, mouse) = match hooks {
+                Ok(hooks) => hooks,
+                Err(err) => {
+                    let _ = started.send(Err(err));
+                    return;
+                }
+            };
+            let _ = started.send(Ok(unsafe { GetCurrentThreadId() }));
+
+            let mut message = MSG::default();
+            while unsafe { GetMessageW(&mut message, HWND::default(), 0, 0) }.as_bool() {
+                unsafe { DispatchMessageW(&message) };
+            }
+            unsafe {
+                let _ = UnhookWindowsHookEx(keyboard);
+                let _ = UnhookWindowsHookEx(mouse);
+            }
+        });
+
+        let thread_id = ready.recv()??;
+        Ok(ListenerHandle {
+            interrupt: Some(Box::new(move || unsafe {
+                let _ = PostThreadMessageW(thread_id, WM_QUIT, WPARAM(0), LPARAM(0));
+            })),
+            thread: Some(thread),
+        })
+    }
+}
+
+#[cfg(target_os = "macos")]
+#[frb(ignore)]
+mod imp {
+    use super::{Callback, InputEvent, ListenerHandle};
+    use crate::api::enigo::_Button;
+    use enigo::{Axis, Coordinate, Direction, Key};
+    use std::ffi::c_void;
+    use std::sync::mpsc;
+
+    type CFMachPortRef = *mut c_void;
+    type CFRunLoopRef = *mut c_void;
+    type CFRunLoopSourceRef = *mut c_void;
+    type CGEventRef = *mut c_void;
+    type CGEventTapProxy = *mut c_void;
+    type CGEventTapCallBack =
+        unsafe extern "C" fn(CGEventTapProxy, u32, CGEventRef, *mut c_void) -> CGEventRef;
+
+    #[repr(C)]
+    struct CGPoint {
+        x: f64,
+        y: f64,
+    }
+
+    const SESSION_EVENT_TAP: u32 = 1;
+    const HEAD_INSERT_EVENT_TAP: u32 = 0;
+    const LISTEN_ONLY: u32 = 1;
+
+    const LEFT_MOUSE_DOWN: u32 = 1;
+    const LEFT_MOUSE_UP: u32 = 2;
+    const RIGHT_MOUSE_DOWN: u32 = 3;
+    const RIGHT_MOUSE_UP: u32 = 4;
+    const MOUSE_MOVED: u32 = 5;
+    const LEFT_MOUSE_DRAGGED: u32 = 6;
+    const RIGHT_MOUSE_DRAGGED: u32 = 7;
+    const KEY_DOWN: u32 = 10;
+    const KEY_UP: u32 = 11;
+    const FLAGS_CHANGED: u32 = 12;
+    const SCROLL_WHEEL: u32 = 22;
+    const OTHER_MOUSE_DOWN: u32 = 25;
+    const OTHER_MOUSE_UP: u32 = 26;
+    const OTHER_MOUSE_DRAGGED: u32 = 27;
+
+    const FIELD_MOUSE_BUTTON_NUMBER: u32 = 3;
+    const FIELD_KEYCODE: u32 = 9;
+    const FIELD_SCROLL_DELTA_AXIS_1: u32 = 11;
+    const FIELD_SCROLL_DELTA_AXIS_2: u32 = 12;
+
+    #[link(name = "ApplicationServices", kind = "framework")]
+    extern "C" {
+        fn CGEventTapCreate(
+            tap: u32,
+            place: u32,
+            options: u32,
+            events_of_interest: u64,
+            callback: CGEventTapCallBack,
+            user_info: *mut c_void,
+        ) -> CFMachPortRef;
+        fn CGEventTapEnable(tap: CFMachPortRef, enable: bool);
+        fn CGEventGetIntegerValueField(event: CGEventRef, field: u32) -> i64;
+        fn CGEventGetFlags(event: CGEventRef) -> u64;
+        fn CGEventGetLocation(event: CGEventRef) -> CGPoint;
+        fn CGEventKeyboardGetUnicodeString(
+            event: CGEventRef,
+            max_length: usize,
+            actual_length: *mut usize,
+            string: *mut u16,
+        );
+    }
+
+    #[link(name = "CoreFoundation", kind = "framework")]
+    extern "C" {
+        static kCFRunLoopCommonModes: *const c_void;
+        fn CFMachPortCreateRunLoopSource(
+            allocator: *const c_void,
+            port: CFMachPortRef,
+            order: isize,
+        ) -> CFRunLoopSourceRef;
+        fn CFRunLoopGetCurrent() -> CFRunLoopRef;
+        fn CFRunLoopAddSource(rl: CFRunLoopRef, source: CFRunLoopSourceRef, mode: *const c_void);
+        fn CFRunLoopRun();
+        fn CFRunLoopStop(rl: CFRunLoopRef);
+        fn CFRelease(cf: *const c_void);
+    }
+
+    struct Tap {
+        callback: Callback,
+        run_loop: CFRunLoopRef,
+    }
+
+    /// Modifiers only report a flags change; whether it was a press depends on
+    /// the flag belonging to the keycode.
+    fn modifier_flag(keycode: i64) -> Option<u64> {
+        Some(match keycode {
+            56 | 60 => 0x0002_0000,
+            59 | 62 => 0x0004_0000,
+            58 | 61 => 0x0008_0000,
+            55 | 54 => 0x0010_0000,
+            57 => 0x0001_0000,
+            63 => 0x0080_0000,
+            _ => return None,
+        })
+    }
+
+    /// The named key for a virtual keycode, the character the event types,
+    /// or `Key::Other(keycode)`, which enigo takes on macOS.
+    unsafe fn key(keycode: i64, event: CGEventRef) -> Key {
+        match keycode {
+            0x24 | 0x4c => Key::Return,
+            0x30 => Key::Tab,
+            0x31 => Key::Space,
+            0x33 => Key::Backspace,
+            0x35 => Key::Escape,
+            0x36 | 0x37 => Key::Meta,
+            0x38 => Key::LShift,
+            0x39 => Key::CapsLock,
+            0x3a | 0x3d => Key::Alt,
+            0x3b => Key::LControl,
+            0x3c => Key::RShift,
+            0x3e => Key::RControl,
+            0x3f => Key::Function,
+            0x40 => Key::F17,
+            0x48 => Key::VolumeUp,
+            0x49 => Key::VolumeDown,
+            0x4a => Key::VolumeMute,
+            0x4f => Key::F18,
+            0x50 => Key::F19,
+            0x5a => Key::F20,
+            0x60 => Key::F5,
+            0x61 => Key::F6,
+            0x62 => Key::F7,
+            0x63 => Key::F3,
+            0x64 => Key::F8,
+            0x65 => Key::F9,
+            0x67 => Key::F11,
+            0x69 => Key::F13,
+            0x6a => Key::F16,
+            0x6b => Key::F14,
+            0x6d => Key::F10,
+            0x6f => Key::F12,
+            0x71 => Key::F15,
+            0x72 => Key::Help,
+            0x73 => Key::Home,
+            0x74 => Key::PageUp,
+            0x75 => Key::Delete,
+            0x76 => Key::F4,
+            0x77 => Key::End,
+            0x78 => Key::F2,
+            0x79 => Key::PageDown,
+            0x7a => Key::F1,
+            0x7b => Key::LeftArrow,
+            0x7c => Key::RightArrow,
+            0x7d => Key::DownArrow,
+            0x7e => Key::UpArrow,
+            _ => {
+                let mut string = [0u16; 4];
+                let mut length = 0;
+                CGEventKeyboardGetUnicodeString(
+                    event,
+                    string.len(),
+                    &mut length,
+                    string.as_mut_ptr(),
+                );
+                // Letters typed with Shift come back upper case.
+                char::decode_utf16(string[..length.min(string.len())].iter().copied())
+                    .next()
+                    .and_then(Result::ok)
+                    .filter(|character| !character.is_control())
+                    .map_or(Key::Other(keycode as u32), |character| {
+                        Key::Unicode(character.to_ascii_lowercase())
+                    })
+            }
+        }
+    }
+
+    unsafe fn translate(kind: u32, event: CGEventRef) -> Option<InputEvent> {
+        let button = |value, direction| {
+            Some(InputEvent::Button {
+                button: _Button { value },
+                direction,
+            })
+        };
+        let keyboard = |direction| {
+            let keycode = CGEventGetIntegerValueField(event, FIELD_KEYCODE);
+            Some(InputEvent::Key {
+                key: key(keycode, event),
+                raw: keycode as u16,
+                direction,
+            })
+        };
+        match kind {
+            KEY_DOWN => keyboard(Direction::Press),
+            KEY_UP => keyboard(Direction::Release),
+            FLAGS_CHANGED => {
+                let keycode = CGEventGetIntegerValueField(event, FIELD_KEYCODE);
+                let flag = modifier_flag(keycode)?;
+                if CGEventGetFlags(event) & flag != 0 {
+                    keyboard(Direction::Press)
+                } else {
+                    keyboard(Direction::Release)
+                }
+            }
+            LEFT_MOUSE_DOWN => button(enigo::Button::Left, Direction::Press),
+            LEFT_MOUSE_UP => button(enigo::Button::Left, Direction::Release),
+            RIGHT_MOUSE_DOWN => button(enigo::Button::Right, Direction::Press),
+            RIGHT_MOUSE_UP => button(enigo::Button::Right, Direction::Release),
+            OTHER_MOUSE_DOWN | OTHER_MOUSE_UP => {
+                if CGEventGetIntegerValueField(event, FIELD_MOUSE_BUTTON_NUMBER) != 2 {
+                    return None;
+                }
+                let direction = if kind == OTHER_MOUSE_DOWN {
+                    Direction::Press
+                } else {
+                    Direction::Release
+                };
+                button(enigo::Button::Middle, direction)
+            }
+            MOUSE_MOVED | LEFT_MOUSE_DRAGGED | RIGHT_MOUSE_DRAGGED | OTHER_MOUSE_DRAGGED => {
+                let location = CGEventGetLocation(event);
+                Some(InputEvent::MouseMove {
+                    x: location.x as i32,
+                    y: location.y as i32,
+                    coordinate: Coordinate::Abs,
+                })
+            }
+            // Axis 1 is vertical and counts up as positive.
+            SCROLL_WHEEL => {
+                let vertical = CGEventGetIntegerValueField(event, FIELD_SCROLL_DELTA_AXIS_1);
+                let horizontal = CGEventGetIntegerValueField(event, FIELD_SCROLL_DELTA_AXIS_2);
+                if vertical != 0 {
+                    Some(InputEvent::Scroll {
+                        length: -vertical as i32,
+                        axis: Axis::Vertical,
+                    })
+                } else {
+                    Some(InputEvent::Scroll {
+                        length: -horizontal as i32,
+                        axis: Axis::Horizontal,
+                    })
+                }
+            }
+            _ => None,
+        }
+    }
+
+    unsafe extern "C" fn tap_callback(
+        _proxy: CGEventTapProxy,
+        kind: u32,
+        event: CGEventRef,
+        user_info: *mut c_void,
+    ) -> CGEventRef {
+        let tap = &mut *(user_info as *mut Tap);
+        if let Some(translated) = translate(kind, event) {
+            if !(tap.callback)(translated) {
+                CFRunLoopStop(tap.run_loop);
+            }
+        }
+        event
+    }
+
+    struct SendRunLoop(CFRunLoopRef);
+
+    unsafe impl Send for SendRunLoop {}
+
+    impl SendRunLoop {
+        fn stop(&self) {
+            unsafe { CFRunLoopStop(self.0) }
+        }
+    }
+
+    pub fn spawn(_display: Option<&str>, callback: Callback) -> anyhow::Result<ListenerHandle> {
+        let (started, ready) = mpsc::channel();
+        let thread = std::thread::spawn(move || unsafe {
+            let run_loop = CFRunLoopGetCurrent();
+            let tap = Box::into_raw(Box::new(Tap { callback, run_loop }));
+            let mask = [
+                LEFT_MOUSE_DOWN,
+                LEFT_MOUSE_UP,
+                RIGHT_MOUSE_DOWN,
+                RIGHT_MOUSE_UP,
+                MOUSE_MOVED,
+                LEFT_MOUSE_DRAGGED,
+                RIGHT_MOUSE_DRAGGED,
+                KEY_DOWN,
+                KEY_UP,
+                FLAGS_CHANGED,
+                SCROLL_WHEEL,
+                OTHER_MOUSE_DOWN,
+                OTHER_MOUSE_UP,
+                OTHER_MOUSE_DRAGGED,
+            ]
+            .iter()
+            .fold(0u64, |mask, kind| mask | 1 << kind);
+            let port = CGEventTapCreate(
+                SESSION_EVENT_TAP,
+                HEAD_INSERT_EVENT_TAP,
+                LISTEN_ONLY,
+                mask,
+                tap_callback,
+                tap.cast(),
+            );
+            if port.is_null() {
+                drop(Box::from_raw(tap));
+                let _ = started.send(Err(anyhow::anyhow!(
+                    "could not create an event tap; is the app trusted for accessibility?"
+                )));
+                return;
+            }
+            let source = CFMachPortCreateRunLoopSource(std::ptr::null(), port, 0);
+            CFRunLoopAddSource(run_loop, source, kCFRunLoopCommonModes);
+            CGEventTapEnable(port, true);
+            let _ = started.send(Ok(SendRunLoop(run_loop)));
+
+            CFRunLoopRun();
+
+            CGEventTapEnable(port, false);
+            CFRelease(source);
+            CFRelease(port);
+            drop(Box::from_raw(tap));
+        });
+
+        let run_loop = ready.recv()??;
+        Ok(ListenerHandle {
+            interrupt: Some(Box::new(move || run_loop.stop())),
+            thread: Some(thread),
+        })
+    }
+}
+
+#[cfg(all(test, target_os = "linux"))]
+mod tests {
+    use super::imp::key;
+    use enigo::Key;
+
+    #[test]
+    fn space_is_named() {
+        assert_eq!(key(0x20), Key::Space);
+        assert_eq!(key(0x61), Key::Unicode('a'));
+    }
+
+    #[test]
+    fn keypad_keys_are_what_they_type() {
+        assert_eq!(key(0xffb7), Key::Unicode('7'));
+        assert_eq!(key(0xffab), Key::Unicode('+'));
+        assert_eq!(key(0xffaf), Key::Unicode('/'));
+        assert_eq!(key(0xff8d), Key::Return);
+        assert_eq!(key(0xff95), Key::Home);
+        assert_eq!(key(0xff9f), Key::Delete);
+    }
+}
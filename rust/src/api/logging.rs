@@ -0,0 +1,269 @@
+use crate::frb_generated::StreamSink;
+use flutter_rust_bridge::*;
+use std::fmt;
+use std::io::Write as _;
+use std::sync::{Mutex, MutexGuard, Once, PoisonError};
+use std::time::{SystemTime, UNIX_EPOCH};
+use tracing::field::{Field, Visit};
+use tracing::span::{Attributes, Id, Record};
+use tracing::subscriber::Interest;
+use tracing::{Event, Level, Metadata, Subscriber};
+use tracing_appender::rolling::{RollingFileAppender, Rotation};
+use tracing_subscriber::layer::{Context, Layer, SubscriberExt};
+use tracing_subscriber::registry::LookupSpan;
+
+/// Severity of a [`LogRecord`], least severe first.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+pub enum LogLevel {
+    Trace,
+    Debug,
+    Info,
+    Warn,
+    Error,
+}
+
+/// An event logged by this library, with the fields of the spans it
+/// happened in: for a call on `Enigo`, the call's parameters followed by the
+/// backend, latency and result.
+pub struct LogRecord {
+    /// Milliseconds since the Unix epoch.
+    pub timestamp_ms: u64,
+    pub level: LogLevel,
+    /// Module the event came from, e.g. `enigo_flutter::api::enigo`.
+    pub target: String,
+    /// Names of the spans the event happened in, outermost first.
+    pub spans: Vec<String>,
+    pub message: String,
+    /// Fields of the spans and then of the event, as name and value.
+    pub fields: Vec<(String, String)>,
+}
+
+/// How often [`log_to_file`] starts a new file.
+pub enum LogRotation {
+    Minutely,
+    Hourly,
+    Daily,
+    Never,
+}
+
+/// Where records go, each with the least severe level it takes.
+struct Sinks {
+    dart: Option<(StreamSink<LogRecord>, LogLevel)>,
+    file: Option<(RollingFileAppender, LogLevel)>,
+}
+
+static SINKS: Mutex<Sinks> = Mutex::new(Sinks {
+    dart: None,
+    file: None,
+});
+
+/// Send records at `level` and above to Dart, replacing an earlier stream.
+#[frb(sync)]
+pub fn log_stream(sink: StreamSink<LogRecord>, level: LogLevel) {
+    install();
+    sinks().dart = Some((sink, level));
+}
+
+#[frb(sync)]
+pub fn stop_log_stream() {
+    sinks().dart = None;
+}
+
+/// Also write records at `level` and above to `<prefix>.<date>.log` in
+/// `directory`, starting a new file as `rotation` says and deleting the
+/// oldest once there are more than `max_files` (0 keeps them all).
+#[frb(sync)]
+pub fn log_to_file(
+    directory: String,
+    prefix: String,
+    rotation: LogRotation,
+    max_files: u32,
+    level: LogLevel,
+) -> anyhow::Result<()> {
+    let rotation = match rotation {
+        LogRotation::Minutely => Rotation::MINUTELY,
+        LogRotation::Hourly => Rotation::HOURLY,
+        LogRotation::Daily => Rotation::DAILY,
+        LogRotation::Never => Rotation::NEVER,
+    };
+    let mut builder = RollingFileAppender::builder()
+        .rotation(rotation)
+        .filename_prefix(prefix)
+        .filename_suffix("log");
+    if max_files > 0 {
+        builder = builder.max_log_files(max_files as usize);
+    }
+    let appender = builder.build(directory)?;
+    install();
+    sinks().file = Some((appender, level));
+    Ok(())
+}
+
+#[frb(sync)]
+pub fn stop_log_file() {
+    sinks().file = None;
+}
+
+/// Make [`Forward`] the global subscriber the first time a sink is added.
+fn install() {
+    static INSTALL: Once = Once::new();
+    INSTALL.call_once(|| {
+        // Fails if the app set a subscriber of its own first, which then
+        // gets the records instead.
+        let _ =
+            tracing::subscriber::set_global_default(tracing_subscriber::registry().with(Forward));
+    });
+}
+
+fn sinks() -> MutexGuard<'static, Sinks> {
+    SINKS.lock().unwrap_or_else(PoisonError::into_inner)
+}
+
+impl Sinks {
+    fn wants(&self, level: LogLevel) -> bool {
+        let dart = self.dart.as_ref().map(|(_, min)| *min);
+        let file = self.file.as_ref().map(|(_, min)| *min);
+        [dart, file].into_iter().flatten().any(|min| level >= min)
+    }
+}
+
+impl From<&Level> for LogLevel {
+    fn from(level: &Level) -> Self {
+        match *level {
+            Level::TRACE => Self::Trace,
+            Level::DEBUG => Self::Debug,
+            Level::INFO => Self::Info,
+            Level::WARN => Self::Warn,
+            Level::ERROR => Self::Error,
+        }
+    }
+}
+
+impl LogRecord {
+    /// `<timestamp_ms> <LEVEL> <target> <span:span>: <message> name=value ...`
+    fn line(&self) -> String {
+        let mut line = format!(
+            "{} {:?} {} {}: {}",
+            self.timestamp_ms,
+            self.level,
+            self.target,
+            self.spans.join(":"),
+            self.message
+        );
+        for (name, value) in &self.fields {
+            line += &format!(" {name}={value}");
+        }
+        line
+    }
+}
+
+/// Hands records to the [`Sinks`]. Their levels can change at any time, so
+/// nothing is filtered once per callsite.
+struct Forward;
+
+/// Formatted fields of a span, kept in its extensions.
+struct SpanFields(Vec<(String, String)>);
+
+impl<S> Layer<S> for Forward
+where
+    S: Subscriber + for<'a> LookupSpan<'a>,
+{
+    fn register_callsite(&self, _metadata: &'static Metadata<'static>) -> Interest {
+        Interest::sometimes()
+    }
+
+    fn enabled(&self, metadata: &Metadata<'_>, _ctx: Context<'_, S>) -> bool {
+        // Spans are always kept so events below the level of their span
+        // still get its fields. `try_lock` drops events logged while a
+        // record is being forwarded instead of deadlocking.
+        metadata.is_span()
+            || SINKS
+                .try_lock()
+                .is_ok_and(|sinks| sinks.wants(metadata.level().into()))
+    }
+
+    fn on_new_span(&self, attrs: &Attributes<'_>, id: &Id, ctx: Context<'_, S>) {
+        let mut visitor = FieldVisitor::default();
+        attrs.record(&mut visitor);
+        if let Some(span) = ctx.span(id) {
+            span.extensions_mut().insert(SpanFields(visitor.fields));
+        }
+    }
+
+    fn on_record(&self, id: &Id, values: &Record<'_>, ctx: Context<'_, S>) {
+        let mut visitor = FieldVisitor::default();
+        values.record(&mut visitor);
+        if let Some(span) = ctx.span(id) {
+            if let Some(SpanFields(fields)) = span.extensions_mut().get_mut::<SpanFields>() {
+                fields.extend(visitor.fields);
+            }
+        }
+    }
+
+    fn on_event(&self, event: &Event<'_>, ctx: Context<'_, S>) {
+        let level = LogLevel::from(event.metadata().level());
+        let mut visitor = FieldVisitor::default();
+        event.record(&mut visitor);
+        let mut spans = Vec::new();
+        let mut fields = Vec::new();
+        if let Some(scope) = ctx.event_scope(event) {
+            for span in scope.from_root() {
+                spans.push(span.name().to_owned());
+                if let Some(SpanFields(span_fields)) = span.extensions().get::<SpanFields>() {
+                    fields.extend(span_fields.iter().cloned());
+                }
+            }
+        }
+        fields.extend(visitor.fields);
+        let record = LogRecord {
+            timestamp_ms: SystemTime::now()
+                .duration_since(UNIX_EPOCH)
+                .unwrap_or_default()
+                .as_millis() as u64,
+            level,
+            target: event.metadata().target().to_owned(),
+            spans,
+            message: visitor.message,
+            fields,
+        };
+
+        let mut sinks = sinks();
+        if let Some((file, min)) = &mut sinks.file {
+            if level >= *min {
+                let _ = writeln!(file, "{}", record.line());
+            }
+        }
+        if let Some((sink, min)) = &sinks.dart {
+            if level >= *min {
+                let _ = sink.add(record);
+            }
+        }
+    }
+}
+
+#[frb(ignore)]
+#[derive(Default)]
+struct FieldVisitor {
+    message: String,
+    fields: Vec<(String, String)>,
+}
+
+impl FieldVisitor {
+    fn push(&mut self, field: &Field, value: String) {
+        if field.name() == "message" {
+            self.message = value;
+        } else {
+            self.fields.push((field.name().to_owned(), value));
+        }
+    }
+}
+
+impl Visit for FieldVisitor {
+    fn record_str(&mut self, field: &Field, value: &str) {
+        self.push(field, value.to_owned());
+    }
+
+    fn record_debug(&mut self, field: &Field, value: &dyn fmt::Debug) {
+        self.push(field, format!("{value:?}"));
+    }
+}
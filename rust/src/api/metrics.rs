@@ -0,0 +1,307 @@
+use enigo::InputError;
+use flutter_rust_bridge::*;
+use serde::Serialize;
+use std::collections::BTreeMap;
+use std::fmt::Write as _;
+use std::time::Duration;
+
+/// Upper bounds of the histogram buckets, in microseconds.
+const BUCKETS_US: [u64; 14] = [
+    10, 50, 100, 250, 500, 1_000, 2_500, 5_000, 10_000, 25_000, 50_000, 100_000, 250_000, 1_000_000,
+];
+
+/// Timings and outcomes of the calls made on an [`Enigo`], per operation;
+/// see [`Enigo::metrics`].
+///
+/// [`Enigo`]: super::enigo::Enigo
+/// [`Enigo::metrics`]: super::enigo::Enigo::metrics
+#[derive(Clone, Serialize)]
+pub struct Metrics {
+    pub operations: Vec<OperationMetrics>,
+}
+
+#[derive(Clone, Serialize)]
+pub struct OperationMetrics {
+    /// The `Enigo` method, e.g. `move_mouse`.
+    pub operation: String,
+    /// From the call until the worker thread picked it up.
+    pub lock_wait: Histogram,
+    /// Each call into the backend; an operation may make several.
+    pub backend_call: Histogram,
+    /// From the call until its result was back with the caller.
+    pub end_to_end: Histogram,
+    /// Backend calls that succeeded.
+    pub successes: u64,
+    /// Backend calls that failed and calls the policy refused, by kind:
+    /// `mapping`, `unmapping`, `no_empty_keycodes`, `simulate`,
+    /// `invalid_input` or `policy`.
+    pub failures: Vec<(String, u64)>,
+}
+
+#[derive(Clone, Default, Serialize)]
+pub struct Histogram {
+    pub count: u64,
+    pub sum_us: u64,
+    pub max_us: u64,
+    /// Upper bound in microseconds and how many observations were at most
+    /// that long, like Prometheus' `le` buckets.
+    pub buckets: Vec<(u64, u64)>,
+}
+
+impl Metrics {
+    /// The Prometheus text exposition format, with durations in seconds.
+    #[frb(sync)]
+    pub fn to_prometheus(&self) -> String {
+        let mut out = String::new();
+        let histograms: [(&str, &str, fn(&OperationMetrics) -> &Histogram); 3] = [
+            (
+                "enigo_lock_wait_seconds",
+                "Time calls waited for the enigo worker thread.",
+                |operation| &operation.lock_wait,
+            ),
+            (
+                "enigo_backend_call_seconds",
+                "Duration of calls into the input backend.",
+                |operation| &operation.backend_call,
+            ),
+            (
+                "enigo_end_to_end_seconds",
+                "Duration of Enigo calls as seen by the caller.",
+                |operation| &operation.end_to_end,
+            ),
+        ];
+        for (name, help, histogram) in histograms {
+            let _ = writeln!(out, "# HELP {name} {help}\n# TYPE {name} histogram");
+            for operation in &self.operations {
+                let label = &operation.operation;
+                let histogram = histogram(operation);
+                for &(le_us, count) in &histogram.buckets {
+                    let le = le_us as f64 / 1e6;
+                    let _ = writeln!(
+                        out,
+                        "{name}_bucket{{operation=\"{label}\",le=\"{le}\"}} {count}"
+                    );
+                }
+                let (count, sum) = (histogram.count, histogram.sum_us as f64 / 1e6);
+                let _ = writeln!(
+                    out,
+                    "{name}_bucket{{operation=\"{label}\",le=\"+Inf\"}} {count}\n\
+                     {name}_sum{{operation=\"{label}\"}} {sum}\n\
+                     {name}_count{{operation=\"{label}\"}} {count}"
+                );
+            }
+        }
+        out += "# HELP enigo_calls_total Backend calls and policy refusals by result.\n\
+                # TYPE enigo_calls_total counter\n";
+        for operation in &self.operations {
+            let label = &operation.operation;
+            let results = [("ok".to_owned(), operation.successes)];
+            for (result, count) in results.iter().chain(&operation.failures) {
+                let _ = writeln!(
+                    out,
+                    "enigo_calls_total{{operation=\"{label}\",result=\"{result}\"}} {count}"
+                );
+            }
+        }
+        out
+    }
+
+    #[frb(sync)]
+    pub fn to_json(&self) -> anyhow::Result<String> {
+        Ok(serde_json::to_string_pretty(self)?)
+    }
+}
+
+/// Which of [`Registry`]'s histograms an observation goes to.
+#[frb(ignore)]
+#[derive(Clone, Copy)]
+pub(crate) enum Stage {
+    LockWait,
+    BackendCall,
+    EndToEnd,
+}
+
+/// Where an `Enigo` collects its [`Metrics`].
+#[frb(ignore)]
+#[derive(Default)]
+pub(crate) struct Registry {
+    operations: BTreeMap<&'static str, Counters>,
+}
+
+#[frb(ignore)]
+#[derive(Default)]
+struct Counters {
+    histograms: [Buckets; 3],
+    successes: u64,
+    failures: BTreeMap<&'static str, u64>,
+}
+
+#[frb(ignore)]
+#[derive(Default)]
+struct Buckets {
+    /// Per bucket, not cumulative; the last one is everything longer than
+    /// the last bound. A literal length, since the codegen can't parse
+    /// `BUCKETS_US.len() + 1`.
+    counts: [u64; 15],
+    sum_us: u64,
+    max_us: u64,
+}
+
+impl Registry {
+    pub fn observe(&mut self, operation: &'static str, stage: Stage, duration: Duration) {
+        let us = duration.as_micros() as u64;
+        let buckets = &mut self.counters(operation).histograms[stage as usize];
+        let index = BUCKETS_US.partition_point(|&bound| bound < us);
+        buckets.counts[index] += 1;
+        buckets.sum_us += us;
+        buckets.max_us = buckets.max_us.max(us);
+    }
+
+    pub fn succeeded(&mut self, operation: &'static str) {
+        self.counters(operation).successes += 1;
+    }
+
+    pub fn failed(&mut self, operation: &'static str, kind: &'static str) {
+        *self.counters(operation).failures.entry(kind).or_default() += 1;
+    }
+
+    pub fn snapshot(&self) -> Metrics {
+        let operations = self
+            .operations
+            .iter()
+            .map(|(operation, counters)| {
+                let [lock_wait, backend_call, end_to_end] =
+                    counters.histograms.each_ref().map(Buckets::histogram);
+                OperationMetrics {
+                    operation: (*operation).to_owned(),
+                    lock_wait,
+                    backend_call,
+                    end_to_end,
+                    successes: counters.successes,
+                    failures: counters
+                        .failures
+                        .iter()
+                        .map(|(kind, count)| ((*kind).to_owned(), *count))
+                        .collect(),
+                }
+            })
+            .collect();
+        Metrics { operations }
+    }
+
+    fn counters(&mut self, operation: &'static str) -> &mut Counters {
+        self.operations.entry(operation).or_default()
+    }
+}
+
+impl Buckets {
+    fn histogram(&self) -> Histogram {
+        let mut cumulative = 0;
+        let buckets = BUCKETS_US
+            .iter()
+            .zip(&self.counts)
+            .map(|(&bound, &count)| {
+                cumulative += count;
+                (bound, cumulative)
+            })
+            .collect();
+        Histogram {
+            count: self.counts.iter().sum(),
+            sum_us: self.sum_us,
+            max_us: self.max_us,
+            buckets,
+        }
+    }
+}
+
+/// The failure kind [`Metrics`] counts `error` under.
+pub(crate) fn input_error_kind(error: &InputError) -> &'static str {
+    match error {
+        InputError::Mapping(_) => "mapping",
+        InputError::Unmapping(_) => "unmapping",
+        InputError::NoEmptyKeycodes => "no_empty_keycodes",
+        InputError::Simulate(_) => "simulate",
+        InputError::InvalidInput(_) => "invalid_input",
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn micros(us: u64) -> Duration {
+        Duration::from_micros(us)
+    }
+
+    #[test]
+    fn observations_land_in_cumulative_buckets() {
+        let mut registry = Registry::default();
+        for us in [10, 11, 50, 2_000_000] {
+            registry.observe("key", Stage::BackendCall, micros(us));
+        }
+        registry.observe("key", Stage::EndToEnd, micros(3));
+        let metrics = registry.snapshot();
+        let [key] = metrics.operations.as_slice() else {
+            panic!("expected one operation");
+        };
+        let histogram = &key.backend_call;
+        assert_eq!(histogram.count, 4);
+        assert_eq!(histogram.sum_us, 2_000_071);
+        assert_eq!(histogram.max_us, 2_000_000);
+        assert_eq!(histogram.buckets.len(), BUCKETS_US.len());
+        assert_eq!(histogram.buckets[0], (10, 1));
+        assert_eq!(histogram.buckets[1], (50, 3));
+        // Longer than every bound only counts towards `+Inf`.
+        assert_eq!(histogram.buckets.last(), Some(&(1_000_000, 3)));
+        assert_eq!(key.end_to_end.buckets[0], (10, 1));
+        assert_eq!(key.lock_wait.count, 0);
+    }
+
+    #[test]
+    fn outcomes_are_counted_per_operation() {
+        let mut registry = Registry::default();
+        registry.succeeded("text");
+        registry.succeeded("text");
+        registry.failed("text", "policy");
+        registry.failed("key", input_error_kind(&InputError::Simulate("x")));
+        registry.failed("key", "simulate");
+        let metrics = registry.snapshot();
+        let names: Vec<_> = metrics.operations.iter().map(|o| &*o.operation).collect();
+        assert_eq!(names, ["key", "text"]);
+        assert_eq!(metrics.operations[0].successes, 0);
+        assert_eq!(metrics.operations[0].failures, [("simulate".to_owned(), 2)]);
+        assert_eq!(metrics.operations[1].successes, 2);
+        assert_eq!(metrics.operations[1].failures, [("policy".to_owned(), 1)]);
+    }
+
+    #[test]
+    fn prometheus_output() {
+        let mut registry = Registry::default();
+        registry.observe("move_mouse", Stage::LockWait, micros(40));
+        registry.observe("move_mouse", Stage::LockWait, micros(2_000_000));
+        registry.succeeded("move_mouse");
+        registry.failed("move_mouse", "policy");
+        let out = registry.snapshot().to_prometheus();
+        let lines: Vec<_> = out.lines().collect();
+        for expected in [
+            "# TYPE enigo_lock_wait_seconds histogram",
+            "enigo_lock_wait_seconds_bucket{operation=\"move_mouse\",le=\"0.00001\"} 0",
+            "enigo_lock_wait_seconds_bucket{operation=\"move_mouse\",le=\"0.00005\"} 1",
+            "enigo_lock_wait_seconds_bucket{operation=\"move_mouse\",le=\"1\"} 1",
+            "enigo_lock_wait_seconds_bucket{operation=\"move_mouse\",le=\"+Inf\"} 2",
+            "enigo_lock_wait_seconds_sum{operation=\"move_mouse\"} 2.00004",
+            "enigo_lock_wait_seconds_count{operation=\"move_mouse\"} 2",
+            "enigo_end_to_end_seconds_count{operation=\"move_mouse\"} 0",
+            "# TYPE enigo_calls_total counter",
+            "enigo_calls_total{operation=\"move_mouse\",result=\"ok\"} 1",
+            "enigo_calls_total{operation=\"move_mouse\",result=\"policy\"} 1",
+        ] {
+            assert!(lines.contains(&expected), "missing {expected:?} in\n{out}");
+        }
+        // Every sample line is `name{labels} value`.
+        for line in lines.iter().filter(|line| !line.starts_with('#')) {
+            let (_, value) = line.rsplit_once(' ').unwrap();
+            assert!(value.parse::<f64>().is_ok(), "{line}");
+        }
+    }
+}
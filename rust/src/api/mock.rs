@@ -0,0 +1,223 @@
+use enigo::{Axis, Coordinate, Direction, InputResult, Key, Keyboard, Mouse, Settings};
+use flutter_rust_bridge::*;
+use std::time::Instant;
+
+use super::backend::InputBackend;
+use super::enigo::_Button;
+use super::system::{LockState, ModifierState};
+
+/// Display size of a mock backend that wasn't given one.
+pub(crate) const DEFAULT_DISPLAY: (i32, i32) = (1920, 1080);
+
+/// A call the mock backend received.
+#[derive(Debug, Clone, PartialEq)]
+pub struct MockCall {
+    /// Microseconds since the mock backend was created.
+    pub timestamp_us: u64,
+    pub action: MockAction,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub enum MockAction {
+    Key {
+        key: Key,
+        direction: Direction,
+    },
+    Raw {
+        keycode: u16,
+        direction: Direction,
+    },
+    Button {
+        button: _Button,
+        direction: Direction,
+    },
+    MoveMouse {
+        x: i32,
+        y: i32,
+        coordinate: Coordinate,
+    },
+    Scroll {
+        length: i32,
+        axis: Axis,
+    },
+    Text {
+        text: String,
+    },
+}
+
+impl MockAction {
+    /// The `Enigo` method that sends this action, as named in its metrics.
+    #[cfg(feature = "server")]
+    pub(crate) fn operation(&self) -> &'static str {
+        match self {
+            MockAction::Key { .. } => "key",
+            MockAction::Raw { .. } => "raw",
+            MockAction::Button { .. } => "button",
+            MockAction::MoveMouse { .. } => "move_mouse",
+            MockAction::Scroll { .. } => "scroll",
+            MockAction::Text { .. } => "text",
+        }
+    }
+}
+
+/// Records calls instead of sending input, with a simulated cursor that
+/// stays on a display of the configured size, and lock keys and modifiers
+/// that follow the keys it receives.
+#[frb(ignore)]
+pub(crate) struct MockBackend {
+    pub display: (i32, i32),
+    pub location: (i32, i32),
+    pub calls: Vec<MockCall>,
+    lock_state: LockState,
+    /// Keys pressed and not released yet.
+    pressed: Vec<Key>,
+    start: Instant,
+}
+
+impl MockBackend {
+    pub fn new(display: (i32, i32)) -> Self {
+        Self {
+            display,
+            location: (display.0 / 2, display.1 / 2),
+            calls: Vec::new(),
+            lock_state: LockState::default(),
+            pressed: Vec::new(),
+            start: Instant::now(),
+        }
+    }
+
+    fn record(&mut self, action: MockAction) {
+        self.calls.push(MockCall {
+            timestamp_us: self.start.elapsed().as_micros() as u64,
+            action,
+        });
+    }
+}
+
+impl InputBackend for MockBackend {
+    fn mock(&mut self) -> Option<&mut MockBackend> {
+        Some(self)
+    }
+
+    fn lock_state(&self, _settings: &Settings) -> anyhow::Result<LockState> {
+        Ok(self.lock_state)
+    }
+
+    fn modifier_state(&self, _settings: &Settings) -> anyhow::Result<ModifierState> {
+        let held = |keys: &[Key]| self.pressed.iter().any(|key| keys.contains(key));
+        Ok(ModifierState {
+            shift: held(&[Key::Shift, Key::LShift, Key::RShift]),
+            control: held(&[Key::Control, Key::LControl, Key::RControl]),
+            alt: held(&[Key::Alt, Key::Option]),
+            meta: held(&[Key::Meta]),
+        })
+    }
+}
+
+impl Keyboard for MockBackend {
+    fn fast_text(&mut self, text: &str) -> InputResult<Option<()>> {
+        self.record(MockAction::Text {
+            text: text.to_owned(),
+        });
+        Ok(Some(()))
+    }
+
+    fn key(&mut self, key: Key, direction: Direction) -> InputResult<()> {
+        self.record(MockAction::Key { key, direction });
+        if direction != Direction::Release {
+            match key {
+                Key::CapsLock => self.lock_state.caps_lock ^= true,
+                #[cfg(not(target_os = "macos"))]
+                Key::Numlock => self.lock_state.num_lock ^= true,
+                #[cfg(all(unix, not(target_os = "macos")))]
+                Key::ScrollLock => self.lock_state.scroll_lock ^= true,
+                _ => {}
+            }
+        }
+        match direction {
+            Direction::Press if !self.pressed.contains(&key) => self.pressed.push(key),
+            Direction::Release => self.pressed.retain(|&pressed| pressed != key),
+            _ => {}
+        }
+        Ok(())
+    }
+
+    fn raw(&mut self, keycode: u16, direction: Direction) -> InputResult<()> {
+        self.record(MockAction::Raw { keycode, direction });
+        Ok(())
+    }
+}
+
+impl Mouse for MockBackend {
+    fn button(&mut self, button: enigo::Button, direction: Direction) -> InputResult<()> {
+        self.record(MockAction::Button {
+            button: _Button { value: button },
+            direction,
+        });
+        Ok(())
+    }
+
+    fn move_mouse(&mut self, x: i32, y: i32, coordinate: Coordinate) -> InputResult<()> {
+        self.record(MockAction::MoveMouse { x, y, coordinate });
+        let (x, y) = match coordinate {
+            Coordinate::Abs => (x, y),
+            Coordinate::Rel => (self.location.0 + x, self.location.1 + y),
+        };
+        let (width, height) = self.display;
+        self.location = (x.clamp(0, width - 1), y.clamp(0, height - 1));
+        Ok(())
+    }
+
+    fn scroll(&mut self, length: i32, axis: Axis) -> InputResult<()> {
+        self.record(MockAction::Scroll { length, axis });
+        Ok(())
+    }
+
+    fn main_display(&self) -> InputResult<(i32, i32)> {
+        Ok(self.display)
+    }
+
+    fn location(&self) -> InputResult<(i32, i32)> {
+        Ok(self.location)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn cursor_stays_on_the_display() {
+        let mut mock = MockBackend::new((100, 50));
+        assert_eq!(mock.location().unwrap(), (50, 25));
+        mock.move_mouse(500, -5, Coordinate::Abs).unwrap();
+        assert_eq!(mock.location().unwrap(), (99, 0));
+        mock.move_mouse(-200, 10, Coordinate::Rel).unwrap();
+        assert_eq!(mock.location().unwrap(), (0, 10));
+    }
+
+    #[test]
+    fn lock_keys_toggle_on_press_and_click() {
+        let mut mock = MockBackend::new(DEFAULT_DISPLAY);
+        let settings = Settings::default();
+        mock.key(Key::CapsLock, Direction::Press).unwrap();
+        mock.key(Key::CapsLock, Direction::Release).unwrap();
+        assert!(mock.lock_state(&settings).unwrap().caps_lock);
+        mock.key(Key::CapsLock, Direction::Click).unwrap();
+        assert!(!mock.lock_state(&settings).unwrap().caps_lock);
+    }
+
+    #[test]
+    fn modifiers_follow_pressed_keys() {
+        let mut mock = MockBackend::new(DEFAULT_DISPLAY);
+        let settings = Settings::default();
+        mock.key(Key::LShift, Direction::Press).unwrap();
+        mock.key(Key::Control, Direction::Press).unwrap();
+        let state = mock.modifier_state(&settings).unwrap();
+        assert!(state.shift && state.control && !state.alt && !state.meta);
+        mock.key(Key::LShift, Direction::Release).unwrap();
+        mock.key(Key::Meta, Direction::Click).unwrap();
+        let state = mock.modifier_state(&settings).unwrap();
+        assert!(!state.shift && state.control && !state.meta);
+    }
+}
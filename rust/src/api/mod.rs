@@ -1 +1,20 @@
+pub mod audit;
+pub mod backend;
+#[cfg(feature = "client")]
+pub mod client;
+pub mod dry_run;
 pub mod enigo;
+pub mod hotkey;
+pub mod listener;
+pub mod logging;
+pub mod metrics;
+pub mod mock;
+pub mod pacer;
+pub mod policy;
+#[cfg(any(feature = "client", feature = "server"))]
+pub mod protocol;
+pub mod recorder;
+pub mod remap;
+pub mod server;
+pub mod settings;
+pub mod system;
@@ -0,0 +1,297 @@
+use flutter_rust_bridge::*;
+use std::time::{Duration, Instant};
+
+use super::mock::MockAction;
+
+/// The lowest rate other than 0, so a wait always fits in a `Duration`.
+pub const MIN_EVENTS_PER_SECOND: f64 = 0.001;
+
+/// How [`Enigo`] spaces out events, see [`Enigo::set_pacer`].
+///
+/// Events wait for a token from a bucket refilled at `events_per_second`
+/// that holds up to `burst` of them; text takes one token per character.
+/// On top of that, two events of the same kind are at least their
+/// `min_gap_ms` apart. On Linux this adds to the per-event `linux_delay`
+/// setting.
+///
+/// [`Enigo`]: super::enigo::Enigo
+/// [`Enigo::set_pacer`]: super::enigo::Enigo::set_pacer
+#[derive(Debug, Clone, Copy)]
+pub struct PacerConfig {
+    /// 0 turns the token bucket off; anything else must be at least
+    /// [`MIN_EVENTS_PER_SECOND`].
+    pub events_per_second: f64,
+    pub burst: u32,
+    pub min_gap_ms: EventGaps,
+}
+
+/// Minimum milliseconds between two events of each kind. Raw keycodes
+/// count as keys.
+#[derive(Debug, Clone, Copy, Default)]
+pub struct EventGaps {
+    pub key: u32,
+    pub button: u32,
+    pub move_mouse: u32,
+    pub scroll: u32,
+    pub text: u32,
+}
+
+/// How much the pacer has held events back.
+#[derive(Debug, Clone, Copy, Default)]
+pub struct PacerStats {
+    /// Events that went through the pacer.
+    pub events: u64,
+    /// Events that had to wait.
+    pub delayed: u64,
+    pub total_wait_ms: u64,
+    pub max_wait_ms: u64,
+    /// Tokens in the bucket now; negative while events wait for theirs or
+    /// after text longer than it holds.
+    pub tokens: f64,
+}
+
+impl Default for PacerConfig {
+    fn default() -> Self {
+        Self {
+            events_per_second: 100.0,
+            burst: 10,
+            min_gap_ms: EventGaps::default(),
+        }
+    }
+}
+
+impl PacerConfig {
+    #[frb(sync)]
+    pub fn preset() -> Self {
+        Self::default()
+    }
+
+    /// Reject a rate that isn't 0 or at least [`MIN_EVENTS_PER_SECOND`],
+    /// which includes negative, infinite and NaN ones.
+    pub(crate) fn validate(&self) -> anyhow::Result<()> {
+        let rate = self.events_per_second;
+        if rate != 0.0 && !(MIN_EVENTS_PER_SECOND..=f64::MAX).contains(&rate) {
+            anyhow::bail!(
+                "events_per_second must be 0 to turn the bucket off or from \
+                 {MIN_EVENTS_PER_SECOND} up, not {rate}"
+            );
+        }
+        Ok(())
+    }
+}
+
+#[derive(Clone, Copy)]
+enum Kind {
+    Key,
+    Button,
+    MoveMouse,
+    Scroll,
+    Text,
+}
+
+/// A [`PacerConfig`] and the state of its bucket.
+#[frb(ignore)]
+pub(crate) struct Pacer {
+    config: PacerConfig,
+    stats: PacerStats,
+    tokens: f64,
+    refilled: Instant,
+    /// When the last event of each [`Kind`] went out.
+    last: [Option<Instant>; 5],
+}
+
+impl Pacer {
+    pub fn new(config: PacerConfig) -> Self {
+        let tokens = f64::from(config.burst.max(1));
+        Self {
+            config,
+            stats: PacerStats {
+                tokens,
+                ..PacerStats::default()
+            },
+            tokens,
+            refilled: Instant::now(),
+            last: [None; 5],
+        }
+    }
+
+    /// Take the tokens for `action` now and return how long to wait before
+    /// sending it. Later events queue up behind it, so callers can wait on
+    /// their own threads without holding the pacer.
+    pub fn reserve(&mut self, action: &MockAction) -> Duration {
+        let (kind, cost) = match action {
+            MockAction::Key { .. } | MockAction::Raw { .. } => (Kind::Key, 1),
+            MockAction::Button { .. } => (Kind::Button, 1),
+            MockAction::MoveMouse { .. } => (Kind::MoveMouse, 1),
+            MockAction::Scroll { .. } => (Kind::Scroll, 1),
+            MockAction::Text { text } => (Kind::Text, text.chars().count()),
+        };
+        let now = Instant::now();
+        let mut due = now;
+        if let Some(last) = self.last[kind as usize] {
+            due = due.max(last + Duration::from_millis(self.gap(kind).into()));
+        }
+        let rate = self.config.events_per_second;
+        if rate > 0.0 {
+            self.refill(now);
+            if self.tokens < 1.0 {
+                due = due.max(now + Duration::from_secs_f64((1.0 - self.tokens) / rate));
+            }
+            self.tokens -= cost as f64;
+        }
+        self.last[kind as usize] = Some(due);
+
+        let wait = due - now;
+        let waited = wait.as_millis() as u64;
+        let stats = &mut self.stats;
+        stats.events += 1;
+        if !wait.is_zero() {
+            stats.delayed += 1;
+            stats.total_wait_ms += waited;
+            stats.max_wait_ms = stats.max_wait_ms.max(waited);
+        }
+        stats.tokens = self.tokens;
+        wait
+    }
+
+    /// The stats with the bucket topped up to now.
+    pub fn current_stats(&mut self) -> PacerStats {
+        if self.config.events_per_second > 0.0 {
+            self.refill(Instant::now());
+        }
+        self.stats.tokens = self.tokens;
+        self.stats
+    }
+
+    fn refill(&mut self, now: Instant) {
+        let elapsed = now.duration_since(self.refilled).as_secs_f64();
+        let burst = f64::from(self.config.burst.max(1));
+        self.tokens = (self.tokens + elapsed * self.config.events_per_second).min(burst);
+        self.refilled = now;
+    }
+
+    fn gap(&self, kind: Kind) -> u32 {
+        let gaps = &self.config.min_gap_ms;
+        match kind {
+            Kind::Key => gaps.key,
+            Kind::Button => gaps.button,
+            Kind::MoveMouse => gaps.move_mouse,
+            Kind::Scroll => gaps.scroll,
+            Kind::Text => gaps.text,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use enigo::{Direction, Key};
+
+    fn key() -> MockAction {
+        MockAction::Key {
+            key: Key::Unicode('a'),
+            direction: Direction::Click,
+        }
+    }
+
+    fn text(text: &str) -> MockAction {
+        MockAction::Text {
+            text: text.to_owned(),
+        }
+    }
+
+    fn bucket(events_per_second: f64, burst: u32) -> Pacer {
+        Pacer::new(PacerConfig {
+            events_per_second,
+            burst,
+            min_gap_ms: EventGaps::default(),
+        })
+    }
+
+    fn ms(wait: Duration) -> f64 {
+        wait.as_secs_f64() * 1000.0
+    }
+
+    #[test]
+    fn burst_goes_through_then_events_queue() {
+        let mut pacer = bucket(100.0, 2);
+        assert_eq!(pacer.reserve(&key()), Duration::ZERO);
+        assert_eq!(pacer.reserve(&key()), Duration::ZERO);
+        let third = ms(pacer.reserve(&key()));
+        let fourth = ms(pacer.reserve(&key()));
+        assert!((8.0..=10.0).contains(&third), "{third}");
+        assert!((18.0..=20.0).contains(&fourth), "{fourth}");
+
+        let stats = pacer.current_stats();
+        assert_eq!((stats.events, stats.delayed), (4, 2));
+        assert!((26..=30).contains(&stats.total_wait_ms));
+        assert!((18..=20).contains(&stats.max_wait_ms));
+        assert!(stats.tokens < -1.0, "{}", stats.tokens);
+    }
+
+    #[test]
+    fn text_takes_a_token_per_character() {
+        let mut pacer = bucket(100.0, 5);
+        assert_eq!(pacer.reserve(&text("héllo wörld")), Duration::ZERO);
+        // 11 characters from 5 tokens leaves -6; 7 more are needed.
+        let wait = ms(pacer.reserve(&key()));
+        assert!((68.0..=70.0).contains(&wait), "{wait}");
+    }
+
+    #[test]
+    fn bucket_refills_up_to_the_burst() {
+        let mut pacer = bucket(1000.0, 3);
+        for _ in 0..3 {
+            pacer.reserve(&key());
+        }
+        std::thread::sleep(Duration::from_millis(20));
+        assert_eq!(pacer.current_stats().tokens, 3.0);
+        assert_eq!(pacer.reserve(&key()), Duration::ZERO);
+    }
+
+    #[test]
+    fn bad_rates_are_rejected() {
+        for rate in [0.0, MIN_EVENTS_PER_SECOND, 1e9] {
+            let config = PacerConfig {
+                events_per_second: rate,
+                ..PacerConfig::default()
+            };
+            assert!(config.validate().is_ok(), "{rate}");
+        }
+        for rate in [1e-300, -1.0, -0.0001, f64::NAN, f64::INFINITY] {
+            let config = PacerConfig {
+                events_per_second: rate,
+                ..PacerConfig::default()
+            };
+            assert!(config.validate().is_err(), "{rate}");
+        }
+    }
+
+    #[test]
+    fn slowest_rate_waits_without_overflowing() {
+        let mut pacer = bucket(MIN_EVENTS_PER_SECOND, 1);
+        pacer.reserve(&text(&"a".repeat(1 << 20)));
+        let wait = pacer.reserve(&key());
+        assert!(wait > Duration::from_secs(1 << 29), "{wait:?}");
+    }
+
+    #[test]
+    fn events_of_a_kind_keep_their_gap() {
+        let mut pacer = Pacer::new(PacerConfig {
+            events_per_second: 0.0,
+            burst: 1,
+            min_gap_ms: EventGaps {
+                key: 30,
+                ..EventGaps::default()
+            },
+        });
+        assert_eq!(pacer.reserve(&key()), Duration::ZERO);
+        let second = ms(pacer.reserve(&key()));
+        let third = ms(pacer.reserve(&key()));
+        assert!((28.0..=30.0).contains(&second), "{second}");
+        assert!((58.0..=60.0).contains(&third), "{third}");
+        // Other kinds aren't held back by keys.
+        assert_eq!(pacer.reserve(&text("a")), Duration::ZERO);
+        assert_eq!(pacer.current_stats().tokens, 1.0);
+    }
+}
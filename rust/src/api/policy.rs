@@ -0,0 +1,476 @@
+use enigo::{Direction, Key};
+use flutter_rust_bridge::*;
+use serde::{Deserialize, Serialize};
+use std::collections::VecDeque;
+use std::fmt;
+use std::time::{Duration, Instant};
+
+use super::enigo::HeldInput;
+use super::mock::MockAction;
+
+/// Limits on what an [`Enigo`] may send; see [`Enigo::set_policy`].
+///
+/// The default allows everything. Releases are never blocked, so nothing
+/// pressed before a policy was set can get stuck.
+///
+/// [`Enigo`]: super::enigo::Enigo
+/// [`Enigo::set_policy`]: super::enigo::Enigo::set_policy
+#[derive(Debug, Clone)]
+pub struct Policy {
+    /// Keys that may be pressed, and characters that may be typed as
+    /// `Key::Unicode`. Empty allows every key that isn't denied.
+    pub allowed_keys: Vec<Key>,
+    pub denied_keys: Vec<Key>,
+    /// Combinations that may not be completed, e.g. `[Control, Alt, Delete]`.
+    pub denied_chords: Vec<Vec<Key>>,
+    /// Raw keycodes bypass the key lists, so they can be turned off.
+    pub allow_raw: bool,
+    /// Where buttons may be pressed. Empty allows the whole screen.
+    pub click_regions: Vec<Region>,
+    /// In characters.
+    pub max_text_len: Option<u32>,
+    pub rate_limit: Option<RateLimit>,
+}
+
+/// A rectangle in screen coordinates.
+#[derive(Debug, Clone, Copy)]
+pub struct Region {
+    pub x: i32,
+    pub y: i32,
+    pub width: i32,
+    pub height: i32,
+}
+
+/// At most `max_events` calls in any `window_ms`. Releases don't count.
+#[derive(Debug, Clone, Copy)]
+pub struct RateLimit {
+    pub max_events: u32,
+    pub window_ms: u32,
+}
+
+/// Why a call was refused by the [`Policy`]; nothing was sent.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum PolicyViolation {
+    KeyDenied { key: Key },
+    ChordDenied { chord: Vec<Key> },
+    RawDenied { keycode: u16 },
+    ClickOutsideRegions { x: i32, y: i32 },
+    TextTooLong { len: u32, max: u32 },
+    RateLimited { max_events: u32, window_ms: u32 },
+}
+
+impl Default for Policy {
+    fn default() -> Self {
+        Self {
+            allowed_keys: Vec::new(),
+            denied_keys: Vec::new(),
+            denied_chords: Vec::new(),
+            allow_raw: true,
+            click_regions: Vec::new(),
+            max_text_len: None,
+            rate_limit: None,
+        }
+    }
+}
+
+impl Policy {
+    #[frb(sync)]
+    pub fn preset() -> Self {
+        Self::default()
+    }
+}
+
+impl Region {
+    fn contains(&self, (x, y): (i32, i32)) -> bool {
+        x >= self.x && y >= self.y && x - self.x < self.width && y - self.y < self.height
+    }
+}
+
+impl fmt::Display for PolicyViolation {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::KeyDenied { key } => write!(f, "{key:?} is not allowed"),
+            Self::ChordDenied { chord } => write!(f, "the chord {chord:?} is not allowed"),
+            Self::RawDenied { keycode } => write!(f, "raw keycode {keycode} is not allowed"),
+            Self::ClickOutsideRegions { x, y } => {
+                write!(f, "clicking at ({x}, {y}) is not allowed")
+            }
+            Self::TextTooLong { len, max } => {
+                write!(f, "text of {len} characters is longer than {max}")
+            }
+            Self::RateLimited {
+                max_events,
+                window_ms,
+            } => write!(f, "more than {max_events} events in {window_ms} ms"),
+        }
+    }
+}
+
+impl std::error::Error for PolicyViolation {}
+
+/// A [`Policy`] and the calls it let through recently, for the rate limit.
+#[frb(ignore)]
+pub(crate) struct Enforcer {
+    pub policy: Policy,
+    recent: VecDeque<Instant>,
+}
+
+impl Enforcer {
+    pub fn new(policy: Policy) -> Self {
+        Self {
+            policy,
+            recent: VecDeque::new(),
+        }
+    }
+
+    /// Whether `action` may be sent while `held` is held down. `location`
+    /// is only asked for button presses when clicks are restricted.
+    pub fn check(
+        &mut self,
+        action: &MockAction,
+        held: &HeldInput,
+        location: impl FnOnce() -> Option<(i32, i32)>,
+    ) -> Result<(), PolicyViolation> {
+        let policy = &self.policy;
+        match action {
+            MockAction::Key {
+                direction: Direction::Release,
+                ..
+            }
+            | MockAction::Raw {
+                direction: Direction::Release,
+                ..
+            }
+            | MockAction::Button {
+                direction: Direction::Release,
+                ..
+            } => return Ok(()),
+            MockAction::Key { key, .. } => {
+                self.check_key(*key)?;
+                self.check_chords(*key, held)?;
+            }
+            MockAction::Raw { keycode, .. } if !policy.allow_raw => {
+                return Err(PolicyViolation::RawDenied { keycode: *keycode });
+            }
+            MockAction::Button { .. } if !policy.click_regions.is_empty() => {
+                // An unknown location can't be shown to be inside a region.
+                let (x, y) = location().unwrap_or((i32::MIN, i32::MIN));
+                if !policy
+                    .click_regions
+                    .iter()
+                    .any(|region| region.contains((x, y)))
+                {
+                    return Err(PolicyViolation::ClickOutsideRegions { x, y });
+                }
+            }
+            MockAction::Text { text } => {
+                let len = text.chars().count() as u32;
+                if let Some(max) = policy.max_text_len.filter(|&max| len > max) {
+                    return Err(PolicyViolation::TextTooLong { len, max });
+                }
+                for c in text.chars() {
+                    self.check_key(Key::Unicode(c))?;
+                }
+            }
+            _ => {}
+        }
+        self.check_rate()
+    }
+
+    fn check_key(&self, key: Key) -> Result<(), PolicyViolation> {
+        let listed = |keys: &[Key]| keys.iter().any(|&k| canonical(k) == canonical(key));
+        let allowed = self.policy.allowed_keys.is_empty() || listed(&self.policy.allowed_keys);
+        if !allowed || listed(&self.policy.denied_keys) {
+            return Err(PolicyViolation::KeyDenied { key });
+        }
+        Ok(())
+    }
+
+    /// Refuse `key` if pressing it would complete a denied chord.
+    fn check_chords(&self, key: Key, held: &HeldInput) -> Result<(), PolicyViolation> {
+        let pressed = |chord_key: Key| {
+            let chord_key = canonical(chord_key);
+            canonical(key) == chord_key || held.keys.iter().any(|&k| canonical(k) == chord_key)
+        };
+        match self
+            .policy
+            .denied_chords
+            .iter()
+            .find(|chord| !chord.is_empty() && chord.iter().all(|&k| pressed(k)))
+        {
+            Some(chord) => Err(PolicyViolation::ChordDenied {
+                chord: chord.clone(),
+            }),
+            None => Ok(()),
+        }
+    }
+
+    fn check_rate(&mut self) -> Result<(), PolicyViolation> {
+        let Some(RateLimit {
+            max_events,
+            window_ms,
+        }) = self.policy.rate_limit
+        else {
+            return Ok(());
+        };
+        let now = Instant::now();
+        let window = Duration::from_millis(window_ms.into());
+        while self
+            .recent
+            .front()
+            .is_some_and(|&sent| now.duration_since(sent) >= window)
+        {
+            self.recent.pop_front();
+        }
+        if self.recent.len() >= max_events as usize {
+            return Err(PolicyViolation::RateLimited {
+                max_events,
+                window_ms,
+            });
+        }
+        self.recent.push_back(now);
+        Ok(())
+    }
+}
+
+/// The same key regardless of side, case or name, so denying `Control`
+/// also denies `LControl`, denying `Meta` also denies `Super` and `LWin`,
+/// and denying `a` also denies `A`.
+#[allow(deprecated)]
+fn canonical(key: Key) -> Key {
+    match key {
+        Key::LControl | Key::RControl => Key::Control,
+        Key::LShift | Key::RShift => Key::Shift,
+        Key::Command | Key::Super | Key::Windows => Key::Meta,
+        #[cfg(target_os = "windows")]
+        Key::LWin | Key::RWin => Key::Meta,
+        #[cfg(target_os = "macos")]
+        Key::RCommand => Key::Meta,
+        Key::Option => Key::Alt,
+        // Left Alt on Windows, but the context menu key on Linux.
+        #[cfg(target_os = "windows")]
+        Key::LMenu | Key::RMenu => Key::Alt,
+        #[cfg(target_os = "macos")]
+        Key::ROption => Key::Alt,
+        Key::Unicode(c) => Key::Unicode(c.to_lowercase().next().unwrap_or(c)),
+        key => key,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::api::enigo::_Button;
+
+    fn key(key: Key, direction: Direction) -> MockAction {
+        MockAction::Key { key, direction }
+    }
+
+    fn check(enforcer: &mut Enforcer, action: &MockAction) -> Result<(), PolicyViolation> {
+        enforcer.check(action, &HeldInput::default(), || Some((0, 0)))
+    }
+
+    #[test]
+    fn denied_and_unlisted_keys_are_refused() {
+        let mut enforcer = Enforcer::new(Policy {
+            allowed_keys: vec![Key::Unicode('a'), Key::Control, Key::Delete],
+            denied_keys: vec![Key::Delete],
+            ..Policy::default()
+        });
+        let refused = |key| Err(PolicyViolation::KeyDenied { key });
+        assert_eq!(
+            check(&mut enforcer, &key(Key::Unicode('A'), Direction::Click)),
+            Ok(())
+        );
+        assert_eq!(
+            check(&mut enforcer, &key(Key::LControl, Direction::Press)),
+            Ok(())
+        );
+        assert_eq!(
+            check(&mut enforcer, &key(Key::Delete, Direction::Click)),
+            refused(Key::Delete)
+        );
+        assert_eq!(
+            check(&mut enforcer, &key(Key::Unicode('b'), Direction::Press)),
+            refused(Key::Unicode('b'))
+        );
+        let text = MockAction::Text {
+            text: "ab".to_owned(),
+        };
+        assert_eq!(check(&mut enforcer, &text), refused(Key::Unicode('b')));
+        // Releases always go through.
+        assert_eq!(
+            check(&mut enforcer, &key(Key::Delete, Direction::Release)),
+            Ok(())
+        );
+    }
+
+    /// Every name for the Meta and Alt keys on this platform.
+    #[allow(deprecated, unused_mut)]
+    fn aliases() -> (Vec<Key>, Vec<Key>) {
+        let mut meta = vec![Key::Meta, Key::Command, Key::Super, Key::Windows];
+        let mut alt = vec![Key::Alt, Key::Option];
+        #[cfg(target_os = "windows")]
+        {
+            meta.extend([Key::LWin, Key::RWin]);
+            alt.extend([Key::LMenu, Key::RMenu]);
+        }
+        #[cfg(target_os = "macos")]
+        {
+            meta.push(Key::RCommand);
+            alt.push(Key::ROption);
+        }
+        (meta, alt)
+    }
+
+    #[test]
+    fn aliases_of_denied_keys_are_refused() {
+        let (meta, alt) = aliases();
+        for (denied, aliases) in [(Key::Meta, meta), (Key::Alt, alt)] {
+            let mut enforcer = Enforcer::new(Policy {
+                denied_keys: vec![denied],
+                ..Policy::default()
+            });
+            for key in aliases {
+                assert_eq!(
+                    check(&mut enforcer, &self::key(key, Direction::Press)),
+                    Err(PolicyViolation::KeyDenied { key }),
+                    "{key:?}"
+                );
+            }
+        }
+    }
+
+    #[test]
+    fn chords_cant_be_completed_with_an_alias() {
+        let chord = vec![Key::Control, Key::Alt, Key::Delete];
+        let mut enforcer = Enforcer::new(Policy {
+            denied_chords: vec![chord.clone()],
+            ..Policy::default()
+        });
+        let delete = key(Key::Delete, Direction::Press);
+        for alt in aliases().1 {
+            let held = HeldInput {
+                keys: vec![Key::LControl, alt],
+                ..HeldInput::default()
+            };
+            assert_eq!(
+                enforcer.check(&delete, &held, || None),
+                Err(PolicyViolation::ChordDenied {
+                    chord: chord.clone()
+                }),
+                "{alt:?}"
+            );
+        }
+    }
+
+    #[test]
+    fn completing_a_denied_chord_is_refused() {
+        let chord = vec![Key::Control, Key::Alt, Key::Delete];
+        let mut enforcer = Enforcer::new(Policy {
+            denied_chords: vec![chord.clone()],
+            ..Policy::default()
+        });
+        let mut held = HeldInput {
+            keys: vec![Key::RControl, Key::Alt],
+            ..HeldInput::default()
+        };
+        let delete = key(Key::Delete, Direction::Press);
+        assert_eq!(
+            enforcer.check(&delete, &held, || None),
+            Err(PolicyViolation::ChordDenied { chord })
+        );
+        held.keys.pop();
+        assert_eq!(enforcer.check(&delete, &held, || None), Ok(()));
+    }
+
+    #[test]
+    fn raw_keycodes_can_be_turned_off() {
+        let mut enforcer = Enforcer::new(Policy {
+            allow_raw: false,
+            ..Policy::default()
+        });
+        let raw = |direction| MockAction::Raw {
+            keycode: 30,
+            direction,
+        };
+        assert_eq!(
+            check(&mut enforcer, &raw(Direction::Press)),
+            Err(PolicyViolation::RawDenied { keycode: 30 })
+        );
+        assert_eq!(check(&mut enforcer, &raw(Direction::Release)), Ok(()));
+    }
+
+    #[test]
+    fn clicks_must_land_in_a_region() {
+        let mut enforcer = Enforcer::new(Policy {
+            click_regions: vec![Region {
+                x: 10,
+                y: 10,
+                width: 20,
+                height: 20,
+            }],
+            ..Policy::default()
+        });
+        let click = MockAction::Button {
+            button: _Button::left(),
+            direction: Direction::Click,
+        };
+        let held = HeldInput::default();
+        assert_eq!(enforcer.check(&click, &held, || Some((29, 10))), Ok(()));
+        assert_eq!(
+            enforcer.check(&click, &held, || Some((30, 10))),
+            Err(PolicyViolation::ClickOutsideRegions { x: 30, y: 10 })
+        );
+        assert_eq!(
+            enforcer.check(&click, &held, || None),
+            Err(PolicyViolation::ClickOutsideRegions {
+                x: i32::MIN,
+                y: i32::MIN
+            })
+        );
+    }
+
+    #[test]
+    fn long_text_is_refused() {
+        let mut enforcer = Enforcer::new(Policy {
+            max_text_len: Some(3),
+            ..Policy::default()
+        });
+        let text = |text: &str| MockAction::Text {
+            text: text.to_owned(),
+        };
+        assert_eq!(check(&mut enforcer, &text("äöü")), Ok(()));
+        assert_eq!(
+            check(&mut enforcer, &text("abcd")),
+            Err(PolicyViolation::TextTooLong { len: 4, max: 3 })
+        );
+    }
+
+    #[test]
+    fn rate_limit_counts_presses_in_the_window() {
+        let limit = RateLimit {
+            max_events: 2,
+            window_ms: 50,
+        };
+        let mut enforcer = Enforcer::new(Policy {
+            rate_limit: Some(limit),
+            ..Policy::default()
+        });
+        let press = key(Key::Unicode('a'), Direction::Press);
+        let release = key(Key::Unicode('a'), Direction::Release);
+        assert_eq!(check(&mut enforcer, &press), Ok(()));
+        assert_eq!(check(&mut enforcer, &release), Ok(()));
+        assert_eq!(check(&mut enforcer, &press), Ok(()));
+        assert_eq!(
+            check(&mut enforcer, &press),
+            Err(PolicyViolation::RateLimited {
+                max_events: 2,
+                window_ms: 50
+            })
+        );
+        std::thread::sleep(Duration::from_millis(60));
+        assert_eq!(check(&mut enforcer, &press), Ok(()));
+    }
+}
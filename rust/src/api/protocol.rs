@@ -0,0 +1,534 @@
+//! Commands and replies exchanged with an [`InputServer`].
+//!
+//! Over a plain TCP connection every message is a frame: a big-endian `u32`
+//! length followed by that many bytes. Over WebSocket every message is one
+//! WebSocket message. A message starting with `{` is JSON, anything else
+//! is binary, and each command is answered in the format it came in.
+//!
+//! JSON commands look like `{"command": "move_mouse", "x": 10, "y": 20,
+//! "coordinate": "Abs"}`, with the same field names as the `Enigo` methods
+//! and enigo's serde names for keys, buttons and directions. A command may
+//! carry `"id": {"client": "...", "seq": 1}`: the server then remembers its
+//! reply, and answers a command with the same client and seq with that
+//! reply instead of carrying it out again, so a client can safely resend a
+//! command after losing the connection. Replies are
+//! `{"status": "ok"}`, `{"status": "point", "x": 0, "y": 0}`,
+//! `{"status": "lock_state", "caps_lock": false, ...}` with the fields of
+//! `LockState`, `{"status": "modifier_state", "shift": false, ...}` with
+//! those of `ModifierState`, or `{"status": "error", "error": ..., ...}`
+//! with the fields of [`RemoteError`].
+//!
+//! Binary commands are an opcode byte followed by big-endian fields:
+//!
+//! | opcode | command          | fields                                        |
+//! |--------|------------------|-----------------------------------------------|
+//! | 0      | `auth`           | token as UTF-8                                |
+//! | 1      | `key`            | kind `u8`, code `u32`, direction `u8`         |
+//! | 2      | `raw`            | keycode `u16`, direction `u8`                 |
+//! | 3      | `button`         | button `u8`, direction `u8`                   |
+//! | 4      | `move_mouse`     | x `i32`, y `i32`, coordinate `u8`             |
+//! | 5      | `scroll`         | length `i32`, axis `u8`                       |
+//! | 6      | `text`           | text as UTF-8                                 |
+//! | 7      | `main_display`   |                                               |
+//! | 8      | `location`       |                                               |
+//! | 9      | `lock_state`     |                                               |
+//! | 10     | `modifier_state` |                                               |
+//! | 11     | `ping`           |                                               |
+//!
+//! A key of kind 0 is a USB HID usage as in Flutter's `PhysicalKeyboardKey`,
+//! kind 1 a Unicode scalar value. Directions are press, release, click;
+//! coordinates abs, rel; axes horizontal, vertical; buttons left, middle,
+//! right, back, forward, scroll up, down, left, right; all numbered from 0.
+//!
+//! Binary replies are 0 for ok, 1 followed by x `i32` and y `i32` for a
+//! point, 2 followed by the JSON error, 3 followed by a `u8` each for caps,
+//! num and scroll lock, or 4 followed by a `u8` each for shift, control,
+//! alt and meta; the flags are 0 or 1.
+//!
+//! [`InputServer`]: super::server::InputServer
+
+use enigo::{Axis, Coordinate, Direction, Key};
+use serde::{Deserialize, Serialize};
+use std::io::{self, Read, Write};
+
+#[cfg(feature = "server")]
+use super::enigo::{_Button, decode_physical_keyboard_key_type};
+#[cfg(feature = "server")]
+use super::mock::MockAction;
+use super::policy::PolicyViolation;
+use super::system::{LockState, ModifierState};
+
+/// Larger frames are refused rather than allocated.
+pub(crate) const MAX_FRAME_LEN: u32 = 1 << 20;
+
+/// A command and, from clients that resend commands, its [`RequestId`].
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub(crate) struct Request {
+    #[serde(flatten)]
+    pub command: Command,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub id: Option<RequestId>,
+}
+
+/// Identifies a command across connections: `client` is the same for all
+/// of a client's connections and `seq` tells its commands apart.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub(crate) struct RequestId {
+    pub client: String,
+    pub seq: u64,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(tag = "command", rename_all = "snake_case")]
+pub(crate) enum Command {
+    Auth {
+        token: String,
+    },
+    Key {
+        key: Key,
+        direction: Direction,
+    },
+    Raw {
+        keycode: u16,
+        direction: Direction,
+    },
+    Button {
+        button: enigo::Button,
+        direction: Direction,
+    },
+    MoveMouse {
+        x: i32,
+        y: i32,
+        coordinate: Coordinate,
+    },
+    Scroll {
+        length: i32,
+        axis: Axis,
+    },
+    Text {
+        text: String,
+    },
+    MainDisplay,
+    Location,
+    LockState,
+    ModifierState,
+    /// Answered with ok, to check the connection.
+    Ping,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(tag = "status", rename_all = "snake_case")]
+pub(crate) enum Reply {
+    Ok,
+    Point { x: i32, y: i32 },
+    Error(RemoteError),
+    LockState(LockState),
+    ModifierState(ModifierState),
+}
+
+/// Why the server didn't carry out a command.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(tag = "error", rename_all = "snake_case")]
+pub(crate) enum RemoteError {
+    /// The connection's or the server's policy refused the command.
+    Policy { violation: PolicyViolation },
+    /// The backend failed. `kind` is what the metrics count the failure
+    /// under, e.g. `mapping`.
+    Input { kind: String, message: String },
+    /// A token is required and this connection hasn't sent a valid one.
+    Unauthorized,
+    /// The message couldn't be parsed.
+    BadCommand { message: String },
+}
+
+#[cfg(feature = "server")]
+impl Request {
+    /// Parse a JSON or binary message; binary ones have no id.
+    pub fn decode(message: &[u8]) -> Result<Self, String> {
+        if message.first() == Some(&b'{') {
+            serde_json::from_slice(message).map_err(|error| error.to_string())
+        } else {
+            Ok(Self {
+                command: Command::decode_binary(message)?,
+                id: None,
+            })
+        }
+    }
+}
+
+#[cfg(feature = "server")]
+impl Command {
+    /// The input this command sends, if it isn't a query.
+    pub fn action(&self) -> Option<MockAction> {
+        Some(match self.clone() {
+            Command::Key { key, direction } => MockAction::Key { key, direction },
+            Command::Raw { keycode, direction } => MockAction::Raw { keycode, direction },
+            Command::Button { button, direction } => MockAction::Button {
+                button: _Button { value: button },
+                direction,
+            },
+            Command::MoveMouse { x, y, coordinate } => MockAction::MoveMouse { x, y, coordinate },
+            Command::Scroll { length, axis } => MockAction::Scroll { length, axis },
+            Command::Text { text } => MockAction::Text { text },
+            Command::Auth { .. }
+            | Command::MainDisplay
+            | Command::Location
+            | Command::LockState
+            | Command::ModifierState
+            | Command::Ping => return None,
+        })
+    }
+
+    fn decode_binary(message: &[u8]) -> Result<Self, String> {
+        let Some((&opcode, fields)) = message.split_first() else {
+            return Err("empty message".to_owned());
+        };
+        let mut fields = Fields(fields);
+        let command = match opcode {
+            0 => Command::Auth {
+                token: fields.rest_str()?,
+            },
+            1 => {
+                let kind = fields.u8()?;
+                let code = fields.u32()?;
+                let key = match kind {
+                    0 => decode_physical_keyboard_key_type(code),
+                    1 => Key::Unicode(
+                        char::from_u32(code).ok_or(format!("{code:#x} is not a character"))?,
+                    ),
+                    _ => return Err(format!("unknown key kind {kind}")),
+                };
+                Command::Key {
+                    key,
+                    direction: fields.direction()?,
+                }
+            }
+            2 => Command::Raw {
+                keycode: fields.u16()?,
+                direction: fields.direction()?,
+            },
+            3 => Command::Button {
+                button: fields.button()?,
+                direction: fields.direction()?,
+            },
+            4 => Command::MoveMouse {
+                x: fields.i32()?,
+                y: fields.i32()?,
+                coordinate: match fields.u8()? {
+                    0 => Coordinate::Abs,
+                    1 => Coordinate::Rel,
+                    other => return Err(format!("unknown coordinate {other}")),
+                },
+            },
+            5 => Command::Scroll {
+                length: fields.i32()?,
+                axis: match fields.u8()? {
+                    0 => Axis::Horizontal,
+                    1 => Axis::Vertical,
+                    other => return Err(format!("unknown axis {other}")),
+                },
+            },
+            6 => Command::Text {
+                text: fields.rest_str()?,
+            },
+            7 => Command::MainDisplay,
+            8 => Command::Location,
+            9 => Command::LockState,
+            10 => Command::ModifierState,
+            11 => Command::Ping,
+            _ => return Err(format!("unknown opcode {opcode}")),
+        };
+        if !fields.0.is_empty() {
+            return Err(format!("{} bytes too many", fields.0.len()));
+        }
+        Ok(command)
+    }
+}
+
+#[cfg(feature = "server")]
+impl Reply {
+    /// Encode as JSON or, if `binary`, in the binary format.
+    pub fn encode(&self, binary: bool) -> Vec<u8> {
+        if !binary {
+            return serde_json::to_vec(self).expect("replies serialize");
+        }
+        match self {
+            Reply::Ok => vec![0],
+            Reply::Point { x, y } => [&[1][..], &x.to_be_bytes(), &y.to_be_bytes()].concat(),
+            Reply::Error(error) => {
+                let mut bytes = vec![2];
+                bytes.extend(serde_json::to_vec(error).expect("errors serialize"));
+                bytes
+            }
+            Reply::LockState(state) => vec![
+                3,
+                state.caps_lock.into(),
+                state.num_lock.into(),
+                state.scroll_lock.into(),
+            ],
+            Reply::ModifierState(state) => vec![
+                4,
+                state.shift.into(),
+                state.control.into(),
+                state.alt.into(),
+                state.meta.into(),
+            ],
+        }
+    }
+}
+
+/// Read one length-prefixed frame.
+pub(crate) fn read_frame(stream: &mut impl Read) -> io::Result<Vec<u8>> {
+    let mut len = [0; 4];
+    stream.read_exact(&mut len)?;
+    let len = u32::from_be_bytes(len);
+    if len > MAX_FRAME_LEN {
+        return Err(io::Error::new(
+            io::ErrorKind::InvalidData,
+            format!("a frame of {len} bytes is too large"),
+        ));
+    }
+    let mut frame = vec![0; len as usize];
+    stream.read_exact(&mut frame)?;
+    Ok(frame)
+}
+
+pub(crate) fn write_frame(stream: &mut impl Write, frame: &[u8]) -> io::Result<()> {
+    let len = u32::try_from(frame.len())
+        .ok()
+        .filter(|&len| len <= MAX_FRAME_LEN)
+        .ok_or_else(|| io::Error::new(io::ErrorKind::InvalidInput, "frame too large"))?;
+    stream.write_all(&[&len.to_be_bytes()[..], frame].concat())?;
+    stream.flush()
+}
+
+/// The fields of a binary command still to be read.
+#[cfg(feature = "server")]
+struct Fields<'a>(&'a [u8]);
+
+#[cfg(feature = "server")]
+impl Fields<'_> {
+    fn take<const N: usize>(&mut self) -> Result<[u8; N], String> {
+        if self.0.len() < N {
+            return Err("message too short".to_owned());
+        }
+        let (field, rest) = self.0.split_at(N);
+        self.0 = rest;
+        Ok(field.try_into().expect("split at N"))
+    }
+
+    fn u8(&mut self) -> Result<u8, String> {
+        Ok(self.take::<1>()?[0])
+    }
+
+    fn u16(&mut self) -> Result<u16, String> {
+        Ok(u16::from_be_bytes(self.take()?))
+    }
+
+    fn u32(&mut self) -> Result<u32, String> {
+        Ok(u32::from_be_bytes(self.take()?))
+    }
+
+    fn i32(&mut self) -> Result<i32, String> {
+        Ok(i32::from_be_bytes(self.take()?))
+    }
+
+    fn rest_str(&mut self) -> Result<String, String> {
+        let rest = std::mem::take(&mut self.0);
+        String::from_utf8(rest.to_vec()).map_err(|error| error.to_string())
+    }
+
+    fn direction(&mut self) -> Result<Direction, String> {
+        match self.u8()? {
+            0 => Ok(Direction::Press),
+            1 => Ok(Direction::Release),
+            2 => Ok(Direction::Click),
+            other => Err(format!("unknown direction {other}")),
+        }
+    }
+
+    fn button(&mut self) -> Result<enigo::Button, String> {
+        match self.u8()? {
+            0 => Ok(enigo::Button::Left),
+            1 => Ok(enigo::Button::Middle),
+            2 => Ok(enigo::Button::Right),
+            #[cfg(not(target_os = "macos"))]
+            3 => Ok(enigo::Button::Back),
+            #[cfg(not(target_os = "macos"))]
+            4 => Ok(enigo::Button::Forward),
+            5 => Ok(enigo::Button::ScrollUp),
+            6 => Ok(enigo::Button::ScrollDown),
+            7 => Ok(enigo::Button::ScrollLeft),
+            8 => Ok(enigo::Button::ScrollRight),
+            other => Err(format!("unknown button {other}")),
+        }
+    }
+}
+
+#[cfg(all(test, feature = "server"))]
+mod tests {
+    use super::*;
+    use std::io::Cursor;
+
+    fn decode(message: &[u8]) -> Command {
+        match Request::decode(message) {
+            Ok(request) => request.command,
+            Err(error) => panic!("{message:?}: {error}"),
+        }
+    }
+
+    #[test]
+    fn binary_commands_decode() {
+        assert!(matches!(
+            decode(&[1, 1, 0, 0, 0, b'a', 2]),
+            Command::Key {
+                key: Key::Unicode('a'),
+                direction: Direction::Click
+            }
+        ));
+        assert!(matches!(
+            decode(&[2, 0x01, 0x02, 1]),
+            Command::Raw {
+                keycode: 0x0102,
+                direction: Direction::Release
+            }
+        ));
+        assert!(matches!(
+            decode(&[3, 2, 0]),
+            Command::Button {
+                button: enigo::Button::Right,
+                direction: Direction::Press
+            }
+        ));
+        assert!(matches!(
+            decode(&[4, 0xff, 0xff, 0xff, 0xf6, 0, 0, 0, 20, 1]),
+            Command::MoveMouse {
+                x: -10,
+                y: 20,
+                coordinate: Coordinate::Rel
+            }
+        ));
+        assert!(matches!(
+            decode(&[5, 0, 0, 0, 3, 1]),
+            Command::Scroll {
+                length: 3,
+                axis: Axis::Vertical
+            }
+        ));
+        assert!(matches!(decode("\u{6}hé".as_bytes()), Command::Text { text } if text == "hé"));
+        assert!(matches!(decode(b"\0secret"), Command::Auth { token } if token == "secret"));
+        assert!(matches!(decode(&[11]), Command::Ping));
+    }
+
+    #[test]
+    fn malformed_binary_commands_are_rejected() {
+        for (message, error) in [
+            (&[][..], "empty message"),
+            (&[99], "unknown opcode 99"),
+            (&[11, 0], "1 bytes too many"),
+            (&[4, 0, 0], "message too short"),
+            (&[1, 1, 0, 0, 0xd8, 0, 2], "0xd800 is not a character"),
+            (&[1, 2, 0, 0, 0, 0, 2], "unknown key kind 2"),
+            (&[2, 0, 1, 3], "unknown direction 3"),
+            (&[5, 0, 0, 0, 1, 2], "unknown axis 2"),
+            (&[6, 0xff], "invalid utf-8"),
+        ] {
+            let decoded = Request::decode(message);
+            assert!(
+                decoded.as_ref().is_err_and(|e| e.contains(error)),
+                "{message:?}: {decoded:?}"
+            );
+        }
+    }
+
+    #[test]
+    fn json_commands_decode() {
+        let json = br#"{"command": "move_mouse", "x": 10, "y": 20, "coordinate": "Abs"}"#;
+        assert!(matches!(
+            decode(json),
+            Command::MoveMouse {
+                x: 10,
+                y: 20,
+                coordinate: Coordinate::Abs
+            }
+        ));
+        assert!(matches!(decode(br#"{"command": "ping"}"#), Command::Ping));
+        assert!(Request::decode(br#"{"command": "fly"}"#).is_err());
+        assert!(Request::decode(br#"{"command": "text"}"#).is_err());
+    }
+
+    #[test]
+    fn request_ids_are_optional() {
+        let json = br#"{"command": "ping", "id": {"client": "c", "seq": 7}}"#;
+        let request = Request::decode(json).unwrap();
+        assert_eq!(request.command, Command::Ping);
+        let id = RequestId {
+            client: "c".to_owned(),
+            seq: 7,
+        };
+        assert_eq!(request.id, Some(id.clone()));
+        assert_eq!(Request::decode(br#"{"command": "ping"}"#).unwrap().id, None);
+        assert_eq!(Request::decode(&[11]).unwrap().id, None);
+
+        let request = Request {
+            command: Command::Text {
+                text: "hi".to_owned(),
+            },
+            id: Some(id),
+        };
+        let json = serde_json::to_vec(&request).unwrap();
+        let decoded = Request::decode(&json).unwrap();
+        assert_eq!((decoded.command, decoded.id), (request.command, request.id));
+        let without = Request {
+            command: Command::Ping,
+            id: None,
+        };
+        let json = serde_json::to_string(&without).unwrap();
+        assert_eq!(json, r#"{"command":"ping"}"#);
+    }
+
+    #[test]
+    fn replies_encode() {
+        assert_eq!(Reply::Ok.encode(true), [0]);
+        assert_eq!(Reply::Ok.encode(false), br#"{"status":"ok"}"#);
+        let point = Reply::Point { x: -1, y: 2 };
+        assert_eq!(point.encode(true), [1, 0xff, 0xff, 0xff, 0xff, 0, 0, 0, 2]);
+        assert_eq!(point.encode(false), br#"{"status":"point","x":-1,"y":2}"#);
+        let lock_state = Reply::LockState(LockState {
+            caps_lock: true,
+            num_lock: false,
+            scroll_lock: true,
+        });
+        assert_eq!(lock_state.encode(true), [3, 1, 0, 1]);
+
+        let error = Reply::Error(RemoteError::Unauthorized).encode(true);
+        assert_eq!(error[0], 2);
+        let error: RemoteError = serde_json::from_slice(&error[1..]).unwrap();
+        assert!(matches!(error, RemoteError::Unauthorized));
+        let json = Reply::Error(RemoteError::Unauthorized).encode(false);
+        assert_eq!(json, br#"{"status":"error","error":"unauthorized"}"#);
+    }
+
+    #[test]
+    fn frames_round_trip() {
+        let mut buffer = Vec::new();
+        write_frame(&mut buffer, b"hello").unwrap();
+        write_frame(&mut buffer, b"").unwrap();
+        assert_eq!(&buffer[..4], [0, 0, 0, 5]);
+        let mut stream = Cursor::new(buffer);
+        assert_eq!(read_frame(&mut stream).unwrap(), b"hello");
+        assert_eq!(read_frame(&mut stream).unwrap(), b"");
+        let end = read_frame(&mut stream).unwrap_err();
+        assert_eq!(end.kind(), io::ErrorKind::UnexpectedEof);
+    }
+
+    #[test]
+    fn oversized_frames_are_refused() {
+        let too_large = (MAX_FRAME_LEN + 1).to_be_bytes();
+        let error = read_frame(&mut Cursor::new(too_large)).unwrap_err();
+        assert_eq!(error.kind(), io::ErrorKind::InvalidData);
+        let frame = vec![0; MAX_FRAME_LEN as usize + 1];
+        let error = write_frame(&mut Vec::new(), &frame).unwrap_err();
+        assert_eq!(error.kind(), io::ErrorKind::InvalidInput);
+    }
+}
@@ -0,0 +1,440 @@
+use enigo::{Axis, Coordinate, Direction, Key, Settings};
+use flutter_rust_bridge::*;
+use serde::{Deserialize, Serialize};
+use std::path::Path;
+use std::sync::{Arc, Mutex, MutexGuard, PoisonError};
+use std::time::Instant;
+
+use super::enigo::_Button;
+use super::listener::{self, InputEvent, ListenerHandle};
+use super::mock::MockAction;
+
+/// Bumped whenever the file layout changes incompatibly.
+const FORMAT_VERSION: u32 = 1;
+
+/// Moves less than this after the first of a run are merged into it, so a
+/// steady stream of moves still gets an event per window.
+const MOVE_COALESCE_MS: u64 = 16;
+
+/// A replayable input action, stamped with its offset from the start of the
+/// recording.
+#[frb(ignore)]
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub(crate) struct RecordedEvent {
+    pub offset_ms: u64,
+    pub action: Action,
+}
+
+#[frb(ignore)]
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub(crate) enum Action {
+    /// Replayed through `raw` when the keycode is known, so the result does
+    /// not depend on the keyboard layout.
+    Key {
+        key: Key,
+        raw: u16,
+        direction: Direction,
+    },
+    Button {
+        button: enigo::Button,
+        direction: Direction,
+    },
+    MoveMouse {
+        x: i32,
+        y: i32,
+        coordinate: Coordinate,
+    },
+    Scroll {
+        length: i32,
+        axis: Axis,
+    },
+}
+
+impl From<InputEvent> for Action {
+    fn from(event: InputEvent) -> Self {
+        match event {
+            InputEvent::Key {
+                key,
+                raw,
+                direction,
+            } => Action::Key {
+                key,
+                raw,
+                direction,
+            },
+            InputEvent::Button { button, direction } => Action::Button {
+                button: button.value,
+                direction,
+            },
+            InputEvent::MouseMove { x, y, coordinate } => Action::MoveMouse { x, y, coordinate },
+            InputEvent::Scroll { length, axis } => Action::Scroll { length, axis },
+        }
+    }
+}
+
+/// The call [`Enigo::play`] makes for an action.
+///
+/// [`Enigo::play`]: super::enigo::Enigo::play
+impl From<&Action> for MockAction {
+    fn from(action: &Action) -> Self {
+        match *action {
+            Action::Key { raw, direction, .. } if raw != 0 => MockAction::Raw {
+                keycode: raw,
+                direction,
+            },
+            Action::Key { key, direction, .. } => MockAction::Key { key, direction },
+            Action::Button { button, direction } => MockAction::Button {
+                button: _Button { value: button },
+                direction,
+            },
+            Action::MoveMouse { x, y, coordinate } => MockAction::MoveMouse { x, y, coordinate },
+            Action::Scroll { length, axis } => MockAction::Scroll { length, axis },
+        }
+    }
+}
+
+#[derive(Serialize, Deserialize)]
+struct RecordingFile {
+    version: u32,
+    events: Vec<RecordedEvent>,
+}
+
+/// Recorded input that [`Enigo::play`] can replay.
+///
+/// [`Enigo::play`]: super::enigo::Enigo::play
+#[frb(opaque)]
+#[derive(Clone, Default)]
+pub struct Recording {
+    pub(crate) events: Vec<RecordedEvent>,
+}
+
+impl Recording {
+    /// Load a recording saved by [`Recording::save`]; `.ron` files are read
+    /// as RON, anything else as JSON.
+    #[frb(sync)]
+    pub fn load(path: String) -> anyhow::Result<Self> {
+        let contents = std::fs::read_to_string(&path)?;
+        if is_ron(&path) {
+            Self::from_file(ron::from_str(&contents)?)
+        } else {
+            Self::from_json(&contents)
+        }
+    }
+
+    #[frb(sync)]
+    pub fn save(&self, path: String) -> anyhow::Result<()> {
+        let contents = if is_ron(&path) {
+            ron::ser::to_string_pretty(&self.to_file(), Default::default())?
+        } else {
+            serde_json::to_string_pretty(&self.to_file())?
+        };
+        std::fs::write(path, contents)?;
+        Ok(())
+    }
+
+    #[frb(sync)]
+    pub fn from_json(json: &str) -> anyhow::Result<Self> {
+        Self::from_file(serde_json::from_str(json)?)
+    }
+
+    #[frb(sync)]
+    pub fn to_json(&self) -> anyhow::Result<String> {
+        Ok(serde_json::to_string(&self.to_file())?)
+    }
+
+    #[frb(sync, getter)]
+    pub fn len(&self) -> usize {
+        self.events.len()
+    }
+
+    #[frb(sync, getter)]
+    pub fn is_empty(&self) -> bool {
+        self.events.is_empty()
+    }
+
+    #[frb(sync, getter)]
+    pub fn duration_ms(&self) -> u64 {
+        self.events.last().map_or(0, |event| event.offset_ms)
+    }
+
+    fn from_file(file: RecordingFile) -> anyhow::Result<Self> {
+        if file.version > FORMAT_VERSION {
+            anyhow::bail!(
+                "recording format version {} is newer than the supported version {FORMAT_VERSION}",
+                file.version
+            );
+        }
+        Ok(Self {
+            events: file.events,
+        })
+    }
+
+    fn to_file(&self) -> RecordingFile {
+        RecordingFile {
+            version: FORMAT_VERSION,
+            events: self.events.clone(),
+        }
+    }
+
+    fn push(&mut self, offset_ms: u64, action: Action) {
+        if let (
+            Action::MoveMouse { x, y, coordinate },
+            Some(RecordedEvent {
+                offset_ms: last_offset,
+                action:
+                    Action::MoveMouse {
+                        x: last_x,
+                        y: last_y,
+                        coordinate: last_coordinate,
+                    },
+            }),
+        ) = (&action, self.events.last_mut())
+        {
+            if coordinate == last_coordinate {
+                if *coordinate == Coordinate::Abs && (*x, *y) == (*last_x, *last_y) {
+                    return;
+                }
+                if offset_ms - *last_offset < MOVE_COALESCE_MS {
+                    match coordinate {
+                        Coordinate::Abs => (*last_x, *last_y) = (*x, *y),
+                        Coordinate::Rel => (*last_x, *last_y) = (*last_x + x, *last_y + y),
+                    }
+                    return;
+                }
+            }
+        }
+        self.events.push(RecordedEvent { offset_ms, action });
+    }
+}
+
+fn is_ron(path: &str) -> bool {
+    Path::new(path)
+        .extension()
+        .is_some_and(|extension| extension.eq_ignore_ascii_case("ron"))
+}
+
+/// How [`Enigo::play`] replays a [`Recording`].
+///
+/// [`Enigo::play`]: super::enigo::Enigo::play
+#[derive(Debug, Clone, Copy)]
+pub struct PlaybackOptions {
+    /// 2.0 plays twice as fast, 0.5 at half speed.
+    pub speed: f64,
+    /// How many times to play the recording; 0 is treated as 1.
+    pub loops: u32,
+    /// Shorten any pause between two events to at most this long.
+    pub max_gap_ms: Option<u32>,
+}
+
+impl Default for PlaybackOptions {
+    fn default() -> Self {
+        Self {
+            speed: 1.0,
+            loops: 1,
+            max_gap_ms: None,
+        }
+    }
+}
+
+impl PlaybackOptions {
+    #[frb(sync)]
+    pub fn preset() -> Self {
+        Self::default()
+    }
+}
+
+/// Records global input into a [`Recording`].
+#[frb(opaque)]
+pub struct Recorder {
+    display: Option<String>,
+    recording: Arc<Mutex<Recording>>,
+    handle: Mutex<Option<ListenerHandle>>,
+}
+
+impl Recorder {
+    #[frb(sync)]
+    pub fn new(settings: &Settings) -> Self {
+        Self {
+            display: settings.x11_display.clone(),
+            recording: Arc::default(),
+            handle: Mutex::new(None),
+        }
+    }
+
+    /// Start a new recording, discarding anything recorded so far.
+    #[frb(sync)]
+    pub fn start(&self) -> anyhow::Result<()> {
+        let mut handle = self.handle.lock().unwrap_or_else(PoisonError::into_inner);
+        handle.take();
+        *lock(&self.recording) = Recording::default();
+
+        let recording = self.recording.clone();
+        let start = Instant::now();
+        *handle = Some(listener::spawn(
+            self.display.as_deref(),
+            Box::new(move |event| {
+                let offset_ms = start.elapsed().as_millis() as u64;
+                lock(&recording).push(offset_ms, event.into());
+                true
+            }),
+        )?);
+        Ok(())
+    }
+
+    /// Stop recording and return what was captured.
+    #[frb(sync)]
+    pub fn stop(&self) -> Recording {
+        self.handle
+            .lock()
+            .unwrap_or_else(PoisonError::into_inner)
+            .take();
+        lock(&self.recording).clone()
+    }
+
+    #[frb(sync, getter)]
+    pub fn is_recording(&self) -> bool {
+        self.handle
+            .lock()
+            .unwrap_or_else(PoisonError::into_inner)
+            .as_ref()
+            .is_some_and(ListenerHandle::is_running)
+    }
+}
+
+fn lock(recording: &Mutex<Recording>) -> MutexGuard<'_, Recording> {
+    recording.lock().unwrap_or_else(PoisonError::into_inner)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn move_to(x: i32, y: i32) -> Action {
+        Action::MoveMouse {
+            x,
+            y,
+            coordinate: Coordinate::Abs,
+        }
+    }
+
+    fn move_by(x: i32, y: i32) -> Action {
+        Action::MoveMouse {
+            x,
+            y,
+            coordinate: Coordinate::Rel,
+        }
+    }
+
+    fn event(offset_ms: u64, action: Action) -> RecordedEvent {
+        RecordedEvent { offset_ms, action }
+    }
+
+    #[test]
+    fn push_merges_moves_within_the_window() {
+        let mut recording = Recording::default();
+        recording.push(0, move_to(1, 1));
+        recording.push(5, move_to(2, 2));
+        recording.push(10, move_to(3, 3));
+        assert_eq!(recording.events, [event(0, move_to(3, 3))]);
+    }
+
+    #[test]
+    fn push_window_does_not_slide() {
+        let mut recording = Recording::default();
+        for offset_ms in (0..=40).step_by(8) {
+            recording.push(offset_ms, move_by(1, 0));
+        }
+        assert_eq!(
+            recording.events,
+            [
+                event(0, move_by(2, 0)),
+                event(16, move_by(2, 0)),
+                event(32, move_by(2, 0)),
+            ]
+        );
+    }
+
+    #[test]
+    fn push_drops_absolute_moves_to_the_same_spot() {
+        let mut recording = Recording::default();
+        recording.push(0, move_to(1, 1));
+        recording.push(100, move_to(1, 1));
+        assert_eq!(recording.events, [event(0, move_to(1, 1))]);
+    }
+
+    #[test]
+    fn push_keeps_moves_of_different_kinds_apart() {
+        let mut recording = Recording::default();
+        recording.push(0, move_to(1, 1));
+        recording.push(1, move_by(1, 1));
+        let click = Action::Button {
+            button: enigo::Button::Left,
+            direction: Direction::Click,
+        };
+        recording.push(2, click.clone());
+        recording.push(3, move_by(1, 1));
+        assert_eq!(
+            recording.events,
+            [
+                event(0, move_to(1, 1)),
+                event(1, move_by(1, 1)),
+                event(2, click),
+                event(3, move_by(1, 1)),
+            ]
+        );
+    }
+
+    fn sample() -> Recording {
+        Recording {
+            events: vec![
+                event(
+                    0,
+                    Action::Key {
+                        key: Key::Unicode('a'),
+                        raw: 38,
+                        direction: Direction::Press,
+                    },
+                ),
+                event(20, move_to(10, 20)),
+                event(
+                    40,
+                    Action::Scroll {
+                        length: -2,
+                        axis: Axis::Vertical,
+                    },
+                ),
+            ],
+        }
+    }
+
+    #[test]
+    fn json_round_trip() {
+        let recording = sample();
+        let json = recording.to_json().unwrap();
+        assert_eq!(
+            Recording::from_json(&json).unwrap().events,
+            recording.events
+        );
+    }
+
+    #[test]
+    fn file_round_trip_in_both_formats() {
+        let recording = sample();
+        let dir = std::env::temp_dir();
+        for name in ["recorder-round-trip.json", "recorder-round-trip.ron"] {
+            let path = dir.join(format!("{}-{name}", std::process::id()));
+            let path = path.to_str().unwrap().to_owned();
+            recording.save(path.clone()).unwrap();
+            let loaded = Recording::load(path.clone());
+            std::fs::remove_file(&path).unwrap();
+            assert_eq!(loaded.unwrap().events, recording.events, "{name}");
+        }
+    }
+
+    #[test]
+    fn newer_versions_are_rejected() {
+        let json = format!(r#"{{"version":{},"events":[]}}"#, FORMAT_VERSION + 1);
+        let error = Recording::from_json(&json).err().unwrap();
+        assert!(error.to_string().contains("newer"), "{error}");
+    }
+}
@@ -0,0 +1,717 @@
+use enigo::Settings;
+use flutter_rust_bridge::*;
+use serde::Deserialize;
+use std::collections::HashMap;
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::{Arc, Mutex, MutexGuard, PoisonError};
+
+#[cfg(all(target_os = "linux", feature = "uinput"))]
+use super::hotkey;
+
+const DEFAULT_TAPPING_TERM_MS: u32 = 200;
+
+#[cfg(not(all(target_os = "linux", feature = "uinput")))]
+const UNSUPPORTED: &str = "key remapping needs Linux and the `uinput` cargo feature";
+
+/// Declarative remapping rules, usually loaded from JSON:
+///
+/// ```json
+/// {
+///   "rules": [
+///     { "from": "capslock", "to": "escape" },
+///     { "from": "space", "tap": "space", "hold": "controlleft" }
+///   ],
+///   "layers": [
+///     { "application": "firefox", "rules": [{ "from": "f1", "to": "f5" }] }
+///   ]
+/// }
+/// ```
+///
+/// Key names are the ones hotkey accelerators use.
+#[frb(ignore)]
+#[derive(Debug, Clone, Default, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub(crate) struct RemapConfig {
+    #[serde(default)]
+    rules: Vec<RuleConfig>,
+    #[serde(default)]
+    layers: Vec<LayerConfig>,
+}
+
+/// Rules that apply on top of the base rules while an application whose
+/// `WM_CLASS` matches `application` has focus.
+#[frb(ignore)]
+#[derive(Debug, Clone, Deserialize)]
+#[serde(deny_unknown_fields)]
+struct LayerConfig {
+    application: String,
+    rules: Vec<RuleConfig>,
+}
+
+#[frb(ignore)]
+#[derive(Debug, Clone, Deserialize)]
+#[serde(untagged, deny_unknown_fields)]
+enum RuleConfig {
+    Remap {
+        from: String,
+        to: String,
+    },
+    /// Acts as `tap` when released within the tapping term with no other key
+    /// pressed in between, and as `hold` otherwise.
+    DualRole {
+        from: String,
+        tap: String,
+        hold: String,
+        #[serde(default)]
+        tapping_term_ms: Option<u32>,
+    },
+}
+
+/// A rule with its keys resolved to evdev keycodes.
+#[frb(ignore)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(crate) enum Rule {
+    Remap(u16),
+    DualRole { tap: u16, hold: u16, term_ms: u32 },
+}
+
+#[frb(ignore)]
+#[derive(Debug, Clone, Default)]
+// Only read by the evdev engine.
+#[cfg_attr(not(all(target_os = "linux", feature = "uinput")), allow(dead_code))]
+pub(crate) struct Rules {
+    pub base: HashMap<u16, Rule>,
+    /// Keyed by lowercase application name.
+    pub layers: HashMap<String, HashMap<u16, Rule>>,
+}
+
+impl Rules {
+    fn compile(config: &RemapConfig) -> anyhow::Result<Self> {
+        let mut layers = HashMap::new();
+        for layer in &config.layers {
+            layers.insert(
+                layer.application.to_lowercase(),
+                compile_rules(&layer.rules)?,
+            );
+        }
+        Ok(Self {
+            base: compile_rules(&config.rules)?,
+            layers,
+        })
+    }
+
+    /// The rule for `code` while `application` has focus.
+    #[cfg_attr(not(all(target_os = "linux", feature = "uinput")), allow(dead_code))]
+    pub fn lookup(&self, application: Option<&str>, code: u16) -> Option<Rule> {
+        application
+            .and_then(|application| self.layers.get(application))
+            .and_then(|layer| layer.get(&code))
+            .or_else(|| self.base.get(&code))
+            .copied()
+    }
+}
+
+fn compile_rules(rules: &[RuleConfig]) -> anyhow::Result<HashMap<u16, Rule>> {
+    let mut compiled = HashMap::new();
+    for rule in rules {
+        let (from, rule) = match rule {
+            RuleConfig::Remap { from, to } => (from, Rule::Remap(keycode(to)?)),
+            RuleConfig::DualRole {
+                from,
+                tap,
+                hold,
+                tapping_term_ms,
+            } => (
+                from,
+                Rule::DualRole {
+                    tap: keycode(tap)?,
+                    hold: keycode(hold)?,
+                    term_ms: tapping_term_ms.unwrap_or(DEFAULT_TAPPING_TERM_MS),
+                },
+            ),
+        };
+        if compiled.insert(keycode(from)?, rule).is_some() {
+            anyhow::bail!("`{from}` is remapped more than once");
+        }
+    }
+    Ok(compiled)
+}
+
+/// Evdev keycode for a key name.
+#[cfg(all(target_os = "linux", feature = "uinput"))]
+fn keycode(name: &str) -> anyhow::Result<u16> {
+    hotkey::usage(&name.trim().to_ascii_lowercase())
+        .and_then(hotkey::raw_keycode)
+        .map(|keycode| keycode - imp::X11_KEYCODE_OFFSET)
+        .ok_or_else(|| anyhow::anyhow!("`{name}` is not a known key"))
+}
+
+/// Rules are compiled to evdev keycodes, which only exist on Linux.
+#[cfg(not(all(target_os = "linux", feature = "uinput")))]
+fn keycode(_name: &str) -> anyhow::Result<u16> {
+    anyhow::bail!("{UNSUPPORTED}")
+}
+
+/// State shared with the remapping thread.
+#[frb(ignore)]
+#[derive(Default)]
+pub(crate) struct Shared {
+    pub enabled: AtomicBool,
+    rules: Mutex<Rules>,
+}
+
+impl Shared {
+    pub fn rules(&self) -> MutexGuard<'_, Rules> {
+        self.rules.lock().unwrap_or_else(PoisonError::into_inner)
+    }
+}
+
+/// Intercepts physical keyboard input and re-emits it with remapping rules
+/// applied.
+///
+/// Only available on Linux with the `uinput` cargo feature: the keyboards
+/// are grabbed through evdev and events re-emitted through a uinput device,
+/// which needs read access to `/dev/input` and write access to
+/// `/dev/uinput`.
+#[frb(opaque)]
+pub struct Remapper {
+    display: Option<String>,
+    shared: Arc<Shared>,
+    running: Mutex<Option<imp::Running>>,
+}
+
+impl Remapper {
+    #[frb(sync)]
+    pub fn from_json(config: &str, settings: &Settings) -> anyhow::Result<Self> {
+        let config: RemapConfig = serde_json::from_str(config)?;
+        let shared = Shared {
+            enabled: AtomicBool::new(true),
+            rules: Mutex::new(Rules::compile(&config)?),
+        };
+        Ok(Self {
+            display: settings.x11_display.clone(),
+            shared: Arc::new(shared),
+            running: Mutex::new(None),
+        })
+    }
+
+    /// Replace the rules; takes effect immediately if the remapper runs.
+    #[frb(sync)]
+    pub fn load_json(&self, config: &str) -> anyhow::Result<()> {
+        let config: RemapConfig = serde_json::from_str(config)?;
+        *self.shared.rules() = Rules::compile(&config)?;
+        Ok(())
+    }
+
+    /// Grab the keyboards and start remapping.
+    #[frb(sync)]
+    pub fn start(&self) -> anyhow::Result<()> {
+        let mut running = self.lock();
+        if running.is_none() {
+            *running = Some(imp::start(self.display.clone(), self.shared.clone())?);
+        }
+        Ok(())
+    }
+
+    /// Release the keyboards.
+    #[frb(sync)]
+    pub fn stop(&self) {
+        self.lock().take();
+    }
+
+    /// While disabled the keyboards stay grabbed but events pass through
+    /// unchanged.
+    #[frb(sync)]
+    pub fn set_enabled(&self, enabled: bool) {
+        self.shared.enabled.store(enabled, Ordering::Relaxed);
+    }
+
+    #[frb(sync, getter)]
+    pub fn enabled(&self) -> bool {
+        self.shared.enabled.load(Ordering::Relaxed)
+    }
+
+    #[frb(sync, getter)]
+    pub fn is_running(&self) -> bool {
+        self.lock().as_ref().is_some_and(imp::Running::is_running)
+    }
+
+    fn lock(&self) -> MutexGuard<'_, Option<imp::Running>> {
+        self.running.lock().unwrap_or_else(PoisonError::into_inner)
+    }
+}
+
+#[cfg(all(target_os = "linux", feature = "uinput"))]
+#[frb(ignore)]
+mod imp {
+    use super::{Rule, Shared};
+    use crate::api::listener::imp::evdev::open_devices;
+    use evdev::uinput::VirtualDevice;
+    use evdev::{AttributeSet, Device, EventSummary, EventType, InputEvent, KeyCode};
+    use std::sync::atomic::{AtomicBool, Ordering};
+    use std::sync::Arc;
+    use std::thread::JoinHandle;
+    use std::time::{Duration, Instant};
+
+    pub use crate::api::listener::imp::evdev::X11_KEYCODE_OFFSET;
+
+    const RELEASE: i32 = 0;
+    const PRESS: i32 = 1;
+
+    /// How often the focused application is looked up for layers.
+    const FOCUS_POLL: Duration = Duration::from_millis(250);
+
+    pub struct Running {
+        running: Arc<AtomicBool>,
+        thread: Option<JoinHandle<()>>,
+    }
+
+    impl Running {
+        pub fn is_running(&self) -> bool {
+            self.thread
+                .as_ref()
+                .is_some_and(|thread| !thread.is_finished())
+        }
+    }
+
+    impl Drop for Running {
+        fn drop(&mut self) {
+            self.running.store(false, Ordering::Relaxed);
+            if let Some(thread) = self.thread.take() {
+                let _ = thread.join();
+            }
+        }
+    }
+
+    pub fn start(display: Option<String>, shared: Arc<Shared>) -> anyhow::Result<Running> {
+        let mut keyboards: Vec<Device> = open_devices()?
+            .into_iter()
+            .filter(|device| {
+                device
+                    .supported_keys()
+                    .is_some_and(|keys| keys.contains(KeyCode::KEY_A))
+            })
+            .collect();
+        if keyboards.is_empty() {
+            anyhow::bail!("no keyboard found under /dev/input");
+        }
+
+        let mut keys = AttributeSet::<KeyCode>::new();
+        for keyboard in &keyboards {
+            for key in keyboard.supported_keys().into_iter().flatten() {
+                keys.insert(key);
+            }
+        }
+        let mut output = VirtualDevice::builder()?
+            .name("enigo_flutter remapper")
+            .with_keys(&keys)?
+            .build()?;
+
+        for keyboard in &mut keyboards {
+            keyboard.set_nonblocking(true)?;
+            keyboard.grab()?;
+        }
+
+        let running = Arc::new(AtomicBool::new(true));
+        let thread = {
+            let running = running.clone();
+            std::thread::spawn(move || {
+                let mut engine = Engine {
+                    shared,
+                    output: Box::new(move |code, value| {
+                        let event = InputEvent::new(EventType::KEY.0, code, value);
+                        let _ = output.emit(&[event]);
+                    }),
+                    focus: Focus::new(display),
+                    dual_role: None,
+                };
+                while running.load(Ordering::Relaxed) {
+                    for keyboard in &mut keyboards {
+                        let Ok(events) = keyboard.fetch_events() else {
+                            continue;
+                        };
+                        let events: Vec<InputEvent> = events.collect();
+                        for event in events {
+                            engine.handle(event);
+                        }
+                    }
+                    engine.tick();
+                    std::thread::sleep(Duration::from_millis(2));
+                }
+                engine.release_pending();
+                for keyboard in &mut keyboards {
+                    let _ = keyboard.ungrab();
+                }
+            })
+        };
+        Ok(Running {
+            running,
+            thread: Some(thread),
+        })
+    }
+
+    /// A dual-role key that has been pressed but not yet resolved.
+    struct DualRole {
+        from: u16,
+        tap: u16,
+        hold: u16,
+        deadline: Instant,
+        /// Set once the key has resolved to `hold` and the press was sent.
+        holding: bool,
+    }
+
+    struct Engine {
+        shared: Arc<Shared>,
+        /// Sends a key event to the virtual device.
+        output: Box<dyn FnMut(u16, i32) + Send>,
+        focus: Focus,
+        dual_role: Option<DualRole>,
+    }
+
+    impl Engine {
+        fn emit(&mut self, code: u16, value: i32) {
+            (self.output)(code, value);
+        }
+
+        fn handle(&mut self, event: InputEvent) {
+            // The virtual device adds its own sync reports.
+            let EventSummary::Key(_, code, value) = event.destructure() else {
+                return;
+            };
+            let code = code.code();
+            if !self.shared.enabled.load(Ordering::Relaxed) {
+                self.emit(code, value);
+                return;
+            }
+
+            if let Some(pending) = &mut self.dual_role {
+                if pending.from == code {
+                    if value == RELEASE {
+                        let pending = self.dual_role.take().expect("checked above");
+                        if pending.holding {
+                            self.emit(pending.hold, RELEASE);
+                        } else {
+                            self.emit(pending.tap, PRESS);
+                            self.emit(pending.tap, RELEASE);
+                        }
+                    }
+                    return;
+                }
+                // Another key while the dual-role key is down makes it a hold.
+                if value == PRESS && !pending.holding {
+                    pending.holding = true;
+                    let hold = pending.hold;
+                    self.emit(hold, PRESS);
+                }
+            }
+
+            let application = self.focus.application();
+            let rule = self.shared.rules().lookup(application.as_deref(), code);
+            match rule {
+                None => self.emit(code, value),
+                Some(Rule::Remap(to)) => self.emit(to, value),
+                Some(Rule::DualRole { tap, hold, term_ms }) => {
+                    if value == PRESS {
+                        self.dual_role = Some(DualRole {
+                            from: code,
+                            tap,
+                            hold,
+                            deadline: Instant::now() + Duration::from_millis(term_ms.into()),
+                            holding: false,
+                        });
+                    } else if value == RELEASE {
+                        // Released after the key was disabled or re-pressed.
+                        self.emit(hold, RELEASE);
+                    }
+                    // Autorepeat of a dual-role key is swallowed.
+                }
+            }
+        }
+
+        /// Resolve a dual-role key held past its tapping term as `hold`.
+        fn tick(&mut self) {
+            if let Some(pending) = &mut self.dual_role {
+                if !pending.holding && Instant::now() >= pending.deadline {
+                    pending.holding = true;
+                    let hold = pending.hold;
+                    self.emit(hold, PRESS);
+                }
+            }
+        }
+
+        fn release_pending(&mut self) {
+            if let Some(pending) = self.dual_role.take() {
+                if pending.holding {
+                    self.emit(pending.hold, RELEASE);
+                }
+            }
+        }
+    }
+
+    /// Tracks the `WM_CLASS` of the focused X11 window. Without an X server
+    /// (Wayland, TTY) only the base rules apply.
+    struct Focus {
+        conn: Option<x11rb::rust_connection::RustConnection>,
+        root: u32,
+        checked: Option<Instant>,
+        application: Option<String>,
+    }
+
+    impl Focus {
+        fn new(display: Option<String>) -> Self {
+            match x11rb::connect(display.as_deref()) {
+                Ok((conn, screen)) => {
+                    use x11rb::connection::Connection;
+                    let root = conn.setup().roots[screen].root;
+                    Self {
+                        conn: Some(conn),
+                        root,
+                        checked: None,
+                        application: None,
+                    }
+                }
+                Err(_) => Self::unavailable(),
+            }
+        }
+
+        fn unavailable() -> Self {
+            Self {
+                conn: None,
+                root: 0,
+                checked: None,
+                application: None,
+            }
+        }
+
+        fn application(&mut self) -> Option<String> {
+            if self
+                .checked
+                .is_none_or(|checked| checked.elapsed() >= FOCUS_POLL)
+            {
+                self.checked = Some(Instant::now());
+                self.application = self.query().ok().flatten();
+            }
+            self.application.clone()
+        }
+
+        fn query(&self) -> anyhow::Result<Option<String>> {
+            use x11rb::protocol::xproto::{AtomEnum, ConnectionExt as _};
+
+            let Some(conn) = &self.conn else {
+                return Ok(None);
+            };
+            let active = conn
+                .intern_atom(false, b"_NET_ACTIVE_WINDOW")?
+                .reply()?
+                .atom;
+            let window = conn
+                .get_property(false, self.root, active, AtomEnum::WINDOW, 0, 1)?
+                .reply()?
+                .value32()
+                .and_then(|mut values| values.next());
+            let Some(window) = window.filter(|window| *window != x11rb::NONE) else {
+                return Ok(None);
+            };
+            let class = conn
+                .get_property(false, window, AtomEnum::WM_CLASS, AtomEnum::STRING, 0, 256)?
+                .reply()?
+                .value;
+            // WM_CLASS is "instance\0class\0"; layers match on the class.
+            Ok(class
+                .split(|byte| *byte == 0)
+                .filter(|part| !part.is_empty())
+                .next_back()
+                .map(|class| String::from_utf8_lossy(class).to_lowercase()))
+        }
+    }
+
+    #[cfg(test)]
+    mod tests {
+        use super::super::{RemapConfig, Rules};
+        use super::*;
+        use std::sync::Mutex;
+
+        const KEY_A: u16 = KeyCode::KEY_A.0;
+        const KEY_B: u16 = KeyCode::KEY_B.0;
+        const KEY_SPACE: u16 = KeyCode::KEY_SPACE.0;
+        const KEY_LEFTCTRL: u16 = KeyCode::KEY_LEFTCTRL.0;
+        const REPEAT: i32 = 2;
+
+        /// An engine for `config` and the events it sends.
+        fn engine(config: &str) -> (Engine, Arc<Mutex<Vec<(u16, i32)>>>) {
+            let config: RemapConfig = serde_json::from_str(config).unwrap();
+            let shared = Shared {
+                enabled: AtomicBool::new(true),
+                rules: Mutex::new(Rules::compile(&config).unwrap()),
+            };
+            let sent = Arc::new(Mutex::new(Vec::new()));
+            let engine = Engine {
+                shared: Arc::new(shared),
+                output: Box::new({
+                    let sent = sent.clone();
+                    move |code, value| sent.lock().unwrap().push((code, value))
+                }),
+                focus: Focus::unavailable(),
+                dual_role: None,
+            };
+            (engine, sent)
+        }
+
+        fn key(engine: &mut Engine, code: u16, value: i32) {
+            engine.handle(InputEvent::new(EventType::KEY.0, code, value));
+        }
+
+        fn dual_role(term_ms: u32) -> String {
+            format!(
+                r#"{{"rules":[{{"from":"space","tap":"space","hold":"controlleft","tapping_term_ms":{term_ms}}}]}}"#
+            )
+        }
+
+        #[test]
+        fn remaps_keys() {
+            let (mut engine, sent) = engine(r#"{"rules":[{"from":"a","to":"b"}]}"#);
+            key(&mut engine, KEY_A, PRESS);
+            key(&mut engine, KEY_A, RELEASE);
+            assert_eq!(*sent.lock().unwrap(), [(KEY_B, PRESS), (KEY_B, RELEASE)]);
+        }
+
+        #[test]
+        fn passes_keys_through_while_disabled() {
+            let (mut engine, sent) = engine(r#"{"rules":[{"from":"a","to":"b"}]}"#);
+            engine.shared.enabled.store(false, Ordering::Relaxed);
+            key(&mut engine, KEY_A, PRESS);
+            assert_eq!(*sent.lock().unwrap(), [(KEY_A, PRESS)]);
+        }
+
+        #[test]
+        fn dual_role_taps_when_released_quickly() {
+            let (mut engine, sent) = engine(&dual_role(10_000));
+            key(&mut engine, KEY_SPACE, PRESS);
+            key(&mut engine, KEY_SPACE, REPEAT);
+            engine.tick();
+            assert!(sent.lock().unwrap().is_empty());
+            key(&mut engine, KEY_SPACE, RELEASE);
+            assert_eq!(
+                *sent.lock().unwrap(),
+                [(KEY_SPACE, PRESS), (KEY_SPACE, RELEASE)]
+            );
+        }
+
+        #[test]
+        fn dual_role_holds_past_the_tapping_term() {
+            let (mut engine, sent) = engine(&dual_role(0));
+            key(&mut engine, KEY_SPACE, PRESS);
+            engine.tick();
+            key(&mut engine, KEY_SPACE, RELEASE);
+            assert_eq!(
+                *sent.lock().unwrap(),
+                [(KEY_LEFTCTRL, PRESS), (KEY_LEFTCTRL, RELEASE)]
+            );
+        }
+
+        #[test]
+        fn dual_role_holds_when_another_key_is_pressed() {
+            let (mut engine, sent) = engine(&dual_role(10_000));
+            key(&mut engine, KEY_SPACE, PRESS);
+            key(&mut engine, KEY_A, PRESS);
+            key(&mut engine, KEY_A, RELEASE);
+            key(&mut engine, KEY_SPACE, RELEASE);
+            assert_eq!(
+                *sent.lock().unwrap(),
+                [
+                    (KEY_LEFTCTRL, PRESS),
+                    (KEY_A, PRESS),
+                    (KEY_A, RELEASE),
+                    (KEY_LEFTCTRL, RELEASE),
+                ]
+            );
+        }
+
+        #[test]
+        fn pending_hold_is_released_on_stop() {
+            let (mut engine, sent) = engine(&dual_role(0));
+            key(&mut engine, KEY_SPACE, PRESS);
+            engine.tick();
+            engine.release_pending();
+            assert_eq!(
+                *sent.lock().unwrap(),
+                [(KEY_LEFTCTRL, PRESS), (KEY_LEFTCTRL, RELEASE)]
+            );
+        }
+    }
+}
+
+#[cfg(not(all(target_os = "linux", feature = "uinput")))]
+#[frb(ignore)]
+mod imp {
+    use super::{Shared, UNSUPPORTED};
+    use std::sync::Arc;
+
+    pub struct Running;
+
+    impl Running {
+        pub fn is_running(&self) -> bool {
+            false
+        }
+    }
+
+    pub fn start(_display: Option<String>, _shared: Arc<Shared>) -> anyhow::Result<Running> {
+        anyhow::bail!("{UNSUPPORTED}")
+    }
+}
+
+#[cfg(all(test, target_os = "linux", feature = "uinput"))]
+mod tests {
+    use super::*;
+
+    fn rules(config: &str) -> anyhow::Result<Rules> {
+        Rules::compile(&serde_json::from_str(config)?)
+    }
+
+    #[test]
+    fn layers_override_the_base_rules() {
+        let rules = rules(
+            r#"{
+                "rules": [{ "from": "f1", "to": "f2" }, { "from": "a", "to": "b" }],
+                "layers": [{ "application": "Firefox", "rules": [{ "from": "f1", "to": "f5" }] }]
+            }"#,
+        )
+        .unwrap();
+        let (f1, f2, f5, a, b) = (
+            keycode("f1").unwrap(),
+            keycode("f2").unwrap(),
+            keycode("f5").unwrap(),
+            keycode("a").unwrap(),
+            keycode("b").unwrap(),
+        );
+        assert_eq!(rules.lookup(Some("firefox"), f1), Some(Rule::Remap(f5)));
+        assert_eq!(rules.lookup(Some("firefox"), a), Some(Rule::Remap(b)));
+        assert_eq!(rules.lookup(Some("xterm"), f1), Some(Rule::Remap(f2)));
+        assert_eq!(rules.lookup(None, f1), Some(Rule::Remap(f2)));
+        assert_eq!(rules.lookup(None, f5), None);
+    }
+
+    #[test]
+    fn dual_role_defaults_the_tapping_term() {
+        let rules =
+            rules(r#"{"rules":[{"from":"space","tap":"space","hold":"controlleft"}]}"#).unwrap();
+        let space = keycode("space").unwrap();
+        assert_eq!(
+            rules.lookup(None, space),
+            Some(Rule::DualRole {
+                tap: space,
+                hold: keycode("controlleft").unwrap(),
+                term_ms: DEFAULT_TAPPING_TERM_MS,
+            })
+        );
+    }
+
+    #[test]
+    fn rejects_unknown_and_duplicate_keys() {
+        assert!(rules(r#"{"rules":[{"from":"nope","to":"a"}]}"#).is_err());
+        let error = rules(r#"{"rules":[{"from":"a","to":"b"},{"from":"a","to":"c"}]}"#)
+            .err()
+            .unwrap();
+        assert!(error.to_string().contains("more than once"), "{error}");
+    }
+}
@@ -0,0 +1,881 @@
+use flutter_rust_bridge::*;
+
+use super::enigo::Enigo;
+use super::policy::Policy;
+
+#[cfg(not(feature = "server"))]
+const UNSUPPORTED: &str = "the input server needs the `server` cargo feature";
+
+/// How an [`InputServer`] listens and whom it lets in.
+#[derive(Debug, Clone)]
+pub struct ServerConfig {
+    /// `host:port` to listen on; port 0 picks a free one. The default only
+    /// accepts connections from this machine.
+    pub address: String,
+    /// Clients must send one of these before anything else. Without
+    /// tokens anyone who can connect may send input, so the server then
+    /// refuses to listen on anything but a loopback address.
+    pub tokens: Vec<ServerToken>,
+    /// For connections whose token has no policy of its own. Applies on
+    /// top of the `Enigo`'s own policy.
+    pub policy: Option<Policy>,
+    /// Connections beyond this many are closed as soon as they are
+    /// accepted.
+    pub max_connections: u32,
+}
+
+#[derive(Debug, Clone)]
+pub struct ServerToken {
+    pub token: String,
+    pub policy: Option<Policy>,
+}
+
+impl Default for ServerConfig {
+    fn default() -> Self {
+        Self {
+            address: "127.0.0.1:7878".to_owned(),
+            tokens: Vec::new(),
+            policy: None,
+            max_connections: 8,
+        }
+    }
+}
+
+impl ServerConfig {
+    #[frb(sync)]
+    pub fn preset() -> Self {
+        Self::default()
+    }
+}
+
+/// Accepts commands over TCP and WebSocket and sends them through an
+/// [`Enigo`]; see the `protocol` module for the wire format.
+///
+/// Each connection has its own policy and held input, and whatever a
+/// client still holds is released when it disconnects. Connections that
+/// don't authenticate within 10 seconds or send a wrong token are closed.
+///
+/// Needs the `server` cargo feature, which the README shows how to turn on
+/// through cargokit; without it [`InputServer::start`] fails.
+#[frb(opaque)]
+pub struct InputServer {
+    inner: imp::Server,
+}
+
+impl InputServer {
+    /// Listen on `config.address` and serve clients on threads of their
+    /// own until [`InputServer::stop`] is called or the server is dropped.
+    /// Fails without the `server` cargo feature.
+    #[frb(sync)]
+    pub fn start(enigo: &Enigo, config: ServerConfig) -> anyhow::Result<Self> {
+        Ok(Self {
+            inner: imp::Server::start(enigo, config)?,
+        })
+    }
+
+    /// The address the server listens on, with the port it got.
+    #[frb(sync, getter)]
+    pub fn address(&self) -> String {
+        self.inner.address()
+    }
+
+    #[frb(sync, getter)]
+    pub fn connections(&self) -> u32 {
+        self.inner.connections()
+    }
+
+    #[frb(sync, getter)]
+    pub fn is_running(&self) -> bool {
+        self.inner.is_running()
+    }
+
+    /// Stop accepting and close every connection, releasing what their
+    /// clients held.
+    #[frb(sync)]
+    pub fn stop(&self) {
+        self.inner.stop();
+    }
+}
+
+#[cfg(feature = "server")]
+#[frb(ignore)]
+mod imp {
+    use super::ServerConfig;
+    use sha2::{Digest, Sha256};
+    use std::collections::{HashMap, VecDeque};
+    use std::io::ErrorKind;
+    use std::net::{Ipv4Addr, Ipv6Addr, Shutdown, SocketAddr, TcpListener, TcpStream};
+    use std::sync::atomic::{AtomicBool, AtomicU64, Ordering};
+    use std::sync::{Arc, Condvar, Mutex, MutexGuard, PoisonError};
+    use std::thread::{self, JoinHandle};
+    use std::time::{Duration, Instant};
+    use tungstenite::{Message, WebSocket};
+
+    use crate::api::enigo::{Enigo, HeldInput};
+    use crate::api::metrics;
+    use crate::api::mock::MockAction;
+    use crate::api::policy::Enforcer;
+    use crate::api::protocol::{
+        self, Command, RemoteError, Reply, Request, RequestId, MAX_FRAME_LEN,
+    };
+
+    /// How long a client has to finish the handshake and send a valid token.
+    const HANDSHAKE_TIMEOUT: Duration = Duration::from_secs(10);
+
+    /// How long a wrong token is answered after, to slow down guessing.
+    const AUTH_FAILURE_DELAY: Duration = Duration::from_secs(1);
+
+    /// How many clients' last replies [`Replies`] keeps.
+    const REMEMBERED_CLIENTS: usize = 1024;
+
+    pub(super) struct Server {
+        address: SocketAddr,
+        shared: Arc<Shared>,
+        accept: Mutex<Option<JoinHandle<()>>>,
+    }
+
+    struct Shared {
+        stopped: AtomicBool,
+        next_id: AtomicU64,
+        /// A handle to every open connection, to shut them down on stop.
+        connections: Mutex<HashMap<u64, TcpStream>>,
+        replies: Mutex<Replies>,
+        /// Signalled when a command in [`Replies`] is answered or abandoned.
+        replied: Condvar,
+    }
+
+    /// A client that sends request ids: the index of the token it sent, so
+    /// clients with different tokens can't see each other's replies, and the
+    /// id it chose.
+    type ClientKey = (Option<usize>, String);
+
+    /// The last command of each client that sends request ids and its reply,
+    /// or `None` while it's being carried out, so a command resent after
+    /// losing the connection isn't carried out twice. The clients first heard
+    /// from longest ago are forgotten first.
+    #[derive(Default)]
+    struct Replies {
+        last: HashMap<ClientKey, (u64, Option<Reply>)>,
+        /// Clients in `last`, oldest first.
+        order: VecDeque<ClientKey>,
+    }
+
+    /// A command marked as being carried out in [`Replies`]. Dropped without a
+    /// reply, e.g. in a panic, it is forgotten, so a resend carries it out.
+    struct InFlight<'a> {
+        shared: &'a Shared,
+        client: ClientKey,
+        seq: u64,
+        reply: Option<Reply>,
+    }
+
+    impl Server {
+        /// Listen on `config.address` and serve clients on threads of their
+        /// own until [`InputServer::stop`] is called or the server is dropped.
+        pub fn start(enigo: &Enigo, config: ServerConfig) -> anyhow::Result<Self> {
+            let listener = TcpListener::bind(&config.address)?;
+            let address = listener.local_addr()?;
+            if config.tokens.is_empty() && !address.ip().is_loopback() {
+                anyhow::bail!("listening on {address} needs at least one token");
+            }
+            tracing::info!(%address, tokens = config.tokens.len(), "server listening");
+
+            let shared = Arc::new(Shared {
+                stopped: AtomicBool::new(false),
+                next_id: AtomicU64::new(0),
+                connections: Mutex::new(HashMap::new()),
+                replies: Mutex::default(),
+                replied: Condvar::new(),
+            });
+            let enigo = enigo.clone();
+            let config = Arc::new(config);
+            let accept_shared = Arc::clone(&shared);
+            let accept = thread::Builder::new()
+                .name("enigo-server".to_owned())
+                .spawn(move || {
+                    for stream in listener.incoming() {
+                        if accept_shared.stopped.load(Ordering::SeqCst) {
+                            break;
+                        }
+                        match stream {
+                            Ok(stream) => {
+                                accept_shared.spawn(stream, enigo.clone(), Arc::clone(&config))
+                            }
+                            Err(error) => tracing::warn!(%error, "accepting a connection failed"),
+                        }
+                    }
+                })?;
+            Ok(Self {
+                address,
+                shared,
+                accept: Mutex::new(Some(accept)),
+            })
+        }
+
+        /// The address the server listens on, with the port it got.
+        pub fn address(&self) -> String {
+            self.address.to_string()
+        }
+
+        pub fn connections(&self) -> u32 {
+            self.shared.connections().len() as u32
+        }
+
+        pub fn is_running(&self) -> bool {
+            !self.shared.stopped.load(Ordering::SeqCst)
+        }
+
+        /// Stop accepting and close every connection, releasing what their
+        /// clients held.
+        pub fn stop(&self) {
+            if self.shared.stopped.swap(true, Ordering::SeqCst) {
+                return;
+            }
+            // Wake the accept loop so it sees the flag.
+            let mut wake = self.address;
+            if wake.ip().is_unspecified() {
+                wake.set_ip(match wake {
+                    SocketAddr::V4(_) => Ipv4Addr::LOCALHOST.into(),
+                    SocketAddr::V6(_) => Ipv6Addr::LOCALHOST.into(),
+                });
+            }
+            let _ = TcpStream::connect_timeout(&wake, Duration::from_secs(1));
+            for stream in self.shared.connections().values() {
+                let _ = stream.shutdown(Shutdown::Both);
+            }
+            let accept = self
+                .accept
+                .lock()
+                .unwrap_or_else(PoisonError::into_inner)
+                .take();
+            if let Some(accept) = accept {
+                let _ = accept.join();
+            }
+            tracing::info!(address = %self.address, "server stopped");
+        }
+    }
+
+    impl Drop for Server {
+        fn drop(&mut self) {
+            self.stop();
+        }
+    }
+
+    impl Replies {
+        /// Mark `seq` as `client`'s command being carried out.
+        fn start(&mut self, client: &ClientKey, seq: u64) {
+            if let Some(last) = self.last.get_mut(client) {
+                *last = (seq, None);
+                return;
+            }
+            if self.order.len() >= REMEMBERED_CLIENTS {
+                if let Some(oldest) = self.order.pop_front() {
+                    self.last.remove(&oldest);
+                }
+            }
+            self.order.push_back(client.clone());
+            self.last.insert(client.clone(), (seq, None));
+        }
+
+        /// Record the reply to `seq`, or forget it without one, unless `client`
+        /// has sent another command since.
+        fn finish(&mut self, client: &ClientKey, seq: u64, reply: Option<Reply>) {
+            match self.last.get_mut(client) {
+                Some(last) if last.0 == seq && last.1.is_none() => match reply {
+                    Some(reply) => last.1 = Some(reply),
+                    None => {
+                        self.last.remove(client);
+                        self.order.retain(|known| known != client);
+                    }
+                },
+                _ => {}
+            }
+        }
+    }
+
+    impl Drop for InFlight<'_> {
+        fn drop(&mut self) {
+            let reply = self.reply.take();
+            self.shared.replies().finish(&self.client, self.seq, reply);
+            self.shared.replied.notify_all();
+        }
+    }
+
+    impl Shared {
+        fn replies(&self) -> MutexGuard<'_, Replies> {
+            self.replies.lock().unwrap_or_else(PoisonError::into_inner)
+        }
+
+        /// Start `seq` for `client` unless it was started before: then wait
+        /// until it is answered and return the reply, or start it again if it
+        /// was abandoned.
+        fn claim(&self, client: ClientKey, seq: u64) -> Result<InFlight<'_>, Reply> {
+            let mut replies = self.replies();
+            loop {
+                match replies.last.get(&client) {
+                    Some((last, Some(reply))) if *last == seq => return Err(reply.clone()),
+                    Some((last, None)) if *last == seq => {
+                        replies = self
+                            .replied
+                            .wait(replies)
+                            .unwrap_or_else(PoisonError::into_inner);
+                    }
+                    _ => break,
+                }
+            }
+            replies.start(&client, seq);
+            Ok(InFlight {
+                shared: self,
+                client,
+                seq,
+                reply: None,
+            })
+        }
+
+        fn connections(&self) -> MutexGuard<'_, HashMap<u64, TcpStream>> {
+            self.connections
+                .lock()
+                .unwrap_or_else(PoisonError::into_inner)
+        }
+
+        fn spawn(self: &Arc<Self>, stream: TcpStream, enigo: Enigo, config: Arc<ServerConfig>) {
+            let id = self.next_id.fetch_add(1, Ordering::Relaxed);
+            let peer = stream
+                .peer_addr()
+                .map_or_else(|_| "?".to_owned(), |peer| peer.to_string());
+            {
+                let mut connections = self.connections();
+                if connections.len() >= config.max_connections as usize {
+                    tracing::warn!(%peer, max = config.max_connections, "too many connections");
+                    let _ = stream.shutdown(Shutdown::Both);
+                    return;
+                }
+                match stream.try_clone() {
+                    Ok(handle) => connections.insert(id, handle),
+                    Err(error) => {
+                        tracing::warn!(%peer, %error, "dropping a connection");
+                        return;
+                    }
+                };
+            }
+            if self.stopped.load(Ordering::SeqCst) {
+                // Accepted while stopping, after the connections were shut down.
+                let _ = stream.shutdown(Shutdown::Both);
+            }
+            let shared = Arc::clone(self);
+            let spawned = thread::Builder::new()
+                .name("enigo-server-connection".to_owned())
+                .spawn(move || {
+                    let span = tracing::info_span!("connection", %peer);
+                    let _entered = span.enter();
+                    tracing::info!("connected");
+                    let mut session = Session::new(&enigo, &config, &shared);
+                    if let Err(error) = session.serve(stream) {
+                        tracing::warn!(%error, "connection failed");
+                    }
+                    session.release_all();
+                    shared.connections().remove(&id);
+                    tracing::info!("disconnected");
+                });
+            if let Err(error) = spawned {
+                tracing::warn!(%error, "dropping a connection");
+                self.connections().remove(&id);
+            }
+        }
+    }
+
+    /// A connection speaking either of the two transports.
+    enum Transport {
+        /// Length-prefixed frames.
+        Tcp(TcpStream),
+        WebSocket(Box<WebSocket<TcpStream>>),
+    }
+
+    impl Transport {
+        /// Tell the transports apart by the start of a WebSocket handshake,
+        /// which as a frame length would be far too large.
+        fn accept(stream: TcpStream, deadline: Instant) -> anyhow::Result<Self> {
+            stream.set_read_timeout(Some(HANDSHAKE_TIMEOUT))?;
+            let mut start = [0; 4];
+            loop {
+                let read = stream.peek(&mut start)?;
+                if read == start.len() || read == 0 {
+                    break;
+                }
+                if Instant::now() >= deadline {
+                    anyhow::bail!("the client didn't finish its first message in time");
+                }
+                thread::sleep(Duration::from_millis(1));
+            }
+            if &start == b"GET " {
+                let socket = tungstenite::accept(stream)
+                    .map_err(|error| anyhow::anyhow!("WebSocket handshake failed: {error}"))?;
+                Ok(Transport::WebSocket(Box::new(socket)))
+            } else {
+                Ok(Transport::Tcp(stream))
+            }
+        }
+
+        fn set_read_timeout(&self, timeout: Option<Duration>) -> anyhow::Result<()> {
+            let stream = match self {
+                Transport::Tcp(stream) => stream,
+                Transport::WebSocket(socket) => socket.get_ref(),
+            };
+            Ok(stream.set_read_timeout(timeout)?)
+        }
+
+        /// The next message, or `None` once the client is gone.
+        fn receive(&mut self) -> anyhow::Result<Option<Vec<u8>>> {
+            match self {
+                Transport::Tcp(stream) => match protocol::read_frame(stream) {
+                    Ok(frame) => Ok(Some(frame)),
+                    Err(error) if error.kind() == ErrorKind::UnexpectedEof => Ok(None),
+                    Err(error) => Err(error.into()),
+                },
+                Transport::WebSocket(socket) => loop {
+                    match socket.read() {
+                        Ok(Message::Text(text)) => return Ok(Some(text.into_bytes())),
+                        Ok(Message::Binary(bytes)) => return Ok(Some(bytes)),
+                        Ok(Message::Close(_)) => return Ok(None),
+                        // Pings are answered on the next write.
+                        Ok(_) => {}
+                        Err(
+                            tungstenite::Error::ConnectionClosed
+                            | tungstenite::Error::AlreadyClosed,
+                        ) => return Ok(None),
+                        Err(error) => return Err(error.into()),
+                    }
+                },
+            }
+        }
+
+        fn send(&mut self, reply: &Reply, binary: bool) -> anyhow::Result<()> {
+            let bytes = reply.encode(binary);
+            match self {
+                Transport::Tcp(stream) => protocol::write_frame(stream, &bytes)?,
+                Transport::WebSocket(socket) => {
+                    let message = if binary {
+                        Message::Binary(bytes)
+                    } else {
+                        Message::Text(String::from_utf8(bytes)?)
+                    };
+                    socket.send(message)?;
+                }
+            }
+            Ok(())
+        }
+    }
+
+    /// What the server knows about one client.
+    struct Session<'a> {
+        enigo: &'a Enigo,
+        config: &'a ServerConfig,
+        shared: &'a Shared,
+        authenticated: bool,
+        /// Which of the config's tokens the client sent.
+        token: Option<usize>,
+        /// Set after a wrong token, to close the connection once it's answered.
+        refused: bool,
+        policy: Option<Enforcer>,
+        held: HeldInput,
+    }
+
+    impl<'a> Session<'a> {
+        fn new(enigo: &'a Enigo, config: &'a ServerConfig, shared: &'a Shared) -> Self {
+            let open = config.tokens.is_empty();
+            Self {
+                enigo,
+                config,
+                shared,
+                authenticated: open,
+                token: None,
+                refused: false,
+                policy: config.policy.clone().filter(|_| open).map(Enforcer::new),
+                held: HeldInput::default(),
+            }
+        }
+
+        fn serve(&mut self, stream: TcpStream) -> anyhow::Result<()> {
+            stream.set_nodelay(true)?;
+            let deadline = Instant::now() + HANDSHAKE_TIMEOUT;
+            let mut transport = Transport::accept(stream, deadline)?;
+            let mut timed = true;
+            loop {
+                // Until the client is let in, all its reads share one deadline.
+                if !self.authenticated {
+                    let left = deadline.saturating_duration_since(Instant::now());
+                    if left.is_zero() {
+                        anyhow::bail!("the client didn't authenticate in time");
+                    }
+                    transport.set_read_timeout(Some(left))?;
+                } else if timed {
+                    transport.set_read_timeout(None)?;
+                    timed = false;
+                }
+                let message = match transport.receive() {
+                    Ok(Some(message)) => message,
+                    Ok(None) => break,
+                    Err(_) if !self.authenticated && Instant::now() >= deadline => {
+                        anyhow::bail!("the client didn't authenticate in time")
+                    }
+                    Err(error) => return Err(error),
+                };
+                let binary = message.first() != Some(&b'{');
+                let reply = if message.len() > MAX_FRAME_LEN as usize {
+                    Reply::Error(RemoteError::BadCommand {
+                        message: format!("a message of {} bytes is too large", message.len()),
+                    })
+                } else {
+                    self.handle(&message)
+                };
+                transport.send(&reply, binary)?;
+                if self.refused {
+                    break;
+                }
+            }
+            Ok(())
+        }
+
+        fn handle(&mut self, message: &[u8]) -> Reply {
+            let Request { command, id } = match Request::decode(message) {
+                Ok(request) => request,
+                Err(message) => return Reply::Error(RemoteError::BadCommand { message }),
+            };
+            if let Command::Auth { token } = &command {
+                return self.authenticate(token);
+            }
+            if !self.authenticated {
+                return Reply::Error(RemoteError::Unauthorized);
+            }
+            let Some(RequestId { client, seq }) = id else {
+                return self.run(command);
+            };
+            let mut in_flight = match self.shared.claim((self.token, client), seq) {
+                Ok(in_flight) => in_flight,
+                Err(reply) => {
+                    tracing::debug!(seq, "answering a resent command again");
+                    return reply;
+                }
+            };
+            let reply = self.run(command);
+            in_flight.reply.replace(reply.clone());
+            reply
+        }
+
+        fn run(&mut self, command: Command) -> Reply {
+            let point = match command {
+                Command::MainDisplay => self.enigo.try_main_display(),
+                Command::Location => self.enigo.try_location(),
+                Command::LockState => {
+                    return state_reply(self.enigo.lock_state().map(Reply::LockState))
+                }
+                Command::ModifierState => {
+                    return state_reply(self.enigo.modifier_state().map(Reply::ModifierState))
+                }
+                Command::Ping => return Reply::Ok,
+                command => {
+                    let action = command.action().expect("every other command is input");
+                    return self.send(action);
+                }
+            };
+            match point {
+                Ok((x, y)) => Reply::Point { x, y },
+                Err(error) => input_error(&error),
+            }
+        }
+
+        fn authenticate(&mut self, token: &str) -> Reply {
+            if self.config.tokens.is_empty() {
+                return Reply::Ok;
+            }
+            match self
+                .config
+                .tokens
+                .iter()
+                .position(|known| same_token(&known.token, token))
+            {
+                Some(index) => {
+                    let known = &self.config.tokens[index];
+                    self.authenticated = true;
+                    self.token = Some(index);
+                    let policy = known.policy.as_ref().or(self.config.policy.as_ref());
+                    self.policy = policy.cloned().map(Enforcer::new);
+                    Reply::Ok
+                }
+                None => {
+                    tracing::warn!("wrong token");
+                    thread::sleep(AUTH_FAILURE_DELAY);
+                    self.refused = true;
+                    Reply::Error(RemoteError::Unauthorized)
+                }
+            }
+        }
+
+        fn send(&mut self, action: MockAction) -> Reply {
+            if let Some(enforcer) = &mut self.policy {
+                let enigo = self.enigo;
+                if let Err(violation) =
+                    enforcer.check(&action, &self.held, || enigo.try_location().ok())
+                {
+                    tracing::warn!(%violation, "refused by the connection's policy");
+                    return Reply::Error(RemoteError::Policy { violation });
+                }
+            }
+            match self.enigo.send_action(action.clone()) {
+                Ok(Ok(())) => {
+                    self.held.track(&action);
+                    Reply::Ok
+                }
+                Ok(Err(error)) => input_error(&error),
+                Err(violation) => Reply::Error(RemoteError::Policy { violation }),
+            }
+        }
+
+        /// Release what this client still holds; releases are never refused.
+        fn release_all(&mut self) {
+            for release in std::mem::take(&mut self.held).releases() {
+                let _ = self.enigo.send_action(release);
+            }
+        }
+    }
+
+    fn input_error(error: &enigo::InputError) -> Reply {
+        Reply::Error(RemoteError::Input {
+            kind: metrics::input_error_kind(error).to_owned(),
+            message: error.to_string(),
+        })
+    }
+
+    fn state_reply(reply: anyhow::Result<Reply>) -> Reply {
+        reply.unwrap_or_else(|error| {
+            Reply::Error(RemoteError::Input {
+                kind: "state".to_owned(),
+                message: error.to_string(),
+            })
+        })
+    }
+
+    /// Compare digests rather than the tokens, so neither their contents nor
+    /// their lengths show in how long the comparison takes.
+    fn same_token(known: &str, sent: &str) -> bool {
+        let (known, sent) = (Sha256::digest(known), Sha256::digest(sent));
+        known
+            .iter()
+            .zip(sent.iter())
+            .fold(0, |difference, (a, b)| difference | (a ^ b))
+            == 0
+    }
+
+    #[cfg(test)]
+    mod tests {
+        use super::*;
+        use crate::api::mock::MockCall;
+        use crate::api::pacer::PacerConfig;
+        use crate::api::server::{InputServer, ServerToken};
+
+        fn start(config: ServerConfig) -> (Enigo, InputServer) {
+            let enigo = Enigo::mock(100, 50).unwrap();
+            let config = ServerConfig {
+                address: "127.0.0.1:0".to_owned(),
+                ..config
+            };
+            let server = InputServer::start(&enigo, config).unwrap();
+            (enigo, server)
+        }
+
+        fn connect(server: &InputServer) -> TcpStream {
+            let stream = TcpStream::connect(server.address()).unwrap();
+            stream
+                .set_read_timeout(Some(Duration::from_secs(5)))
+                .unwrap();
+            stream
+        }
+
+        fn request(stream: &mut TcpStream, json: &str) -> std::io::Result<String> {
+            protocol::write_frame(stream, json.as_bytes())?;
+            let reply = protocol::read_frame(stream)?;
+            Ok(String::from_utf8(reply).unwrap())
+        }
+
+        const OK: &str = r#"{"status":"ok"}"#;
+        const UNAUTHORIZED: &str = r#"{"status":"error","error":"unauthorized"}"#;
+
+        fn with_token() -> ServerConfig {
+            ServerConfig {
+                tokens: vec![ServerToken {
+                    token: "secret".to_owned(),
+                    policy: None,
+                }],
+                ..ServerConfig::default()
+            }
+        }
+
+        #[test]
+        fn commands_need_a_valid_token() {
+            let (enigo, server) = start(with_token());
+            let mut stream = connect(&server);
+            let key = r#"{"command":"key","key":{"Unicode":"a"},"direction":"Click"}"#;
+            assert_eq!(request(&mut stream, key).unwrap(), UNAUTHORIZED);
+            let auth = r#"{"command":"auth","token":"secret"}"#;
+            assert_eq!(request(&mut stream, auth).unwrap(), OK);
+            assert_eq!(request(&mut stream, key).unwrap(), OK);
+            let calls = enigo.mock_calls().unwrap();
+            assert!(matches!(
+                calls.as_slice(),
+                [MockCall {
+                    action: MockAction::Key { .. },
+                    ..
+                }]
+            ));
+        }
+
+        /// A key click with request id `client`/`seq`.
+        fn key(client: &str, seq: u64) -> String {
+            format!(
+                r#"{{"command":"key","key":{{"Unicode":"a"}},"direction":"Click","id":{{"client":"{client}","seq":{seq}}}}}"#
+            )
+        }
+
+        fn auth(stream: &mut TcpStream, token: &str) {
+            let auth = format!(r#"{{"command":"auth","token":"{token}"}}"#);
+            assert_eq!(request(stream, &auth).unwrap(), OK);
+        }
+
+        #[test]
+        fn resent_commands_are_answered_without_sending_again() {
+            let (enigo, server) = start(ServerConfig::default());
+            let mut first = connect(&server);
+            assert_eq!(request(&mut first, &key("c", 0)).unwrap(), OK);
+            drop(first);
+            let mut second = connect(&server);
+            assert_eq!(request(&mut second, &key("c", 0)).unwrap(), OK);
+            assert_eq!(enigo.mock_calls().unwrap().len(), 1);
+            assert_eq!(request(&mut second, &key("c", 1)).unwrap(), OK);
+            assert_eq!(enigo.mock_calls().unwrap().len(), 2);
+        }
+
+        #[test]
+        fn request_ids_are_per_token() {
+            let mut config = with_token();
+            config.tokens.push(ServerToken {
+                token: "other".to_owned(),
+                policy: None,
+            });
+            let (enigo, server) = start(config);
+            let mut first = connect(&server);
+            auth(&mut first, "secret");
+            let mut second = connect(&server);
+            auth(&mut second, "other");
+            assert_eq!(request(&mut first, &key("c", 0)).unwrap(), OK);
+            assert_eq!(request(&mut second, &key("c", 0)).unwrap(), OK);
+            assert_eq!(enigo.mock_calls().unwrap().len(), 2);
+            assert_eq!(request(&mut first, &key("c", 0)).unwrap(), OK);
+            assert_eq!(enigo.mock_calls().unwrap().len(), 2);
+        }
+
+        #[test]
+        fn resends_wait_for_the_command_in_flight() {
+            let (enigo, server) = start(ServerConfig::default());
+            // Hold every key after the first back by a tenth of a second.
+            enigo
+                .set_pacer(Some(PacerConfig {
+                    events_per_second: 10.0,
+                    burst: 1,
+                    ..PacerConfig::default()
+                }))
+                .unwrap();
+            let mut first = connect(&server);
+            let shift = r#"{"command":"key","key":"Shift","direction":"Click"}"#;
+            assert_eq!(request(&mut first, shift).unwrap(), OK);
+            protocol::write_frame(&mut first, key("c", 0).as_bytes()).unwrap();
+            thread::sleep(Duration::from_millis(20));
+            let mut second = connect(&server);
+            assert_eq!(request(&mut second, &key("c", 0)).unwrap(), OK);
+            assert_eq!(protocol::read_frame(&mut first).unwrap(), OK.as_bytes());
+            assert_eq!(enigo.mock_calls().unwrap().len(), 2);
+        }
+
+        #[test]
+        fn abandoned_commands_are_carried_out_when_resent() {
+            let shared = Shared {
+                stopped: AtomicBool::new(false),
+                next_id: AtomicU64::new(0),
+                connections: Mutex::default(),
+                replies: Mutex::default(),
+                replied: Condvar::new(),
+            };
+            let client = (None, "c".to_owned());
+            drop(shared.claim(client.clone(), 0).ok().unwrap());
+            let mut in_flight = shared.claim(client.clone(), 0).ok().unwrap();
+            in_flight.reply = Some(Reply::Ok);
+            drop(in_flight);
+            assert!(matches!(shared.claim(client.clone(), 0), Err(Reply::Ok)));
+            assert!(shared.claim(client, 1).is_ok());
+        }
+
+        #[test]
+        fn wrong_tokens_are_answered_late_and_closed() {
+            let (_enigo, server) = start(with_token());
+            let mut stream = connect(&server);
+            let start = Instant::now();
+            let auth = r#"{"command":"auth","token":"guess"}"#;
+            assert_eq!(request(&mut stream, auth).unwrap(), UNAUTHORIZED);
+            assert!(start.elapsed() >= AUTH_FAILURE_DELAY);
+            assert!(request(&mut stream, auth).is_err());
+        }
+
+        #[test]
+        fn connections_beyond_the_limit_are_closed() {
+            let (_enigo, server) = start(ServerConfig {
+                max_connections: 1,
+                ..ServerConfig::default()
+            });
+            let ping = r#"{"command":"ping"}"#;
+            let mut first = connect(&server);
+            assert_eq!(request(&mut first, ping).unwrap(), OK);
+            let mut second = connect(&server);
+            assert!(request(&mut second, ping).is_err());
+            assert_eq!(server.connections(), 1);
+
+            drop(first);
+            let deadline = Instant::now() + Duration::from_secs(5);
+            while server.connections() > 0 && Instant::now() < deadline {
+                thread::sleep(Duration::from_millis(5));
+            }
+            let mut third = connect(&server);
+            assert_eq!(request(&mut third, ping).unwrap(), OK);
+        }
+    }
+}
+
+#[cfg(not(feature = "server"))]
+#[frb(ignore)]
+mod imp {
+    use super::{ServerConfig, UNSUPPORTED};
+    use crate::api::enigo::Enigo;
+    use std::convert::Infallible;
+
+    pub struct Server(Infallible);
+
+    impl Server {
+        pub fn start(_enigo: &Enigo, _config: ServerConfig) -> anyhow::Result<Self> {
+            anyhow::bail!("{UNSUPPORTED}")
+        }
+
+        pub fn address(&self) -> String {
+            match self.0 {}
+        }
+
+        pub fn connections(&self) -> u32 {
+            match self.0 {}
+        }
+
+        pub fn is_running(&self) -> bool {
+            match self.0 {}
+        }
+
+        pub fn stop(&self) {
+            match self.0 {}
+        }
+    }
+}
@@ -0,0 +1,296 @@
+use enigo::Settings;
+use flutter_rust_bridge::*;
+use std::fmt::Display;
+use std::path::Path;
+use std::str::FromStr;
+
+use super::backend::Backend;
+use super::enigo::SettingsPatch;
+
+/// Prefix of the environment variables [`settings_from_env`] reads, followed
+/// by the field name in upper case, e.g. `ENIGO_LINUX_DELAY`.
+const ENV_PREFIX: &str = "ENIGO_";
+
+/// A longer delay per event is certainly a typo.
+const MAX_LINUX_DELAY_MS: u32 = 1000;
+
+/// Read settings from JSON. Missing fields keep their default.
+#[frb(sync)]
+pub fn settings_from_json(json: &str) -> anyhow::Result<Settings> {
+    from_patch(serde_json::from_str(json)?)
+}
+
+#[frb(sync)]
+pub fn settings_to_json(settings: &Settings) -> anyhow::Result<String> {
+    Ok(serde_json::to_string_pretty(settings)?)
+}
+
+/// Read settings from a file; `.toml` files are read as TOML, anything else
+/// as JSON. Missing fields keep their default.
+#[frb(sync)]
+pub fn load_settings(path: String) -> anyhow::Result<Settings> {
+    let contents = std::fs::read_to_string(&path)?;
+    if is_toml(&path) {
+        from_patch(toml::from_str(&contents)?)
+    } else {
+        settings_from_json(&contents)
+    }
+}
+
+#[frb(sync)]
+pub fn save_settings(settings: &Settings, path: String) -> anyhow::Result<()> {
+    let contents = if is_toml(&path) {
+        toml::to_string_pretty(settings)?
+    } else {
+        settings_to_json(settings)?
+    };
+    std::fs::write(path, contents)?;
+    Ok(())
+}
+
+/// `settings` with every `ENIGO_*` environment variable applied on top, for
+/// example `ENIGO_X11_DISPLAY=:1` or `ENIGO_LINUX_DELAY=0`.
+///
+/// Unknown `ENIGO_*` variables are logged as a warning and otherwise
+/// ignored, since other tools may use the prefix too.
+#[frb(sync)]
+pub fn settings_from_env(settings: &Settings) -> anyhow::Result<Settings> {
+    apply_vars(settings, std::env::vars())
+}
+
+fn apply_vars(
+    settings: &Settings,
+    vars: impl IntoIterator<Item = (String, String)>,
+) -> anyhow::Result<Settings> {
+    let mut patch = SettingsPatch::default();
+    for (name, value) in vars {
+        let Some(field) = name.strip_prefix(ENV_PREFIX) else {
+            continue;
+        };
+        match field {
+            "LINUX_DELAY" => patch.linux_delay = Some(parse(&name, &value)?),
+            "X11_DISPLAY" => patch.x11_display = Some(value),
+            "WAYLAND_DISPLAY" => patch.wayland_display = Some(value),
+            "WINDOWS_DW_EXTRA_INFO" => patch.windows_dw_extra_info = Some(parse(&name, &value)?),
+            "EVENT_SOURCE_USER_DATA" => patch.event_source_user_data = Some(parse(&name, &value)?),
+            "RELEASE_KEYS_WHEN_DROPPED" => {
+                patch.release_keys_when_dropped = Some(parse_bool(&name, &value)?);
+            }
+            "OPEN_PROMPT_TO_GET_PERMISSIONS" => {
+                patch.open_prompt_to_get_permissions = Some(parse_bool(&name, &value)?);
+            }
+            "INDEPENDENT_OF_KEYBOARD_STATE" => {
+                patch.independent_of_keyboard_state = Some(parse_bool(&name, &value)?);
+            }
+            "WINDOWS_SUBJECT_TO_MOUSE_SPEED_AND_ACCELERATION_LEVEL" => {
+                patch.windows_subject_to_mouse_speed_and_acceleration_level =
+                    Some(parse_bool(&name, &value)?);
+            }
+            // Not part of `Settings`; read by `Enigo::new`, but checked here.
+            "BACKEND" => {
+                parse_backends(&name, &value)?;
+            }
+            _ => tracing::warn!(name, "ignoring an unknown setting in the environment"),
+        }
+    }
+    let mut settings = settings.clone();
+    patch.apply(&mut settings);
+    validate(&settings)?;
+    Ok(settings)
+}
+
+/// The backend preference in `ENIGO_BACKEND`, e.g. `uinput,native`.
+pub(crate) fn backend_from_env() -> anyhow::Result<Option<Vec<Backend>>> {
+    let name = format!("{ENV_PREFIX}BACKEND");
+    std::env::var(&name)
+        .ok()
+        .map(|value| parse_backends(&name, &value))
+        .transpose()
+}
+
+fn parse_backends(name: &str, value: &str) -> anyhow::Result<Vec<Backend>> {
+    value
+        .split(',')
+        .map(|backend| {
+            let backend = backend.trim().to_ascii_lowercase();
+            serde_json::from_value(serde_json::Value::String(backend))
+                .map_err(|error| anyhow::anyhow!("{name}=`{value}`: {error}"))
+        })
+        .collect()
+}
+
+/// Reject settings that can't work.
+#[frb(sync)]
+pub fn validate_settings(settings: &Settings) -> anyhow::Result<()> {
+    validate(settings)
+}
+
+fn from_patch(patch: SettingsPatch) -> anyhow::Result<Settings> {
+    let mut settings = Settings::default();
+    patch.apply(&mut settings);
+    validate(&settings)?;
+    Ok(settings)
+}
+
+pub(crate) fn validate(settings: &Settings) -> anyhow::Result<()> {
+    if settings.linux_delay > MAX_LINUX_DELAY_MS {
+        anyhow::bail!(
+            "linux_delay of {} ms is longer than the maximum of {MAX_LINUX_DELAY_MS} ms",
+            settings.linux_delay
+        );
+    }
+    if let Some(display) = &settings.x11_display {
+        // `[host]:display[.screen]`
+        if !display.contains(':') || display.contains('\0') {
+            anyhow::bail!("x11_display `{display}` is not an X11 display name such as `:0`");
+        }
+    }
+    if let Some(display) = &settings.wayland_display {
+        if display.is_empty() || display.contains('\0') {
+            anyhow::bail!("wayland_display `{display}` is not a Wayland socket name");
+        }
+    }
+    Ok(())
+}
+
+fn parse<T: FromStr>(name: &str, value: &str) -> anyhow::Result<T>
+where
+    T::Err: Display,
+{
+    value
+        .trim()
+        .parse()
+        .map_err(|error| anyhow::anyhow!("{name}=`{value}`: {error}"))
+}
+
+fn parse_bool(name: &str, value: &str) -> anyhow::Result<bool> {
+    match value.trim().to_ascii_lowercase().as_str() {
+        "1" | "true" | "yes" | "on" => Ok(true),
+        "0" | "false" | "no" | "off" => Ok(false),
+        _ => anyhow::bail!("{name}=`{value}`: expected true or false"),
+    }
+}
+
+fn is_toml(path: &str) -> bool {
+    Path::new(path)
+        .extension()
+        .is_some_and(|extension| extension.eq_ignore_ascii_case("toml"))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn vars(vars: &[(&str, &str)]) -> Vec<(String, String)> {
+        vars.iter()
+            .map(|(name, value)| (name.to_string(), value.to_string()))
+            .collect()
+    }
+
+    #[test]
+    fn json_keeps_defaults_for_missing_fields() {
+        let settings = settings_from_json(r#"{"linux_delay": 5, "x11_display": ":1"}"#).unwrap();
+        assert_eq!(settings.linux_delay, 5);
+        assert_eq!(settings.x11_display.as_deref(), Some(":1"));
+        assert_eq!(
+            settings.release_keys_when_dropped,
+            Settings::default().release_keys_when_dropped
+        );
+    }
+
+    #[test]
+    fn json_rejects_unknown_fields_and_invalid_settings() {
+        assert!(settings_from_json(r#"{"linux_dealy": 5}"#).is_err());
+        assert!(settings_from_json(r#"{"linux_delay": 5000}"#).is_err());
+    }
+
+    #[test]
+    fn json_round_trip() {
+        let settings = Settings {
+            linux_delay: 3,
+            wayland_display: Some("wayland-1".to_owned()),
+            ..Settings::default()
+        };
+        let json = settings_to_json(&settings).unwrap();
+        assert_eq!(settings_from_json(&json).unwrap(), settings);
+    }
+
+    #[test]
+    fn validate_checks_each_setting() {
+        assert!(validate(&Settings::default()).is_ok());
+        let invalid = [
+            Settings {
+                linux_delay: MAX_LINUX_DELAY_MS + 1,
+                ..Settings::default()
+            },
+            Settings {
+                x11_display: Some("localhost".to_owned()),
+                ..Settings::default()
+            },
+            Settings {
+                x11_display: Some(":0\0".to_owned()),
+                ..Settings::default()
+            },
+            Settings {
+                wayland_display: Some(String::new()),
+                ..Settings::default()
+            },
+        ];
+        for settings in invalid {
+            assert!(validate(&settings).is_err(), "{settings:?}");
+        }
+        let valid = Settings {
+            linux_delay: MAX_LINUX_DELAY_MS,
+            x11_display: Some("remote:10.0".to_owned()),
+            ..Settings::default()
+        };
+        assert!(validate(&valid).is_ok());
+    }
+
+    #[test]
+    fn env_applies_known_variables() {
+        let settings = apply_vars(
+            &Settings::default(),
+            vars(&[
+                ("ENIGO_LINUX_DELAY", " 7 "),
+                ("ENIGO_X11_DISPLAY", ":2"),
+                ("ENIGO_RELEASE_KEYS_WHEN_DROPPED", "off"),
+                ("ENIGO_BACKEND", "uinput, Native"),
+                ("PATH", "/bin"),
+            ]),
+        )
+        .unwrap();
+        assert_eq!(settings.linux_delay, 7);
+        assert_eq!(settings.x11_display.as_deref(), Some(":2"));
+        assert!(!settings.release_keys_when_dropped);
+    }
+
+    #[test]
+    fn env_ignores_unknown_variables() {
+        let settings = apply_vars(&Settings::default(), vars(&[("ENIGO_LINUX_DELYA", "7")]));
+        assert_eq!(settings.unwrap(), Settings::default());
+    }
+
+    #[test]
+    fn env_rejects_bad_values() {
+        for var in [
+            ("ENIGO_LINUX_DELAY", "soon"),
+            ("ENIGO_LINUX_DELAY", "5000"),
+            ("ENIGO_INDEPENDENT_OF_KEYBOARD_STATE", "maybe"),
+            ("ENIGO_BACKEND", "x11,telepathy"),
+        ] {
+            assert!(
+                apply_vars(&Settings::default(), vars(&[var])).is_err(),
+                "{var:?}"
+            );
+        }
+    }
+
+    #[test]
+    fn backends_parse_case_insensitively() {
+        assert_eq!(
+            parse_backends("ENIGO_BACKEND", "X11, libei").unwrap(),
+            [Backend::X11, Backend::Libei]
+        );
+    }
+}
@@ -0,0 +1,343 @@
+use flutter_rust_bridge::*;
+use serde::{Deserialize, Serialize};
+
+/// Whether each lock key is currently on.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct LockState {
+    pub caps_lock: bool,
+    pub num_lock: bool,
+    pub scroll_lock: bool,
+}
+
+/// Which modifiers are physically held down, regardless of who pressed them.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct ModifierState {
+    pub shift: bool,
+    pub control: bool,
+    pub alt: bool,
+    pub meta: bool,
+}
+
+/// Keyboard auto-repeat timing, in milliseconds.
+#[frb(ignore)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(crate) struct RepeatInfo {
+    pub delay: u32,
+    pub interval: u32,
+}
+
+pub(crate) type SystemResult<T> = Result<T, Box<dyn std::error::Error>>;
+
+#[cfg(target_os = "linux")]
+#[frb(ignore)]
+pub(crate) mod imp {
+    use super::{LockState, ModifierState, RepeatInfo, SystemResult};
+    use x11rb::protocol::xkb::{ConnectionExt as _, LedClass, ID};
+    use x11rb::protocol::xproto::{AtomEnum, ConnectionExt as _, ModMask};
+    use x11rb::rust_connection::RustConnection;
+
+    fn connect(display: Option<&str>) -> SystemResult<RustConnection> {
+        let (conn, _) = x11rb::connect(display)?;
+        let reply = conn.xkb_use_extension(1, 0)?.reply()?;
+        if !reply.supported {
+            return Err("the X server does not support XKB".into());
+        }
+        Ok(conn)
+    }
+
+    fn indicator(conn: &RustConnection, name: &str) -> SystemResult<bool> {
+        let atom = conn.intern_atom(true, name.as_bytes())?.reply()?.atom;
+        if atom == x11rb::NONE {
+            return Ok(false);
+        }
+        let reply = conn
+            .xkb_get_named_indicator(
+                ID::USE_CORE_KBD.into(),
+                LedClass::DFLT_XI_CLASS,
+                ID::DFLT_XI_ID,
+                atom,
+            )?
+            .reply()?;
+        Ok(reply.found && reply.on)
+    }
+
+    pub fn lock_state(display: Option<&str>) -> SystemResult<LockState> {
+        let conn = connect(display)?;
+        Ok(LockState {
+            caps_lock: indicator(&conn, "Caps Lock")?,
+            num_lock: indicator(&conn, "Num Lock")?,
+            scroll_lock: indicator(&conn, "Scroll Lock")?,
+        })
+    }
+
+    pub fn modifier_state(display: Option<&str>) -> SystemResult<ModifierState> {
+        let conn = connect(display)?;
+        let mods = conn
+            .xkb_get_state(ID::USE_CORE_KBD.into())?
+            .reply()?
+            .base_mods;
+        Ok(ModifierState {
+            shift: mods.contains(ModMask::SHIFT),
+            control: mods.contains(ModMask::CONTROL),
+            alt: mods.contains(ModMask::M1),
+            meta: mods.contains(ModMask::M4),
+        })
+    }
+
+    pub fn repeat_info(display: Option<&str>) -> SystemResult<RepeatInfo> {
+        let conn = connect(display)?;
+        let controls = conn.xkb_get_controls(ID::USE_CORE_KBD.into())?.reply()?;
+        Ok(RepeatInfo {
+            delay: controls.repeat_delay.into(),
+            interval: controls.repeat_interval.into(),
+        })
+    }
+
+    /// X11 itself has no double-click time; desktops publish theirs as the
+    /// `Net/DoubleClickTime` XSETTINGS entry. Falls back to GTK's default.
+    pub fn double_click_time(display: Option<&str>) -> SystemResult<u32> {
+        let (conn, screen) = x11rb::connect(display)?;
+        let selection = format!("_XSETTINGS_S{screen}");
+        let selection = conn.intern_atom(false, selection.as_bytes())?.reply()?.atom;
+        let owner = conn.get_selection_owner(selection)?.reply()?.owner;
+        if owner == x11rb::NONE {
+            return Ok(400);
+        }
+        let property = conn
+            .intern_atom(false, b"_XSETTINGS_SETTINGS")?
+            .reply()?
+            .atom;
+        let reply = conn
+            .get_property(false, owner, property, AtomEnum::ANY, 0, u32::MAX / 4)?
+            .reply()?;
+        Ok(xsettings_int(&reply.value, b"Net/DoubleClickTime")
+            .map_or(400, |time| time.max(0) as u32))
+    }
+
+    /// XTest can only click the wheel buttons, so there are no partial notches.
+    pub const SCROLL_RESOLUTION: i32 = 1;
+
+    fn xsettings_int(data: &[u8], wanted: &[u8]) -> Option<i32> {
+        let big_endian = *data.first()? == 1;
+        let u16_at = |at: usize| -> Option<u16> {
+            let bytes = data.get(at..at + 2)?.try_into().ok()?;
+            Some(if big_endian {
+                u16::from_be_bytes(bytes)
+            } else {
+                u16::from_le_bytes(bytes)
+            })
+        };
+        let u32_at = |at: usize| -> Option<u32> {
+            let bytes = data.get(at..at + 4)?.try_into().ok()?;
+            Some(if big_endian {
+                u32::from_be_bytes(bytes)
+            } else {
+                u32::from_le_bytes(bytes)
+            })
+        };
+        let pad = |len: usize| (len + 3) & !3;
+
+        let count = u32_at(8)?;
+        let mut at = 12;
+        for _ in 0..count {
+            let kind = *data.get(at)?;
+            let name_len = usize::from(u16_at(at + 2)?);
+            let name = data.get(at + 4..at + 4 + name_len)?;
+            // Skip the header, the padded name and the last-change serial.
+            at += 4 + pad(name_len) + 4;
+            match kind {
+                0 if name == wanted => return Some(u32_at(at)? as i32),
+                0 => at += 4,
+                1 => at += 4 + pad(u32_at(at)? as usize),
+                2 => at += 8,
+                _ => return None,
+            }
+        }
+        None
+    }
+}
+
+#[cfg(target_os = "windows")]
+#[frb(ignore)]
+pub(crate) mod imp {
+    use super::{LockState, ModifierState, RepeatInfo, SystemResult};
+    use enigo::{Axis, InputError, InputResult};
+    use windows::Win32::UI::Input::KeyboardAndMouse::{
+        GetAsyncKeyState, GetDoubleClickTime, GetKeyState, SendInput, INPUT, INPUT_0, INPUT_MOUSE,
+        MOUSEEVENTF_HWHEEL, MOUSEEVENTF_WHEEL, MOUSEINPUT, VIRTUAL_KEY, VK_CAPITAL, VK_CONTROL,
+        VK_LWIN, VK_MENU, VK_NUMLOCK, VK_RWIN, VK_SCROLL, VK_SHIFT,
+    };
+    use windows::Win32::UI::WindowsAndMessaging::{
+        SystemParametersInfoW, SPI_GETKEYBOARDDELAY, SPI_GETKEYBOARDSPEED,
+        SYSTEM_PARAMETERS_INFO_ACTION, SYSTEM_PARAMETERS_INFO_UPDATE_FLAGS,
+    };
+
+    fn toggled(key: VIRTUAL_KEY) -> bool {
+        unsafe { GetKeyState(key.0 as i32) & 1 != 0 }
+    }
+
+    fn down(key: VIRTUAL_KEY) -> bool {
+        unsafe { GetAsyncKeyState(key.0 as i32) < 0 }
+    }
+
+    pub fn lock_state(_display: Option<&str>) -> SystemResult<LockState> {
+        Ok(LockState {
+            caps_lock: toggled(VK_CAPITAL),
+            num_lock: toggled(VK_NUMLOCK),
+            scroll_lock: toggled(VK_SCROLL),
+        })
+    }
+
+    pub fn modifier_state(_display: Option<&str>) -> SystemResult<ModifierState> {
+        Ok(ModifierState {
+            shift: down(VK_SHIFT),
+            control: down(VK_CONTROL),
+            alt: down(VK_MENU),
+            meta: down(VK_LWIN) || down(VK_RWIN),
+        })
+    }
+
+    fn parameter(action: SYSTEM_PARAMETERS_INFO_ACTION) -> SystemResult<u32> {
+        let mut value = 0u32;
+        unsafe {
+            SystemParametersInfoW(
+                action,
+                0,
+                Some(&mut value as *mut u32 as *mut _),
+                SYSTEM_PARAMETERS_INFO_UPDATE_FLAGS(0),
+            )?
+        };
+        Ok(value)
+    }
+
+    pub fn repeat_info(_display: Option<&str>) -> SystemResult<RepeatInfo> {
+        // The delay is 0..=3 in steps of 250ms, the speed 0..=31 maps linearly
+        // onto roughly 2.5..=30 repeats per second.
+        let delay = parameter(SPI_GETKEYBOARDDELAY)?;
+        let speed = parameter(SPI_GETKEYBOARDSPEED)?;
+        let rate = 2.5 + speed as f64 * 27.5 / 31.0;
+        Ok(RepeatInfo {
+            delay: (delay + 1) * 250,
+            interval: (1000.0 / rate) as u32,
+        })
+    }
+
+    pub fn double_click_time(_display: Option<&str>) -> SystemResult<u32> {
+        Ok(unsafe { GetDoubleClickTime() })
+    }
+
+    /// `WHEEL_DELTA`: the wheel accepts deltas smaller than one notch.
+    pub const SCROLL_RESOLUTION: i32 = 120;
+
+    /// A wheel event of `delta` 120ths of a notch.
+    pub fn scroll_hi_res(settings: &enigo::Settings, delta: i32, axis: Axis) -> InputResult<()> {
+        // Positive means down for enigo, but up for the vertical wheel.
+        let (flags, delta) = match axis {
+            Axis::Horizontal => (MOUSEEVENTF_HWHEEL, delta),
+            Axis::Vertical => (MOUSEEVENTF_WHEEL, -delta),
+        };
+        let input = INPUT {
+            r#type: INPUT_MOUSE,
+            Anonymous: INPUT_0 {
+                mi: MOUSEINPUT {
+                    mouseData: delta as u32,
+                    dwFlags: flags,
+                    dwExtraInfo: settings.windows_dw_extra_info.unwrap_or_default(),
+                    ..Default::default()
+                },
+            },
+        };
+        let sent = unsafe { SendInput(&[input], std::mem::size_of::<INPUT>() as i32) };
+        if sent == 1 {
+            Ok(())
+        } else {
+            Err(InputError::Simulate(
+                "SendInput did not insert the wheel event",
+            ))
+        }
+    }
+}
+
+#[cfg(target_os = "macos")]
+#[frb(ignore)]
+pub(crate) mod imp {
+    use super::{LockState, ModifierState, RepeatInfo, SystemResult};
+    use core_foundation::{
+        base::{CFType, CFTypeRef, TCFType},
+        number::CFNumber,
+        string::{CFString, CFStringRef},
+    };
+
+    const COMBINED_SESSION_STATE: i32 = 0;
+    const FLAG_ALPHA_SHIFT: u64 = 0x0001_0000;
+    const FLAG_SHIFT: u64 = 0x0002_0000;
+    const FLAG_CONTROL: u64 = 0x0004_0000;
+    const FLAG_ALTERNATE: u64 = 0x0008_0000;
+    const FLAG_COMMAND: u64 = 0x0010_0000;
+
+    #[link(name = "ApplicationServices", kind = "framework")]
+    extern "C" {
+        fn CGEventSourceFlagsState(state_id: i32) -> u64;
+    }
+
+    #[link(name = "CoreFoundation", kind = "framework")]
+    extern "C" {
+        static kCFPreferencesAnyApplication: CFStringRef;
+        fn CFPreferencesCopyAppValue(key: CFStringRef, application_id: CFStringRef) -> CFTypeRef;
+    }
+
+    fn flags() -> u64 {
+        unsafe { CGEventSourceFlagsState(COMBINED_SESSION_STATE) }
+    }
+
+    // macOS keyboards have no Num Lock or Scroll Lock.
+    pub fn lock_state(_display: Option<&str>) -> SystemResult<LockState> {
+        Ok(LockState {
+            caps_lock: flags() & FLAG_ALPHA_SHIFT != 0,
+            ..Default::default()
+        })
+    }
+
+    pub fn modifier_state(_display: Option<&str>) -> SystemResult<ModifierState> {
+        let flags = flags();
+        Ok(ModifierState {
+            shift: flags & FLAG_SHIFT != 0,
+            control: flags & FLAG_CONTROL != 0,
+            alt: flags & FLAG_ALTERNATE != 0,
+            meta: flags & FLAG_COMMAND != 0,
+        })
+    }
+
+    fn global_preference(name: &str) -> Option<CFNumber> {
+        let name = CFString::new(name);
+        let value = unsafe {
+            CFPreferencesCopyAppValue(name.as_concrete_TypeRef(), kCFPreferencesAnyApplication)
+        };
+        if value.is_null() {
+            return None;
+        }
+        unsafe { CFType::wrap_under_create_rule(value) }.downcast::<CFNumber>()
+    }
+
+    /// Reads a global preference stored in ticks of 15ms.
+    fn key_repeat_preference(name: &str, default: i64) -> u32 {
+        let ticks = global_preference(name).and_then(|number| number.to_i64());
+        (ticks.unwrap_or(default).max(1) * 15) as u32
+    }
+
+    pub fn repeat_info(_display: Option<&str>) -> SystemResult<RepeatInfo> {
+        Ok(RepeatInfo {
+            delay: key_repeat_preference("InitialKeyRepeat", 25),
+            interval: key_repeat_preference("KeyRepeat", 6),
+        })
+    }
+
+    pub fn double_click_time(_display: Option<&str>) -> SystemResult<u32> {
+        let seconds = global_preference("com.apple.mouse.doubleClickThreshold")
+            .and_then(|number| number.to_f64())
+            .unwrap_or(0.5);
+        Ok((seconds * 1000.0) as u32)
+    }
+
+    pub const SCROLL_RESOLUTION: i32 = 1;
+}